@@ -20,23 +20,24 @@ fn main() -> Result<(), Error> {
         .enable_io()
         .build()
         .unwrap();
-    rt.block_on(dump_scan(index));
+    rt.block_on(dump_scan(wl_nl80211::Nl80211IfIndex::new(index)));
 
     Ok(())
 }
 
-async fn dump_scan(if_index: u32) {
+async fn dump_scan(if_index: wl_nl80211::Nl80211IfIndex) {
     let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
     tokio::spawn(connection);
 
-    let mut scan_handle = handle.scan().dump(if_index).execute().await;
-
-    let mut msgs = Vec::new();
-    while let Some(msg) = scan_handle.try_next().await.unwrap() {
-        msgs.push(msg);
-    }
-    assert!(!msgs.is_empty());
-    for msg in msgs {
-        println!("{:?}", msg);
+    let entries: Vec<_> = handle
+        .scan()
+        .get_bss_list(if_index)
+        .execute()
+        .await
+        .try_collect()
+        .await
+        .unwrap();
+    for entry in entries {
+        println!("{:?}", wl_nl80211::Nl80211BssDescription::from(&entry));
     }
 }
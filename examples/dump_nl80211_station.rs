@@ -20,12 +20,12 @@ fn main() -> Result<(), Error> {
         .enable_io()
         .build()
         .unwrap();
-    rt.block_on(dump_station(index));
+    rt.block_on(dump_station(wl_nl80211::Nl80211IfIndex::new(index)));
 
     Ok(())
 }
 
-async fn dump_station(if_index: u32) {
+async fn dump_station(if_index: wl_nl80211::Nl80211IfIndex) {
     let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
     tokio::spawn(connection);
 
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use std::env::args;
+
+use anyhow::{bail, Context, Error};
+use futures::stream::TryStreamExt;
+
+fn main() -> Result<(), Error> {
+    let argv: Vec<_> = args().collect();
+
+    if argv.len() < 2 {
+        eprintln!("Usage: dump_nl80211_survey <interface index>");
+        bail!("Required arguments not given");
+    }
+
+    let err_msg = format!("Invalid interface index value: {}", argv[1]);
+    let index = argv[1].parse::<u32>().context(err_msg)?;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(dump_survey(wl_nl80211::Nl80211IfIndex::new(index)));
+
+    Ok(())
+}
+
+async fn dump_survey(if_index: wl_nl80211::Nl80211IfIndex) {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let results: Vec<_> = handle
+        .survey()
+        .dump(if_index)
+        .radio_stats()
+        .results()
+        .await
+        .try_collect()
+        .await
+        .unwrap();
+    for result in results {
+        println!("{:?}", result);
+    }
+}
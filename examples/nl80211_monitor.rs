@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+use wl_nl80211::{
+    Nl80211Attr, Nl80211Command, Nl80211Message, Nl80211MlmeEvent,
+    Nl80211ScanEvent, Nl80211StationEvent,
+};
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(monitor());
+}
+
+async fn monitor() {
+    let (mut connection, handle, messages) =
+        wl_nl80211::new_connection().unwrap();
+    wl_nl80211::subscribe_config(&mut connection).await.unwrap();
+    wl_nl80211::subscribe_scan(&mut connection).await.unwrap();
+    wl_nl80211::subscribe_regulatory(&mut connection)
+        .await
+        .unwrap();
+    wl_nl80211::subscribe_mlme(&mut connection).await.unwrap();
+    tokio::spawn(connection);
+
+    let mut notifications = handle.notifications(messages).await.unwrap();
+    while let Some(msg) = notifications.next().await {
+        print_event(&msg);
+    }
+}
+
+fn print_event(msg: &Nl80211Message) {
+    if let Some(event) = Nl80211ScanEvent::from_message(msg) {
+        println!("scan: {:?}", event);
+    } else if let Some(event) = Nl80211MlmeEvent::from_message(msg) {
+        println!("mlme: {:?}", event);
+    } else if let Some(event) = Nl80211StationEvent::from_message(msg) {
+        println!("station: {:?}", event);
+    } else {
+        match msg.cmd {
+            Nl80211Command::NewInterface
+            | Nl80211Command::DelInterface
+            | Nl80211Command::SetInterface => {
+                let name = msg.attributes.iter().find_map(|attr| {
+                    if let Nl80211Attr::IfName(name) = attr {
+                        Some(name.as_str())
+                    } else {
+                        None
+                    }
+                });
+                println!("interface: {:?} {:?}", msg.cmd, name);
+            }
+            Nl80211Command::RegChange
+            | Nl80211Command::WiphyRegChange
+            | Nl80211Command::RegBeaconHint => {
+                println!("regulatory: {:?}", msg.cmd);
+            }
+            _ => println!("other: {:?}", msg.cmd),
+        }
+    }
+}
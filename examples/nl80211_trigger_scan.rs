@@ -21,12 +21,12 @@ fn main() -> Result<(), Error> {
         .enable_time()
         .build()
         .unwrap();
-    rt.block_on(dump_scan(index));
+    rt.block_on(dump_scan(wl_nl80211::Nl80211IfIndex::new(index)));
 
     Ok(())
 }
 
-async fn dump_scan(if_index: u32) {
+async fn dump_scan(if_index: wl_nl80211::Nl80211IfIndex) {
     let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
     tokio::spawn(connection);
 
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use crate::Nl80211Attr;
+
+/// Beacon template and the information elements of probe and
+/// (re)association response frames of AP interface
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211BeaconData {
+    /// Portion of the beacon before the TIM element
+    pub head: Option<Vec<u8>>,
+    /// Portion of the beacon after the TIM element
+    pub tail: Option<Vec<u8>>,
+    /// Extra information elements for all the management frames
+    pub ies: Option<Vec<u8>>,
+    pub probe_resp_ies: Option<Vec<u8>>,
+    pub assoc_resp_ies: Option<Vec<u8>>,
+    /// Probe response template
+    pub probe_resp: Option<Vec<u8>>,
+}
+
+impl Nl80211BeaconData {
+    pub fn new(head: Vec<u8>, tail: Vec<u8>) -> Self {
+        Self {
+            head: Some(head),
+            tail: Some(tail),
+            ..Default::default()
+        }
+    }
+
+    pub fn ies(mut self, ies: Vec<u8>) -> Self {
+        self.ies = Some(ies);
+        self
+    }
+
+    pub fn probe_resp_ies(mut self, ies: Vec<u8>) -> Self {
+        self.probe_resp_ies = Some(ies);
+        self
+    }
+
+    pub fn assoc_resp_ies(mut self, ies: Vec<u8>) -> Self {
+        self.assoc_resp_ies = Some(ies);
+        self
+    }
+
+    pub fn probe_resp(mut self, frame: Vec<u8>) -> Self {
+        self.probe_resp = Some(frame);
+        self
+    }
+
+    pub fn attributes(&self) -> Vec<Nl80211Attr> {
+        let mut ret = Vec::new();
+        if let Some(v) = self.head.as_ref() {
+            ret.push(Nl80211Attr::BeaconHead(v.clone()));
+        }
+        if let Some(v) = self.tail.as_ref() {
+            ret.push(Nl80211Attr::BeaconTail(v.clone()));
+        }
+        if let Some(v) = self.ies.as_ref() {
+            ret.push(Nl80211Attr::Ie(v.clone()));
+        }
+        if let Some(v) = self.probe_resp_ies.as_ref() {
+            ret.push(Nl80211Attr::IeProbeResp(v.clone()));
+        }
+        if let Some(v) = self.assoc_resp_ies.as_ref() {
+            ret.push(Nl80211Attr::IeAssocResp(v.clone()));
+        }
+        if let Some(v) = self.probe_resp.as_ref() {
+            ret.push(Nl80211Attr::ProbeResp(v.clone()));
+        }
+        ret
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211ChannelSwitchRequest {
+    handle: Nl80211Handle,
+    attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211ChannelSwitchRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211ChannelSwitchRequest { handle, attributes }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ChannelSwitchRequest {
+            mut handle,
+            attributes,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::ChannelSwitch,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Request the channel switch and wait for the
+    /// `NL80211_CMD_CH_SWITCH_NOTIFY` notification of this interface
+    /// reporting the new channel, which is sent by kernel once the switch
+    /// actually happened. The `events` could be generated by
+    /// [Nl80211Handle::notifications()] with connection socket joined the
+    /// `mlme` multicast group before this request.
+    /// The `timeout` should cover the number of beacons of the count-down,
+    /// [Nl80211Error::Timeout] is returned when no matching notification
+    /// arrived in time.
+    #[cfg(all(
+        feature = "events",
+        any(feature = "tokio_socket", feature = "smol_socket")
+    ))]
+    pub async fn execute_and_wait<S>(
+        self,
+        events: S,
+        timeout: std::time::Duration,
+    ) -> Result<Nl80211Message, Nl80211Error>
+    where
+        S: futures::Stream<Item = Nl80211Message>,
+    {
+        use futures::TryStreamExt;
+
+        let deadline = std::time::Instant::now() + timeout;
+        let mut if_index = None;
+        let mut expected = Vec::new();
+        for attr in self.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => if_index = Some(*d),
+                Nl80211Attr::WiphyFreq(_)
+                | Nl80211Attr::ChannelWidth(_)
+                | Nl80211Attr::CenterFreq1(_)
+                | Nl80211Attr::CenterFreq2(_) => expected.push(attr.clone()),
+                _ => (),
+            }
+        }
+        futures::pin_mut!(events);
+
+        self.execute()
+            .await
+            .try_collect::<Vec<GenlMessage<Nl80211Message>>>()
+            .await?;
+
+        crate::runtime::wait_event(&mut events, deadline, |msg| {
+            (msg.cmd == Nl80211Command::ChSwitchNotify
+                && if_index
+                    .map(Nl80211Attr::IfIndex)
+                    .map_or(true, |attr| msg.attributes.contains(&attr))
+                && expected.iter().all(|attr| msg.attributes.contains(attr)))
+            .then(|| msg.clone())
+        })
+        .await
+        .map_err(|_| {
+            Nl80211Error::Timeout(format!(
+                "Channel switch of interface {if_index:?} did not complete \
+                within {timeout:?}"
+            ))
+        })
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211ColorEventKind {
+    /// BSS color collision detected, the colors used by overlapping BSSes
+    /// are stored in [Nl80211ColorEvent::color_bitmap]
+    ObssCollision,
+    /// BSS color change started, the number of beacons before the change
+    /// is stored in [Nl80211ColorEvent::count]
+    ChangeStarted,
+    ChangeAborted,
+    ChangeCompleted,
+}
+
+/// BSS color notification sent by kernel to the `mlme` multicast group
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ColorEvent {
+    pub kind: Nl80211ColorEventKind,
+    pub if_index: Option<Nl80211IfIndex>,
+    pub count: Option<u8>,
+    pub color_bitmap: Option<u64>,
+}
+
+impl Nl80211ColorEvent {
+    /// Return `None` if specified message is not a BSS color notification
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let kind = match msg.cmd {
+            Nl80211Command::ObssColorCollision => {
+                Nl80211ColorEventKind::ObssCollision
+            }
+            Nl80211Command::ColorChangeStarted => {
+                Nl80211ColorEventKind::ChangeStarted
+            }
+            Nl80211Command::ColorChangeAborted => {
+                Nl80211ColorEventKind::ChangeAborted
+            }
+            Nl80211Command::ColorChangeCompleted => {
+                Nl80211ColorEventKind::ChangeCompleted
+            }
+            _ => return None,
+        };
+        let mut ret = Self {
+            kind,
+            if_index: None,
+            count: None,
+            color_bitmap: None,
+        };
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::ColorChangeCount(d) => ret.count = Some(*d),
+                Nl80211Attr::ObssColorBitmap(d) => ret.color_bitmap = Some(*d),
+                _ => (),
+            }
+        }
+        Some(ret)
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::bytes::write_u32;
+
+const NL80211_FILS_DISCOVERY_ATTR_INT_MIN: u16 = 1;
+const NL80211_FILS_DISCOVERY_ATTR_INT_MAX: u16 = 2;
+const NL80211_FILS_DISCOVERY_ATTR_TMPL: u16 = 3;
+
+/// FILS discovery frame transmission configuration of AP
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211FilsDiscovery {
+    /// Minimum packet interval in TUs
+    IntervalMin(u32),
+    /// Maximum packet interval in TUs, 0 disables FILS discovery
+    IntervalMax(u32),
+    /// FILS discovery frame template (including 802.11 header)
+    Template(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211FilsDiscovery {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IntervalMin(_) | Self::IntervalMax(_) => 4,
+            Self::Template(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IntervalMin(_) => NL80211_FILS_DISCOVERY_ATTR_INT_MIN,
+            Self::IntervalMax(_) => NL80211_FILS_DISCOVERY_ATTR_INT_MAX,
+            Self::Template(_) => NL80211_FILS_DISCOVERY_ATTR_TMPL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IntervalMin(d) | Self::IntervalMax(d) => {
+                write_u32(buffer, *d)
+            }
+            Self::Template(v) => buffer[..v.len()].copy_from_slice(v),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FilsDiscovery
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_FILS_DISCOVERY_ATTR_INT_MIN => {
+                Self::IntervalMin(parse_u32(payload).context(format!(
+                    "Invalid NL80211_FILS_DISCOVERY_ATTR_INT_MIN {payload:?}"
+                ))?)
+            }
+            NL80211_FILS_DISCOVERY_ATTR_INT_MAX => {
+                Self::IntervalMax(parse_u32(payload).context(format!(
+                    "Invalid NL80211_FILS_DISCOVERY_ATTR_INT_MAX {payload:?}"
+                ))?)
+            }
+            NL80211_FILS_DISCOVERY_ATTR_TMPL => {
+                Self::Template(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT: u16 = 1;
+const NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL: u16 = 2;
+
+/// Unsolicited broadcast probe response transmission configuration of AP
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211UnsolBcastProbeResp {
+    /// Packet interval in TUs, 0 disables the transmission
+    Interval(u32),
+    /// Probe response frame template (including 802.11 header)
+    Template(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211UnsolBcastProbeResp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Interval(_) => 4,
+            Self::Template(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Interval(_) => NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT,
+            Self::Template(_) => NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Interval(d) => write_u32(buffer, *d),
+            Self::Template(v) => buffer[..v.len()].copy_from_slice(v),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211UnsolBcastProbeResp
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT => {
+                Self::Interval(parse_u32(payload).context(format!(
+                    "Invalid NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_INT \
+                    {payload:?}"
+                ))?)
+            }
+            NL80211_UNSOL_BCAST_PROBE_RESP_ATTR_TMPL => {
+                Self::Template(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+use netlink_packet_utils::nla::Nla;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use crate::{
+    Nl80211ApStartRequest, Nl80211ApStopRequest, Nl80211Attr,
+    Nl80211AttrsBuilder, Nl80211BeaconData, Nl80211ChannelDef,
+    Nl80211ChannelSwitchRequest, Nl80211ChannelWidth,
+    Nl80211ColorChangeRequest, Nl80211FilsDiscovery, Nl80211Freq,
+    Nl80211Handle, Nl80211IfIndex, Nl80211RadarDetectRequest,
+    Nl80211UnsolBcastProbeResp,
+};
+
+#[cfg(feature = "events")]
+use crate::{Nl80211ColorEvent, Nl80211Error, Nl80211RadarEvent};
+
+/// Operations of beaconing interface, for example AP and P2P-GO
+pub struct Nl80211ApHandle(Nl80211Handle);
+
+impl Nl80211ApHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211ApHandle(handle)
+    }
+
+    /// Start beaconing on the AP or P2P-GO interface
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211StartAp].
+    pub fn start(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ApStartRequest {
+        Nl80211ApStartRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Stop beaconing on specified interface
+    pub fn stop(&mut self, if_index: Nl80211IfIndex) -> Nl80211ApStopRequest {
+        Nl80211ApStopRequest::new(self.0.clone(), if_index.get())
+    }
+
+    /// Switch the operating channel announced by CSA(Channel Switch
+    /// Announcement) (equivalent to `iw dev DEVICE switch freq`).
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211ChannelSwitch].
+    pub fn channel_switch(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ChannelSwitchRequest {
+        Nl80211ChannelSwitchRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Change the HE BSS color after the number of beacons, for example on
+    /// BSS color collision reported by [Self::color_events()].
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211ColorChange].
+    pub fn color_change(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ColorChangeRequest {
+        Nl80211ColorChangeRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Start radar detection(CAC, Channel Availability Check) on specified
+    /// channel before operating on DFS channel. The result is reported by
+    /// [Self::radar_events()].
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211RadarDetect].
+    pub fn radar_detect(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211RadarDetectRequest {
+        Nl80211RadarDetectRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Stream of radar events decoded from the unsolicited message receiver
+    /// of connection, see [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn radar_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211RadarEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211RadarEvent::from_message(&msg))
+        }))
+    }
+
+    /// Stream of BSS color collision and color change events decoded from
+    /// the unsolicited message receiver of connection, see
+    /// [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn color_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211ColorEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211ColorEvent::from_message(&msg))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211StartAp;
+
+impl Nl80211StartAp {
+    /// Start AP on specified interface with beacon template, beacon
+    /// interval in TUs and DTIM period in beacon intervals
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        beacon: Nl80211BeaconData,
+        beacon_interval: u32,
+        dtim_period: u32,
+    ) -> Nl80211AttrsBuilder<Self> {
+        let mut ret = Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::BeaconInterval(beacon_interval))
+            .replace(Nl80211Attr::DtimPeriod(dtim_period));
+        for attr in beacon.attributes() {
+            ret = ret.replace(attr);
+        }
+        ret
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211StartAp> {
+    /// Userspace will handle the authentication offloaded by driver, see
+    /// [crate::Nl80211ExternalAuthEvent]. Also valid for
+    /// `NL80211_CMD_CONNECT`.
+    pub fn external_auth_support(self) -> Self {
+        self.replace(Nl80211Attr::ExternalAuthSupport)
+    }
+
+    /// Operating control frequency, channel is selected by kernel or
+    /// driver when not defined. A `u32` is taken as MHz.
+    pub fn frequency(self, frequency: impl Into<Nl80211Freq>) -> Self {
+        self.set_frequency(frequency)
+    }
+
+    /// Channel width and center frequency of the operating channel
+    pub fn channel_width(
+        self,
+        width: Nl80211ChannelWidth,
+        center_freq1: impl Into<Nl80211Freq>,
+    ) -> Self {
+        self.set_channel_width(width, center_freq1)
+    }
+
+    /// Operating channel, overriding [Self::frequency()] and
+    /// [Self::channel_width()]
+    pub fn channel_def(self, channel: Nl80211ChannelDef) -> Self {
+        self.set_channel_def(&channel)
+    }
+
+    /// Transmit FILS discovery frame built from `template` at interval
+    /// between `interval_min` and `interval_max` TUs, required for in-band
+    /// discovery of 6 GHz AP. Zero `interval_max` disables it.
+    pub fn fils_discovery(
+        self,
+        interval_min: u32,
+        interval_max: u32,
+        template: Vec<u8>,
+    ) -> Self {
+        let mut attrs = vec![
+            Nl80211FilsDiscovery::IntervalMin(interval_min),
+            Nl80211FilsDiscovery::IntervalMax(interval_max),
+        ];
+        if !template.is_empty() {
+            attrs.push(Nl80211FilsDiscovery::Template(template));
+        }
+        self.replace(Nl80211Attr::FilsDiscovery(attrs))
+    }
+
+    /// Transmit unsolicited broadcast probe response built from `template`
+    /// every `interval` TUs, alternative to [Self::fils_discovery()] for
+    /// 6 GHz AP. Zero `interval` disables it.
+    pub fn unsol_bcast_probe_resp(
+        self,
+        interval: u32,
+        template: Vec<u8>,
+    ) -> Self {
+        let mut attrs = vec![Nl80211UnsolBcastProbeResp::Interval(interval)];
+        if !template.is_empty() {
+            attrs.push(Nl80211UnsolBcastProbeResp::Template(template));
+        }
+        self.replace(Nl80211Attr::UnsolBcastProbeResp(attrs))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211ChannelSwitch;
+
+impl Nl80211ChannelSwitch {
+    /// Switch specified interface to new frequency after `count`
+    /// beacons including current one, 0 means switching immediately.
+    /// A `u32` frequency is taken as MHz.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        frequency: impl Into<Nl80211Freq>,
+        count: u32,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .set_frequency(frequency)
+            .replace(Nl80211Attr::ChSwitchCount(count))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211ChannelSwitch> {
+    /// Channel width and center frequency of the new channel
+    pub fn channel_width(
+        self,
+        width: Nl80211ChannelWidth,
+        center_freq1: impl Into<Nl80211Freq>,
+    ) -> Self {
+        self.set_channel_width(width, center_freq1)
+    }
+
+    /// Center frequency of the second segment of 80+80 MHz channel.
+    /// Only the MHz part is sent as kernel has no kHz offset attribute for
+    /// it.
+    pub fn center_freq2(self, center_freq2: impl Into<Nl80211Freq>) -> Self {
+        self.replace(Nl80211Attr::CenterFreq2(center_freq2.into()))
+    }
+
+    /// The new channel, overriding the frequency, [Self::channel_width()]
+    /// and [Self::center_freq2()]
+    pub fn channel_def(self, channel: Nl80211ChannelDef) -> Self {
+        self.set_channel_def(&channel)
+    }
+
+    /// Block transmission of the associated stations until the switch
+    /// completes, default is false.
+    pub fn block_tx(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::ChSwitchBlockTx)
+        } else {
+            self.remove(Nl80211Attr::ChSwitchBlockTx.kind())
+        }
+    }
+
+    /// Beacon used during the channel switch holding the CSA element, the
+    /// `counter_offsets` are the offsets of the countdown counter in the
+    /// beacon (head followed by tail) which will be updated by kernel.
+    pub fn csa_beacon(
+        self,
+        beacon: Nl80211BeaconData,
+        counter_offsets: Vec<u16>,
+    ) -> Self {
+        self.replace(Nl80211Attr::CsaIes(beacon.attributes()))
+            .replace(Nl80211Attr::CntdwnOffsBeacon(counter_offsets))
+    }
+
+    /// Offsets of the countdown counter in the probe response template of
+    /// [Self::csa_beacon()].
+    pub fn probe_resp_counter_offsets(self, counter_offsets: Vec<u16>) -> Self {
+        self.replace(Nl80211Attr::CntdwnOffsPresp(counter_offsets))
+    }
+
+    /// Beacon to use after the channel switch completes
+    pub fn beacon_after(self, beacon: Nl80211BeaconData) -> Self {
+        let mut ret = self;
+        for attr in beacon.attributes() {
+            ret = ret.replace(attr);
+        }
+        ret
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211ColorChange;
+
+impl Nl80211ColorChange {
+    /// Change the BSS color of specified interface to `color` after `count`
+    /// beacons. The `beacon` is used during the color change, the
+    /// `counter_offsets` are the offsets of the countdown counter in the
+    /// beacon (head followed by tail) which will be updated by kernel.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        color: u8,
+        count: u8,
+        beacon: Nl80211BeaconData,
+        counter_offsets: Vec<u16>,
+    ) -> Nl80211AttrsBuilder<Self> {
+        let mut elems = beacon.attributes();
+        elems.push(Nl80211Attr::CntdwnOffsBeacon(counter_offsets));
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::ColorChangeColor(color))
+            .replace(Nl80211Attr::ColorChangeCount(count))
+            .replace(Nl80211Attr::ColorChangeElems(elems))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211ColorChange> {
+    /// Offsets of the countdown counter in the probe response template of
+    /// the beacon used during the color change
+    pub fn probe_resp_counter_offsets(self, counter_offsets: Vec<u16>) -> Self {
+        let mut elems =
+            match self.get(Nl80211Attr::ColorChangeElems(Vec::new()).kind()) {
+                Some(Nl80211Attr::ColorChangeElems(v)) => v.clone(),
+                _ => Vec::new(),
+            };
+        elems.retain(|attr| !matches!(attr, Nl80211Attr::CntdwnOffsPresp(_)));
+        elems.push(Nl80211Attr::CntdwnOffsPresp(counter_offsets));
+        self.replace(Nl80211Attr::ColorChangeElems(elems))
+    }
+
+    /// Beacon to use after the color change completes
+    pub fn beacon_after(self, beacon: Nl80211BeaconData) -> Self {
+        let mut ret = self;
+        for attr in beacon.attributes() {
+            ret = ret.replace(attr);
+        }
+        ret
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211RadarDetect;
+
+impl Nl80211RadarDetect {
+    /// Radar detection on specified control frequency, a `u32` is taken as
+    /// MHz.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        frequency: impl Into<Nl80211Freq>,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .set_frequency(frequency)
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211RadarDetect> {
+    /// Channel width and center frequency of the channel
+    pub fn channel_width(
+        self,
+        width: Nl80211ChannelWidth,
+        center_freq1: impl Into<Nl80211Freq>,
+    ) -> Self {
+        self.set_channel_width(width, center_freq1)
+    }
+
+    /// Center frequency of the second segment of 80+80 MHz channel.
+    /// Only the MHz part is sent as kernel has no kHz offset attribute for
+    /// it.
+    pub fn center_freq2(self, center_freq2: impl Into<Nl80211Freq>) -> Self {
+        self.replace(Nl80211Attr::CenterFreq2(center_freq2.into()))
+    }
+
+    /// The channel to detect radar on, overriding the frequency,
+    /// [Self::channel_width()] and [Self::center_freq2()]
+    pub fn channel_def(self, channel: Nl80211ChannelDef) -> Self {
+        self.set_channel_def(&channel)
+    }
+
+    /// Use the dedicated background radar detection chain, which allows
+    /// CAC without interrupting the operating channel. Default is false.
+    pub fn background(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::RadarBackground)
+        } else {
+            self.remove(Nl80211Attr::RadarBackground.kind())
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+mod beacon;
+mod channel_switch;
+mod color_change;
+#[cfg(feature = "events")]
+mod color_event;
+mod discovery;
+mod handle;
+mod radar;
+#[cfg(feature = "events")]
+mod radar_event;
+mod start;
+
+pub use self::beacon::Nl80211BeaconData;
+pub use self::channel_switch::Nl80211ChannelSwitchRequest;
+pub use self::color_change::Nl80211ColorChangeRequest;
+#[cfg(feature = "events")]
+pub use self::color_event::{Nl80211ColorEvent, Nl80211ColorEventKind};
+pub use self::discovery::{Nl80211FilsDiscovery, Nl80211UnsolBcastProbeResp};
+pub use self::handle::{
+    Nl80211ApHandle, Nl80211ChannelSwitch, Nl80211ColorChange,
+    Nl80211RadarDetect, Nl80211StartAp,
+};
+pub use self::radar::{Nl80211RadarDetectRequest, Nl80211RadarEventKind};
+#[cfg(feature = "events")]
+pub use self::radar_event::Nl80211RadarEvent;
+pub use self::start::{Nl80211ApStartRequest, Nl80211ApStopRequest};
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const NL80211_RADAR_DETECTED: u32 = 0;
+const NL80211_RADAR_CAC_FINISHED: u32 = 1;
+const NL80211_RADAR_CAC_ABORTED: u32 = 2;
+const NL80211_RADAR_NOP_FINISHED: u32 = 3;
+const NL80211_RADAR_PRE_CAC_EXPIRED: u32 = 4;
+const NL80211_RADAR_CAC_STARTED: u32 = 5;
+
+/// Type of radar event for DFS operation
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211RadarEventKind {
+    /// A radar pattern has been detected
+    Detected,
+    /// Channel Availability Check has been finished, no radar detected
+    CacFinished,
+    /// Channel Availability Check has been aborted
+    CacAborted,
+    /// The Non-Occupancy Period for this channel is over, channel becomes
+    /// usable
+    NopFinished,
+    /// Channel Availability Check done on this channel has expired
+    PreCacExpired,
+    /// Channel Availability Check has been started by driver offload
+    CacStarted,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211RadarEventKind {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_RADAR_DETECTED => Self::Detected,
+            NL80211_RADAR_CAC_FINISHED => Self::CacFinished,
+            NL80211_RADAR_CAC_ABORTED => Self::CacAborted,
+            NL80211_RADAR_NOP_FINISHED => Self::NopFinished,
+            NL80211_RADAR_PRE_CAC_EXPIRED => Self::PreCacExpired,
+            NL80211_RADAR_CAC_STARTED => Self::CacStarted,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211RadarEventKind> for u32 {
+    fn from(v: Nl80211RadarEventKind) -> u32 {
+        match v {
+            Nl80211RadarEventKind::Detected => NL80211_RADAR_DETECTED,
+            Nl80211RadarEventKind::CacFinished => NL80211_RADAR_CAC_FINISHED,
+            Nl80211RadarEventKind::CacAborted => NL80211_RADAR_CAC_ABORTED,
+            Nl80211RadarEventKind::NopFinished => NL80211_RADAR_NOP_FINISHED,
+            Nl80211RadarEventKind::PreCacExpired => {
+                NL80211_RADAR_PRE_CAC_EXPIRED
+            }
+            Nl80211RadarEventKind::CacStarted => NL80211_RADAR_CAC_STARTED,
+            Nl80211RadarEventKind::Other(d) => d,
+        }
+    }
+}
+
+pub struct Nl80211RadarDetectRequest {
+    handle: Nl80211Handle,
+    attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211RadarDetectRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211RadarDetectRequest { handle, attributes }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RadarDetectRequest {
+            mut handle,
+            attributes,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::RadarDetect,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211ChannelWidth, Nl80211Command, Nl80211Freq,
+    Nl80211IfIndex, Nl80211Message, Nl80211RadarEventKind, Nl80211WdevId,
+    Nl80211WiphyIndex,
+};
+
+/// Radar detection notification sent by kernel to the `mlme` multicast
+/// group
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211RadarEvent {
+    pub kind: Nl80211RadarEventKind,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Control frequency of the channel
+    pub frequency: Option<Nl80211Freq>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    pub center_freq1: Option<Nl80211Freq>,
+    pub center_freq2: Option<Nl80211Freq>,
+    /// Event is from the dedicated background radar detection chain
+    pub background: bool,
+}
+
+impl Nl80211RadarEvent {
+    /// Return `None` if specified message is not a radar notification
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::RadarDetect {
+            return None;
+        }
+        let mut kind = None;
+        let mut ret = Self {
+            kind: Nl80211RadarEventKind::Other(u32::MAX),
+            wiphy: None,
+            if_index: None,
+            wdev: None,
+            frequency: None,
+            channel_width: None,
+            center_freq1: None,
+            center_freq2: None,
+            background: false,
+        };
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::RadarEvent(d) => kind = Some(*d),
+                Nl80211Attr::Wiphy(d) => {
+                    ret.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(Nl80211WdevId::new(*d)),
+                Nl80211Attr::WiphyFreq(d) => ret.frequency = Some(*d),
+                Nl80211Attr::ChannelWidth(d) => ret.channel_width = Some(*d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(*d),
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(*d),
+                Nl80211Attr::RadarBackground => ret.background = true,
+                _ => (),
+            }
+        }
+        ret.kind = kind?;
+        Some(ret)
+    }
+}
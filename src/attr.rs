@@ -32,26 +32,44 @@
 use anyhow::Context;
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_string, parse_u16, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_i32, parse_string, parse_u16, parse_u32, parse_u64, parse_u8,
+    },
     DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
 use crate::{
-    bytes::{write_u16, write_u32, write_u64},
+    bytes::{write_i32, write_u16, write_u32, write_u64},
+    iface::parse_iface_combinations,
     scan::{Nla80211ScanFreqNlas, Nla80211ScanSsidNlas},
-    wiphy::Nl80211Commands,
-    Nl80211Band, Nl80211BandTypes, Nl80211BssInfo, Nl80211ChannelWidth,
-    Nl80211CipherSuit, Nl80211Command, Nl80211ExtFeature, Nl80211ExtFeatures,
-    Nl80211ExtendedCapability, Nl80211Features, Nl80211HtCapabilityMask,
-    Nl80211HtWiphyChannelType, Nl80211IfMode, Nl80211IfTypeExtCapa,
-    Nl80211IfTypeExtCapas, Nl80211IfaceComb, Nl80211IfaceFrameType,
-    Nl80211InterfaceType, Nl80211InterfaceTypes, Nl80211MloLink,
-    Nl80211ScanFlags, Nl80211SchedScanMatch, Nl80211SchedScanPlan,
-    Nl80211StationInfo, Nl80211TransmitQueueStat, Nl80211VhtCapability,
-    Nl80211WowlanTrigersSupport,
+    vendor::Nl80211VendorCmdInfoNla,
+    wiphy::{Nl80211Commands, Nl80211TxqParamsNla},
+    Nl80211AkmSuite, Nl80211AuthType, Nl80211Band, Nl80211BandTypes,
+    Nl80211BssInfo, Nl80211ChannelWidth, Nl80211CipherSuite,
+    Nl80211CoalesceRule, Nl80211CoalesceSupport, Nl80211Command,
+    Nl80211CritProtocolId, Nl80211EmlCapability, Nl80211ExtFeature,
+    Nl80211ExtFeatures, Nl80211ExtendedCapability, Nl80211ExternalAuthAction,
+    Nl80211Features, Nl80211FilsDiscovery, Nl80211FrameType,
+    Nl80211FrameTypeMgmt, Nl80211Freq, Nl80211FtmResponderStats,
+    Nl80211HtCapabilityMask, Nl80211HtWiphyChannelType, Nl80211IfMode,
+    Nl80211IfTypeExtCapa, Nl80211IfTypeExtCapas, Nl80211IfaceComb,
+    Nl80211IfaceFrameType, Nl80211InterfaceType, Nl80211InterfaceTypes,
+    Nl80211KeyAttr, Nl80211KeyDefaultType, Nl80211MldCapaAndOps,
+    Nl80211MloLink, Nl80211MonitorFlag, Nl80211PeerMeasurementAttr,
+    Nl80211ProtocolFeatures, Nl80211RadarEventKind, Nl80211RekeyData,
+    Nl80211RxMgmtFlags, Nl80211SarAttr, Nl80211ScanFlags,
+    Nl80211SchedScanMatch, Nl80211SchedScanPlan, Nl80211SchedScanRssiAdjust,
+    Nl80211Ssid, Nl80211StationFlagUpdate, Nl80211StationInfo,
+    Nl80211SurveyInfo, Nl80211TidConfig, Nl80211TidConfigSupport,
+    Nl80211TransmitQueueStat, Nl80211TxPowerSetting, Nl80211TxRateMask,
+    Nl80211TxqParams, Nl80211UnsolBcastProbeResp, Nl80211VendorCmdInfo,
+    Nl80211VhtCapability, Nl80211WiphyRadio, Nl80211WowlanTrigger,
+    Nl80211WowlanTriggersSupport,
 };
 
 const ETH_ALEN: usize = 6;
+const WLAN_PMKID_LEN: usize = 16;
+const IEEE80211_TTLM_NUM_TIDS: usize = 8;
 
 struct MacAddressNlas(Vec<MacAddressNla>);
 
@@ -132,19 +150,19 @@ const NL80211_ATTR_MAC: u16 = 6;
 // const NL80211_ATTR_KEY_CIPHER:u16 = 9;
 // const NL80211_ATTR_KEY_SEQ:u16 = 10;
 // const NL80211_ATTR_KEY_DEFAULT:u16 = 11;
-// const NL80211_ATTR_BEACON_INTERVAL:u16 = 12;
-// const NL80211_ATTR_DTIM_PERIOD:u16 = 13;
-// const NL80211_ATTR_BEACON_HEAD:u16 = 14;
-// const NL80211_ATTR_BEACON_TAIL:u16 = 15;
+const NL80211_ATTR_BEACON_INTERVAL: u16 = 12;
+const NL80211_ATTR_DTIM_PERIOD: u16 = 13;
+const NL80211_ATTR_BEACON_HEAD: u16 = 14;
+const NL80211_ATTR_BEACON_TAIL: u16 = 15;
 // const NL80211_ATTR_STA_AID:u16 = 16;
 // const NL80211_ATTR_STA_FLAGS:u16 = 17;
 // const NL80211_ATTR_STA_LISTEN_INTERVAL:u16 = 18;
-// const NL80211_ATTR_STA_SUPPORTED_RATES:u16 = 19;
+const NL80211_ATTR_STA_SUPPORTED_RATES: u16 = 19;
 // const NL80211_ATTR_STA_VLAN:u16 = 20;
 const NL80211_ATTR_STA_INFO: u16 = 21;
 const NL80211_ATTR_WIPHY_BANDS: u16 = 22;
-// const NL80211_ATTR_MNTR_FLAGS:u16 = 23;
-// const NL80211_ATTR_MESH_ID:u16 = 24;
+const NL80211_ATTR_MNTR_FLAGS: u16 = 23;
+const NL80211_ATTR_MESH_ID: u16 = 24;
 // const NL80211_ATTR_STA_PLINK_ACTION:u16 = 25;
 // const NL80211_ATTR_MPATH_NEXT_HOP:u16 = 26;
 // const NL80211_ATTR_MPATH_INFO:u16 = 27;
@@ -157,12 +175,12 @@ const NL80211_ATTR_SUPPORTED_IFTYPES: u16 = 32;
 // const NL80211_ATTR_REG_RULES:u16 = 34;
 // const NL80211_ATTR_MESH_CONFIG:u16 = 35;
 // const NL80211_ATTR_BSS_BASIC_RATES:u16 = 36;
-// const NL80211_ATTR_WIPHY_TXQ_PARAMS:u16 = 37;
+const NL80211_ATTR_WIPHY_TXQ_PARAMS: u16 = 37;
 const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
 const NL80211_ATTR_WIPHY_CHANNEL_TYPE: u16 = 39;
 // const NL80211_ATTR_KEY_DEFAULT_MGMT:u16 = 40;
-// const NL80211_ATTR_MGMT_SUBTYPE:u16 = 41;
-// const NL80211_ATTR_IE:u16 = 42;
+const NL80211_ATTR_MGMT_SUBTYPE: u16 = 41;
+const NL80211_ATTR_IE: u16 = 42;
 const NL80211_ATTR_MAX_NUM_SCAN_SSIDS: u16 = 43;
 const NL80211_ATTR_SCAN_FREQUENCIES: u16 = 44;
 const NL80211_ATTR_SCAN_SSIDS: u16 = 45;
@@ -171,10 +189,10 @@ const NL80211_ATTR_BSS: u16 = 47;
 // const NL80211_ATTR_REG_INITIATOR:u16 = 48;
 // const NL80211_ATTR_REG_TYPE:u16 = 49;
 const NL80211_ATTR_SUPPORTED_COMMANDS: u16 = 50;
-// const NL80211_ATTR_FRAME:u16 = 51;
+const NL80211_ATTR_FRAME: u16 = 51;
 const NL80211_ATTR_SSID: u16 = 52;
-// const NL80211_ATTR_AUTH_TYPE:u16 = 53;
-// const NL80211_ATTR_REASON_CODE:u16 = 54;
+const NL80211_ATTR_AUTH_TYPE: u16 = 53;
+const NL80211_ATTR_REASON_CODE: u16 = 54;
 // const NL80211_ATTR_KEY_TYPE:u16 = 55;
 const NL80211_ATTR_MAX_SCAN_IE_LEN: u16 = 56;
 const NL80211_ATTR_CIPHER_SUITES: u16 = 57;
@@ -185,94 +203,94 @@ const NL80211_ATTR_WIPHY_RETRY_SHORT: u16 = 61;
 const NL80211_ATTR_WIPHY_RETRY_LONG: u16 = 62;
 const NL80211_ATTR_WIPHY_FRAG_THRESHOLD: u16 = 63;
 const NL80211_ATTR_WIPHY_RTS_THRESHOLD: u16 = 64;
-// const NL80211_ATTR_TIMED_OUT:u16 = 65;
+const NL80211_ATTR_TIMED_OUT: u16 = 65;
 // const NL80211_ATTR_USE_MFP:u16 = 66;
-// const NL80211_ATTR_STA_FLAGS2:u16 = 67;
-// const NL80211_ATTR_CONTROL_PORT:u16 = 68;
+const NL80211_ATTR_STA_FLAGS2: u16 = 67;
+const NL80211_ATTR_CONTROL_PORT: u16 = 68;
 // const NL80211_ATTR_TESTDATA:u16 = 69;
 // const NL80211_ATTR_PRIVACY:u16 = 70;
-// const NL80211_ATTR_DISCONNECTED_BY_AP:u16 = 71;
-// const NL80211_ATTR_STATUS_CODE:u16 = 72;
+const NL80211_ATTR_DISCONNECTED_BY_AP: u16 = 71;
+const NL80211_ATTR_STATUS_CODE: u16 = 72;
 // const NL80211_ATTR_CIPHER_SUITES_PAIRWISE:u16 = 73;
 // const NL80211_ATTR_CIPHER_SUITE_GROUP:u16 = 74;
 // const NL80211_ATTR_WPA_VERSIONS:u16 = 75;
-// const NL80211_ATTR_AKM_SUITES:u16 = 76;
+const NL80211_ATTR_AKM_SUITES: u16 = 76;
 // const NL80211_ATTR_REQ_IE:u16 = 77;
 // const NL80211_ATTR_RESP_IE:u16 = 78;
-// const NL80211_ATTR_PREV_BSSID:u16 = 79;
-// const NL80211_ATTR_KEY:u16 = 80;
+const NL80211_ATTR_PREV_BSSID: u16 = 79;
+const NL80211_ATTR_KEY: u16 = 80;
 // const NL80211_ATTR_KEYS:u16 = 81;
-// const NL80211_ATTR_PID:u16 = 82;
+const NL80211_ATTR_PID: u16 = 82;
 const NL80211_ATTR_4ADDR: u16 = 83;
-// const NL80211_ATTR_SURVEY_INFO:u16 = 84;
-// const NL80211_ATTR_PMKID:u16 = 85;
+const NL80211_ATTR_SURVEY_INFO: u16 = 84;
+const NL80211_ATTR_PMKID: u16 = 85;
 const NL80211_ATTR_MAX_NUM_PMKIDS: u16 = 86;
-// const NL80211_ATTR_DURATION:u16 = 87;
-// const NL80211_ATTR_COOKIE:u16 = 88;
+const NL80211_ATTR_DURATION: u16 = 87;
+const NL80211_ATTR_COOKIE: u16 = 88;
 const NL80211_ATTR_WIPHY_COVERAGE_CLASS: u16 = 89;
-// const NL80211_ATTR_TX_RATES:u16 = 90;
-// const NL80211_ATTR_FRAME_MATCH:u16 = 91;
-// const NL80211_ATTR_ACK:u16 = 92;
+const NL80211_ATTR_TX_RATES: u16 = 90;
+const NL80211_ATTR_FRAME_MATCH: u16 = 91;
+const NL80211_ATTR_ACK: u16 = 92;
 // const NL80211_ATTR_PS_STATE:u16 = 93;
 // const NL80211_ATTR_CQM:u16 = 94;
-// const NL80211_ATTR_LOCAL_STATE_CHANGE:u16 = 95;
+const NL80211_ATTR_LOCAL_STATE_CHANGE: u16 = 95;
 // const NL80211_ATTR_AP_ISOLATE:u16 = 96;
-// const NL80211_ATTR_WIPHY_TX_POWER_SETTING:u16 = 97;
+const NL80211_ATTR_WIPHY_TX_POWER_SETTING: u16 = 97;
 const NL80211_ATTR_WIPHY_TX_POWER_LEVEL: u16 = 98;
 const NL80211_ATTR_TX_FRAME_TYPES: u16 = 99;
 const NL80211_ATTR_RX_FRAME_TYPES: u16 = 100;
 // Covered by frame_type.rs
-// const NL80211_ATTR_FRAME_TYPE:u16 = 101;
+const NL80211_ATTR_FRAME_TYPE: u16 = 101;
 const NL80211_ATTR_CONTROL_PORT_ETHERTYPE: u16 = 102;
-// const NL80211_ATTR_CONTROL_PORT_NO_ENCRYPT:u16 = 103;
+const NL80211_ATTR_CONTROL_PORT_NO_ENCRYPT: u16 = 103;
 const NL80211_ATTR_SUPPORT_IBSS_RSN: u16 = 104;
 const NL80211_ATTR_WIPHY_ANTENNA_TX: u16 = 105;
 const NL80211_ATTR_WIPHY_ANTENNA_RX: u16 = 106;
 // const NL80211_ATTR_MCAST_RATE:u16 = 107;
 const NL80211_ATTR_OFFCHANNEL_TX_OK: u16 = 108;
 // const NL80211_ATTR_BSS_HT_OPMODE:u16 = 109;
-// const NL80211_ATTR_KEY_DEFAULT_TYPES:u16 = 110;
+const NL80211_ATTR_KEY_DEFAULT_TYPES: u16 = 110;
 const NL80211_ATTR_MAX_REMAIN_ON_CHANNEL_DURATION: u16 = 111;
 // const NL80211_ATTR_MESH_SETUP:u16 = 112;
 const NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX: u16 = 113;
 const NL80211_ATTR_WIPHY_ANTENNA_AVAIL_RX: u16 = 114;
 const NL80211_ATTR_SUPPORT_MESH_AUTH: u16 = 115;
 // const NL80211_ATTR_STA_PLINK_STATE:u16 = 116;
-// const NL80211_ATTR_WOWLAN_TRIGGERS:u16 = 117;
+const NL80211_ATTR_WOWLAN_TRIGGERS: u16 = 117;
 const NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED: u16 = 118;
 const NL80211_ATTR_SCHED_SCAN_INTERVAL: u16 = 119;
 const NL80211_ATTR_INTERFACE_COMBINATIONS: u16 = 120;
 const NL80211_ATTR_SOFTWARE_IFTYPES: u16 = 121;
-// const NL80211_ATTR_REKEY_DATA:u16 = 122;
+const NL80211_ATTR_REKEY_DATA: u16 = 122;
 const NL80211_ATTR_MAX_NUM_SCHED_SCAN_SSIDS: u16 = 123;
 const NL80211_ATTR_MAX_SCHED_SCAN_IE_LEN: u16 = 124;
 // const NL80211_ATTR_SCAN_SUPP_RATES:u16 = 125;
 // const NL80211_ATTR_HIDDEN_SSID:u16 = 126;
-// const NL80211_ATTR_IE_PROBE_RESP:u16 = 127;
-// const NL80211_ATTR_IE_ASSOC_RESP:u16 = 128;
+const NL80211_ATTR_IE_PROBE_RESP: u16 = 127;
+const NL80211_ATTR_IE_ASSOC_RESP: u16 = 128;
 // const NL80211_ATTR_STA_WME:u16 = 129;
 const NL80211_ATTR_SUPPORT_AP_UAPSD: u16 = 130;
 const NL80211_ATTR_ROAM_SUPPORT: u16 = 131;
 const NL80211_ATTR_SCHED_SCAN_MATCH: u16 = 132;
 const NL80211_ATTR_MAX_MATCH_SETS: u16 = 133;
 // const NL80211_ATTR_PMKSA_CANDIDATE:u16 = 134;
-// const NL80211_ATTR_TX_NO_CCK_RATE:u16 = 135;
+const NL80211_ATTR_TX_NO_CCK_RATE: u16 = 135;
 // const NL80211_ATTR_TDLS_ACTION:u16 = 136;
 // const NL80211_ATTR_TDLS_DIALOG_TOKEN:u16 = 137;
 // const NL80211_ATTR_TDLS_OPERATION:u16 = 138;
 const NL80211_ATTR_TDLS_SUPPORT: u16 = 139;
 const NL80211_ATTR_TDLS_EXTERNAL_SETUP: u16 = 140;
 // const NL80211_ATTR_DEVICE_AP_SME:u16 = 141;
-// const NL80211_ATTR_DONT_WAIT_FOR_ACK:u16 = 142;
+const NL80211_ATTR_DONT_WAIT_FOR_ACK: u16 = 142;
 const NL80211_ATTR_FEATURE_FLAGS: u16 = 143;
 const NL80211_ATTR_PROBE_RESP_OFFLOAD: u16 = 144;
-// const NL80211_ATTR_PROBE_RESP:u16 = 145;
+const NL80211_ATTR_PROBE_RESP: u16 = 145;
 // const NL80211_ATTR_DFS_REGION:u16 = 146;
 // const NL80211_ATTR_DISABLE_HT:u16 = 147;
 const NL80211_ATTR_HT_CAPABILITY_MASK: u16 = 148;
-// const NL80211_ATTR_NOACK_MAP:u16 = 149;
+const NL80211_ATTR_NOACK_MAP: u16 = 149;
 // const NL80211_ATTR_INACTIVITY_TIMEOUT:u16 = 150;
-// const NL80211_ATTR_RX_SIGNAL_DBM:u16 = 151;
+const NL80211_ATTR_RX_SIGNAL_DBM: u16 = 151;
 // const NL80211_ATTR_BG_SCAN_PERIOD:u16 = 152;
 const NL80211_ATTR_WDEV: u16 = 153;
 // const NL80211_ATTR_USER_REG_HINT_TYPE:u16 = 154;
@@ -283,49 +301,49 @@ const NL80211_ATTR_SCAN_FLAGS: u16 = 158;
 const NL80211_ATTR_CHANNEL_WIDTH: u16 = 159;
 const NL80211_ATTR_CENTER_FREQ1: u16 = 160;
 const NL80211_ATTR_CENTER_FREQ2: u16 = 161;
-// const NL80211_ATTR_P2P_CTWINDOW:u16 = 162;
-// const NL80211_ATTR_P2P_OPPPS:u16 = 163;
+const NL80211_ATTR_P2P_CTWINDOW: u16 = 162;
+const NL80211_ATTR_P2P_OPPPS: u16 = 163;
 // const NL80211_ATTR_LOCAL_MESH_POWER_MODE:u16 = 164;
 // const NL80211_ATTR_ACL_POLICY:u16 = 165;
 const NL80211_ATTR_MAC_ADDRS: u16 = 166;
 // const NL80211_ATTR_MAC_ACL_MAX:u16 = 167;
-// const NL80211_ATTR_RADAR_EVENT:u16 = 168;
+const NL80211_ATTR_RADAR_EVENT: u16 = 168;
 const NL80211_ATTR_EXT_CAPA: u16 = 169;
 const NL80211_ATTR_EXT_CAPA_MASK: u16 = 170;
 // const NL80211_ATTR_STA_CAPABILITY:u16 = 171;
 // const NL80211_ATTR_STA_EXT_CAPABILITY:u16 = 172;
-// const NL80211_ATTR_PROTOCOL_FEATURES:u16 = 173;
+const NL80211_ATTR_PROTOCOL_FEATURES: u16 = 173;
 const NL80211_ATTR_SPLIT_WIPHY_DUMP: u16 = 174;
 // const NL80211_ATTR_DISABLE_VHT:u16 = 175;
 const NL80211_ATTR_VHT_CAPABILITY_MASK: u16 = 176;
 // const NL80211_ATTR_MDID:u16 = 177;
 // const NL80211_ATTR_IE_RIC:u16 = 178;
-// const NL80211_ATTR_CRIT_PROT_ID:u16 = 179;
-// const NL80211_ATTR_MAX_CRIT_PROT_DURATION:u16 = 180;
+const NL80211_ATTR_CRIT_PROT_ID: u16 = 179;
+const NL80211_ATTR_MAX_CRIT_PROT_DURATION: u16 = 180;
 // const NL80211_ATTR_PEER_AID:u16 = 181;
-// const NL80211_ATTR_COALESCE_RULE:u16 = 182;
-// const NL80211_ATTR_CH_SWITCH_COUNT:u16 = 183;
-// const NL80211_ATTR_CH_SWITCH_BLOCK_TX:u16 = 184;
-// const NL80211_ATTR_CSA_IES:u16 = 185;
-// const NL80211_ATTR_CNTDWN_OFFS_BEACON:u16 = 186;
-// const NL80211_ATTR_CNTDWN_OFFS_PRESP:u16 = 187;
-// const NL80211_ATTR_RXMGMT_FLAGS:u16 = 188;
+const NL80211_ATTR_COALESCE_RULE: u16 = 182;
+const NL80211_ATTR_CH_SWITCH_COUNT: u16 = 183;
+const NL80211_ATTR_CH_SWITCH_BLOCK_TX: u16 = 184;
+const NL80211_ATTR_CSA_IES: u16 = 185;
+const NL80211_ATTR_CNTDWN_OFFS_BEACON: u16 = 186;
+const NL80211_ATTR_CNTDWN_OFFS_PRESP: u16 = 187;
+const NL80211_ATTR_RXMGMT_FLAGS: u16 = 188;
 // const NL80211_ATTR_STA_SUPPORTED_CHANNELS:u16 = 189;
 // const NL80211_ATTR_STA_SUPPORTED_OPER_CLASSES:u16 = 190;
 // const NL80211_ATTR_HANDLE_DFS:u16 = 191;
 // const NL80211_ATTR_SUPPORT_5_MHZ:u16 = 192;
 // const NL80211_ATTR_SUPPORT_10_MHZ:u16 = 193;
 // const NL80211_ATTR_OPMODE_NOTIF:u16 = 194;
-// const NL80211_ATTR_VENDOR_ID:u16 = 195;
-// const NL80211_ATTR_VENDOR_SUBCMD:u16 = 196;
-// const NL80211_ATTR_VENDOR_DATA:u16 = 197;
-// const NL80211_ATTR_VENDOR_EVENTS:u16 = 198;
+const NL80211_ATTR_VENDOR_ID: u16 = 195;
+const NL80211_ATTR_VENDOR_SUBCMD: u16 = 196;
+const NL80211_ATTR_VENDOR_DATA: u16 = 197;
+const NL80211_ATTR_VENDOR_EVENTS: u16 = 198;
 // const NL80211_ATTR_QOS_MAP:u16 = 199;
 // const NL80211_ATTR_MAC_HINT:u16 = 200;
 // const NL80211_ATTR_WIPHY_FREQ_HINT:u16 = 201;
 // const NL80211_ATTR_MAX_AP_ASSOC_STA:u16 = 202;
 // const NL80211_ATTR_TDLS_PEER_CAPABILITY:u16 = 203;
-// const NL80211_ATTR_SOCKET_OWNER:u16 = 204;
+const NL80211_ATTR_SOCKET_OWNER: u16 = 204;
 // const NL80211_ATTR_CSA_C_OFFSETS_TX:u16 = 205;
 const NL80211_ATTR_MAX_CSA_COUNTERS: u16 = 206;
 // const NL80211_ATTR_TDLS_INITIATOR:u16 = 207;
@@ -339,8 +357,8 @@ const NL80211_ATTR_MAX_CSA_COUNTERS: u16 = 206;
 const NL80211_ATTR_MAC_MASK: u16 = 215;
 const NL80211_ATTR_WIPHY_SELF_MANAGED_REG: u16 = 216;
 const NL80211_ATTR_EXT_FEATURES: u16 = 217;
-// const NL80211_ATTR_SURVEY_RADIO_STATS:u16 = 218;
-// const NL80211_ATTR_NETNS_FD:u16 = 219;
+const NL80211_ATTR_SURVEY_RADIO_STATS: u16 = 218;
+const NL80211_ATTR_NETNS_FD: u16 = 219;
 const NL80211_ATTR_SCHED_SCAN_DELAY: u16 = 220;
 // const NL80211_ATTR_REG_INDOOR:u16 = 221;
 const NL80211_ATTR_MAX_NUM_SCHED_SCAN_PLANS: u16 = 222;
@@ -357,7 +375,7 @@ const NL80211_ATTR_IFTYPE_EXT_CAPA: u16 = 230;
 // const NL80211_ATTR_SCAN_START_TIME_TSF:u16 = 233;
 // const NL80211_ATTR_SCAN_START_TIME_TSF_BSSID:u16 = 234;
 const NL80211_ATTR_MEASUREMENT_DURATION: u16 = 235;
-// const NL80211_ATTR_MEASUREMENT_DURATION_MANDATORY:u16 = 236;
+const NL80211_ATTR_MEASUREMENT_DURATION_MANDATORY: u16 = 236;
 // const NL80211_ATTR_MESH_PEER_AID:u16 = 237;
 // const NL80211_ATTR_NAN_MASTER_PREF:u16 = 238;
 const NL80211_ATTR_BANDS: u16 = 239;
@@ -365,15 +383,15 @@ const NL80211_ATTR_BANDS: u16 = 239;
 // const NL80211_ATTR_NAN_MATCH:u16 = 241;
 // const NL80211_ATTR_FILS_KEK:u16 = 242;
 // const NL80211_ATTR_FILS_NONCES:u16 = 243;
-// const NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED:u16 = 244;
-// const NL80211_ATTR_BSSID:u16 = 245;
-// const NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI:u16 = 246;
-// const NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST:u16 = 247;
+const NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED: u16 = 244;
+const NL80211_ATTR_BSSID: u16 = 245;
+const NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI: u16 = 246;
+const NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST: u16 = 247;
 // const NL80211_ATTR_TIMEOUT_REASON:u16 = 248;
-// const NL80211_ATTR_FILS_ERP_USERNAME:u16 = 249;
-// const NL80211_ATTR_FILS_ERP_REALM:u16 = 250;
-// const NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM:u16 = 251;
-// const NL80211_ATTR_FILS_ERP_RRK:u16 = 252;
+const NL80211_ATTR_FILS_ERP_USERNAME: u16 = 249;
+const NL80211_ATTR_FILS_ERP_REALM: u16 = 250;
+const NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM: u16 = 251;
+const NL80211_ATTR_FILS_ERP_RRK: u16 = 252;
 // const NL80211_ATTR_FILS_CACHE_ID:u16 = 253;
 // const NL80211_ATTR_PMK:u16 = 254;
 // const NL80211_ATTR_SCHED_SCAN_MULTI:u16 = 255;
@@ -381,62 +399,62 @@ const NL80211_ATTR_SCHED_SCAN_MAX_REQS: u16 = 256;
 // const NL80211_ATTR_WANT_1X_4WAY_HS:u16 = 257;
 // const NL80211_ATTR_PMKR0_NAME:u16 = 258;
 // const NL80211_ATTR_PORT_AUTHORIZED:u16 = 259;
-// const NL80211_ATTR_EXTERNAL_AUTH_ACTION:u16 = 260;
-// const NL80211_ATTR_EXTERNAL_AUTH_SUPPORT:u16 = 261;
+const NL80211_ATTR_EXTERNAL_AUTH_ACTION: u16 = 260;
+const NL80211_ATTR_EXTERNAL_AUTH_SUPPORT: u16 = 261;
 // const NL80211_ATTR_NSS:u16 = 262;
-// const NL80211_ATTR_ACK_SIGNAL:u16 = 263;
-// const NL80211_ATTR_CONTROL_PORT_OVER_NL80211:u16 = 264;
+const NL80211_ATTR_ACK_SIGNAL: u16 = 263;
+const NL80211_ATTR_CONTROL_PORT_OVER_NL80211: u16 = 264;
 const NL80211_ATTR_TXQ_STATS: u16 = 265;
 const NL80211_ATTR_TXQ_LIMIT: u16 = 266;
 const NL80211_ATTR_TXQ_MEMORY_LIMIT: u16 = 267;
 const NL80211_ATTR_TXQ_QUANTUM: u16 = 268;
 // const NL80211_ATTR_HE_CAPABILITY:u16 = 269;
 // const NL80211_ATTR_FTM_RESPONDER:u16 = 270;
-// const NL80211_ATTR_FTM_RESPONDER_STATS:u16 = 271;
-// const NL80211_ATTR_TIMEOUT:u16 = 272;
-// const NL80211_ATTR_PEER_MEASUREMENTS:u16 = 273;
+const NL80211_ATTR_FTM_RESPONDER_STATS: u16 = 271;
+const NL80211_ATTR_TIMEOUT: u16 = 272;
+const NL80211_ATTR_PEER_MEASUREMENTS: u16 = 273;
 // const NL80211_ATTR_AIRTIME_WEIGHT:u16 = 274;
-// const NL80211_ATTR_STA_TX_POWER_SETTING:u16 = 275;
-// const NL80211_ATTR_STA_TX_POWER:u16 = 276;
+const NL80211_ATTR_STA_TX_POWER_SETTING: u16 = 275;
+const NL80211_ATTR_STA_TX_POWER: u16 = 276;
 // const NL80211_ATTR_SAE_PASSWORD:u16 = 277;
 // const NL80211_ATTR_TWT_RESPONDER:u16 = 278;
 // const NL80211_ATTR_HE_OBSS_PD:u16 = 279;
-// const NL80211_ATTR_WIPHY_EDMG_CHANNELS:u16 = 280;
-// const NL80211_ATTR_WIPHY_EDMG_BW_CONFIG:u16 = 281;
+const NL80211_ATTR_WIPHY_EDMG_CHANNELS: u16 = 280;
+const NL80211_ATTR_WIPHY_EDMG_BW_CONFIG: u16 = 281;
 // const NL80211_ATTR_VLAN_ID:u16 = 282;
 // const NL80211_ATTR_HE_BSS_COLOR:u16 = 283;
 // const NL80211_ATTR_IFTYPE_AKM_SUITES:u16 = 284;
-// const NL80211_ATTR_TID_CONFIG:u16 = 285;
-// const NL80211_ATTR_CONTROL_PORT_NO_PREAUTH:u16 = 286;
+const NL80211_ATTR_TID_CONFIG: u16 = 285;
+const NL80211_ATTR_CONTROL_PORT_NO_PREAUTH: u16 = 286;
 // const NL80211_ATTR_PMK_LIFETIME:u16 = 287;
 // const NL80211_ATTR_PMK_REAUTH_THRESHOLD:u16 = 288;
 // const NL80211_ATTR_RECEIVE_MULTICAST:u16 = 289;
 const NL80211_ATTR_WIPHY_FREQ_OFFSET: u16 = 290;
-// const NL80211_ATTR_CENTER_FREQ1_OFFSET:u16 = 291;
+const NL80211_ATTR_CENTER_FREQ1_OFFSET: u16 = 291;
 // const NL80211_ATTR_SCAN_FREQ_KHZ:u16 = 292;
 // const NL80211_ATTR_HE_6GHZ_CAPABILITY:u16 = 293;
-// const NL80211_ATTR_FILS_DISCOVERY:u16 = 294;
-// const NL80211_ATTR_UNSOL_BCAST_PROBE_RESP:u16 = 295;
+const NL80211_ATTR_FILS_DISCOVERY: u16 = 294;
+const NL80211_ATTR_UNSOL_BCAST_PROBE_RESP: u16 = 295;
 // const NL80211_ATTR_S1G_CAPABILITY:u16 = 296;
 // const NL80211_ATTR_S1G_CAPABILITY_MASK:u16 = 297;
 // const NL80211_ATTR_SAE_PWE:u16 = 298;
 // const NL80211_ATTR_RECONNECT_REQUESTED:u16 = 299;
-// const NL80211_ATTR_SAR_SPEC:u16 = 300;
+const NL80211_ATTR_SAR_SPEC: u16 = 300;
 // const NL80211_ATTR_DISABLE_HE:u16 = 301;
-// const NL80211_ATTR_OBSS_COLOR_BITMAP:u16 = 302;
-// const NL80211_ATTR_COLOR_CHANGE_COUNT:u16 = 303;
-// const NL80211_ATTR_COLOR_CHANGE_COLOR:u16 = 304;
-// const NL80211_ATTR_COLOR_CHANGE_ELEMS:u16 = 305;
+const NL80211_ATTR_OBSS_COLOR_BITMAP: u16 = 302;
+const NL80211_ATTR_COLOR_CHANGE_COUNT: u16 = 303;
+const NL80211_ATTR_COLOR_CHANGE_COLOR: u16 = 304;
+const NL80211_ATTR_COLOR_CHANGE_ELEMS: u16 = 305;
 // const NL80211_ATTR_MBSSID_CONFIG:u16 = 306;
 // const NL80211_ATTR_MBSSID_ELEMS:u16 = 307;
-// const NL80211_ATTR_RADAR_BACKGROUND:u16 = 308;
+const NL80211_ATTR_RADAR_BACKGROUND: u16 = 308;
 // const NL80211_ATTR_AP_SETTINGS_FLAGS:u16 = 309;
 // const NL80211_ATTR_EHT_CAPABILITY:u16 = 310;
 // const NL80211_ATTR_DISABLE_EHT:u16 = 311;
 const NL80211_ATTR_MLO_LINKS: u16 = 312;
 // Covered in mlo.rs
-// const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
-// const NL80211_ATTR_MLD_ADDR:u16 = 314;
+const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
+const NL80211_ATTR_MLD_ADDR: u16 = 314;
 // const NL80211_ATTR_MLO_SUPPORT:u16 = 315;
 const NL80211_ATTR_MAX_NUM_AKM_SUITES: u16 = 316;
 const NL80211_ATTR_EML_CAPABILITY: u16 = 317;
@@ -446,17 +464,18 @@ const NL80211_ATTR_MLD_CAPA_AND_OPS: u16 = 318;
 // const NL80211_ATTR_TD_BITMAP:u16 = 321;
 // const NL80211_ATTR_PUNCT_BITMAP:u16 = 322;
 const NL80211_ATTR_MAX_HW_TIMESTAMP_PEERS: u16 = 323;
-// const NL80211_ATTR_HW_TIMESTAMP_ENABLED:u16 = 324;
+const NL80211_ATTR_HW_TIMESTAMP_ENABLED: u16 = 324;
 // const NL80211_ATTR_EMA_RNR_ELEMS:u16 = 325;
 // const NL80211_ATTR_MLO_LINK_DISABLED:u16 = 326;
-// const NL80211_ATTR_BSS_DUMP_INCLUDE_USE_DATA:u16 = 327;
-// const NL80211_ATTR_MLO_TTLM_DLINK:u16 = 328;
-// const NL80211_ATTR_MLO_TTLM_ULINK:u16 = 329;
+const NL80211_ATTR_BSS_DUMP_INCLUDE_USE_DATA: u16 = 327;
+const NL80211_ATTR_MLO_TTLM_DLINK: u16 = 328;
+const NL80211_ATTR_MLO_TTLM_ULINK: u16 = 329;
 // const NL80211_ATTR_ASSOC_SPP_AMSDU:u16 = 330;
-// const NL80211_ATTR_WIPHY_RADIOS:u16 = 331;
-// const NL80211_ATTR_WIPHY_INTERFACE_COMBINATIONS:u16 = 332;
+const NL80211_ATTR_WIPHY_RADIOS: u16 = 331;
+const NL80211_ATTR_WIPHY_INTERFACE_COMBINATIONS: u16 = 332;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211Attr {
     Wiphy(u32),
@@ -471,14 +490,21 @@ pub enum Nl80211Attr {
     Wdev(u64),
     Generation(u32),
     Use4Addr(bool),
-    WiphyFreq(u32),
+    /// Frequency of the channel, emitted in MHz, the kHz part is carried by
+    /// [Nl80211Attr::WiphyFreqOffset]
+    WiphyFreq(Nl80211Freq),
+    /// Frequency offset in kHz
     WiphyFreqOffset(u32),
     WiphyChannelType(Nl80211HtWiphyChannelType),
     ChannelWidth(Nl80211ChannelWidth),
-    CenterFreq1(u32),
-    CenterFreq2(u32),
+    /// Center frequency of the first part of the channel, emitted in MHz
+    CenterFreq1(Nl80211Freq),
+    /// Center frequency of the second part of the channel, used only for
+    /// 80+80 MHz bandwidth, emitted in MHz
+    CenterFreq2(Nl80211Freq),
+    /// Transmit power level in mBm (100 * dBm)
     WiphyTxPowerLevel(u32),
-    Ssid(String),
+    Ssid(Nl80211Ssid),
     StationInfo(Vec<Nl80211StationInfo>),
     TransmitQueueStats(Vec<Nl80211TransmitQueueStat>),
     TransmitQueueLimit(u32),
@@ -501,7 +527,7 @@ pub enum Nl80211Attr {
     RoamSupport,
     TdlsSupport,
     TdlsExternalSetup,
-    CipherSuites(Vec<Nl80211CipherSuit>),
+    CipherSuites(Vec<Nl80211CipherSuite>),
     MaxNumPmkids(u8),
     ControlPortEthertype,
     WiphyAntennaAvailTx(u32),
@@ -519,7 +545,7 @@ pub enum Nl80211Attr {
     /// in milliseconds
     MaxRemainOnChannelDuration(u32),
     OffchannelTxOk,
-    WowlanTrigersSupport(Vec<Nl80211WowlanTrigersSupport>),
+    WowlanTriggersSupport(Vec<Nl80211WowlanTriggersSupport>),
     SoftwareIftypes(Vec<Nl80211InterfaceType>),
     Features(Nl80211Features),
     ExtFeatures(Vec<Nl80211ExtFeature>),
@@ -537,8 +563,8 @@ pub enum Nl80211Attr {
     MaxCsaCounters(u8),
     WiphySelfManagedReg,
     SchedScanMaxReqs(u32),
-    EmlCapability(u16),
-    MldCapaAndOps(u16),
+    EmlCapability(Nl80211EmlCapability),
+    MldCapaAndOps(Nl80211MldCapaAndOps),
     Bands(Nl80211BandTypes),
     /// Maximum number of AKM suites allowed for connect command.
     MaxNumAkmSuites(u16),
@@ -549,7 +575,7 @@ pub enum Nl80211Attr {
     MaxHwTimestampPeers(u16),
     /// Basic Service Set (BSS)
     Bss(Vec<Nl80211BssInfo>),
-    ScanSsids(Vec<String>),
+    ScanSsids(Vec<Nl80211Ssid>),
     ScanFlags(Nl80211ScanFlags),
     MeasurementDuration(u16),
     /// Scan interval in millisecond(ms)
@@ -558,8 +584,8 @@ pub enum Nl80211Attr {
     /// delay before a WoWLAN net-detect scan is started, counting from the
     /// moment the system is suspended. This value is in seconds.
     SchedScanDelay(u32),
-    /// Scan frequencies in MHz.
-    ScanFrequencies(Vec<u32>),
+    /// Scan frequencies, emitted in MHz.
+    ScanFrequencies(Vec<Nl80211Freq>),
     /// Sets of attributes to match during scheduled scans. Only BSSs
     /// that match any of the sets will be reported. These are pass-thru
     /// filter rules. For a match to succeed, the BSS must match all
@@ -576,6 +602,249 @@ pub enum Nl80211Attr {
     /// iterations, only the interval between scans. The scan plans are
     /// executed sequentially.
     SchedScanPlans(Vec<Nl80211SchedScanPlan>),
+    /// Duration of an operation in milliseconds, for example remain on
+    /// channel.
+    Duration(u32),
+    /// Generic 64-bit cookie to identify objects, for example the remain on
+    /// channel request.
+    Cookie(u64),
+    /// Management frame including the 802.11 header
+    Frame(Vec<u8>),
+    /// Prefix of frame body to match when registering for frame
+    /// notification, empty for all frames of specified type.
+    FrameMatch(Vec<u8>),
+    FrameType(Nl80211FrameType),
+    /// Flag attribute indicating the transmitted frame was acknowledged by
+    /// the recipient
+    Ack,
+    /// Flag attribute indicating kernel should not wait for ACK of the
+    /// transmitted frame, hence no cookie will be returned
+    DontWaitForAck,
+    /// Signal strength of the received frame in dBm
+    RxSignalDbm(i32),
+    /// Information elements to be added into management frames or
+    /// received from peer
+    Ie(Vec<u8>),
+    AuthType(Nl80211AuthType),
+    /// IEEE 802.11 reason code used in deauthentication and disassociation
+    ReasonCode(u16),
+    /// IEEE 802.11 status code of authentication or association response
+    StatusCode(u16),
+    /// Flag attribute indicating the authentication or association timed
+    /// out
+    TimedOut,
+    /// Previous BSSID used in reassociation request
+    PrevBssid([u8; ETH_ALEN]),
+    /// Flag attribute indicating the request is only for local state change
+    /// without sending frames to peer
+    LocalStateChange,
+    ProtocolFeatures(Nl80211ProtocolFeatures),
+    /// Transmit rate masks per band
+    TxRates(Vec<Nl80211TxRateMask>),
+    /// Station flags to change
+    StationFlags2(Nl80211StationFlagUpdate),
+    /// Flag attribute requesting BSS dump to include BSSes usable only for
+    /// restricted purpose along with their use data
+    BssDumpIncludeUseData,
+    WiphyTxPowerSetting(Nl80211TxPowerSetting),
+    /// WoWLAN triggers to enable, omit to disable WoWLAN
+    WowlanTriggers(Vec<Nl80211WowlanTrigger>),
+    /// Monitor configuration flags, only valid for
+    /// [Nl80211InterfaceType::Monitor] interfaces
+    MonitorFlags(Vec<Nl80211MonitorFlag>),
+    /// Survey information of a channel
+    SurveyInfo(Vec<Nl80211SurveyInfo>),
+    /// Flag attribute requesting overall radio statistics in survey dump
+    SurveyRadioStats,
+    /// Key information
+    Key(Vec<Nl80211KeyAttr>),
+    /// Default key types for legacy non-nested key attributes
+    KeyDefaultTypes(Vec<Nl80211KeyDefaultType>),
+    /// Only report BSSes with RSSI better than the current connected BSS
+    /// by this value in dB during scheduled scan
+    SchedScanRelativeRssi(i8),
+    /// RSSI adjustment applied before comparing with
+    /// [Nl80211Attr::SchedScanRelativeRssi]
+    SchedScanRssiAdjust(Nl80211SchedScanRssiAdjust),
+    /// Flag attribute indicating the [Nl80211Attr::MeasurementDuration] is
+    /// mandatory, the scan request will be rejected if the driver cannot
+    /// honor it
+    MeasurementDurationMandatory,
+    /// The BSSID to scan for, or the BSSID of the current association
+    Bssid([u8; ETH_ALEN]),
+    /// TX queue parameters of access categories, only valid for
+    /// [Nl80211InterfaceType::Ap] and [Nl80211InterfaceType::P2pGo]
+    /// interfaces
+    WiphyTxqParams(Vec<Nl80211TxqParams>),
+    /// Vendor OUI of vendor command or event, see [Nl80211VendorOui]
+    VendorId(u32),
+    VendorSubcmd(u32),
+    /// Vendor specific data of vendor command or event. In wiphy dump, this
+    /// is a nested array of [Nl80211VendorCmdInfo] of supported vendor
+    /// commands.
+    VendorData(Vec<u8>),
+    /// Vendor events supported by wiphy
+    VendorEvents(Vec<Nl80211VendorCmdInfo>),
+    /// Process ID of a process in the target network namespace
+    Pid(u32),
+    /// File descriptor of the target network namespace
+    NetnsFd(u32),
+    /// FTM responder statistics of AP interface
+    FtmResponderStats(Vec<Nl80211FtmResponderStats>),
+    /// Timeout in milliseconds
+    Timeout(u32),
+    /// Peer measurement capabilities in wiphy dump, or the request and
+    /// results of peer measurement
+    PeerMeasurements(Vec<Nl80211PeerMeasurementAttr>),
+    /// P2P GO client traffic window in TUs, 0 to disable
+    P2pCtwindow(u8),
+    /// P2P GO opportunistic power save
+    P2pOppps(bool),
+    /// Flags of received management frame
+    RxmgmtFlags(Nl80211RxMgmtFlags),
+    /// Portion of the beacon before the TIM element
+    BeaconHead(Vec<u8>),
+    /// Portion of the beacon after the TIM element
+    BeaconTail(Vec<u8>),
+    /// Information elements for probe response frames
+    IeProbeResp(Vec<u8>),
+    /// Information elements for (re)association response frames
+    IeAssocResp(Vec<u8>),
+    /// Probe response template for offloading probe response to driver
+    ProbeResp(Vec<u8>),
+    /// Number of beacons (including current) until the channel switch
+    ChSwitchCount(u32),
+    /// Block transmission until the channel switch completes
+    ChSwitchBlockTx,
+    /// Beacon attributes used during the channel switch, e.g.
+    /// [Nl80211Attr::BeaconHead] and [Nl80211Attr::BeaconTail]
+    CsaIes(Vec<Nl80211Attr>),
+    /// Offsets of the countdown counters in the beacon
+    CntdwnOffsBeacon(Vec<u16>),
+    /// Offsets of the countdown counters in the probe response
+    CntdwnOffsPresp(Vec<u16>),
+    /// Mesh ID, up to 32 bytes
+    MeshId(Vec<u8>),
+    /// Type of radar event
+    RadarEvent(Nl80211RadarEventKind),
+    /// Radar detection on the dedicated background chain instead of the
+    /// operating channel
+    RadarBackground,
+    /// Per-TID configurations of [Nl80211Command::SetTidConfig]
+    TidConfig(Vec<Nl80211TidConfig>),
+    /// TID configuration capabilities in wiphy dump
+    TidConfigSupport(Nl80211TidConfigSupport),
+    /// Packet coalesce rules
+    CoalesceRule(Vec<Nl80211CoalesceRule>),
+    /// Coalesce capabilities in wiphy dump
+    CoalesceRuleSupport(Nl80211CoalesceSupport),
+    /// SAR (specific absorption rate) configuration, in wiphy dump, the
+    /// supported SAR type and frequency ranges
+    SarSpec(Vec<Nl80211SarAttr>),
+    /// Bitmap of the BSS colors used by overlapping BSSes
+    ObssColorBitmap(u64),
+    /// Number of beacons until the BSS color change
+    ColorChangeCount(u8),
+    /// New BSS color
+    ColorChangeColor(u8),
+    /// Beacon attributes used during the BSS color change
+    ColorChangeElems(Vec<Nl80211Attr>),
+    /// Beacon interval in TUs
+    BeaconInterval(u32),
+    /// DTIM period in beacon intervals
+    DtimPeriod(u32),
+    FilsDiscovery(Vec<Nl80211FilsDiscovery>),
+    UnsolBcastProbeResp(Vec<Nl80211UnsolBcastProbeResp>),
+    AkmSuites(Vec<Nl80211AkmSuite>),
+    Pmkid([u8; WLAN_PMKID_LEN]),
+    ExternalAuthAction(Nl80211ExternalAuthAction),
+    /// Userspace supports handling authentication offloaded by driver
+    ExternalAuthSupport,
+    /// Link ID of the multi-link operation
+    MloLinkId(u8),
+    /// MLD(Multi-Link Device) MAC address
+    MldAddr([u8; ETH_ALEN]),
+    RekeyData(Vec<Nl80211RekeyData>),
+    /// Userspace controls the port authorization(802.1X) of the connection
+    ControlPort,
+    /// Ethertype of control port frames, for example `0x888e` for EAPOL.
+    /// Shares the attribute ID with [Self::ControlPortEthertype] which is
+    /// the flag of wiphy capability.
+    ControlPortEthertypeValue(u16),
+    /// Control port frames are transmitted and received unencrypted
+    ControlPortNoEncrypt,
+    /// Exchange control port frames over nl80211 instead of the network
+    /// interface
+    ControlPortOverNl80211,
+    /// Do not deliver RSN pre-authentication frames(ethertype `0x88c7`)
+    /// over nl80211
+    ControlPortNoPreauth,
+    /// The socket sending the request owns the created object or
+    /// connection, which is removed when the socket is closed
+    SocketOwner,
+    CritProtId(Nl80211CritProtocolId),
+    /// Duration in milliseconds of the critical protocol period, at most
+    /// 5000
+    MaxCritProtDuration(u16),
+    /// EAP re-authentication protocol(ERP) username part of keyName-NAI
+    FilsErpUsername(Vec<u8>),
+    /// ERP realm part of keyName-NAI
+    FilsErpRealm(Vec<u8>),
+    /// ERP next sequence number
+    FilsErpNextSeqNum(u16),
+    /// ERP re-authentication root key(rRK)
+    FilsErpRrk(Vec<u8>),
+    /// Management frame subtype, for example the frame sent to station
+    /// when removing it
+    MgmtSubtype(Nl80211FrameTypeMgmt),
+    /// Flag indicating multicast frames are converted to unicast frames
+    /// for each associated station in AP mode
+    MulticastToUnicastEnabled,
+    /// Bitmap of TIDs(bit N for TID N) using no-ACK policy for QoS data
+    /// frames
+    NoackMap(u16),
+    /// TX power setting of station, [Nl80211TxPowerSetting::Fixed] is not
+    /// allowed
+    StaTxPowerSetting(Nl80211TxPowerSetting),
+    /// TX power limit of station in mBm(100 * dBm)
+    StaTxPower(i16),
+    /// Signal strength(dBm) of the ACK frame
+    AckSignal(i32),
+    /// Flag in disconnect notification indicating the disconnection was
+    /// initiated by AP instead of local request
+    DisconnectedByAp,
+    /// Supported rates of station in units of 500 kb/s, with the basic
+    /// rate bit(0x80) cleared
+    StaSupportedRates(Vec<u8>),
+    /// Downlink TID-to-link mapping, the bitmap of link IDs each TID(0-7)
+    /// is mapped to
+    MloTtlmDlink([u16; IEEE80211_TTLM_NUM_TIDS]),
+    /// Uplink TID-to-link mapping, the bitmap of link IDs each TID(0-7) is
+    /// mapped to
+    MloTtlmUlink([u16; IEEE80211_TTLM_NUM_TIDS]),
+    /// Flag indicating hardware timestamping is to be enabled, absent to
+    /// disable it
+    HwTimestampEnabled,
+    /// Flag indicating CCK rates must not be used for transmitting probe
+    /// requests of scan or management frame
+    TxNoCckRate,
+    /// Bitmap of the 60 GHz channels(bit 0 for channel 1) aggregated into
+    /// the EDMG channel
+    WiphyEdmgChannels(u8),
+    /// EDMG channel bandwidth configuration, see IEEE 802.11ay-2021
+    /// Table 13
+    WiphyEdmgBwConfig(u8),
+    /// Center frequency offset in kHz of the first part of the channel
+    CenterFreq1Offset(u32),
+    /// Physical radios of multi-radio wiphy
+    WiphyRadios(Vec<Nl80211WiphyRadio>),
+    /// Interface combinations across all radios of multi-radio wiphy
+    WiphyInterfaceCombinations(Vec<Nl80211IfaceComb>),
+    /// Attribute of specified kind holding raw payload failed to parse along
+    /// with the error message, only generated in lenient parse mode, see
+    /// [crate::Nl80211Handle::set_lenient_parse()]
+    MalformedNested(u16, Vec<u8>, String),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -608,9 +877,11 @@ impl Nla for Nl80211Attr {
             | Self::TransmitQueueMemoryLimit(_)
             | Self::TransmitQueueQuantum(_)
             | Self::SchedScanInterval(_)
-            | Self::SchedScanDelay(_) => 4,
-            Self::Wdev(_) => 8,
-            Self::IfName(s) | Self::Ssid(s) | Self::WiphyName(s) => s.len() + 1,
+            | Self::SchedScanDelay(_)
+            | Self::Duration(_) => 4,
+            Self::Wdev(_) | Self::Cookie(_) => 8,
+            Self::IfName(s) | Self::WiphyName(s) => s.len() + 1,
+            Self::Ssid(s) => s.as_bytes().len(),
             Self::Mac(_) | Self::MacMask(_) => ETH_ALEN,
             Self::MacAddrs(s) => {
                 MacAddressNlas::from(s).as_slice().buffer_len()
@@ -644,7 +915,7 @@ impl Nla for Nl80211Attr {
                 Nl80211Commands::from(s).as_slice().buffer_len()
             }
             Self::MaxRemainOnChannelDuration(_) => 4,
-            Self::WowlanTrigersSupport(s) => s.as_slice().buffer_len(),
+            Self::WowlanTriggersSupport(s) => s.as_slice().buffer_len(),
             Self::SoftwareIftypes(s) => {
                 Nl80211InterfaceTypes::from(s).as_slice().buffer_len()
             }
@@ -678,6 +949,103 @@ impl Nla for Nl80211Attr {
             }
             Self::SchedScanMatch(v) => v.as_slice().buffer_len(),
             Self::SchedScanPlans(v) => v.as_slice().buffer_len(),
+            Self::Frame(v) | Self::FrameMatch(v) => v.len(),
+            Self::FrameType(v) => v.value_len(),
+            Self::Ack | Self::DontWaitForAck => 0,
+            Self::RxSignalDbm(_) => 4,
+            Self::Ie(v) => v.len(),
+            Self::AuthType(_) => 4,
+            Self::ReasonCode(_) | Self::StatusCode(_) => 2,
+            Self::TimedOut | Self::LocalStateChange => 0,
+            Self::PrevBssid(_) => ETH_ALEN,
+            Self::ProtocolFeatures(_) => 4,
+            Self::TxRates(v) => v.as_slice().buffer_len(),
+            Self::StationFlags2(v) => v.buffer_len(),
+            Self::BssDumpIncludeUseData => 0,
+            Self::WiphyTxPowerSetting(_) => 4,
+            Self::WowlanTriggers(s) => s.as_slice().buffer_len(),
+            Self::MonitorFlags(s) => s.as_slice().buffer_len(),
+            Self::SurveyInfo(s) => s.as_slice().buffer_len(),
+            Self::SurveyRadioStats => 0,
+            Self::Key(s) => s.as_slice().buffer_len(),
+            Self::KeyDefaultTypes(s) => s.as_slice().buffer_len(),
+            Self::SchedScanRelativeRssi(_) => 1,
+            Self::SchedScanRssiAdjust(v) => v.buffer_len(),
+            Self::MeasurementDurationMandatory => 0,
+            Self::Bssid(_) => ETH_ALEN,
+            Self::WiphyTxqParams(v) => {
+                Nl80211TxqParamsNla::new_list(v).as_slice().buffer_len()
+            }
+            Self::VendorId(_) | Self::VendorSubcmd(_) => 4,
+            Self::VendorData(v) => v.len(),
+            Self::VendorEvents(v) => {
+                Nl80211VendorCmdInfoNla::new_list(v).as_slice().buffer_len()
+            }
+            Self::Pid(_) | Self::NetnsFd(_) => 4,
+            Self::FtmResponderStats(v) => v.as_slice().buffer_len(),
+            Self::Timeout(_) => 4,
+            Self::PeerMeasurements(v) => v.as_slice().buffer_len(),
+            Self::P2pCtwindow(_) | Self::P2pOppps(_) => 1,
+            Self::RxmgmtFlags(_) => 4,
+            Self::BeaconHead(v)
+            | Self::BeaconTail(v)
+            | Self::IeProbeResp(v)
+            | Self::IeAssocResp(v)
+            | Self::ProbeResp(v) => v.len(),
+            Self::ChSwitchCount(_) => 4,
+            Self::ChSwitchBlockTx => 0,
+            Self::CsaIes(v) => v.as_slice().buffer_len(),
+            Self::CntdwnOffsBeacon(v) | Self::CntdwnOffsPresp(v) => v.len() * 2,
+            Self::MeshId(v) => v.len(),
+            Self::RadarEvent(_) => 4,
+            Self::RadarBackground => 0,
+            Self::TidConfig(v) => v.as_slice().buffer_len(),
+            Self::TidConfigSupport(v) => v.buffer_len(),
+            Self::CoalesceRule(v) => v.as_slice().buffer_len(),
+            Self::CoalesceRuleSupport(v) => v.buffer_len(),
+            Self::SarSpec(v) => v.as_slice().buffer_len(),
+            Self::ObssColorBitmap(_) => 8,
+            Self::ColorChangeCount(_) | Self::ColorChangeColor(_) => 1,
+            Self::ColorChangeElems(v) => v.as_slice().buffer_len(),
+            Self::BeaconInterval(_) | Self::DtimPeriod(_) => 4,
+            Self::FilsDiscovery(v) => v.as_slice().buffer_len(),
+            Self::UnsolBcastProbeResp(v) => v.as_slice().buffer_len(),
+            Self::AkmSuites(s) => 4 * s.len(),
+            Self::Pmkid(_) => WLAN_PMKID_LEN,
+            Self::ExternalAuthAction(_) => 4,
+            Self::ExternalAuthSupport => 0,
+            Self::MloLinkId(_) => 1,
+            Self::MldAddr(_) => ETH_ALEN,
+            Self::RekeyData(v) => v.as_slice().buffer_len(),
+            Self::ControlPortEthertypeValue(_) => 2,
+            Self::ControlPort
+            | Self::ControlPortNoEncrypt
+            | Self::ControlPortOverNl80211
+            | Self::ControlPortNoPreauth => 0,
+            Self::SocketOwner => 0,
+            Self::CritProtId(_) | Self::MaxCritProtDuration(_) => 2,
+            Self::FilsErpUsername(v)
+            | Self::FilsErpRealm(v)
+            | Self::FilsErpRrk(v) => v.len(),
+            Self::FilsErpNextSeqNum(_) => 2,
+            Self::MgmtSubtype(_) => 1,
+            Self::MulticastToUnicastEnabled => 0,
+            Self::NoackMap(_) => 2,
+            Self::StaTxPowerSetting(_) => 1,
+            Self::StaTxPower(_) => 2,
+            Self::AckSignal(_) => 4,
+            Self::DisconnectedByAp => 0,
+            Self::StaSupportedRates(v) => v.len(),
+            Self::MloTtlmDlink(_) | Self::MloTtlmUlink(_) => {
+                IEEE80211_TTLM_NUM_TIDS * 2
+            }
+            Self::HwTimestampEnabled => 0,
+            Self::TxNoCckRate => 0,
+            Self::WiphyEdmgChannels(_) | Self::WiphyEdmgBwConfig(_) => 1,
+            Self::CenterFreq1Offset(_) => 4,
+            Self::WiphyRadios(v) => v.as_slice().buffer_len(),
+            Self::WiphyInterfaceCombinations(v) => v.as_slice().buffer_len(),
+            Self::MalformedNested(_, d, _) => d.len(),
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -743,7 +1111,7 @@ impl Nla for Nl80211Attr {
                 NL80211_ATTR_MAX_REMAIN_ON_CHANNEL_DURATION
             }
             Self::OffchannelTxOk => NL80211_ATTR_OFFCHANNEL_TX_OK,
-            Self::WowlanTrigersSupport(_) => {
+            Self::WowlanTriggersSupport(_) => {
                 NL80211_ATTR_WOWLAN_TRIGGERS_SUPPORTED
             }
             Self::SoftwareIftypes(_) => NL80211_ATTR_SOFTWARE_IFTYPES,
@@ -784,6 +1152,128 @@ impl Nla for Nl80211Attr {
             Self::ScanFrequencies(_) => NL80211_ATTR_SCAN_FREQUENCIES,
             Self::SchedScanMatch(_) => NL80211_ATTR_SCHED_SCAN_MATCH,
             Self::SchedScanPlans(_) => NL80211_ATTR_SCHED_SCAN_PLANS,
+            Self::Duration(_) => NL80211_ATTR_DURATION,
+            Self::Cookie(_) => NL80211_ATTR_COOKIE,
+            Self::Frame(_) => NL80211_ATTR_FRAME,
+            Self::FrameMatch(_) => NL80211_ATTR_FRAME_MATCH,
+            Self::FrameType(_) => NL80211_ATTR_FRAME_TYPE,
+            Self::Ack => NL80211_ATTR_ACK,
+            Self::DontWaitForAck => NL80211_ATTR_DONT_WAIT_FOR_ACK,
+            Self::RxSignalDbm(_) => NL80211_ATTR_RX_SIGNAL_DBM,
+            Self::Ie(_) => NL80211_ATTR_IE,
+            Self::AuthType(_) => NL80211_ATTR_AUTH_TYPE,
+            Self::ReasonCode(_) => NL80211_ATTR_REASON_CODE,
+            Self::StatusCode(_) => NL80211_ATTR_STATUS_CODE,
+            Self::TimedOut => NL80211_ATTR_TIMED_OUT,
+            Self::PrevBssid(_) => NL80211_ATTR_PREV_BSSID,
+            Self::LocalStateChange => NL80211_ATTR_LOCAL_STATE_CHANGE,
+            Self::ProtocolFeatures(_) => NL80211_ATTR_PROTOCOL_FEATURES,
+            Self::TxRates(_) => NL80211_ATTR_TX_RATES,
+            Self::StationFlags2(_) => NL80211_ATTR_STA_FLAGS2,
+            Self::BssDumpIncludeUseData => {
+                NL80211_ATTR_BSS_DUMP_INCLUDE_USE_DATA
+            }
+            Self::WiphyTxPowerSetting(_) => NL80211_ATTR_WIPHY_TX_POWER_SETTING,
+            Self::WowlanTriggers(_) => NL80211_ATTR_WOWLAN_TRIGGERS,
+            Self::MonitorFlags(_) => NL80211_ATTR_MNTR_FLAGS,
+            Self::SurveyInfo(_) => NL80211_ATTR_SURVEY_INFO,
+            Self::SurveyRadioStats => NL80211_ATTR_SURVEY_RADIO_STATS,
+            Self::Key(_) => NL80211_ATTR_KEY,
+            Self::KeyDefaultTypes(_) => NL80211_ATTR_KEY_DEFAULT_TYPES,
+            Self::SchedScanRelativeRssi(_) => {
+                NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI
+            }
+            Self::SchedScanRssiAdjust(_) => NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST,
+            Self::MeasurementDurationMandatory => {
+                NL80211_ATTR_MEASUREMENT_DURATION_MANDATORY
+            }
+            Self::Bssid(_) => NL80211_ATTR_BSSID,
+            Self::WiphyTxqParams(_) => NL80211_ATTR_WIPHY_TXQ_PARAMS,
+            Self::VendorId(_) => NL80211_ATTR_VENDOR_ID,
+            Self::VendorSubcmd(_) => NL80211_ATTR_VENDOR_SUBCMD,
+            Self::VendorData(_) => NL80211_ATTR_VENDOR_DATA,
+            Self::VendorEvents(_) => NL80211_ATTR_VENDOR_EVENTS,
+            Self::Pid(_) => NL80211_ATTR_PID,
+            Self::NetnsFd(_) => NL80211_ATTR_NETNS_FD,
+            Self::FtmResponderStats(_) => NL80211_ATTR_FTM_RESPONDER_STATS,
+            Self::Timeout(_) => NL80211_ATTR_TIMEOUT,
+            Self::PeerMeasurements(_) => NL80211_ATTR_PEER_MEASUREMENTS,
+            Self::P2pCtwindow(_) => NL80211_ATTR_P2P_CTWINDOW,
+            Self::P2pOppps(_) => NL80211_ATTR_P2P_OPPPS,
+            Self::RxmgmtFlags(_) => NL80211_ATTR_RXMGMT_FLAGS,
+            Self::BeaconHead(_) => NL80211_ATTR_BEACON_HEAD,
+            Self::BeaconTail(_) => NL80211_ATTR_BEACON_TAIL,
+            Self::IeProbeResp(_) => NL80211_ATTR_IE_PROBE_RESP,
+            Self::IeAssocResp(_) => NL80211_ATTR_IE_ASSOC_RESP,
+            Self::ProbeResp(_) => NL80211_ATTR_PROBE_RESP,
+            Self::ChSwitchCount(_) => NL80211_ATTR_CH_SWITCH_COUNT,
+            Self::ChSwitchBlockTx => NL80211_ATTR_CH_SWITCH_BLOCK_TX,
+            Self::CsaIes(_) => NL80211_ATTR_CSA_IES,
+            Self::CntdwnOffsBeacon(_) => NL80211_ATTR_CNTDWN_OFFS_BEACON,
+            Self::CntdwnOffsPresp(_) => NL80211_ATTR_CNTDWN_OFFS_PRESP,
+            Self::MeshId(_) => NL80211_ATTR_MESH_ID,
+            Self::RadarEvent(_) => NL80211_ATTR_RADAR_EVENT,
+            Self::RadarBackground => NL80211_ATTR_RADAR_BACKGROUND,
+            Self::TidConfig(_) | Self::TidConfigSupport(_) => {
+                NL80211_ATTR_TID_CONFIG
+            }
+            Self::CoalesceRule(_) | Self::CoalesceRuleSupport(_) => {
+                NL80211_ATTR_COALESCE_RULE
+            }
+            Self::SarSpec(_) => NL80211_ATTR_SAR_SPEC,
+            Self::ObssColorBitmap(_) => NL80211_ATTR_OBSS_COLOR_BITMAP,
+            Self::ColorChangeCount(_) => NL80211_ATTR_COLOR_CHANGE_COUNT,
+            Self::ColorChangeColor(_) => NL80211_ATTR_COLOR_CHANGE_COLOR,
+            Self::ColorChangeElems(_) => NL80211_ATTR_COLOR_CHANGE_ELEMS,
+            Self::BeaconInterval(_) => NL80211_ATTR_BEACON_INTERVAL,
+            Self::DtimPeriod(_) => NL80211_ATTR_DTIM_PERIOD,
+            Self::FilsDiscovery(_) => NL80211_ATTR_FILS_DISCOVERY,
+            Self::UnsolBcastProbeResp(_) => NL80211_ATTR_UNSOL_BCAST_PROBE_RESP,
+            Self::AkmSuites(_) => NL80211_ATTR_AKM_SUITES,
+            Self::Pmkid(_) => NL80211_ATTR_PMKID,
+            Self::ExternalAuthAction(_) => NL80211_ATTR_EXTERNAL_AUTH_ACTION,
+            Self::ExternalAuthSupport => NL80211_ATTR_EXTERNAL_AUTH_SUPPORT,
+            Self::MloLinkId(_) => NL80211_ATTR_MLO_LINK_ID,
+            Self::MldAddr(_) => NL80211_ATTR_MLD_ADDR,
+            Self::RekeyData(_) => NL80211_ATTR_REKEY_DATA,
+            Self::ControlPort => NL80211_ATTR_CONTROL_PORT,
+            Self::ControlPortEthertypeValue(_) => {
+                NL80211_ATTR_CONTROL_PORT_ETHERTYPE
+            }
+            Self::ControlPortNoEncrypt => NL80211_ATTR_CONTROL_PORT_NO_ENCRYPT,
+            Self::ControlPortOverNl80211 => {
+                NL80211_ATTR_CONTROL_PORT_OVER_NL80211
+            }
+            Self::ControlPortNoPreauth => NL80211_ATTR_CONTROL_PORT_NO_PREAUTH,
+            Self::SocketOwner => NL80211_ATTR_SOCKET_OWNER,
+            Self::CritProtId(_) => NL80211_ATTR_CRIT_PROT_ID,
+            Self::MaxCritProtDuration(_) => NL80211_ATTR_MAX_CRIT_PROT_DURATION,
+            Self::FilsErpUsername(_) => NL80211_ATTR_FILS_ERP_USERNAME,
+            Self::FilsErpRealm(_) => NL80211_ATTR_FILS_ERP_REALM,
+            Self::FilsErpNextSeqNum(_) => NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM,
+            Self::FilsErpRrk(_) => NL80211_ATTR_FILS_ERP_RRK,
+            Self::MgmtSubtype(_) => NL80211_ATTR_MGMT_SUBTYPE,
+            Self::MulticastToUnicastEnabled => {
+                NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED
+            }
+            Self::NoackMap(_) => NL80211_ATTR_NOACK_MAP,
+            Self::StaTxPowerSetting(_) => NL80211_ATTR_STA_TX_POWER_SETTING,
+            Self::StaTxPower(_) => NL80211_ATTR_STA_TX_POWER,
+            Self::AckSignal(_) => NL80211_ATTR_ACK_SIGNAL,
+            Self::DisconnectedByAp => NL80211_ATTR_DISCONNECTED_BY_AP,
+            Self::StaSupportedRates(_) => NL80211_ATTR_STA_SUPPORTED_RATES,
+            Self::MloTtlmDlink(_) => NL80211_ATTR_MLO_TTLM_DLINK,
+            Self::MloTtlmUlink(_) => NL80211_ATTR_MLO_TTLM_ULINK,
+            Self::HwTimestampEnabled => NL80211_ATTR_HW_TIMESTAMP_ENABLED,
+            Self::TxNoCckRate => NL80211_ATTR_TX_NO_CCK_RATE,
+            Self::WiphyEdmgChannels(_) => NL80211_ATTR_WIPHY_EDMG_CHANNELS,
+            Self::WiphyEdmgBwConfig(_) => NL80211_ATTR_WIPHY_EDMG_BW_CONFIG,
+            Self::CenterFreq1Offset(_) => NL80211_ATTR_CENTER_FREQ1_OFFSET,
+            Self::WiphyRadios(_) => NL80211_ATTR_WIPHY_RADIOS,
+            Self::WiphyInterfaceCombinations(_) => {
+                NL80211_ATTR_WIPHY_INTERFACE_COMBINATIONS
+            }
+            Self::MalformedNested(kind, _, _) => *kind,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -793,10 +1283,7 @@ impl Nla for Nl80211Attr {
             Self::IfIndex(d)
             | Self::Wiphy(d)
             | Self::Generation(d)
-            | Self::WiphyFreq(d)
             | Self::WiphyFreqOffset(d)
-            | Self::CenterFreq1(d)
-            | Self::CenterFreq2(d)
             | Self::WiphyTxPowerLevel(d)
             | Self::WiphyFragThreshold(d)
             | Self::WiphyRtsThreshold(d)
@@ -813,20 +1300,25 @@ impl Nla for Nl80211Attr {
             | Self::TransmitQueueMemoryLimit(d)
             | Self::TransmitQueueQuantum(d)
             | Self::SchedScanInterval(d)
-            | Self::SchedScanDelay(d) => write_u32(buffer, *d),
+            | Self::SchedScanDelay(d)
+            | Self::Duration(d) => write_u32(buffer, *d),
+            Self::WiphyFreq(d)
+            | Self::CenterFreq1(d)
+            | Self::CenterFreq2(d) => write_u32(buffer, d.mhz()),
             Self::MaxScanIeLen(d) | Self::MaxSchedScanIeLen(d) => {
                 write_u16(buffer, *d)
             }
-            Self::Wdev(d) => write_u64(buffer, *d),
+            Self::Wdev(d) | Self::Cookie(d) => write_u64(buffer, *d),
             Self::IfType(d) => write_u32(buffer, (*d).into()),
             Self::Mac(s) | Self::MacMask(s) => buffer.copy_from_slice(s),
             Self::MacAddrs(s) => {
                 MacAddressNlas::from(s).as_slice().emit(buffer)
             }
-            Self::IfName(s) | Self::Ssid(s) | Self::WiphyName(s) => {
+            Self::IfName(s) | Self::WiphyName(s) => {
                 buffer[..s.len()].copy_from_slice(s.as_bytes());
                 buffer[s.len()] = 0;
             }
+            Self::Ssid(s) => buffer.copy_from_slice(s.as_bytes()),
             Self::Use4Addr(d) => buffer[0] = *d as u8,
             Self::SupportIbssRsn
             | Self::SupportMeshAuth
@@ -849,9 +1341,9 @@ impl Nla for Nl80211Attr {
             | Self::MaxNumSchedScanSsids(d)
             | Self::MaxMatchSets(d)
             | Self::MaxNumPmkids(d) => buffer[0] = *d,
-            Self::CipherSuites(suits) => {
+            Self::CipherSuites(suites) => {
                 let nums: Vec<u32> =
-                    suits.as_slice().iter().map(|s| u32::from(*s)).collect();
+                    suites.as_slice().iter().map(|s| u32::from(*s)).collect();
                 for (i, v) in nums.as_slice().iter().enumerate() {
                     buffer[i * 4..(i + 1) * 4]
                         .copy_from_slice(&v.to_ne_bytes());
@@ -864,7 +1356,7 @@ impl Nla for Nl80211Attr {
                 Nl80211Commands::from(s).as_slice().emit(buffer)
             }
             Self::MaxRemainOnChannelDuration(d) => write_u32(buffer, *d),
-            Self::WowlanTrigersSupport(s) => s.as_slice().emit(buffer),
+            Self::WowlanTriggersSupport(s) => s.as_slice().emit(buffer),
             Self::SoftwareIftypes(s) => {
                 Nl80211InterfaceTypes::from(s).as_slice().emit(buffer)
             }
@@ -884,9 +1376,9 @@ impl Nla for Nl80211Attr {
             Self::IfTypeExtCap(s) => {
                 Nl80211IfTypeExtCapas::from(s).as_slice().emit(buffer)
             }
-            Self::EmlCapability(d)
-            | Self::MldCapaAndOps(d)
-            | Self::MaxNumAkmSuites(d)
+            Self::EmlCapability(d) => write_u16(buffer, d.0),
+            Self::MldCapaAndOps(d) => write_u16(buffer, d.0),
+            Self::MaxNumAkmSuites(d)
             | Self::MaxHwTimestampPeers(d)
             | Self::MeasurementDuration(d) => write_u16(buffer, *d),
             Self::Bands(v) => v.emit(buffer),
@@ -900,6 +1392,130 @@ impl Nla for Nl80211Attr {
             }
             Self::SchedScanMatch(v) => v.as_slice().emit(buffer),
             Self::SchedScanPlans(v) => v.as_slice().emit(buffer),
+            Self::Frame(v) | Self::FrameMatch(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_slice())
+            }
+            Self::FrameType(v) => v.emit_value(buffer),
+            Self::Ack | Self::DontWaitForAck => (),
+            Self::RxSignalDbm(d) => write_i32(buffer, *d),
+            Self::Ie(v) => buffer[..v.len()].copy_from_slice(v.as_slice()),
+            Self::AuthType(d) => write_u32(buffer, (*d).into()),
+            Self::ReasonCode(d) | Self::StatusCode(d) => write_u16(buffer, *d),
+            Self::TimedOut | Self::LocalStateChange => (),
+            Self::PrevBssid(s) => buffer.copy_from_slice(s),
+            Self::ProtocolFeatures(d) => write_u32(buffer, d.bits()),
+            Self::TxRates(v) => v.as_slice().emit(buffer),
+            Self::StationFlags2(v) => v.emit(buffer),
+            Self::BssDumpIncludeUseData => (),
+            Self::WiphyTxPowerSetting(d) => write_u32(buffer, (*d).into()),
+            Self::WowlanTriggers(s) => s.as_slice().emit(buffer),
+            Self::MonitorFlags(s) => s.as_slice().emit(buffer),
+            Self::SurveyInfo(s) => s.as_slice().emit(buffer),
+            Self::SurveyRadioStats => (),
+            Self::Key(s) => s.as_slice().emit(buffer),
+            Self::KeyDefaultTypes(s) => s.as_slice().emit(buffer),
+            Self::SchedScanRelativeRssi(d) => buffer[0] = *d as u8,
+            Self::SchedScanRssiAdjust(v) => v.emit(buffer),
+            Self::MeasurementDurationMandatory => (),
+            Self::Bssid(s) => buffer.copy_from_slice(s),
+            Self::WiphyTxqParams(v) => {
+                Nl80211TxqParamsNla::new_list(v).as_slice().emit(buffer)
+            }
+            Self::VendorId(d) | Self::VendorSubcmd(d) => write_u32(buffer, *d),
+            Self::VendorData(v) => buffer[..v.len()].copy_from_slice(v),
+            Self::VendorEvents(v) => {
+                Nl80211VendorCmdInfoNla::new_list(v).as_slice().emit(buffer)
+            }
+            Self::Pid(d) | Self::NetnsFd(d) => write_u32(buffer, *d),
+            Self::FtmResponderStats(v) => v.as_slice().emit(buffer),
+            Self::Timeout(d) => write_u32(buffer, *d),
+            Self::PeerMeasurements(v) => v.as_slice().emit(buffer),
+            Self::P2pCtwindow(d) => buffer[0] = *d,
+            Self::P2pOppps(d) => buffer[0] = *d as u8,
+            Self::RxmgmtFlags(d) => write_u32(buffer, d.bits()),
+            Self::BeaconHead(v)
+            | Self::BeaconTail(v)
+            | Self::IeProbeResp(v)
+            | Self::IeAssocResp(v)
+            | Self::ProbeResp(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_slice())
+            }
+            Self::ChSwitchCount(d) => write_u32(buffer, *d),
+            Self::ChSwitchBlockTx => (),
+            Self::CsaIes(v) => v.as_slice().emit(buffer),
+            Self::CntdwnOffsBeacon(v) | Self::CntdwnOffsPresp(v) => {
+                for (i, d) in v.iter().enumerate() {
+                    write_u16(&mut buffer[i * 2..], *d);
+                }
+            }
+            Self::MeshId(v) => buffer[..v.len()].copy_from_slice(v.as_slice()),
+            Self::RadarEvent(d) => write_u32(buffer, (*d).into()),
+            Self::RadarBackground => (),
+            Self::TidConfig(v) => v.as_slice().emit(buffer),
+            Self::TidConfigSupport(v) => v.emit(buffer),
+            Self::CoalesceRule(v) => v.as_slice().emit(buffer),
+            Self::CoalesceRuleSupport(v) => v.emit(buffer),
+            Self::SarSpec(v) => v.as_slice().emit(buffer),
+            Self::ObssColorBitmap(d) => write_u64(buffer, *d),
+            Self::ColorChangeCount(d) | Self::ColorChangeColor(d) => {
+                buffer[0] = *d
+            }
+            Self::ColorChangeElems(v) => v.as_slice().emit(buffer),
+            Self::BeaconInterval(d) | Self::DtimPeriod(d) => {
+                write_u32(buffer, *d)
+            }
+            Self::FilsDiscovery(v) => v.as_slice().emit(buffer),
+            Self::UnsolBcastProbeResp(v) => v.as_slice().emit(buffer),
+            Self::AkmSuites(suites) => {
+                for (i, v) in suites.as_slice().iter().enumerate() {
+                    buffer[i * 4..(i + 1) * 4]
+                        .copy_from_slice(&v.suite_selector().to_ne_bytes());
+                }
+            }
+            Self::Pmkid(v) => buffer[..WLAN_PMKID_LEN].copy_from_slice(v),
+            Self::ExternalAuthAction(d) => write_u32(buffer, (*d).into()),
+            Self::ExternalAuthSupport => (),
+            Self::MloLinkId(d) => buffer[0] = *d,
+            Self::MldAddr(s) => buffer[..ETH_ALEN].copy_from_slice(s),
+            Self::RekeyData(v) => v.as_slice().emit(buffer),
+            Self::ControlPortEthertypeValue(d) => write_u16(buffer, *d),
+            Self::ControlPort
+            | Self::ControlPortNoEncrypt
+            | Self::ControlPortOverNl80211
+            | Self::ControlPortNoPreauth => (),
+            Self::SocketOwner => (),
+            Self::CritProtId(d) => write_u16(buffer, (*d).into()),
+            Self::MaxCritProtDuration(d) => write_u16(buffer, *d),
+            Self::FilsErpUsername(v)
+            | Self::FilsErpRealm(v)
+            | Self::FilsErpRrk(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_slice())
+            }
+            Self::FilsErpNextSeqNum(d) => write_u16(buffer, *d),
+            Self::MgmtSubtype(d) => buffer[0] = d.subtype(),
+            Self::MulticastToUnicastEnabled => (),
+            Self::NoackMap(d) => write_u16(buffer, *d),
+            Self::StaTxPowerSetting(d) => buffer[0] = u32::from(*d) as u8,
+            Self::StaTxPower(d) => write_u16(buffer, *d as u16),
+            Self::AckSignal(d) => write_i32(buffer, *d),
+            Self::DisconnectedByAp => (),
+            Self::StaSupportedRates(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::MloTtlmDlink(d) | Self::MloTtlmUlink(d) => {
+                for (i, link_map) in d.iter().enumerate() {
+                    write_u16(&mut buffer[i * 2..], *link_map)
+                }
+            }
+            Self::HwTimestampEnabled => (),
+            Self::TxNoCckRate => (),
+            Self::WiphyEdmgChannels(d) | Self::WiphyEdmgBwConfig(d) => {
+                buffer[0] = *d
+            }
+            Self::CenterFreq1Offset(d) => write_u32(buffer, *d),
+            Self::WiphyRadios(v) => v.as_slice().emit(buffer),
+            Self::WiphyInterfaceCombinations(v) => v.as_slice().emit(buffer),
+            Self::MalformedNested(_, d, _) => {
+                buffer[..d.len()].copy_from_slice(d.as_slice())
+            }
             Self::Other(attr) => attr.emit(buffer),
         }
     }
@@ -995,7 +1611,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                     "Invalid NL80211_ATTR_WIPHY_FREQ value {:?}",
                     payload
                 );
-                Self::WiphyFreq(parse_u32(payload).context(err_msg)?)
+                Self::WiphyFreq(Nl80211Freq::from_mhz(
+                    parse_u32(payload).context(err_msg)?,
+                ))
             }
             NL80211_ATTR_WIPHY_FREQ_OFFSET => {
                 let err_msg = format!(
@@ -1025,14 +1643,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                     "Invalid NL80211_ATTR_CENTER_FREQ1 value {:?}",
                     payload
                 );
-                Self::CenterFreq1(parse_u32(payload).context(err_msg)?)
+                Self::CenterFreq1(Nl80211Freq::from_mhz(
+                    parse_u32(payload).context(err_msg)?,
+                ))
             }
             NL80211_ATTR_CENTER_FREQ2 => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_CENTER_FREQ2 value {:?}",
                     payload
                 );
-                Self::CenterFreq2(parse_u32(payload).context(err_msg)?)
+                Self::CenterFreq2(Nl80211Freq::from_mhz(
+                    parse_u32(payload).context(err_msg)?,
+                ))
             }
             NL80211_ATTR_WIPHY_TX_POWER_LEVEL => {
                 let err_msg = format!(
@@ -1041,11 +1663,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 );
                 Self::WiphyTxPowerLevel(parse_u32(payload).context(err_msg)?)
             }
-            NL80211_ATTR_SSID => {
-                let err_msg =
-                    format!("Invalid NL80211_ATTR_SSID value {:?}", payload);
-                Self::Ssid(parse_string(payload).context(err_msg)?)
-            }
+            NL80211_ATTR_SSID => Self::Ssid(Nl80211Ssid::from(payload)),
             NL80211_ATTR_STA_INFO => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_STA_INFO value {:?}",
@@ -1186,15 +1804,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                     "Invalid NL80211_ATTR_CIPHER_SUITES value {:?}",
                     payload
                 );
-                let mut suits = Vec::new();
+                let mut suites = Vec::new();
                 for i in 0..(payload.len() / 4) {
-                    suits.push(
+                    suites.push(
                         parse_u32(&payload[i * 4..(i + 1) * 4])
                             .context(err_msg.clone())?
                             .into(),
                     );
                 }
-                Self::CipherSuites(suits)
+                Self::CipherSuites(suites)
             }
             NL80211_ATTR_MAX_NUM_PMKIDS => {
                 let err_msg = format!(
@@ -1203,7 +1821,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 );
                 Self::MaxNumPmkids(parse_u8(payload).context(err_msg)?)
             }
-            NL80211_ATTR_CONTROL_PORT_ETHERTYPE => Self::ControlPortEthertype,
+            // Flag in wiphy dump, ethertype value in other messages
+            NL80211_ATTR_CONTROL_PORT_ETHERTYPE => {
+                if payload.is_empty() {
+                    Self::ControlPortEthertype
+                } else {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_CONTROL_PORT_ETHERTYPE value {:?}",
+                        payload
+                    );
+                    Self::ControlPortEthertypeValue(
+                        parse_u16(payload).context(err_msg)?,
+                    )
+                }
+            }
             NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX => {
                 let err_msg = format!(
                     "Invalid NL80211_ATTR_WIPHY_ANTENNA_AVAIL_TX value {:?}",
@@ -1287,9 +1918,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                         nla
                     );
                     let nla = &nla.context(err_msg.clone())?;
-                    nlas.push(Nl80211WowlanTrigersSupport::parse(nla)?);
+                    nlas.push(Nl80211WowlanTriggersSupport::parse(nla)?);
                 }
-                Self::WowlanTrigersSupport(nlas)
+                Self::WowlanTriggersSupport(nlas)
             }
             NL80211_ATTR_OFFCHANNEL_TX_OK => Self::OffchannelTxOk,
             NL80211_ATTR_SOFTWARE_IFTYPES => Self::SoftwareIftypes(
@@ -1308,20 +1939,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 Self::ExtFeatures(Nl80211ExtFeatures::parse(payload)?.0)
             }
             NL80211_ATTR_INTERFACE_COMBINATIONS => {
-                let mut nlas = Vec::new();
-                for (index, nla) in NlasIterator::new(payload).enumerate() {
-                    let err_msg = format!(
-                        "Invalid NL80211_ATTR_INTERFACE_COMBINATIONS \
-                        value {:?}",
-                        nla
-                    );
-                    let nla = &nla.context(err_msg.clone())?;
-                    nlas.push(Nl80211IfaceComb::parse_with_param(
-                        nla,
-                        index as u16,
-                    )?);
-                }
-                Self::InterfaceCombination(nlas)
+                Self::InterfaceCombination(parse_iface_combinations(
+                    payload,
+                    "NL80211_ATTR_INTERFACE_COMBINATIONS",
+                )?)
             }
             NL80211_ATTR_HT_CAPABILITY_MASK => {
                 Self::HtCapabilityMask(Nl80211HtCapabilityMask::new(payload))
@@ -1400,16 +2021,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
             NL80211_ATTR_IFTYPE_EXT_CAPA => {
                 Self::IfTypeExtCap(Nl80211IfTypeExtCapas::parse(buf)?.into())
             }
-            NL80211_ATTR_EML_CAPABILITY => {
-                Self::EmlCapability(parse_u16(payload).context(format!(
-                    "Invalid NL80211_ATTR_EML_CAPABILITY {payload:?}"
-                ))?)
-            }
-            NL80211_ATTR_MLD_CAPA_AND_OPS => {
-                Self::MldCapaAndOps(parse_u16(payload).context(format!(
-                    "Invalid NL80211_ATTR_MLD_CAPA_AND_OPS {payload:?}"
-                ))?)
-            }
+            NL80211_ATTR_EML_CAPABILITY => Self::EmlCapability(
+                parse_u16(payload)
+                    .context(format!(
+                        "Invalid NL80211_ATTR_EML_CAPABILITY {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            NL80211_ATTR_MLD_CAPA_AND_OPS => Self::MldCapaAndOps(
+                parse_u16(payload)
+                    .context(format!(
+                        "Invalid NL80211_ATTR_MLD_CAPA_AND_OPS {payload:?}"
+                    ))?
+                    .into(),
+            ),
             NL80211_ATTR_BANDS => {
                 Self::Bands(Nl80211BandTypes::parse(payload)?)
             }
@@ -1479,9 +2104,722 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nl80211Attr {
                 }
                 Self::SchedScanPlans(nlas)
             }
+            NL80211_ATTR_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DURATION value {:?}",
+                    payload
+                );
+                Self::Duration(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COOKIE => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_COOKIE value {:?}", payload);
+                Self::Cookie(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FRAME => Self::Frame(payload.to_vec()),
+            NL80211_ATTR_FRAME_MATCH => Self::FrameMatch(payload.to_vec()),
+            NL80211_ATTR_FRAME_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FRAME_TYPE value {:?}",
+                    payload
+                );
+                Self::FrameType(parse_u16(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_ACK => Self::Ack,
+            NL80211_ATTR_DONT_WAIT_FOR_ACK => Self::DontWaitForAck,
+            NL80211_ATTR_RX_SIGNAL_DBM => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RX_SIGNAL_DBM value {:?}",
+                    payload
+                );
+                Self::RxSignalDbm(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_IE => Self::Ie(payload.to_vec()),
+            NL80211_ATTR_AUTH_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_AUTH_TYPE value {:?}",
+                    payload
+                );
+                Self::AuthType(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_REASON_CODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REASON_CODE value {:?}",
+                    payload
+                );
+                Self::ReasonCode(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STATUS_CODE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STATUS_CODE value {:?}",
+                    payload
+                );
+                Self::StatusCode(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_TIMED_OUT => Self::TimedOut,
+            NL80211_ATTR_PREV_BSSID => {
+                Self::PrevBssid(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_ATTR_PREV_BSSID, \
+                    expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_ATTR_LOCAL_STATE_CHANGE => Self::LocalStateChange,
+            NL80211_ATTR_PROTOCOL_FEATURES => Self::ProtocolFeatures(
+                Nl80211ProtocolFeatures::from_bits_retain(
+                    parse_u32(payload).context(format!(
+                        "Invalid NL80211_ATTR_PROTOCOL_FEATURES {payload:?}"
+                    ))?,
+                ),
+            ),
+            NL80211_ATTR_TX_RATES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_TX_RATES value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211TxRateMask::parse(nla)?);
+                }
+                Self::TxRates(nlas)
+            }
+            NL80211_ATTR_STA_FLAGS2 => Self::StationFlags2(
+                Nl80211StationFlagUpdate::parse(payload)
+                    .context("Invalid NL80211_ATTR_STA_FLAGS2")?,
+            ),
+            NL80211_ATTR_BSS_DUMP_INCLUDE_USE_DATA => {
+                Self::BssDumpIncludeUseData
+            }
+            NL80211_ATTR_WIPHY_TX_POWER_SETTING => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_TX_POWER_SETTING value {:?}",
+                    payload
+                );
+                Self::WiphyTxPowerSetting(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_WOWLAN_TRIGGERS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_WOWLAN_TRIGGERS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211WowlanTrigger::parse(nla)?);
+                }
+                Self::WowlanTriggers(nlas)
+            }
+            NL80211_ATTR_MNTR_FLAGS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_MNTR_FLAGS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211MonitorFlag::parse(nla)?);
+                }
+                Self::MonitorFlags(nlas)
+            }
+            NL80211_ATTR_SURVEY_INFO => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SURVEY_INFO value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211SurveyInfo::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::SurveyInfo(nlas)
+            }
+            NL80211_ATTR_SURVEY_RADIO_STATS => Self::SurveyRadioStats,
+            NL80211_ATTR_KEY => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_KEY value {:?}", payload);
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211KeyAttr::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::Key(nlas)
+            }
+            NL80211_ATTR_KEY_DEFAULT_TYPES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_KEY_DEFAULT_TYPES value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211KeyDefaultType::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::KeyDefaultTypes(nlas)
+            }
+            NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_SCHED_SCAN_RELATIVE_RSSI value {:?}",
+                    payload
+                );
+                Self::SchedScanRelativeRssi(
+                    parse_u8(payload).context(err_msg)? as i8,
+                )
+            }
+            NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST => Self::SchedScanRssiAdjust(
+                Nl80211SchedScanRssiAdjust::parse(payload)?,
+            ),
+            NL80211_ATTR_MEASUREMENT_DURATION_MANDATORY => {
+                Self::MeasurementDurationMandatory
+            }
+            NL80211_ATTR_BSSID => Self::Bssid(if payload.len() == ETH_ALEN {
+                let mut ret = [0u8; ETH_ALEN];
+                ret.copy_from_slice(&payload[..ETH_ALEN]);
+                ret
+            } else {
+                return Err(format!(
+                    "Invalid length of NL80211_ATTR_BSSID, \
+                    expected length {} got {:?}",
+                    ETH_ALEN, payload
+                )
+                .into());
+            }),
+            NL80211_ATTR_WIPHY_TXQ_PARAMS => {
+                let mut params = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_WIPHY_TXQ_PARAMS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    params.push(Nl80211TxqParamsNla::parse(nla)?.into());
+                }
+                Self::WiphyTxqParams(params)
+            }
+            NL80211_ATTR_VENDOR_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_VENDOR_ID value {:?}",
+                    payload
+                );
+                Self::VendorId(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_VENDOR_SUBCMD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_VENDOR_SUBCMD value {:?}",
+                    payload
+                );
+                Self::VendorSubcmd(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_VENDOR_DATA => Self::VendorData(payload.to_vec()),
+            NL80211_ATTR_VENDOR_EVENTS => {
+                let mut infos = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_VENDOR_EVENTS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    infos.push(
+                        Nl80211VendorCmdInfoNla::parse(nla)
+                            .context(err_msg)?
+                            .into(),
+                    );
+                }
+                Self::VendorEvents(infos)
+            }
+            NL80211_ATTR_PID => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_PID value {:?}", payload);
+                Self::Pid(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_NETNS_FD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_NETNS_FD value {:?}",
+                    payload
+                );
+                Self::NetnsFd(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FTM_RESPONDER_STATS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FTM_RESPONDER_STATS value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211FtmResponderStats::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::FtmResponderStats(nlas)
+            }
+            NL80211_ATTR_TIMEOUT => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_TIMEOUT value {:?}", payload);
+                Self::Timeout(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_PEER_MEASUREMENTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_PEER_MEASUREMENTS value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PeerMeasurementAttr::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::PeerMeasurements(nlas)
+            }
+            NL80211_ATTR_P2P_CTWINDOW => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_P2P_CTWINDOW value {:?}",
+                    payload
+                );
+                Self::P2pCtwindow(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_P2P_OPPPS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_P2P_OPPPS value {:?}",
+                    payload
+                );
+                Self::P2pOppps(parse_u8(payload).context(err_msg)? > 0)
+            }
+            NL80211_ATTR_RXMGMT_FLAGS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RXMGMT_FLAGS value {:?}",
+                    payload
+                );
+                Self::RxmgmtFlags(Nl80211RxMgmtFlags::from_bits_retain(
+                    parse_u32(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_ATTR_BEACON_HEAD => Self::BeaconHead(payload.to_vec()),
+            NL80211_ATTR_BEACON_TAIL => Self::BeaconTail(payload.to_vec()),
+            NL80211_ATTR_IE_PROBE_RESP => Self::IeProbeResp(payload.to_vec()),
+            NL80211_ATTR_IE_ASSOC_RESP => Self::IeAssocResp(payload.to_vec()),
+            NL80211_ATTR_PROBE_RESP => Self::ProbeResp(payload.to_vec()),
+            NL80211_ATTR_CH_SWITCH_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CH_SWITCH_COUNT value {:?}",
+                    payload
+                );
+                Self::ChSwitchCount(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CH_SWITCH_BLOCK_TX => Self::ChSwitchBlockTx,
+            NL80211_ATTR_CSA_IES => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_CSA_IES value {:?}", payload);
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Self::parse(nla).context(err_msg.clone())?);
+                }
+                Self::CsaIes(nlas)
+            }
+            NL80211_ATTR_CNTDWN_OFFS_BEACON => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CNTDWN_OFFS_BEACON value {:?}",
+                    payload
+                );
+                let mut offsets = Vec::new();
+                for chunk in payload.chunks(2) {
+                    offsets.push(parse_u16(chunk).context(err_msg.clone())?);
+                }
+                Self::CntdwnOffsBeacon(offsets)
+            }
+            NL80211_ATTR_CNTDWN_OFFS_PRESP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CNTDWN_OFFS_PRESP value {:?}",
+                    payload
+                );
+                let mut offsets = Vec::new();
+                for chunk in payload.chunks(2) {
+                    offsets.push(parse_u16(chunk).context(err_msg.clone())?);
+                }
+                Self::CntdwnOffsPresp(offsets)
+            }
+            NL80211_ATTR_MESH_ID => Self::MeshId(payload.to_vec()),
+            NL80211_ATTR_RADAR_EVENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_RADAR_EVENT value {:?}",
+                    payload
+                );
+                Self::RadarEvent(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_RADAR_BACKGROUND => Self::RadarBackground,
+            NL80211_ATTR_TID_CONFIG
+                if Nl80211TidConfigSupport::is_support_payload(payload) =>
+            {
+                Self::TidConfigSupport(Nl80211TidConfigSupport::parse(payload)?)
+            }
+            NL80211_ATTR_TID_CONFIG => {
+                let mut nlas = Vec::new();
+                for (index, nla) in NlasIterator::new(payload).enumerate() {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_TID_CONFIG value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211TidConfig::parse_with_param(
+                        nla,
+                        index as u16,
+                    )?);
+                }
+                Self::TidConfig(nlas)
+            }
+            NL80211_ATTR_COALESCE_RULE
+                if Nl80211CoalesceSupport::is_support_payload(payload) =>
+            {
+                Self::CoalesceRuleSupport(Nl80211CoalesceSupport::parse(
+                    payload,
+                )?)
+            }
+            NL80211_ATTR_COALESCE_RULE => {
+                let mut nlas = Vec::new();
+                for (index, nla) in NlasIterator::new(payload).enumerate() {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_COALESCE_RULE value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211CoalesceRule::parse_with_param(
+                        nla,
+                        index as u16,
+                    )?);
+                }
+                Self::CoalesceRule(nlas)
+            }
+            NL80211_ATTR_SAR_SPEC => {
+                let err_msg =
+                    format!("Invalid NL80211_ATTR_SAR_SPEC value {payload:?}");
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211SarAttr::parse(nla)?);
+                }
+                Self::SarSpec(nlas)
+            }
+            NL80211_ATTR_OBSS_COLOR_BITMAP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_OBSS_COLOR_BITMAP value {:?}",
+                    payload
+                );
+                Self::ObssColorBitmap(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COLOR_CHANGE_COUNT => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_COUNT value {:?}",
+                    payload
+                );
+                // Kernel sends u32 in COLOR_CHANGE_STARTED notification
+                Self::ColorChangeCount(if payload.len() == 4 {
+                    parse_u32(payload).context(err_msg)? as u8
+                } else {
+                    parse_u8(payload).context(err_msg)?
+                })
+            }
+            NL80211_ATTR_COLOR_CHANGE_COLOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_COLOR value {:?}",
+                    payload
+                );
+                Self::ColorChangeColor(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_COLOR_CHANGE_ELEMS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_COLOR_CHANGE_ELEMS value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Self::parse(nla).context(err_msg.clone())?);
+                }
+                Self::ColorChangeElems(nlas)
+            }
+            NL80211_ATTR_BEACON_INTERVAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_BEACON_INTERVAL value {:?}",
+                    payload
+                );
+                Self::BeaconInterval(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_DTIM_PERIOD => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_DTIM_PERIOD value {:?}",
+                    payload
+                );
+                Self::DtimPeriod(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FILS_DISCOVERY => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FILS_DISCOVERY value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211FilsDiscovery::parse(nla)?);
+                }
+                Self::FilsDiscovery(nlas)
+            }
+            NL80211_ATTR_UNSOL_BCAST_PROBE_RESP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_UNSOL_BCAST_PROBE_RESP value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211UnsolBcastProbeResp::parse(nla)?);
+                }
+                Self::UnsolBcastProbeResp(nlas)
+            }
+            NL80211_ATTR_AKM_SUITES => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_AKM_SUITES value {:?}",
+                    payload
+                );
+                let mut suites = Vec::new();
+                for i in 0..(payload.len() / 4) {
+                    suites.push(Nl80211AkmSuite::from_suite_selector(
+                        parse_u32(&payload[i * 4..(i + 1) * 4])
+                            .context(err_msg.clone())?,
+                    ));
+                }
+                Self::AkmSuites(suites)
+            }
+            NL80211_ATTR_PMKID => {
+                Self::Pmkid(if payload.len() == WLAN_PMKID_LEN {
+                    let mut ret = [0u8; WLAN_PMKID_LEN];
+                    ret.copy_from_slice(payload);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_ATTR_PMKID, \
+                        expected length {} got {:?}",
+                        WLAN_PMKID_LEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_ATTR_EXTERNAL_AUTH_ACTION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_EXTERNAL_AUTH_ACTION value {:?}",
+                    payload
+                );
+                Self::ExternalAuthAction(
+                    parse_u32(payload).context(err_msg)?.into(),
+                )
+            }
+            NL80211_ATTR_EXTERNAL_AUTH_SUPPORT => Self::ExternalAuthSupport,
+            NL80211_ATTR_MLO_LINK_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MLO_LINK_ID value {:?}",
+                    payload
+                );
+                Self::MloLinkId(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MLD_ADDR => {
+                Self::MldAddr(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_ATTR_MLD_ADDR, \
+                        expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_ATTR_REKEY_DATA => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_REKEY_DATA value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211RekeyData::parse(nla)?);
+                }
+                Self::RekeyData(nlas)
+            }
+            NL80211_ATTR_CONTROL_PORT => Self::ControlPort,
+            NL80211_ATTR_CONTROL_PORT_NO_ENCRYPT => Self::ControlPortNoEncrypt,
+            NL80211_ATTR_CONTROL_PORT_OVER_NL80211 => {
+                Self::ControlPortOverNl80211
+            }
+            NL80211_ATTR_CONTROL_PORT_NO_PREAUTH => Self::ControlPortNoPreauth,
+            NL80211_ATTR_SOCKET_OWNER => Self::SocketOwner,
+            NL80211_ATTR_CRIT_PROT_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CRIT_PROT_ID value {:?}",
+                    payload
+                );
+                Self::CritProtId(parse_u16(payload).context(err_msg)?.into())
+            }
+            NL80211_ATTR_MAX_CRIT_PROT_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MAX_CRIT_PROT_DURATION value {:?}",
+                    payload
+                );
+                Self::MaxCritProtDuration(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FILS_ERP_USERNAME => {
+                Self::FilsErpUsername(payload.to_vec())
+            }
+            NL80211_ATTR_FILS_ERP_REALM => Self::FilsErpRealm(payload.to_vec()),
+            NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_FILS_ERP_NEXT_SEQ_NUM value {:?}",
+                    payload
+                );
+                Self::FilsErpNextSeqNum(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_FILS_ERP_RRK => Self::FilsErpRrk(payload.to_vec()),
+            NL80211_ATTR_MGMT_SUBTYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MGMT_SUBTYPE value {:?}",
+                    payload
+                );
+                Self::MgmtSubtype(Nl80211FrameTypeMgmt::from_subtype(
+                    parse_u8(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_ATTR_MULTICAST_TO_UNICAST_ENABLED => {
+                Self::MulticastToUnicastEnabled
+            }
+            NL80211_ATTR_NOACK_MAP => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_NOACK_MAP value {:?}",
+                    payload
+                );
+                Self::NoackMap(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_STA_TX_POWER_SETTING => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STA_TX_POWER_SETTING value {:?}",
+                    payload
+                );
+                Self::StaTxPowerSetting(
+                    u32::from(parse_u8(payload).context(err_msg)?).into(),
+                )
+            }
+            NL80211_ATTR_STA_TX_POWER => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_STA_TX_POWER value {:?}",
+                    payload
+                );
+                Self::StaTxPower(parse_u16(payload).context(err_msg)? as i16)
+            }
+            NL80211_ATTR_ACK_SIGNAL => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_ACK_SIGNAL value {:?}",
+                    payload
+                );
+                Self::AckSignal(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_DISCONNECTED_BY_AP => Self::DisconnectedByAp,
+            NL80211_ATTR_STA_SUPPORTED_RATES => {
+                Self::StaSupportedRates(payload.to_vec())
+            }
+            NL80211_ATTR_MLO_TTLM_DLINK => Self::MloTtlmDlink(
+                parse_ttlm(payload)
+                    .context("Invalid NL80211_ATTR_MLO_TTLM_DLINK value")?,
+            ),
+            NL80211_ATTR_MLO_TTLM_ULINK => Self::MloTtlmUlink(
+                parse_ttlm(payload)
+                    .context("Invalid NL80211_ATTR_MLO_TTLM_ULINK value")?,
+            ),
+            NL80211_ATTR_HW_TIMESTAMP_ENABLED => Self::HwTimestampEnabled,
+            NL80211_ATTR_TX_NO_CCK_RATE => Self::TxNoCckRate,
+            NL80211_ATTR_WIPHY_EDMG_CHANNELS => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_EDMG_CHANNELS value {:?}",
+                    payload
+                );
+                Self::WiphyEdmgChannels(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_EDMG_BW_CONFIG => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_WIPHY_EDMG_BW_CONFIG value {:?}",
+                    payload
+                );
+                Self::WiphyEdmgBwConfig(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_CENTER_FREQ1_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_CENTER_FREQ1_OFFSET value {:?}",
+                    payload
+                );
+                Self::CenterFreq1Offset(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_WIPHY_RADIOS => {
+                let mut nlas = Vec::new();
+                for (index, nla) in NlasIterator::new(payload).enumerate() {
+                    let err_msg = format!(
+                        "Invalid NL80211_ATTR_WIPHY_RADIOS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211WiphyRadio::parse_with_param(
+                        nla,
+                        index as u16,
+                    )?);
+                }
+                Self::WiphyRadios(nlas)
+            }
+            NL80211_ATTR_WIPHY_INTERFACE_COMBINATIONS => {
+                Self::WiphyInterfaceCombinations(parse_iface_combinations(
+                    payload,
+                    "NL80211_ATTR_WIPHY_INTERFACE_COMBINATIONS",
+                )?)
+            }
             _ => Self::Other(
                 DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
             ),
         })
     }
 }
+
+fn parse_ttlm(
+    payload: &[u8],
+) -> Result<[u16; IEEE80211_TTLM_NUM_TIDS], DecodeError> {
+    if payload.len() != IEEE80211_TTLM_NUM_TIDS * 2 {
+        return Err(format!(
+            "Invalid length of TID-to-link mapping, expected length {} got \
+             {:?}",
+            IEEE80211_TTLM_NUM_TIDS * 2,
+            payload
+        )
+        .into());
+    }
+    let mut ret = [0u16; IEEE80211_TTLM_NUM_TIDS];
+    for (i, link_map) in ret.iter_mut().enumerate() {
+        *link_map = parse_u16(&payload[i * 2..i * 2 + 2])?;
+    }
+    Ok(ret)
+}
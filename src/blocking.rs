@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: MIT
+
+//! Synchronous nl80211 requests for applications without async runtime.
+
+use netlink_packet_core::{
+    NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST,
+};
+use netlink_packet_generic::{
+    ctrl::{nlas::GenlCtrlAttrs, GenlCtrl, GenlCtrlCmd},
+    GenlFamily, GenlHeader, GenlMessage,
+};
+use netlink_packet_utils::{Emitable, ParseableParametrized};
+use netlink_sys::{protocols::NETLINK_GENERIC, Socket, SocketAddr};
+
+use crate::{
+    ext_ack::invalid_attribute_error, message::Nl80211RawMessage,
+    wiphy::merge_wiphy_messages, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211IfIndex, Nl80211Message, Nl80211WiphyCapabilities,
+};
+
+/// Handle sending nl80211 requests through blocking netlink socket, each
+/// request blocks the calling thread until kernel finished the reply.
+///
+/// Unlike [crate::Nl80211Handle], this handle cannot be cloned or shared
+/// between threads, open one handle per thread instead.
+///
+/// For example:
+/// ```no_run
+/// let mut handle = wl_nl80211::Nl80211SyncHandle::new().unwrap();
+/// for msg in handle.get_interfaces().unwrap() {
+///     println!("{:?}", msg);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Nl80211SyncHandle {
+    socket: Socket,
+    family_id: u16,
+    sequence_number: u32,
+    lenient_parse: bool,
+}
+
+impl Nl80211SyncHandle {
+    /// Open netlink socket and resolve the nl80211 generic netlink family
+    pub fn new() -> Result<Self, Nl80211Error> {
+        let mut socket = Socket::new(NETLINK_GENERIC).map_err(|e| {
+            Nl80211Error::RequestFailed(format!(
+                "Failed to create netlink socket: {e}"
+            ))
+        })?;
+        socket
+            .bind_auto()
+            .and_then(|_| socket.connect(&SocketAddr::new(0, 0)))
+            .map_err(|e| {
+                Nl80211Error::RequestFailed(format!(
+                    "Failed to bind netlink socket: {e}"
+                ))
+            })?;
+        // Let kernel point out the offending attribute of rejected request
+        if let Err(e) = socket.set_ext_ack(true) {
+            log::debug!("Failed to enable netlink extended ACK: {e}");
+        }
+
+        let mut ret = Self {
+            socket,
+            family_id: 0,
+            sequence_number: 0,
+            lenient_parse: false,
+        };
+        ret.family_id = ret.resolve_family_id()?;
+        Ok(ret)
+    }
+
+    /// Enable or disable the lenient parse mode, see
+    /// [crate::Nl80211Handle::set_lenient_parse()]
+    pub fn set_lenient_parse(&mut self, enabled: bool) {
+        self.lenient_parse = enabled;
+    }
+
+    /// Whether lenient parse mode is enabled, see [Self::set_lenient_parse()]
+    pub fn is_lenient_parse(&self) -> bool {
+        self.lenient_parse
+    }
+
+    /// Send nl80211 message with specified netlink header flags and return
+    /// all the replies.
+    pub fn request(
+        &mut self,
+        message: Nl80211Message,
+        header_flags: u16,
+    ) -> Result<Vec<Nl80211Message>, Nl80211Error> {
+        let GenlMessage {
+            header, payload, ..
+        } = GenlMessage::from_payload(message);
+        let attributes = payload.attributes;
+        let genl_msg = GenlMessage::new(
+            header,
+            Nl80211RawMessage::new(header, &attributes),
+            self.family_id,
+        );
+        let replies = self.send_and_receive(genl_msg, header_flags)?;
+
+        let mut ret = Vec::new();
+        for (flags, payload) in replies {
+            match payload {
+                NetlinkPayload::InnerMessage(genl_msg) => {
+                    ret.push(genl_msg.payload.decode(self.lenient_parse)?)
+                }
+                NetlinkPayload::Error(e) if e.code.is_some() => {
+                    if let Some(e) =
+                        invalid_attribute_error(flags, &e, &attributes)
+                    {
+                        return Err(e);
+                    }
+                    return Err(Nl80211Error::from(e));
+                }
+                _ => (),
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Send nl80211 command expecting no reply but ACK, for example the
+    /// attributes generated by [crate::Nl80211Interface] with
+    /// [Nl80211Command::NewInterface].
+    pub fn execute(
+        &mut self,
+        cmd: Nl80211Command,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Result<(), Nl80211Error> {
+        self.request(
+            Nl80211Message { cmd, attributes },
+            NLM_F_REQUEST | NLM_F_ACK,
+        )
+        .map(|_| ())
+    }
+
+    /// Dump nl80211 objects of specified command
+    pub fn dump(
+        &mut self,
+        cmd: Nl80211Command,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Result<Vec<Nl80211Message>, Nl80211Error> {
+        self.request(
+            Nl80211Message { cmd, attributes },
+            NLM_F_REQUEST | NLM_F_DUMP,
+        )
+    }
+
+    /// Retrieve the wireless interfaces, blocking counterpart of
+    /// [crate::Nl80211InterfaceHandle::get()]
+    pub fn get_interfaces(
+        &mut self,
+    ) -> Result<Vec<Nl80211Message>, Nl80211Error> {
+        self.dump(Nl80211Command::GetInterface, Vec::new())
+    }
+
+    /// Retrieve the capabilities of all wiphys, blocking counterpart of
+    /// [crate::Nl80211WiphyGetRequest::capabilities()]
+    pub fn get_wiphy_capabilities(
+        &mut self,
+    ) -> Result<Vec<Nl80211WiphyCapabilities>, Nl80211Error> {
+        let msgs = self.dump(
+            Nl80211Command::GetWiphy,
+            vec![Nl80211Attr::SplitWiphyDump],
+        )?;
+        merge_wiphy_messages(msgs.into_iter().map(|msg| msg.attributes))
+    }
+
+    /// Retrieve the stations of specified interface, blocking counterpart
+    /// of [crate::Nl80211StationHandle::dump()]
+    pub fn get_stations(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Result<Vec<Nl80211Message>, Nl80211Error> {
+        self.dump(
+            Nl80211Command::GetStation,
+            vec![Nl80211Attr::IfIndex(if_index.get())],
+        )
+    }
+
+    /// Retrieve the current scan data of specified interface, blocking
+    /// counterpart of [crate::Nl80211ScanHandle::dump()]
+    pub fn get_scan(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Result<Vec<Nl80211Message>, Nl80211Error> {
+        self.dump(
+            Nl80211Command::GetScan,
+            vec![Nl80211Attr::IfIndex(if_index.get())],
+        )
+    }
+
+    fn resolve_family_id(&mut self) -> Result<u16, Nl80211Error> {
+        let genl_msg = GenlMessage::from_payload(GenlCtrl {
+            cmd: GenlCtrlCmd::GetFamily,
+            nlas: vec![GenlCtrlAttrs::FamilyName(
+                Nl80211Message::family_name().to_string(),
+            )],
+        });
+        for (_, payload) in self.send_and_receive(genl_msg, NLM_F_REQUEST)? {
+            match payload {
+                NetlinkPayload::InnerMessage(genl_msg) => {
+                    for nla in genl_msg.payload.nlas {
+                        if let GenlCtrlAttrs::FamilyId(id) = nla {
+                            return Ok(id);
+                        }
+                    }
+                }
+                NetlinkPayload::Error(e) => return Err(Nl80211Error::from(e)),
+                _ => (),
+            }
+        }
+        Err(Nl80211Error::RequestFailed(
+            "Failed to resolve nl80211 generic netlink family".into(),
+        ))
+    }
+
+    // Return the header flags and payload of each reply until the end of
+    // dump, ACK or error.
+    #[allow(clippy::type_complexity)]
+    fn send_and_receive<F>(
+        &mut self,
+        genl_msg: GenlMessage<F>,
+        header_flags: u16,
+    ) -> Result<Vec<(u16, NetlinkPayload<GenlMessage<F>>)>, Nl80211Error>
+    where
+        F: GenlFamily
+            + Emitable
+            + ParseableParametrized<[u8], GenlHeader>
+            + std::fmt::Debug,
+    {
+        self.sequence_number = self.sequence_number.wrapping_add(1);
+        let sequence_number = self.sequence_number;
+
+        let mut nl_msg = NetlinkMessage::from(genl_msg);
+        nl_msg.header.flags = header_flags;
+        nl_msg.header.sequence_number = sequence_number;
+        nl_msg.finalize();
+        let mut buffer = vec![0u8; nl_msg.buffer_len()];
+        nl_msg.serialize(&mut buffer);
+        self.socket.send(&buffer, 0).map_err(|e| {
+            Nl80211Error::RequestFailed(format!(
+                "Failed to send netlink message: {e}"
+            ))
+        })?;
+
+        let is_dump = header_flags & NLM_F_DUMP == NLM_F_DUMP;
+        let expect_ack = header_flags & NLM_F_ACK > 0;
+        let mut ret = Vec::new();
+        loop {
+            let (data, _) = self.socket.recv_from_full().map_err(|e| {
+                Nl80211Error::RequestFailed(format!(
+                    "Failed to receive netlink message: {e}"
+                ))
+            })?;
+            let mut offset = 0;
+            while offset < data.len() {
+                let msg = NetlinkMessage::<GenlMessage<F>>::deserialize(
+                    &data[offset..],
+                )
+                .map_err(Nl80211Error::from)?;
+                let len = msg.header.length as usize;
+                if len == 0 {
+                    break;
+                }
+                offset += len;
+                if msg.header.sequence_number != sequence_number {
+                    continue;
+                }
+                let flags = msg.header.flags;
+                match msg.payload {
+                    NetlinkPayload::Done(_) => return Ok(ret),
+                    NetlinkPayload::Error(e) => {
+                        let is_ack = e.code.is_none();
+                        ret.push((flags, NetlinkPayload::Error(e)));
+                        if !is_ack || !is_dump {
+                            return Ok(ret);
+                        }
+                    }
+                    payload => {
+                        ret.push((flags, payload));
+                        if !is_dump && !expect_ack {
+                            return Ok(ret);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,7 +5,10 @@ use std::marker::PhantomData;
 
 use netlink_packet_utils::nla::Nla;
 
-use crate::Nl80211Attr;
+use crate::{
+    Nl80211Attr, Nl80211ChannelDef, Nl80211ChannelWidth, Nl80211Freq,
+    Nl80211IfIndex, Nl80211Ssid,
+};
 
 #[derive(Debug)]
 pub struct Nl80211AttrsBuilder<T> {
@@ -47,6 +50,12 @@ impl<T> Nl80211AttrsBuilder<T> {
         ret
     }
 
+    pub(crate) fn get(&self, kind: u16) -> Option<&Nl80211Attr> {
+        self.attribute_map
+            .get(&kind)
+            .and_then(|attrs| attrs.first())
+    }
+
     pub fn build(self) -> Vec<Nl80211Attr> {
         let mut data = self;
         let mut ret: Vec<Nl80211Attr> = Vec::new();
@@ -58,11 +67,66 @@ impl<T> Nl80211AttrsBuilder<T> {
         ret
     }
 
-    pub fn if_index(self, if_index: u32) -> Self {
-        self.replace(Nl80211Attr::IfIndex(if_index))
+    pub fn if_index(self, if_index: Nl80211IfIndex) -> Self {
+        self.replace(Nl80211Attr::IfIndex(if_index.get()))
+    }
+
+    pub fn ssid(self, ssid: impl Into<Nl80211Ssid>) -> Self {
+        self.append(Nl80211Attr::Ssid(ssid.into()))
+    }
+
+    // Control frequency along with its kHz offset
+    pub(crate) fn set_frequency(self, freq: impl Into<Nl80211Freq>) -> Self {
+        let freq = freq.into();
+        let ret = self
+            .replace(Nl80211Attr::WiphyFreq(freq))
+            .remove(Nl80211Attr::WiphyFreqOffset(0).kind());
+        if freq.offset_khz() == 0 {
+            ret
+        } else {
+            ret.replace(Nl80211Attr::WiphyFreqOffset(freq.offset_khz()))
+        }
+    }
+
+    // Channel width and the center frequency of first segment along with its
+    // kHz offset
+    pub(crate) fn set_channel_width(
+        self,
+        width: Nl80211ChannelWidth,
+        center_freq1: impl Into<Nl80211Freq>,
+    ) -> Self {
+        let center_freq1 = center_freq1.into();
+        let ret = self
+            .replace(Nl80211Attr::ChannelWidth(width))
+            .replace(Nl80211Attr::CenterFreq1(center_freq1))
+            .remove(Nl80211Attr::CenterFreq1Offset(0).kind());
+        if center_freq1.offset_khz() == 0 {
+            ret
+        } else {
+            ret.replace(Nl80211Attr::CenterFreq1Offset(
+                center_freq1.offset_khz(),
+            ))
+        }
     }
 
-    pub fn ssid(self, ssid: &str) -> Self {
-        self.append(Nl80211Attr::Ssid(ssid.to_string()))
+    // Replace all attributes of previous channel definition
+    pub(crate) fn set_channel_def(self, channel: &Nl80211ChannelDef) -> Self {
+        let mut ret = self;
+        for attr in [
+            Nl80211Attr::WiphyFreq(Default::default()),
+            Nl80211Attr::WiphyFreqOffset(0),
+            Nl80211Attr::ChannelWidth(Nl80211ChannelWidth::NoHt20),
+            Nl80211Attr::CenterFreq1(Default::default()),
+            Nl80211Attr::CenterFreq1Offset(0),
+            Nl80211Attr::CenterFreq2(Default::default()),
+            Nl80211Attr::WiphyEdmgChannels(0),
+            Nl80211Attr::WiphyEdmgBwConfig(0),
+        ] {
+            ret = ret.remove(attr.kind());
+        }
+        for attr in channel.attributes() {
+            ret = ret.replace(attr);
+        }
+        ret
     }
 }
@@ -1,5 +1,9 @@
 // SPDX-License-Identifier: MIT
 
+use netlink_packet_utils::Emitable;
+
+use crate::{Nl80211Attr, Nl80211BandType, Nl80211Freq};
+
 const NL80211_CHAN_WIDTH_20_NOHT: u32 = 0;
 const NL80211_CHAN_WIDTH_20: u32 = 1;
 const NL80211_CHAN_WIDTH_40: u32 = 2;
@@ -16,6 +20,7 @@ const NL80211_CHAN_WIDTH_16: u32 = 12;
 const NL80211_CHAN_WIDTH_320: u32 = 13;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211ChannelWidth {
     NoHt20,
     Mhz80Plus80,
@@ -70,3 +75,396 @@ impl From<Nl80211ChannelWidth> for u32 {
         }
     }
 }
+
+/// Channel definition, the control frequency with the width and center
+/// frequencies of the channel, carried by [Nl80211Attr::WiphyFreq],
+/// [Nl80211Attr::ChannelWidth], [Nl80211Attr::CenterFreq1],
+/// [Nl80211Attr::CenterFreq2] and friends.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ChannelDef {
+    /// Control frequency, the kHz part is emitted as
+    /// [Nl80211Attr::WiphyFreqOffset]
+    pub frequency: Nl80211Freq,
+    /// Channel width, 20 MHz without HT when not defined
+    pub width: Option<Nl80211ChannelWidth>,
+    /// Center frequency of the first part of the channel, the kHz part is
+    /// emitted as [Nl80211Attr::CenterFreq1Offset]
+    pub center_freq1: Option<Nl80211Freq>,
+    /// Center frequency of the second part of 80+80 MHz channel
+    pub center_freq2: Option<Nl80211Freq>,
+    /// Bitmap of 60 GHz channels aggregated into the EDMG channel
+    pub edmg_channels: Option<u8>,
+    /// EDMG channel bandwidth configuration
+    pub edmg_bw_config: Option<u8>,
+}
+
+impl Nl80211ChannelDef {
+    /// Channel of 20 MHz without HT on specified control frequency
+    pub fn new(frequency: Nl80211Freq) -> Self {
+        Self {
+            frequency,
+            ..Default::default()
+        }
+    }
+
+    /// Channel of specified width centered on `center_freq1`, which is the
+    /// control frequency only for 20 MHz and narrower channels.
+    pub fn with_width(
+        frequency: Nl80211Freq,
+        width: Nl80211ChannelWidth,
+        center_freq1: Nl80211Freq,
+    ) -> Self {
+        Self {
+            frequency,
+            width: Some(width),
+            center_freq1: Some(center_freq1),
+            ..Default::default()
+        }
+    }
+
+    /// Extract the channel definition from attributes of message, for
+    /// example interface dump or channel switch notification. Return `None`
+    /// if [Nl80211Attr::WiphyFreq] is not found.
+    pub fn from_attrs(attributes: &[Nl80211Attr]) -> Option<Self> {
+        let mut ret = Self::default();
+        let mut frequency = None;
+        let mut freq_offset = 0;
+        let mut center_freq1_offset = 0;
+        for attr in attributes {
+            match attr {
+                Nl80211Attr::WiphyFreq(d) => frequency = Some(*d),
+                Nl80211Attr::WiphyFreqOffset(d) => freq_offset = *d,
+                Nl80211Attr::ChannelWidth(d) => ret.width = Some(*d),
+                Nl80211Attr::CenterFreq1(d) => ret.center_freq1 = Some(*d),
+                Nl80211Attr::CenterFreq1Offset(d) => center_freq1_offset = *d,
+                Nl80211Attr::CenterFreq2(d) => ret.center_freq2 = Some(*d),
+                Nl80211Attr::WiphyEdmgChannels(d) => {
+                    ret.edmg_channels = Some(*d)
+                }
+                Nl80211Attr::WiphyEdmgBwConfig(d) => {
+                    ret.edmg_bw_config = Some(*d)
+                }
+                _ => (),
+            }
+        }
+        ret.frequency =
+            Nl80211Freq::from_mhz_and_offset(frequency?.mhz(), freq_offset);
+        ret.center_freq1 = ret.center_freq1.map(|f| {
+            Nl80211Freq::from_mhz_and_offset(f.mhz(), center_freq1_offset)
+        });
+        Some(ret)
+    }
+
+    /// The attributes describing this channel in request
+    pub fn attributes(&self) -> Vec<Nl80211Attr> {
+        let mut ret = vec![Nl80211Attr::WiphyFreq(self.frequency)];
+        if self.frequency.offset_khz() != 0 {
+            ret.push(Nl80211Attr::WiphyFreqOffset(self.frequency.offset_khz()));
+        }
+        if let Some(width) = self.width {
+            ret.push(Nl80211Attr::ChannelWidth(width));
+        }
+        if let Some(freq) = self.center_freq1 {
+            ret.push(Nl80211Attr::CenterFreq1(freq));
+            if freq.offset_khz() != 0 {
+                ret.push(Nl80211Attr::CenterFreq1Offset(freq.offset_khz()));
+            }
+        }
+        if let Some(freq) = self.center_freq2 {
+            ret.push(Nl80211Attr::CenterFreq2(freq));
+        }
+        if let Some(d) = self.edmg_channels {
+            ret.push(Nl80211Attr::WiphyEdmgChannels(d));
+        }
+        if let Some(d) = self.edmg_bw_config {
+            ret.push(Nl80211Attr::WiphyEdmgBwConfig(d));
+        }
+        ret
+    }
+}
+
+impl Emitable for Nl80211ChannelDef {
+    fn buffer_len(&self) -> usize {
+        self.attributes().as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.attributes().as_slice().emit(buffer)
+    }
+}
+
+/// Frequency of IEEE 802.11 channel number in specified band, `None` for
+/// invalid channel number. S1G channels use the 902 MHz starting frequency
+/// of the US operating classes.
+pub fn channel_to_frequency(
+    channel: u32,
+    band: Nl80211BandType,
+) -> Option<Nl80211Freq> {
+    if channel == 0 {
+        return None;
+    }
+    match band {
+        Nl80211BandType::Band2GHz | Nl80211BandType::BandLc => {
+            if channel == 14 {
+                Some(Nl80211Freq::from_mhz(2484))
+            } else if channel < 14 {
+                Some(Nl80211Freq::from_mhz(2407 + channel * 5))
+            } else {
+                None
+            }
+        }
+        Nl80211BandType::Band5GHz => {
+            if (182..=196).contains(&channel) {
+                Some(Nl80211Freq::from_mhz(4000 + channel * 5))
+            } else if channel <= 177 {
+                Some(Nl80211Freq::from_mhz(5000 + channel * 5))
+            } else {
+                None
+            }
+        }
+        Nl80211BandType::Band6GHz => {
+            // Channel 2 is the only exception of 6 GHz band
+            if channel == 2 {
+                Some(Nl80211Freq::from_mhz(5935))
+            } else if channel <= 233 {
+                Some(Nl80211Freq::from_mhz(5950 + channel * 5))
+            } else {
+                None
+            }
+        }
+        Nl80211BandType::Band60GHz => {
+            if channel <= 6 {
+                Some(Nl80211Freq::from_mhz(56160 + channel * 2160))
+            } else {
+                None
+            }
+        }
+        Nl80211BandType::BandS1GHz => {
+            Some(Nl80211Freq::from_khz(902000 + channel * 500))
+        }
+        Nl80211BandType::Other(_) => None,
+    }
+}
+
+/// IEEE 802.11 channel number of specified frequency, `None` if the
+/// frequency does not belong to any known band
+pub fn frequency_to_channel(freq: Nl80211Freq) -> Option<u32> {
+    let mhz = freq.mhz();
+    match frequency_to_band(freq)? {
+        Nl80211BandType::BandS1GHz => Some((freq.khz() - 902000) / 500),
+        Nl80211BandType::Band2GHz if mhz == 2484 => Some(14),
+        Nl80211BandType::Band2GHz => Some((mhz - 2407) / 5),
+        Nl80211BandType::Band5GHz if mhz <= 4980 => Some((mhz - 4000) / 5),
+        Nl80211BandType::Band5GHz => Some((mhz - 5000) / 5),
+        Nl80211BandType::Band6GHz if mhz == 5935 => Some(2),
+        Nl80211BandType::Band6GHz => Some((mhz - 5950) / 5),
+        Nl80211BandType::Band60GHz => Some((mhz - 56160) / 2160),
+        _ => None,
+    }
+}
+
+/// Band of specified frequency, `None` for frequency outside of the
+/// known bands
+pub fn frequency_to_band(freq: Nl80211Freq) -> Option<Nl80211BandType> {
+    match freq.mhz() {
+        902..=927 => Some(Nl80211BandType::BandS1GHz),
+        2412..=2484 => Some(Nl80211BandType::Band2GHz),
+        4910..=4980 | 5000..=5885 => Some(Nl80211BandType::Band5GHz),
+        5935 | 5955..=7115 => Some(Nl80211BandType::Band6GHz),
+        58320..=70200 => Some(Nl80211BandType::Band60GHz),
+        _ => None,
+    }
+}
+
+/// Band of IEEE 802.11 global operating class(IEEE 802.11-2020 Annex E
+/// Table E-4)
+pub fn operating_class_to_band(operating_class: u8) -> Option<Nl80211BandType> {
+    match operating_class {
+        81..=84 => Some(Nl80211BandType::Band2GHz),
+        112 | 115..=130 => Some(Nl80211BandType::Band5GHz),
+        131..=137 => Some(Nl80211BandType::Band6GHz),
+        180 => Some(Nl80211BandType::Band60GHz),
+        _ => None,
+    }
+}
+
+// IEEE 802.11-2020 Annex E Table E-4 global operating classes of 2.4 GHz and
+// 5 GHz bands: (operating class, band, bandwidth in MHz, offset in MHz from
+// primary channel to center of 40 MHz channel, channels). The channels are
+// primary channels for 20 MHz and 40 MHz classes, and center channels for
+// 80 MHz and wider classes.
+const GLOBAL_OPERATING_CLASSES: &[(u8, Nl80211BandType, u32, i32, &[u32])] = &[
+    (
+        81,
+        Nl80211BandType::Band2GHz,
+        20,
+        0,
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+    ),
+    (
+        83,
+        Nl80211BandType::Band2GHz,
+        40,
+        10,
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+    ),
+    (
+        84,
+        Nl80211BandType::Band2GHz,
+        40,
+        -10,
+        &[5, 6, 7, 8, 9, 10, 11, 12, 13],
+    ),
+    (115, Nl80211BandType::Band5GHz, 20, 0, &[36, 40, 44, 48]),
+    (116, Nl80211BandType::Band5GHz, 40, 10, &[36, 44]),
+    (117, Nl80211BandType::Band5GHz, 40, -10, &[40, 48]),
+    (118, Nl80211BandType::Band5GHz, 20, 0, &[52, 56, 60, 64]),
+    (119, Nl80211BandType::Band5GHz, 40, 10, &[52, 60]),
+    (120, Nl80211BandType::Band5GHz, 40, -10, &[56, 64]),
+    (
+        121,
+        Nl80211BandType::Band5GHz,
+        20,
+        0,
+        &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140, 144],
+    ),
+    (
+        122,
+        Nl80211BandType::Band5GHz,
+        40,
+        10,
+        &[100, 108, 116, 124, 132, 140],
+    ),
+    (
+        123,
+        Nl80211BandType::Band5GHz,
+        40,
+        -10,
+        &[104, 112, 120, 128, 136, 144],
+    ),
+    (124, Nl80211BandType::Band5GHz, 20, 0, &[149, 153, 157, 161]),
+    (
+        125,
+        Nl80211BandType::Band5GHz,
+        20,
+        0,
+        &[149, 153, 157, 161, 165, 169, 173, 177],
+    ),
+    (
+        126,
+        Nl80211BandType::Band5GHz,
+        40,
+        10,
+        &[149, 157, 165, 173],
+    ),
+    (
+        127,
+        Nl80211BandType::Band5GHz,
+        40,
+        -10,
+        &[153, 161, 169, 177],
+    ),
+    (
+        128,
+        Nl80211BandType::Band5GHz,
+        80,
+        0,
+        &[42, 58, 106, 122, 138, 155, 171],
+    ),
+    (129, Nl80211BandType::Band5GHz, 160, 0, &[50, 114, 163]),
+];
+
+/// IEEE 802.11 global operating class of specified channel, `None` if the
+/// channel is not covered by any operating class. The operating class is
+/// looked up by the control channel and the channel width following IEEE
+/// 802.11-2020 Annex E Table E-4. When multiple operating classes contain
+/// the channel, the first one is returned, for example 124 instead of 125
+/// for channel 149. The 5 and 10 MHz channels are not supported.
+pub fn channel_def_to_operating_class(
+    channel: &Nl80211ChannelDef,
+) -> Option<u8> {
+    let width = channel.width.unwrap_or(Nl80211ChannelWidth::NoHt20);
+    let control = channel.frequency;
+    let band = frequency_to_band(control)?;
+    let control_channel = frequency_to_channel(control)?;
+
+    match band {
+        Nl80211BandType::Band2GHz if control_channel == 14 => {
+            // Channel 14 is only for IEEE 802.11b
+            return (width == Nl80211ChannelWidth::NoHt20).then_some(82);
+        }
+        Nl80211BandType::Band2GHz | Nl80211BandType::Band5GHz => (),
+        Nl80211BandType::Band6GHz => {
+            // 6 GHz channel 2 has dedicated operating class
+            if control_channel == 2 {
+                return Some(136);
+            }
+            return match width {
+                Nl80211ChannelWidth::NoHt20 | Nl80211ChannelWidth::Mhz(20) => {
+                    Some(131)
+                }
+                Nl80211ChannelWidth::Mhz(40) => Some(132),
+                Nl80211ChannelWidth::Mhz(80) => Some(133),
+                Nl80211ChannelWidth::Mhz(160) => Some(134),
+                Nl80211ChannelWidth::Mhz80Plus80 => Some(135),
+                Nl80211ChannelWidth::Mhz(320) => Some(137),
+                _ => None,
+            };
+        }
+        Nl80211BandType::Band60GHz => {
+            return matches!(
+                width,
+                Nl80211ChannelWidth::NoHt20 | Nl80211ChannelWidth::Mhz(20)
+            )
+            .then_some(180);
+        }
+        _ => return None,
+    }
+
+    let (bandwidth, is_80p80) = match width {
+        Nl80211ChannelWidth::NoHt20 => (20, false),
+        Nl80211ChannelWidth::Mhz80Plus80 => (80, true),
+        Nl80211ChannelWidth::Mhz(w @ (20 | 40 | 80 | 160)) => (w, false),
+        _ => return None,
+    };
+    let center = channel.center_freq1.map(|f| f.mhz() as i32);
+    let control_mhz = control.mhz() as i32;
+    let center_channel = channel.center_freq1.and_then(frequency_to_channel);
+
+    let class = GLOBAL_OPERATING_CLASSES.iter().find_map(
+        |(class, class_band, class_bandwidth, offset, channels)| {
+            if *class_band != band || *class_bandwidth != bandwidth {
+                return None;
+            }
+            let found = match bandwidth {
+                20 => channels.contains(&control_channel),
+                // The primary channel of 2.4 GHz could be either the upper or
+                // the lower 20 MHz channel, hence the center frequency is
+                // required.
+                40 => {
+                    channels.contains(&control_channel)
+                        && match center {
+                            Some(c) => c == control_mhz + offset,
+                            None => band == Nl80211BandType::Band5GHz,
+                        }
+                }
+                // The control channel should be inside of the first segment
+                _ => {
+                    center_channel.is_some_and(|c| channels.contains(&c))
+                        && center.is_some_and(|c| {
+                            (c - control_mhz).unsigned_abs() < bandwidth / 2
+                        })
+                }
+            };
+            found.then_some(*class)
+        },
+    )?;
+    // 80+80 MHz channels use the same center channels as 80 MHz ones
+    if is_80p80 {
+        Some(130)
+    } else {
+        Some(class)
+    }
+}
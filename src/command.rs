@@ -163,6 +163,7 @@ const NL80211_CMD_ACTION: u8 = NL80211_CMD_FRAME;
 const NL80211_CMD_ACTION_TX_STATUS: u8 = NL80211_CMD_FRAME_TX_STATUS;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211Command {
     GetWiphy,
     SetWiphy,
@@ -1,15 +1,22 @@
 // SPDX-License-Identifier: MIT
 
+use std::fs::File;
 use std::io;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd};
+use std::path::Path;
 
 use futures::channel::mpsc::UnboundedReceiver;
 use genetlink::message::RawGenlMessage;
 use netlink_packet_core::NetlinkMessage;
 use netlink_proto::Connection;
+#[cfg(feature = "smol_socket")]
+use netlink_sys::SmolSocket;
 use netlink_sys::{AsyncSocket, SocketAddr};
 
 use crate::Nl80211Handle;
 
+const SELF_NETNS_PATH: &str = "/proc/thread-self/ns/net";
+
 #[cfg(feature = "tokio_socket")]
 #[allow(clippy::type_complexity)]
 pub fn new_connection() -> io::Result<(
@@ -20,6 +27,32 @@ pub fn new_connection() -> io::Result<(
     new_connection_with_socket()
 }
 
+/// Create connection on specified socket type, for example
+/// [netlink_sys::TokioSocket] or the `async-io` based
+/// [netlink_sys::SmolSocket] which works with smol, async-std or any other
+/// executor. The returned connection should be spawned on the executor.
+///
+/// For example, with `smol_socket` feature:
+/// ```no_run
+/// # #[cfg(feature = "smol_socket")]
+/// # fn main() {
+/// use futures::TryStreamExt;
+///
+/// let (connection, handle, _) =
+///     wl_nl80211::new_connection_with_socket::<netlink_sys::SmolSocket>()
+///         .unwrap();
+/// std::thread::spawn(move || async_io::block_on(connection));
+///
+/// async_io::block_on(async {
+///     let mut response = handle.interface().get().execute().await;
+///     while let Some(msg) = response.try_next().await.unwrap() {
+///         println!("{:?}", msg);
+///     }
+/// });
+/// # }
+/// # #[cfg(not(feature = "smol_socket"))]
+/// # fn main() {}
+/// ```
 #[allow(clippy::type_complexity)]
 pub fn new_connection_with_socket<S>() -> io::Result<(
     Connection<RawGenlMessage, S>,
@@ -29,6 +62,145 @@ pub fn new_connection_with_socket<S>() -> io::Result<(
 where
     S: AsyncSocket,
 {
-    let (conn, handle, messages) = genetlink::new_connection_with_socket()?;
+    let (mut conn, handle, messages) =
+        genetlink::new_connection_with_socket::<S>()?;
+    // Let kernel point out the offending attribute of rejected request, see
+    // [crate::Nl80211Error::InvalidAttribute].
+    conn.socket_mut().socket_mut().set_ext_ack(true)?;
     Ok((conn, Nl80211Handle::new(handle), messages))
 }
+
+/// Similar to [new_connection()], but using the `async-io` based
+/// [netlink_sys::SmolSocket], see [new_connection_with_socket()].
+#[cfg(feature = "smol_socket")]
+#[allow(clippy::type_complexity)]
+pub fn new_smol_connection() -> io::Result<(
+    Connection<RawGenlMessage, SmolSocket>,
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)> {
+    new_connection_with_socket()
+}
+
+/// Similar to [new_smol_connection()], but the connection is spawned on
+/// the global executor of `async-global-executor` crate, so only the
+/// handle and the receiver of unsolicited messages are returned.
+///
+/// For example:
+/// ```no_run
+/// # #[cfg(feature = "async_global_executor")]
+/// # fn main() {
+/// use futures::TryStreamExt;
+///
+/// async_global_executor::block_on(async {
+///     let (handle, _) = wl_nl80211::new_smol_connection_spawned().unwrap();
+///     let mut response = handle.interface().get().execute().await;
+///     while let Some(msg) = response.try_next().await.unwrap() {
+///         println!("{:?}", msg);
+///     }
+/// });
+/// # }
+/// # #[cfg(not(feature = "async_global_executor"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "async_global_executor")]
+#[allow(clippy::type_complexity)]
+pub fn new_smol_connection_spawned() -> io::Result<(
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)> {
+    let (connection, handle, messages) = new_smol_connection()?;
+    async_global_executor::spawn(connection).detach();
+    Ok((handle, messages))
+}
+
+/// Similar to [new_connection()], but the netlink socket is opened inside
+/// the network namespace referred by `netns`, for example a file descriptor
+/// of `/proc/<PID>/ns/net` or `/run/netns/<NAME>`, hence the wiphys and
+/// interfaces of that network namespace are managed by this connection.
+/// Requires `CAP_SYS_ADMIN`.
+#[cfg(feature = "tokio_socket")]
+#[allow(clippy::type_complexity)]
+pub fn new_connection_in_netns(
+    netns: BorrowedFd<'_>,
+) -> io::Result<(
+    Connection<RawGenlMessage>,
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)> {
+    new_connection_with_socket_in_netns(netns)
+}
+
+/// Similar to [new_connection_in_netns()], but the network namespace is
+/// referred by file path, for example `/run/netns/<NAME>`.
+#[cfg(feature = "tokio_socket")]
+#[allow(clippy::type_complexity)]
+pub fn new_connection_in_netns_path<P: AsRef<Path>>(
+    netns_path: P,
+) -> io::Result<(
+    Connection<RawGenlMessage>,
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)> {
+    new_connection_with_socket_in_netns_path(netns_path)
+}
+
+/// Variant of [new_connection_in_netns()] for specified socket type.
+#[allow(clippy::type_complexity)]
+pub fn new_connection_with_socket_in_netns<S>(
+    netns: BorrowedFd<'_>,
+) -> io::Result<(
+    Connection<RawGenlMessage, S>,
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)>
+where
+    S: AsyncSocket,
+{
+    with_netns(netns, new_connection_with_socket)
+}
+
+/// Variant of [new_connection_in_netns_path()] for specified socket type.
+#[allow(clippy::type_complexity)]
+pub fn new_connection_with_socket_in_netns_path<S, P>(
+    netns_path: P,
+) -> io::Result<(
+    Connection<RawGenlMessage, S>,
+    Nl80211Handle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)>
+where
+    S: AsyncSocket,
+    P: AsRef<Path>,
+{
+    let netns = File::open(netns_path)?;
+    new_connection_with_socket_in_netns(netns.as_fd())
+}
+
+// The network namespace of socket is decided at creation and kept after
+// the creating thread switched back to original network namespace.
+fn with_netns<T, F>(netns: BorrowedFd<'_>, func: F) -> io::Result<T>
+where
+    F: FnOnce() -> io::Result<T>,
+{
+    let origin = File::open(SELF_NETNS_PATH)?;
+    setns(netns.as_raw_fd())?;
+    let result = func();
+    if let Err(e) = setns(origin.as_raw_fd()) {
+        return Err(io::Error::new(
+            e.kind(),
+            format!("Failed to restore original network namespace: {e}"),
+        ));
+    }
+    result
+}
+
+fn setns(fd: i32) -> io::Result<()> {
+    // SAFETY: `setns()` does not access memory, invalid fd is reported as
+    // error.
+    if unsafe { libc::setns(fd, libc::CLONE_NEWNET) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -2,35 +2,127 @@
 
 use anyhow::Context;
 use netlink_packet_utils::{
-    parsers::{parse_string, parse_u8},
-    DecodeError, Emitable, Parseable,
+    parsers::parse_u8, DecodeError, Emitable, Parseable,
 };
 
 use crate::{
     bytes::{parse_u16_le, write_u16_le, write_u32_le},
-    Nl80211ElementHtCap,
+    Nl80211CipherSuite, Nl80211ElementEhtCap, Nl80211ElementHeCap,
+    Nl80211ElementHeOperation, Nl80211ElementHtCap, Nl80211ElementHtOperation,
+    Nl80211ElementVhtOperation, Nl80211Ssid, Nl80211VhtCapability,
 };
 
 pub(crate) struct Nl80211Elements(Vec<Nl80211Element>);
 
+/// Decode the information elements leniently: element failed to decode
+/// is stored as [Nl80211Element::Other] holding its raw payload, and the
+/// truncated last element is stored as [Nl80211Element::Other] holding
+/// the remaining bytes, so single broken element from AP does not fail the
+/// decoding of the whole BSS. Use [Nl80211ElementIter] to get the decode
+/// error of each element.
 impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211Elements {
     fn parse(buf: &T) -> Result<Self, DecodeError> {
-        let buf = buf.as_ref();
-        let mut offset = 0;
+        let mut iter = Nl80211ElementIter::new(buf.as_ref());
         let mut ret = Vec::new();
-        while offset < buf.len() && offset + 1 < buf.len() {
-            let length = buf[offset + 1] as usize + 2;
-            if buf.len() < offset + length {
-                break;
+        while let Some(raw) = iter.next_raw() {
+            if raw.truncated {
+                log::warn!(
+                    "Truncated element ID {} with length {}, \
+                    only {} bytes remaining: {:?}",
+                    raw.id,
+                    raw.length,
+                    raw.payload.len(),
+                    raw.payload
+                );
+                ret.push(Nl80211Element::Other(raw.id, raw.payload.to_vec()));
+                continue;
+            }
+            match Nl80211Element::parse(raw.element) {
+                Ok(element) => ret.push(element),
+                Err(e) => {
+                    log::warn!("Failed to decode element ID {}: {e}", raw.id);
+                    ret.push(Nl80211Element::Other(
+                        raw.id,
+                        raw.payload.to_vec(),
+                    ));
+                }
             }
-            let element = Nl80211Element::parse(&buf[offset..offset + length])?;
-            offset += length;
-            ret.push(element);
         }
         Ok(Self(ret))
     }
 }
 
+struct Nl80211RawElement<'a> {
+    id: u8,
+    /// Length declared in the element header
+    length: usize,
+    /// Whole element including the header
+    element: &'a [u8],
+    /// Payload of element, shorter than `length` when truncated
+    payload: &'a [u8],
+    truncated: bool,
+}
+
+/// Iterator of the IEEE 802.11 information elements in raw buffer,
+/// yielding decode error for each malformed element. Iteration stops
+/// after the truncated element whose length exceeds the remaining buffer.
+#[derive(Debug, Clone)]
+pub struct Nl80211ElementIter<'a> {
+    buf: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Nl80211ElementIter<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, offset: 0 }
+    }
+
+    fn next_raw(&mut self) -> Option<Nl80211RawElement<'a>> {
+        let remain = self.buf.get(self.offset..)?;
+        let id = *remain.first()?;
+        let length = remain.get(1).copied().unwrap_or_default() as usize;
+        if remain.len() < length + 2 {
+            self.offset = self.buf.len();
+            return Some(Nl80211RawElement {
+                id,
+                length,
+                element: remain,
+                payload: remain.get(2..).unwrap_or_default(),
+                truncated: true,
+            });
+        }
+        self.offset += length + 2;
+        Some(Nl80211RawElement {
+            id,
+            length,
+            element: &remain[..length + 2],
+            payload: &remain[2..length + 2],
+            truncated: false,
+        })
+    }
+}
+
+impl Iterator for Nl80211ElementIter<'_> {
+    type Item = Result<Nl80211Element, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let raw = self.next_raw()?;
+        Some(if raw.truncated {
+            Err(format!(
+                "Truncated element ID {} with length {}, only {} bytes \
+                remaining: {:?}",
+                raw.id,
+                raw.length,
+                raw.payload.len(),
+                raw.payload
+            )
+            .into())
+        } else {
+            Nl80211Element::parse(raw.element)
+        })
+    }
+}
+
 impl Emitable for Nl80211Elements {
     fn buffer_len(&self) -> usize {
         self.0.as_slice().iter().map(|e| e.buffer_len()).sum()
@@ -64,13 +156,23 @@ const ELEMENT_ID_CHANNEL: u8 = 3;
 const ELEMENT_ID_COUNTRY: u8 = 7;
 const ELEMENT_ID_HT_CAP: u8 = 45;
 const ELEMENT_ID_RSN: u8 = 48;
+const ELEMENT_ID_EXT_SUPPORTED_RATES: u8 = 50;
+const ELEMENT_ID_MOBILITY_DOMAIN: u8 = 54;
+const ELEMENT_ID_SUPPORTED_OPERATING_CLASSES: u8 = 59;
+const ELEMENT_ID_HT_OPERATION: u8 = 61;
+const ELEMENT_ID_RM_ENABLED_CAP: u8 = 70;
+const ELEMENT_ID_VHT_CAP: u8 = 191;
+const ELEMENT_ID_VHT_OPERATION: u8 = 192;
 const ELEMENT_ID_VENDOR: u8 = 221;
+const ELEMENT_ID_RSNX: u8 = 244;
+const ELEMENT_ID_EXTENSION: u8 = 255;
 
 /// IEEE 802.11-2020 `9.4.2 Elements`
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211Element {
-    Ssid(String),
+    Ssid(Nl80211Ssid),
     /// Supported rates in units of 500 kb/s, if necessary rounded up to the
     /// next 500 kb/
     SupportedRatesAndSelectors(Vec<Nl80211RateAndSelector>),
@@ -79,8 +181,22 @@ pub enum Nl80211Element {
     Country(Nl80211ElementCountry),
     HtCapability(Nl80211ElementHtCap),
     Rsn(Nl80211ElementRsn),
+    /// Extended Supported Rates and BSS Membership Selectors, holding rates
+    /// exceeding the 8 allowed in
+    /// [Nl80211Element::SupportedRatesAndSelectors].
+    ExtSupportedRatesAndSelectors(Vec<Nl80211RateAndSelector>),
+    MobilityDomain(Nl80211ElementMobilityDomain),
+    SupportedOperatingClasses(Nl80211ElementSupportedOperatingClasses),
+    HtOperation(Nl80211ElementHtOperation),
+    RmEnabledCapabilities(Nl80211RmEnabledCapabilities),
+    VhtCapability(Nl80211VhtCapability),
+    VhtOperation(Nl80211ElementVhtOperation),
     /// Vendor specific data.
     Vendor(Vec<u8>),
+    /// RSN Extension
+    Rsnx(Nl80211RsnxCapabilities),
+    /// Element identified by Element ID Extension
+    Extension(Nl80211ElementExtension),
     Other(u8, Vec<u8>),
 }
 
@@ -95,6 +211,19 @@ impl Nl80211Element {
             Self::Rsn(_) => ELEMENT_ID_RSN,
             Self::Vendor(_) => ELEMENT_ID_VENDOR,
             Self::HtCapability(_) => ELEMENT_ID_HT_CAP,
+            Self::ExtSupportedRatesAndSelectors(_) => {
+                ELEMENT_ID_EXT_SUPPORTED_RATES
+            }
+            Self::MobilityDomain(_) => ELEMENT_ID_MOBILITY_DOMAIN,
+            Self::SupportedOperatingClasses(_) => {
+                ELEMENT_ID_SUPPORTED_OPERATING_CLASSES
+            }
+            Self::HtOperation(_) => ELEMENT_ID_HT_OPERATION,
+            Self::RmEnabledCapabilities(_) => ELEMENT_ID_RM_ENABLED_CAP,
+            Self::VhtCapability(_) => ELEMENT_ID_VHT_CAP,
+            Self::VhtOperation(_) => ELEMENT_ID_VHT_OPERATION,
+            Self::Rsnx(_) => ELEMENT_ID_RSNX,
+            Self::Extension(_) => ELEMENT_ID_EXTENSION,
             Self::Other(id, _) => *id,
         }
     }
@@ -102,13 +231,22 @@ impl Nl80211Element {
     /// The length field in IEEE 802.11-2020 `Figure 9-145 Element format`
     pub(crate) fn length(&self) -> u8 {
         match self {
-            Self::Ssid(v) => v.len() as u8,
+            Self::Ssid(v) => v.as_bytes().len() as u8,
             Self::SupportedRatesAndSelectors(v) => v.len() as u8,
             Self::Channel(_) => 1,
             Self::Country(v) => v.buffer_len() as u8,
             Self::Rsn(v) => v.buffer_len() as u8,
             Self::Vendor(v) => v.len() as u8,
             Self::HtCapability(v) => v.buffer_len() as u8,
+            Self::ExtSupportedRatesAndSelectors(v) => v.len() as u8,
+            Self::MobilityDomain(v) => v.buffer_len() as u8,
+            Self::SupportedOperatingClasses(v) => v.buffer_len() as u8,
+            Self::HtOperation(v) => v.buffer_len() as u8,
+            Self::RmEnabledCapabilities(v) => v.buffer_len() as u8,
+            Self::VhtCapability(v) => v.buffer_len() as u8,
+            Self::VhtOperation(v) => v.buffer_len() as u8,
+            Self::Rsnx(v) => v.buffer_len() as u8,
+            Self::Extension(v) => v.buffer_len() as u8,
             Self::Other(_, data) => (data.len()) as u8,
         }
     }
@@ -117,7 +255,7 @@ impl Nl80211Element {
 impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211Element {
     fn parse(buf: &T) -> Result<Self, DecodeError> {
         let buf = buf.as_ref();
-        if buf.len() <= 2 {
+        if buf.len() < 2 || buf.len() < buf[1] as usize + 2 {
             return Err(
                 format!("Invalid length of Nl80211Element {buf:?}").into()
             );
@@ -126,10 +264,7 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211Element {
         let length = buf[1];
         let payload = &buf[2..length as usize + 2];
         Ok(match id {
-            ELEMENT_ID_SSID => Self::Ssid(
-                parse_string(payload)
-                    .context(format!("Invalid SSID {payload:?}"))?,
-            ),
+            ELEMENT_ID_SSID => Self::Ssid(Nl80211Ssid::from(payload)),
             ELEMENT_ID_SUPPORTED_RATES => Self::SupportedRatesAndSelectors(
                 payload
                     .iter()
@@ -147,6 +282,40 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211Element {
             ELEMENT_ID_HT_CAP => {
                 Self::HtCapability(Nl80211ElementHtCap::parse(payload)?)
             }
+            ELEMENT_ID_EXT_SUPPORTED_RATES => {
+                Self::ExtSupportedRatesAndSelectors(
+                    payload
+                        .iter()
+                        .map(|d| Nl80211RateAndSelector::from(*d))
+                        .collect(),
+                )
+            }
+            ELEMENT_ID_MOBILITY_DOMAIN => Self::MobilityDomain(
+                Nl80211ElementMobilityDomain::parse(payload)?,
+            ),
+            ELEMENT_ID_SUPPORTED_OPERATING_CLASSES => {
+                Self::SupportedOperatingClasses(
+                    Nl80211ElementSupportedOperatingClasses::parse(payload)?,
+                )
+            }
+            ELEMENT_ID_HT_OPERATION => {
+                Self::HtOperation(Nl80211ElementHtOperation::parse(payload)?)
+            }
+            ELEMENT_ID_RM_ENABLED_CAP => Self::RmEnabledCapabilities(
+                Nl80211RmEnabledCapabilities::parse(payload)?,
+            ),
+            ELEMENT_ID_VHT_CAP => {
+                Self::VhtCapability(Nl80211VhtCapability::parse(payload)?)
+            }
+            ELEMENT_ID_VHT_OPERATION => {
+                Self::VhtOperation(Nl80211ElementVhtOperation::parse(payload)?)
+            }
+            ELEMENT_ID_RSNX => {
+                Self::Rsnx(Nl80211RsnxCapabilities::parse(payload)?)
+            }
+            ELEMENT_ID_EXTENSION => {
+                Self::Extension(Nl80211ElementExtension::parse(payload)?)
+            }
             _ => Self::Other(id, payload.to_vec()),
         })
     }
@@ -172,11 +341,24 @@ impl Emitable for Nl80211Element {
                     v.as_slice().iter().map(|v| u8::from(*v)).collect();
                 payload.copy_from_slice(raw.as_slice());
             }
-            Self::Channel(v) => buffer[0] = *v,
-            Self::Country(v) => v.emit(buffer),
-            Self::Rsn(v) => v.emit(buffer),
-            Self::Vendor(v) => buffer[..v.len()].copy_from_slice(v.as_slice()),
-            Self::HtCapability(v) => v.emit(buffer),
+            Self::Channel(v) => payload[0] = *v,
+            Self::Country(v) => v.emit(payload),
+            Self::Rsn(v) => v.emit(payload),
+            Self::Vendor(v) => payload.copy_from_slice(v.as_slice()),
+            Self::HtCapability(v) => v.emit(payload),
+            Self::ExtSupportedRatesAndSelectors(v) => {
+                let raw: Vec<u8> =
+                    v.as_slice().iter().map(|v| u8::from(*v)).collect();
+                payload.copy_from_slice(raw.as_slice());
+            }
+            Self::MobilityDomain(v) => v.emit(payload),
+            Self::SupportedOperatingClasses(v) => v.emit(payload),
+            Self::HtOperation(v) => v.emit(payload),
+            Self::RmEnabledCapabilities(v) => v.emit(payload),
+            Self::VhtCapability(v) => v.emit(payload),
+            Self::VhtOperation(v) => v.emit(payload),
+            Self::Rsnx(v) => v.emit(payload),
+            Self::Extension(v) => v.emit(payload),
             Self::Other(_, data) => {
                 payload.copy_from_slice(data.as_slice());
             }
@@ -191,6 +373,7 @@ const BSS_MEMBERSHIP_SELECTOR_VHT_PHY: u8 = 126;
 const BSS_MEMBERSHIP_SELECTOR_HT_PHY: u8 = 127;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211RateAndSelector {
     /// BSS basic rate set in Mb/s.
@@ -236,21 +419,21 @@ impl From<u8> for Nl80211RateAndSelector {
 impl From<Nl80211RateAndSelector> for u8 {
     fn from(v: Nl80211RateAndSelector) -> u8 {
         match v {
-            Nl80211RateAndSelector::BssBasicRateSet(r) => (r * 2) & 1 << 7,
+            Nl80211RateAndSelector::BssBasicRateSet(r) => (r * 2) | 1 << 7,
             Nl80211RateAndSelector::SelectorHt => {
-                BSS_MEMBERSHIP_SELECTOR_HT_PHY & 1 << 7
+                BSS_MEMBERSHIP_SELECTOR_HT_PHY | 1 << 7
             }
             Nl80211RateAndSelector::SelectorVht => {
-                BSS_MEMBERSHIP_SELECTOR_VHT_PHY & 1 << 7
+                BSS_MEMBERSHIP_SELECTOR_VHT_PHY | 1 << 7
             }
             Nl80211RateAndSelector::SelectorGlk => {
-                BSS_MEMBERSHIP_SELECTOR_GLK & 1 << 7
+                BSS_MEMBERSHIP_SELECTOR_GLK | 1 << 7
             }
             Nl80211RateAndSelector::SelectorEpd => {
-                BSS_MEMBERSHIP_SELECTOR_EPD & 1 << 7
+                BSS_MEMBERSHIP_SELECTOR_EPD | 1 << 7
             }
             Nl80211RateAndSelector::SelectorSaeHash => {
-                BSS_MEMBERSHIP_SELECTOR_SAE_HASH & 1 << 7
+                BSS_MEMBERSHIP_SELECTOR_SAE_HASH | 1 << 7
             }
             Nl80211RateAndSelector::Rate(r) => r * 2,
         }
@@ -258,6 +441,7 @@ impl From<Nl80211RateAndSelector> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Nl80211ElementCountry {
     pub country: String,
@@ -299,7 +483,7 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211ElementCountry {
 
 impl Emitable for Nl80211ElementCountry {
     fn buffer_len(&self) -> usize {
-        (self.triplets.len() * 3 + 3 + 1) / 2 * 2
+        (self.triplets.len() * 3 + 3).div_ceil(2) * 2
     }
 
     fn emit(&self, buffer: &mut [u8]) {
@@ -313,7 +497,7 @@ impl Emitable for Nl80211ElementCountry {
             buffer[0] = self.country.as_bytes()[0];
             buffer[1] = self.country.as_bytes()[1];
         }
-        buffer[3] = self.environment.into();
+        buffer[2] = self.environment.into();
         for (i, triplet) in self.triplets.as_slice().iter().enumerate() {
             triplet.emit(&mut buffer[(i + 1) * 3..(i + 2) * 3]);
         }
@@ -321,6 +505,7 @@ impl Emitable for Nl80211ElementCountry {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211ElementCountryEnvironment {
     Indoor,
@@ -357,6 +542,7 @@ impl From<u8> for Nl80211ElementCountryEnvironment {
 const IEEE80211_COUNTRY_EXTENSION_ID: u8 = 201;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211ElementCountryTriplet {
     Subband(Nl80211ElementSubBand),
@@ -399,6 +585,7 @@ impl Nl80211ElementCountryTriplet {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211ElementSubBand {
     pub channel_start: u8,
     pub channel_count: u8,
@@ -430,6 +617,7 @@ impl From<[u8; 3]> for Nl80211ElementSubBand {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211ElementOperating {
     pub extention_id: u8,
     pub operating_class: u8,
@@ -462,23 +650,24 @@ impl From<[u8; 3]> for Nl80211ElementOperating {
 
 /// Robust Security Network Element
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211ElementRsn {
     pub version: u16,
     pub group_cipher: Option<Nl80211CipherSuite>,
     pub pairwise_ciphers: Vec<Nl80211CipherSuite>,
-    /// Authentication Key Management(AKM) suits
-    pub akm_suits: Vec<Nl80211AkmSuite>,
-    pub rsn_capbilities: Option<Nl80211RsnCapbilities>,
+    /// Authentication Key Management(AKM) suites
+    pub akm_suites: Vec<Nl80211AkmSuite>,
+    pub rsn_capabilities: Option<Nl80211RsnCapabilities>,
     pub pmkids: Vec<Nl80211Pmkid>,
     pub group_mgmt_cipher: Option<Nl80211CipherSuite>,
 }
 
 impl Nl80211ElementRsn {
     pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
-        if payload.len() != 2 && payload.len() < 8 {
+        if payload.len() < 2 {
             return Err(format!(
                 "Invalid buffer length of Nl80211ElementRsn, \
-                expecting 2 or bigger than 7, but got {payload:?}"
+                expecting 2 or bigger, but got {payload:?}"
             )
             .into());
         }
@@ -489,7 +678,7 @@ impl Nl80211ElementRsn {
 
         let mut offset = 2;
 
-        if offset >= payload.len() {
+        if offset + Nl80211CipherSuite::LENGTH > payload.len() {
             return Ok(ret);
         }
 
@@ -498,18 +687,15 @@ impl Nl80211ElementRsn {
         )?);
         offset += Nl80211CipherSuite::LENGTH;
 
-        if offset >= payload.len() || offset + 2 >= payload.len() {
+        if offset + 2 > payload.len() {
             return Ok(ret);
         }
         let pairwise_cipher_count =
             u16::from_le_bytes([payload[offset], payload[offset + 1]]) as usize;
         offset += 2;
-        if offset >= payload.len() {
-            return Ok(ret);
-        }
 
         for _ in 0..pairwise_cipher_count {
-            if offset + Nl80211CipherSuite::LENGTH >= payload.len() {
+            if offset + Nl80211CipherSuite::LENGTH > payload.len() {
                 return Ok(ret);
             }
             ret.pairwise_ciphers.push(Nl80211CipherSuite::parse(
@@ -518,43 +704,37 @@ impl Nl80211ElementRsn {
             offset += Nl80211CipherSuite::LENGTH;
         }
 
-        if offset >= payload.len() || offset + 2 >= payload.len() {
+        if offset + 2 > payload.len() {
             return Ok(ret);
         }
         let akm_count =
             u16::from_le_bytes([payload[offset], payload[offset + 1]]) as usize;
         offset += 2;
-        if offset >= payload.len() {
-            return Ok(ret);
-        }
         for _ in 0..akm_count {
-            if offset + Nl80211AkmSuite::LENGTH >= payload.len() {
+            if offset + Nl80211AkmSuite::LENGTH > payload.len() {
                 return Ok(ret);
             }
-            ret.akm_suits.push(Nl80211AkmSuite::parse(
+            ret.akm_suites.push(Nl80211AkmSuite::parse(
                 &payload[offset..offset + Nl80211AkmSuite::LENGTH],
             )?);
             offset += Nl80211AkmSuite::LENGTH;
         }
-        if offset >= payload.len() || offset + 2 >= payload.len() {
+        if offset + 2 > payload.len() {
             return Ok(ret);
         }
 
-        ret.rsn_capbilities =
-            Some(Nl80211RsnCapbilities::parse(&payload[offset..offset + 2])?);
+        ret.rsn_capabilities =
+            Some(Nl80211RsnCapabilities::parse(&payload[offset..offset + 2])?);
         offset += 2;
 
-        if offset >= payload.len() || offset + 2 >= payload.len() {
+        if offset + 2 > payload.len() {
             return Ok(ret);
         }
         let pmkids_count =
             u16::from_le_bytes([payload[offset], payload[offset + 1]]) as usize;
         offset += 2;
-        if offset >= payload.len() {
-            return Ok(ret);
-        }
         for _ in 0..pmkids_count {
-            if offset + Nl80211Pmkid::LENGTH >= payload.len() {
+            if offset + Nl80211Pmkid::LENGTH > payload.len() {
                 return Ok(ret);
             }
             ret.pmkids.push(Nl80211Pmkid::parse(
@@ -563,9 +743,7 @@ impl Nl80211ElementRsn {
             offset += Nl80211Pmkid::LENGTH;
         }
 
-        if offset >= payload.len()
-            || offset + Nl80211CipherSuite::LENGTH >= payload.len()
-        {
+        if offset + Nl80211CipherSuite::LENGTH > payload.len() {
             return Ok(ret);
         }
 
@@ -577,166 +755,109 @@ impl Nl80211ElementRsn {
     }
 }
 
+impl Nl80211ElementRsn {
+    // The fields are positional, hence a field is emitted when itself or any
+    // later field is present. Return the number of fields following the
+    // version field to emit.
+    fn emitted_field_count(&self) -> usize {
+        [
+            self.group_cipher.is_some(),
+            !self.pairwise_ciphers.is_empty(),
+            !self.akm_suites.is_empty(),
+            self.rsn_capabilities.is_some(),
+            !self.pmkids.is_empty(),
+            self.group_mgmt_cipher.is_some(),
+        ]
+        .iter()
+        .rposition(|present| *present)
+        .map_or(0, |i| i + 1)
+    }
+}
+
 impl Emitable for Nl80211ElementRsn {
     fn buffer_len(&self) -> usize {
+        let field_lens = [
+            Nl80211CipherSuite::LENGTH,
+            2 + self.pairwise_ciphers.len() * Nl80211CipherSuite::LENGTH,
+            2 + self.akm_suites.len() * Nl80211AkmSuite::LENGTH,
+            Nl80211RsnCapabilities::LENGTH,
+            2 + self.pmkids.len() * Nl80211Pmkid::LENGTH,
+            Nl80211CipherSuite::LENGTH,
+        ];
         // version field
-        let mut len = 2usize;
-        if self.group_cipher.is_none() {
-            return len;
-        } else {
-            len += Nl80211CipherSuite::LENGTH;
-        }
+        2 + field_lens[..self.emitted_field_count()]
+            .iter()
+            .sum::<usize>()
+    }
 
-        if self.pairwise_ciphers.is_empty() {
-            return len;
-        } else {
-            len += 2 + self.pairwise_ciphers.len() * Nl80211CipherSuite::LENGTH;
-        }
+    // Absent fields followed by present ones are emitted as empty list or
+    // default value, matching `buffer_len()`.
+    fn emit(&self, buffer: &mut [u8]) {
+        let count = self.emitted_field_count();
+        write_u16_le(&mut buffer[0..2], self.version);
+        let mut offset = 2;
 
-        if self.akm_suits.is_empty() {
-            return len;
-        } else {
-            len += 2 + self.akm_suits.len() * Nl80211AkmSuite::LENGTH;
+        if count < 1 {
+            return;
         }
+        self.group_cipher
+            .unwrap_or_default()
+            .emit(&mut buffer[offset..]);
+        offset += Nl80211CipherSuite::LENGTH;
 
-        if self.rsn_capbilities.is_none() {
-            return len;
-        } else {
-            len += 2;
+        if count < 2 {
+            return;
+        }
+        write_u16_le(
+            &mut buffer[offset..offset + 2],
+            self.pairwise_ciphers.len() as u16,
+        );
+        offset += 2;
+        for cipher in self.pairwise_ciphers.as_slice() {
+            cipher.emit(&mut buffer[offset..]);
+            offset += Nl80211CipherSuite::LENGTH;
         }
 
-        if self.pmkids.is_empty() {
-            return len;
-        } else {
-            len += 2 + self.pmkids.len() * Nl80211Pmkid::LENGTH;
+        if count < 3 {
+            return;
         }
-        if self.group_mgmt_cipher.is_none() {
-            return len;
-        } else {
-            len += Nl80211CipherSuite::LENGTH;
+        write_u16_le(
+            &mut buffer[offset..offset + 2],
+            self.akm_suites.len() as u16,
+        );
+        offset += 2;
+        for akm in self.akm_suites.as_slice() {
+            write_u32_le(&mut buffer[offset..offset + 4], u32::from(*akm));
+            offset += Nl80211AkmSuite::LENGTH;
         }
 
-        len
-    }
-
-    fn emit(&self, buffer: &mut [u8]) {
-        write_u16_le(&mut buffer[0..2], self.version);
-        if let Some(g) = self.group_cipher {
-            write_u32_le(&mut buffer[2..6], u32::from(g));
-            write_u16_le(&mut buffer[6..8], self.pairwise_ciphers.len() as u16);
-        }
-        for (i, cipher) in self.pairwise_ciphers.as_slice().iter().enumerate() {
-            write_u32_le(
-                &mut buffer[(8 + i * 4)..(12 + i * 4)],
-                u32::from(*cipher),
-            );
+        if count < 4 {
+            return;
         }
-    }
-}
-
-const IEEE_80211_OUI: u32 = 0x00ac0f00;
-const CIPHER_USE_GROUP: u32 = IEEE_80211_OUI;
-const CIPHER_WEP_40: u32 = IEEE_80211_OUI | 1 << 24;
-const CIPHER_TKIP: u32 = IEEE_80211_OUI | 2 << 24;
-const CIPHER_CCMP_128: u32 = IEEE_80211_OUI | 4 << 24;
-const CIPHER_WEP_104: u32 = IEEE_80211_OUI | 5 << 24;
-const CIPHER_BIP_CMAC_128: u32 = IEEE_80211_OUI | 6 << 24;
-const CIPHER_GROUP_ADDRESSED_TRACFFIC_NOT_ALLOWED: u32 =
-    IEEE_80211_OUI | 7 << 24;
-const CIPHER_GCMP_128: u32 = IEEE_80211_OUI | 8 << 24;
-const CIPHER_GCMP_256: u32 = IEEE_80211_OUI | 9 << 24;
-const CIPHER_CCMP_256: u32 = IEEE_80211_OUI | 10 << 24;
-const CIPHER_BIP_GMAC_128: u32 = IEEE_80211_OUI | 11 << 24;
-const CIPHER_BIP_GMAC_256: u32 = IEEE_80211_OUI | 12 << 24;
-const CIPHER_BIP_CMAC_256: u32 = IEEE_80211_OUI | 13 << 24;
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
-#[non_exhaustive]
-pub enum Nl80211CipherSuite {
-    UseGroup,
-    Wep40,
-    Tkip,
-    // The 802.11-2020 said only non-DMG default to CCMP-128.
-    // But considering 60G 802.11ad(DMG) is rarely used, it is reasonable to
-    // assume Ccmp128 is default
-    #[default]
-    Ccmp128,
-    Wep104,
-    BipCmac128,
-    GroupAddressedTrafficNotAllowed,
-    Gcmp128,
-    Gcmp256,
-    Ccmp256,
-    BipGmac128,
-    BipGmac256,
-    BipCmac256,
-    Other(u32),
-}
+        self.rsn_capabilities
+            .unwrap_or_default()
+            .emit(&mut buffer[offset..offset + Nl80211RsnCapabilities::LENGTH]);
+        offset += Nl80211RsnCapabilities::LENGTH;
 
-impl From<u32> for Nl80211CipherSuite {
-    fn from(d: u32) -> Self {
-        match d {
-            CIPHER_USE_GROUP => Self::UseGroup,
-            CIPHER_WEP_40 => Self::Wep40,
-            CIPHER_TKIP => Self::Tkip,
-            CIPHER_CCMP_128 => Self::Ccmp128,
-            CIPHER_WEP_104 => Self::Wep104,
-            CIPHER_BIP_CMAC_128 => Self::BipCmac128,
-            CIPHER_GROUP_ADDRESSED_TRACFFIC_NOT_ALLOWED => {
-                Self::GroupAddressedTrafficNotAllowed
-            }
-            CIPHER_GCMP_128 => Self::Gcmp128,
-            CIPHER_GCMP_256 => Self::Gcmp256,
-            CIPHER_CCMP_256 => Self::Ccmp256,
-            CIPHER_BIP_GMAC_128 => Self::BipGmac128,
-            CIPHER_BIP_GMAC_256 => Self::BipGmac256,
-            CIPHER_BIP_CMAC_256 => Self::BipCmac256,
-            _ => Self::Other(d),
+        if count < 5 {
+            return;
+        }
+        write_u16_le(&mut buffer[offset..offset + 2], self.pmkids.len() as u16);
+        offset += 2;
+        for pmkid in self.pmkids.as_slice() {
+            buffer[offset..offset + Nl80211Pmkid::LENGTH]
+                .copy_from_slice(&pmkid.0);
+            offset += Nl80211Pmkid::LENGTH;
         }
-    }
-}
 
-impl From<Nl80211CipherSuite> for u32 {
-    fn from(v: Nl80211CipherSuite) -> u32 {
-        match v {
-            Nl80211CipherSuite::UseGroup => CIPHER_USE_GROUP,
-            Nl80211CipherSuite::Wep40 => CIPHER_WEP_40,
-            Nl80211CipherSuite::Tkip => CIPHER_TKIP,
-            Nl80211CipherSuite::Ccmp128 => CIPHER_CCMP_128,
-            Nl80211CipherSuite::Wep104 => CIPHER_WEP_104,
-            Nl80211CipherSuite::BipCmac128 => CIPHER_BIP_CMAC_128,
-            Nl80211CipherSuite::GroupAddressedTrafficNotAllowed => {
-                CIPHER_GROUP_ADDRESSED_TRACFFIC_NOT_ALLOWED
-            }
-            Nl80211CipherSuite::Gcmp128 => CIPHER_GCMP_128,
-            Nl80211CipherSuite::Gcmp256 => CIPHER_GCMP_256,
-            Nl80211CipherSuite::Ccmp256 => CIPHER_CCMP_256,
-            Nl80211CipherSuite::BipGmac128 => CIPHER_BIP_GMAC_128,
-            Nl80211CipherSuite::BipGmac256 => CIPHER_BIP_GMAC_256,
-            Nl80211CipherSuite::BipCmac256 => CIPHER_BIP_CMAC_256,
-            Nl80211CipherSuite::Other(d) => d,
+        if let Some(g) = self.group_mgmt_cipher {
+            g.emit(&mut buffer[offset..]);
         }
     }
 }
 
-impl Nl80211CipherSuite {
-    pub const LENGTH: usize = 4;
+const IEEE_80211_OUI: u32 = 0x00ac0f00;
 
-    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
-        if payload.len() < 4 {
-            Err(format!(
-                "Invalid buffer length for Nl80211CipherSuite, \
-                expecting 4, but got {payload:?}"
-            )
-            .into())
-        } else {
-            Ok(u32::from_le_bytes([
-                payload[0], payload[1], payload[2], payload[3],
-            ])
-            .into())
-        }
-    }
-}
 const AKM_1X: u32 = IEEE_80211_OUI | 1 << 24;
 const AKM_PSK: u32 = IEEE_80211_OUI | 2 << 24;
 const AKM_FT_1X: u32 = IEEE_80211_OUI | 3 << 24;
@@ -754,6 +875,7 @@ const AKM_FILS_SHA256_AES_SIV256_OR_1X: u32 = IEEE_80211_OUI | 14 << 24;
 const AKM_FILS_SHA384_AES_SIV512_OR_1X: u32 = IEEE_80211_OUI | 15 << 24;
 const AKM_FT_FILS_SHA256_AES_SIV256_OR_1X: u32 = IEEE_80211_OUI | 16 << 24;
 const AKM_FT_FILS_SHA384_AES_SIV512_OR_1X: u32 = IEEE_80211_OUI | 17 << 24;
+const AKM_OWE: u32 = IEEE_80211_OUI | 18 << 24;
 const AKM_FT_PSK_SHA384: u32 = IEEE_80211_OUI | 19 << 24;
 const AKM_PSK_SHA384: u32 = IEEE_80211_OUI | 20 << 24;
 const AKM_SAE_GROUP_HASH: u32 = IEEE_80211_OUI | 24 << 24;
@@ -761,6 +883,7 @@ const AKM_FT_SAE_GROUP_HASH: u32 = IEEE_80211_OUI | 25 << 24;
 
 /// Authentication Key Management Suite
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211AkmSuite {
     Ieee8021x,
@@ -780,6 +903,8 @@ pub enum Nl80211AkmSuite {
     FilsSha384AesSiv512OrIeee8021x,
     FtFilsSha256AesSiv256OrIeee8021x,
     FtFilsSha384AesSiv512OrIeee8021x,
+    /// Opportunistic Wireless Encryption
+    Owe,
     FtPskSha384,
     PskSha384,
     // Defined in WPA 3 as 00-0F-AC:24
@@ -817,6 +942,7 @@ impl From<u32> for Nl80211AkmSuite {
             AKM_FT_FILS_SHA384_AES_SIV512_OR_1X => {
                 Self::FtFilsSha384AesSiv512OrIeee8021x
             }
+            AKM_OWE => Self::Owe,
             AKM_FT_PSK_SHA384 => Self::FtPskSha384,
             AKM_PSK_SHA384 => Self::PskSha384,
             AKM_SAE_GROUP_HASH => Self::SaeGroupDependentHash,
@@ -854,6 +980,7 @@ impl From<Nl80211AkmSuite> for u32 {
             Nl80211AkmSuite::FtFilsSha384AesSiv512OrIeee8021x => {
                 AKM_FT_FILS_SHA384_AES_SIV512_OR_1X
             }
+            Nl80211AkmSuite::Owe => AKM_OWE,
             Nl80211AkmSuite::FtPskSha384 => AKM_FT_PSK_SHA384,
             Nl80211AkmSuite::PskSha384 => AKM_PSK_SHA384,
             Nl80211AkmSuite::SaeGroupDependentHash => AKM_SAE_GROUP_HASH,
@@ -865,6 +992,17 @@ impl From<Nl80211AkmSuite> for u32 {
 impl Nl80211AkmSuite {
     pub const LENGTH: usize = 4;
 
+    /// The nl80211 attributes store the suite selector as
+    /// `OUI << 8 | type` while this enum follows the byte order of the RSN
+    /// element.
+    pub(crate) fn from_suite_selector(d: u32) -> Self {
+        Self::from(d.swap_bytes())
+    }
+
+    pub(crate) fn suite_selector(self) -> u32 {
+        u32::from(self).swap_bytes()
+    }
+
     pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
         if payload.len() < 4 {
             Err(format!(
@@ -901,8 +1039,9 @@ bitflags::bitflags! {
     /// If not bands are set, it means don't care and the device will decide
     /// what to use
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
-    pub struct Nl80211RsnCapbilities: u16 {
+    pub struct Nl80211RsnCapabilities: u16 {
         /// Indicates the AP support preauthentication.
         const PreAuth = RSN_CAP_PRE_AUTH;
         /// Indicates the STA does not support WEP default key 0 simultaneously
@@ -951,17 +1090,17 @@ bitflags::bitflags! {
     }
 }
 
-impl Nl80211RsnCapbilities {
+impl Nl80211RsnCapabilities {
     pub const LENGTH: usize = 2;
 
     pub fn parse(raw: &[u8]) -> Result<Self, DecodeError> {
         Ok(Self::from_bits_retain(parse_u16_le(raw).context(
-            format!("Invalid Nl80211RsnCapbilities payload {raw:?}"),
+            format!("Invalid Nl80211RsnCapabilities payload {raw:?}"),
         )?))
     }
 }
 
-impl Emitable for Nl80211RsnCapbilities {
+impl Emitable for Nl80211RsnCapabilities {
     fn buffer_len(&self) -> usize {
         Self::LENGTH
     }
@@ -973,6 +1112,7 @@ impl Emitable for Nl80211RsnCapbilities {
 
 /// Authentication Key Management Suite
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211Pmkid(pub [u8; 16]);
 
 impl Nl80211Pmkid {
@@ -993,3 +1133,314 @@ impl Nl80211Pmkid {
         }
     }
 }
+
+/// Mobility Domain element (MDE)
+///
+/// IEEE 802.11-2020 section 9.4.2.46
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementMobilityDomain {
+    /// Mobility Domain Identifier (MDID)
+    pub mdid: u16,
+    /// FT Capability and Policy
+    pub ft_capability_policy: u8,
+}
+
+impl Nl80211ElementMobilityDomain {
+    pub const LENGTH: usize = 3;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid buffer length for Nl80211ElementMobilityDomain, \
+                expecting {}, but got {payload:?}",
+                Self::LENGTH
+            )
+            .into())
+        } else {
+            Ok(Self {
+                mdid: u16::from_le_bytes([payload[0], payload[1]]),
+                ft_capability_policy: payload[2],
+            })
+        }
+    }
+
+    /// Whether Fast BSS Transition over DS is supported
+    pub fn ft_over_ds(&self) -> bool {
+        self.ft_capability_policy & 1 << 0 > 0
+    }
+
+    /// Whether Resource Request Protocol is supported
+    pub fn resource_request_protocol(&self) -> bool {
+        self.ft_capability_policy & 1 << 1 > 0
+    }
+}
+
+impl Emitable for Nl80211ElementMobilityDomain {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        write_u16_le(&mut buffer[0..2], self.mdid);
+        buffer[2] = self.ft_capability_policy;
+    }
+}
+
+/// Supported Operating Classes element
+///
+/// IEEE 802.11-2020 section 9.4.2.53
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementSupportedOperatingClasses {
+    pub current: u8,
+    /// Operating classes, might be followed by Current Operating Class
+    /// Extension Sequence and Operating Class Duple Sequence which are
+    /// separated by value 130 and 0.
+    pub classes: Vec<u8>,
+}
+
+impl Nl80211ElementSupportedOperatingClasses {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.is_empty() {
+            Err("Empty buffer for Nl80211ElementSupportedOperatingClasses"
+                .to_string()
+                .into())
+        } else {
+            Ok(Self {
+                current: payload[0],
+                classes: payload[1..].to_vec(),
+            })
+        }
+    }
+}
+
+impl Emitable for Nl80211ElementSupportedOperatingClasses {
+    fn buffer_len(&self) -> usize {
+        self.classes.len() + 1
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.current;
+        buffer[1..self.classes.len() + 1].copy_from_slice(&self.classes);
+    }
+}
+
+const RM_ENABLED_CAP_LEN: usize = 5;
+
+bitflags::bitflags! {
+    /// RM Enabled Capabilities element
+    ///
+    /// IEEE 802.11-2020 section 9.4.2.44
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211RmEnabledCapabilities: u64 {
+        const LinkMeasurement = 1 << 0;
+        const NeighborReport = 1 << 1;
+        const ParallelMeasurements = 1 << 2;
+        const RepeatedMeasurements = 1 << 3;
+        const BeaconPassiveMeasurement = 1 << 4;
+        const BeaconActiveMeasurement = 1 << 5;
+        const BeaconTableMeasurement = 1 << 6;
+        const BeaconMeasurementReportingConditions = 1 << 7;
+        const FrameMeasurement = 1 << 8;
+        const ChannelLoadMeasurement = 1 << 9;
+        const NoiseHistogramMeasurement = 1 << 10;
+        const StatisticsMeasurement = 1 << 11;
+        const LciMeasurement = 1 << 12;
+        const LciAzimuth = 1 << 13;
+        const TransmitStreamCategoryMeasurement = 1 << 14;
+        const TriggeredTransmitStreamCategoryMeasurement = 1 << 15;
+        const ApChannelReport = 1 << 16;
+        const RmMib = 1 << 17;
+        // Bits 18-26 are durations and measurement pilot capability
+        const MeasurementPilotTransmissionInformation = 1 << 27;
+        const NeighborReportTsfOffset = 1 << 28;
+        const RcpiMeasurement = 1 << 29;
+        const RsniMeasurement = 1 << 30;
+        const BssAverageAccessDelay = 1 << 31;
+        const BssAvailableAdmissionCapacity = 1 << 32;
+        const Antenna = 1 << 33;
+        const FtmRangeReport = 1 << 34;
+        const CivicLocationMeasurement = 1 << 35;
+        const _ = !0;
+    }
+}
+
+impl Nl80211RmEnabledCapabilities {
+    pub const LENGTH: usize = RM_ENABLED_CAP_LEN;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid buffer length for Nl80211RmEnabledCapabilities, \
+                expecting {}, but got {payload:?}",
+                Self::LENGTH
+            )
+            .into())
+        } else {
+            let mut raw = [0u8; 8];
+            raw[..Self::LENGTH].copy_from_slice(&payload[..Self::LENGTH]);
+            Ok(Self::from_bits_retain(u64::from_le_bytes(raw)))
+        }
+    }
+
+    /// Operating Channel Max Measurement Duration
+    pub fn operating_channel_max_measurement_duration(&self) -> u8 {
+        ((self.bits() >> 18) & 0b111) as u8
+    }
+
+    /// Nonoperating Channel Max Measurement Duration
+    pub fn nonoperating_channel_max_measurement_duration(&self) -> u8 {
+        ((self.bits() >> 21) & 0b111) as u8
+    }
+
+    /// Measurement Pilot Capability
+    pub fn measurement_pilot_capability(&self) -> u8 {
+        ((self.bits() >> 24) & 0b111) as u8
+    }
+}
+
+impl Emitable for Nl80211RmEnabledCapabilities {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..Self::LENGTH]
+            .copy_from_slice(&self.bits().to_le_bytes()[..Self::LENGTH])
+    }
+}
+
+// The lowest 4 bits of RSNXE are `Field Length` instead of capabilities
+const RSNX_FIELD_LENGTH_MASK: u32 = 0b1111;
+const RSNX_MAX_LEN: usize = 4;
+
+bitflags::bitflags! {
+    /// Capabilities in RSN Extension element (RSNXE)
+    ///
+    /// IEEE 802.11-2020 section 9.4.2.241
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211RsnxCapabilities: u32 {
+        /// Protected TWT Operations Support
+        const ProtectedTwt = 1 << 4;
+        /// SAE hash-to-element
+        const SaeH2e = 1 << 5;
+        /// SAE Public Key
+        const SaePk = 1 << 6;
+        /// Protected WUR Frame Support
+        const ProtectedWurFrame = 1 << 7;
+        /// Secure LTF Support
+        const SecureLtf = 1 << 8;
+        /// Secure RTT Support
+        const SecureRtt = 1 << 9;
+        const _ = !RSNX_FIELD_LENGTH_MASK;
+    }
+}
+
+impl Nl80211RsnxCapabilities {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.is_empty() {
+            return Err("Empty buffer for Nl80211RsnxCapabilities"
+                .to_string()
+                .into());
+        }
+        let mut raw = [0u8; RSNX_MAX_LEN];
+        let len = std::cmp::min(payload.len(), RSNX_MAX_LEN);
+        raw[..len].copy_from_slice(&payload[..len]);
+        Ok(Self::from_bits_truncate(u32::from_le_bytes(raw)))
+    }
+}
+
+impl Emitable for Nl80211RsnxCapabilities {
+    fn buffer_len(&self) -> usize {
+        // Field length covers all the octets holding the highest set bit
+        std::cmp::max(
+            1,
+            (32 - self.bits().leading_zeros() as usize).div_ceil(8),
+        )
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let raw = self.bits() | (len as u32 - 1);
+        buffer[..len].copy_from_slice(&raw.to_le_bytes()[..len])
+    }
+}
+
+// These are `Element ID Extension` defined in IEEE 802.11ax-2021 and
+// IEEE 802.11be
+const ELEMENT_ID_EXT_HE_CAP: u8 = 35;
+const ELEMENT_ID_EXT_HE_OPERATION: u8 = 36;
+const ELEMENT_ID_EXT_EHT_CAP: u8 = 108;
+
+/// Element with Element ID 255 and identified by the Element ID Extension
+/// field
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211ElementExtension {
+    HeCapability(Nl80211ElementHeCap),
+    HeOperation(Nl80211ElementHeOperation),
+    EhtCapability(Nl80211ElementEhtCap),
+    Other(u8, Vec<u8>),
+}
+
+impl Nl80211ElementExtension {
+    /// The Element ID Extension field
+    pub fn id(&self) -> u8 {
+        match self {
+            Self::HeCapability(_) => ELEMENT_ID_EXT_HE_CAP,
+            Self::HeOperation(_) => ELEMENT_ID_EXT_HE_OPERATION,
+            Self::EhtCapability(_) => ELEMENT_ID_EXT_EHT_CAP,
+            Self::Other(id, _) => *id,
+        }
+    }
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.is_empty() {
+            return Err("Empty buffer for Nl80211ElementExtension"
+                .to_string()
+                .into());
+        }
+        let id = payload[0];
+        let payload = &payload[1..];
+        Ok(match id {
+            ELEMENT_ID_EXT_HE_CAP => {
+                Self::HeCapability(Nl80211ElementHeCap::parse(payload)?)
+            }
+            ELEMENT_ID_EXT_HE_OPERATION => {
+                Self::HeOperation(Nl80211ElementHeOperation::parse(payload)?)
+            }
+            ELEMENT_ID_EXT_EHT_CAP => {
+                Self::EhtCapability(Nl80211ElementEhtCap::parse(payload)?)
+            }
+            _ => Self::Other(id, payload.to_vec()),
+        })
+    }
+}
+
+impl Emitable for Nl80211ElementExtension {
+    fn buffer_len(&self) -> usize {
+        1 + match self {
+            Self::HeCapability(v) => v.buffer_len(),
+            Self::HeOperation(v) => v.buffer_len(),
+            Self::EhtCapability(v) => v.buffer_len(),
+            Self::Other(_, data) => data.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.id();
+        let payload = &mut buffer[1..self.buffer_len()];
+        match self {
+            Self::HeCapability(v) => v.emit(payload),
+            Self::HeOperation(v) => v.emit(payload),
+            Self::EhtCapability(v) => v.emit(payload),
+            Self::Other(_, data) => payload.copy_from_slice(data),
+        }
+    }
+}
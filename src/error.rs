@@ -4,24 +4,154 @@ use thiserror::Error;
 
 use netlink_packet_core::{ErrorMessage, NetlinkMessage};
 use netlink_packet_generic::GenlMessage;
-use netlink_packet_utils::DecodeError;
+use netlink_packet_utils::{nla::NlasIterator, DecodeError, Parseable};
 
-use crate::Nl80211Message;
+use crate::{Nl80211Attr, Nl80211Message};
+
+const NLMSG_HDRLEN: usize = 16;
+const GENL_HDRLEN: usize = 4;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Nl80211Error {
     #[error("Received an unexpected message {0:?}")]
     UnexpectedMessage(NetlinkMessage<GenlMessage<Nl80211Message>>),
 
-    #[error("Received a netlink error message {0}")]
-    NetlinkError(ErrorMessage),
+    /// Kernel replied error not covered by the dedicated variants, use
+    /// [Nl80211Error::errno()] to get the error number.
+    #[error("Kernel replied error: {0}")]
+    Kernel(ErrorMessage),
 
-    #[error("A netlink request failed")]
+    #[error("A netlink request failed: {0}")]
     RequestFailed(String),
 
-    #[error("Failed to decode netlink package: {0}")]
-    DecodeFailed(DecodeError),
+    /// Failed to decode netlink message, `attr_kind` and `offset` locate the
+    /// offending top level attribute in the message when known.
+    #[error(
+        "Failed to decode netlink package{}: {source}",
+        decode_location(.attr_kind, .offset)
+    )]
+    Decode {
+        attr_kind: Option<u16>,
+        offset: Option<usize>,
+        source: DecodeError,
+    },
+
+    /// Kernel replied `EBUSY`
+    #[error("Device or resource busy: {0}")]
+    Busy(ErrorMessage),
+
+    /// Kernel replied `ENOENT`, for example no such wiphy, interface or
+    /// station
+    #[error("No such entity: {0}")]
+    NotFound(ErrorMessage),
+
+    /// Kernel replied `EOPNOTSUPP`, the operation is not supported by the
+    /// driver or the interface type
+    #[error("Operation not supported: {0}")]
+    NotSupported(ErrorMessage),
+
+    /// Kernel replied `EBUSY` to `NL80211_CMD_TRIGGER_SCAN` as another scan
+    /// is running on the wiphy
+    #[error("Scan already in progress: {0}")]
+    ScanInProgress(ErrorMessage),
+
+    /// Kernel replied `EINVAL` or `ERANGE` pointing out the offending
+    /// attribute via extended ACK, `attribute` is the name of
+    /// [crate::Nl80211Attr] variant and `message` is the kernel
+    /// explanation if any.
+    #[error("Invalid attribute {attribute}(kind {kind}): {message}")]
+    InvalidAttribute {
+        attribute: String,
+        kind: u16,
+        message: String,
+        error: ErrorMessage,
+    },
+
+    #[error("Timeout: {0}")]
+    Timeout(String),
 
     #[error("A bug in this crate")]
     Bug(String),
 }
+
+impl Nl80211Error {
+    /// The positive error number(e.g. `libc::EINVAL`) replied by kernel,
+    /// `None` if this error is not originated from kernel.
+    pub fn errno(&self) -> Option<i32> {
+        match self {
+            Self::Kernel(e)
+            | Self::Busy(e)
+            | Self::NotFound(e)
+            | Self::NotSupported(e)
+            | Self::ScanInProgress(e)
+            | Self::InvalidAttribute { error: e, .. } => {
+                Some(e.raw_code().abs())
+            }
+            _ => None,
+        }
+    }
+
+    // Locate the first top level nl80211 attribute failed to parse in the
+    // attributes following generic netlink header, the offset counts from
+    // the start of netlink header like the kernel extended ACK.
+    pub(crate) fn decode_attributes(
+        attributes: &[u8],
+        source: DecodeError,
+    ) -> Self {
+        let mut attr_kind = None;
+        let mut offset = None;
+        let mut pos = NLMSG_HDRLEN + GENL_HDRLEN;
+        for nla in NlasIterator::new(attributes) {
+            let Ok(nla) = nla else {
+                offset = Some(pos);
+                break;
+            };
+            if Nl80211Attr::parse(&nla).is_err() {
+                attr_kind = Some(nla.kind());
+                offset = Some(pos);
+                break;
+            }
+            pos += (nla.length() as usize + 3) & !3;
+        }
+        Self::Decode {
+            attr_kind,
+            offset,
+            source,
+        }
+    }
+}
+
+/// Map the common error numbers to dedicated variants, others are stored
+/// in [Nl80211Error::Kernel].
+impl From<ErrorMessage> for Nl80211Error {
+    fn from(e: ErrorMessage) -> Self {
+        match -e.raw_code() {
+            libc::EBUSY => Self::Busy(e),
+            libc::ENOENT => Self::NotFound(e),
+            libc::EOPNOTSUPP => Self::NotSupported(e),
+            _ => Self::Kernel(e),
+        }
+    }
+}
+
+impl From<DecodeError> for Nl80211Error {
+    fn from(source: DecodeError) -> Self {
+        Self::Decode {
+            attr_kind: None,
+            offset: None,
+            source,
+        }
+    }
+}
+
+fn decode_location(attr_kind: &Option<u16>, offset: &Option<usize>) -> String {
+    match (attr_kind, offset) {
+        (Some(kind), Some(offset)) => {
+            format!(" at attribute kind {kind} offset {offset}")
+        }
+        (Some(kind), None) => format!(" at attribute kind {kind}"),
+        (None, Some(offset)) => format!(" at offset {offset}"),
+        (None, None) => String::new(),
+    }
+}
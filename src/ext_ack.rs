@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{ErrorMessage, NLM_F_ACK_TLVS, NLM_F_CAPPED};
+use netlink_packet_utils::{
+    nla::{Nla, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    Emitable,
+};
+
+use crate::{Nl80211Attr, Nl80211Error};
+
+const NLMSG_HDRLEN: usize = 16;
+const GENL_HDRLEN: usize = 4;
+
+const NLMSGERR_ATTR_MSG: u16 = 1;
+const NLMSGERR_ATTR_OFFS: u16 = 2;
+
+/// Translate the extended ACK of netlink error replying request holding
+/// `attributes` into [Nl80211Error::InvalidAttribute] when kernel pointed
+/// out the offending attribute of `EINVAL` or `ERANGE` error. Other errors
+/// (e.g. `EOPNOTSUPP`) are left to their dedicated [Nl80211Error] variants.
+pub(crate) fn invalid_attribute_error(
+    flags: u16,
+    error: &ErrorMessage,
+    attributes: &[Nl80211Attr],
+) -> Option<Nl80211Error> {
+    error.code?;
+    if !matches!(-error.raw_code(), libc::EINVAL | libc::ERANGE) {
+        return None;
+    }
+    if flags & NLM_F_ACK_TLVS == 0 {
+        return None;
+    }
+    // The error payload holds the header of original request, followed by
+    // the request payload unless capped, then the extended ACK TLVs.
+    let tlv_offset = if flags & NLM_F_CAPPED > 0 {
+        NLMSG_HDRLEN
+    } else {
+        parse_u32(error.header.get(..4)?).ok()? as usize
+    };
+
+    let mut message = None;
+    let mut offset = None;
+    for nla in NlasIterator::new(error.header.get(tlv_offset..)?) {
+        let Ok(nla) = nla else {
+            break;
+        };
+        match nla.kind() {
+            NLMSGERR_ATTR_MSG => message = parse_string(nla.value()).ok(),
+            NLMSGERR_ATTR_OFFS => offset = parse_u32(nla.value()).ok(),
+            _ => (),
+        }
+    }
+
+    let attr = attr_at_offset(attributes, offset? as usize)?;
+    Some(Nl80211Error::InvalidAttribute {
+        attribute: variant_name(attr),
+        kind: attr.kind(),
+        message: message
+            .filter(|m| !m.is_empty())
+            .unwrap_or_else(|| error.to_string()),
+        error: error.clone(),
+    })
+}
+
+// The offset counts from the start of netlink header of the request, the
+// attribute holding it is reported even if the offset points into its
+// nested attributes.
+fn attr_at_offset(
+    attributes: &[Nl80211Attr],
+    offset: usize,
+) -> Option<&Nl80211Attr> {
+    let mut start = NLMSG_HDRLEN + GENL_HDRLEN;
+    for attr in attributes {
+        let end = start + attr.buffer_len();
+        if (start..end).contains(&offset) {
+            return Some(attr);
+        }
+        start = end;
+    }
+    None
+}
+
+fn variant_name(attr: &Nl80211Attr) -> String {
+    let debug = format!("{attr:?}");
+    match debug.find(['(', ' ', '{']) {
+        Some(end) => debug[..end].to_string(),
+        None => debug,
+    }
+}
@@ -9,6 +9,7 @@ use netlink_packet_utils::{
 use crate::Nl80211Attr;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211ExtendedCapability(pub Vec<u8>);
 
 //TODO: 802.11-2020 section `9.4.2.26 Extended Capabilities element` has
@@ -46,6 +47,7 @@ impl std::ops::Deref for Nl80211ExtendedCapability {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211IfTypeExtCapas(pub Vec<Nl80211IfTypeExtCapa>);
 
 impl std::ops::Deref for Nl80211IfTypeExtCapas {
@@ -88,6 +90,7 @@ impl From<Nl80211IfTypeExtCapas> for Vec<Nl80211IfTypeExtCapa> {
 // `capa_start`, hence we expose the index to user in case they want to generate
 // identical data as linux kernel does.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211IfTypeExtCapa {
     pub index: u16,
     pub attributes: Vec<Nl80211Attr>,
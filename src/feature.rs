@@ -37,6 +37,7 @@ const NL80211_FEATURE_ND_RANDOM_MAC_ADDR: u32 = 1 << 31;
 
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211Features: u32 {
         const SkTxStatus = NL80211_FEATURE_SK_TX_STATUS;
@@ -75,12 +76,29 @@ bitflags::bitflags! {
     }
 }
 
+const NL80211_PROTOCOL_FEATURE_SPLIT_WIPHY_DUMP: u32 = 1 << 0;
+
+bitflags::bitflags! {
+    /// nl80211 protocol features supported by kernel, not bound to any
+    /// wiphy.
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211ProtocolFeatures: u32 {
+        /// Kernel supports splitting wiphy dump into multiple messages when
+        /// [crate::Nl80211Attr::SplitWiphyDump] requested.
+        const SplitWiphyDump = NL80211_PROTOCOL_FEATURE_SPLIT_WIPHY_DUMP;
+        const _ = !0;
+    }
+}
+
 // Kernel is using [u8; DIV_ROUND_UP(NUM_NL80211_EXT_FEATURES, 8)] to
 // store these extended features, allowing it to support any count of
 // features more than u128. The maximum data type bitflags can use is u128,
 // which might be not enough in the future, hence we do it by ourselves without
 // using bitflags.
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub(crate) struct Nl80211ExtFeatures(pub(crate) Vec<Nl80211ExtFeature>);
 
@@ -128,6 +146,7 @@ impl From<&Vec<Nl80211ExtFeature>> for Nl80211ExtFeatures {
 // We cannot have Other() as it would make `repr(usize)` not supporting `as`
 // casting so we just discard unknown features with a log
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[repr(usize)]
 pub enum Nl80211ExtFeature {
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211Freq, Nl80211IfIndex, Nl80211Message,
+    Nl80211RxMgmtFlags, Nl80211WdevId,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Management frame received on a registered interface
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211FrameRx {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Frequency the frame was received on
+    pub frequency: Option<Nl80211Freq>,
+    /// Signal strength in dBm
+    pub signal_dbm: Option<i32>,
+    /// Whether the frame was already answered by device or is meant for
+    /// external authentication
+    pub flags: Nl80211RxMgmtFlags,
+    /// Frame including 802.11 header
+    pub frame: Vec<u8>,
+}
+
+impl Nl80211FrameRx {
+    /// Whether the frame was already answered by device or driver, the
+    /// userspace MLME should not reply to it again.
+    pub fn answered(&self) -> bool {
+        self.flags.contains(Nl80211RxMgmtFlags::Answered)
+    }
+
+    /// Whether the frame is for the authentication offloaded to userspace
+    pub fn external_auth(&self) -> bool {
+        self.flags.contains(Nl80211RxMgmtFlags::ExternalAuth)
+    }
+}
+
+/// Transmit status of frame sent by [crate::Nl80211FrameHandle::send()]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211FrameTxStatus {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Cookie returned in the reply of the transmit request
+    pub cookie: u64,
+    /// Whether the frame was acknowledged by the recipient
+    pub ack: bool,
+    /// Transmitted frame including 802.11 header
+    pub frame: Vec<u8>,
+}
+
+/// Control port frame(for example EAPOL) received over nl80211 when
+/// [Nl80211Attr::ControlPortOverNl80211] was requested on association
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ControlPortRx {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Source MAC address of the frame
+    pub src: Option<[u8; ETH_ALEN]>,
+    pub ethertype: Option<u16>,
+    /// The frame was received unencrypted
+    pub unencrypted: bool,
+    /// Frame payload without the ethernet header
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211FrameEvent {
+    Received(Nl80211FrameRx),
+    TxStatus(Nl80211FrameTxStatus),
+    /// Sent as unicast to the socket owning the connection
+    ControlPortReceived(Nl80211ControlPortRx),
+    /// Transmit status of frame sent by
+    /// [crate::Nl80211FrameHandle::control_port_send()]. The
+    /// [Nl80211FrameTxStatus::frame] holds the payload without ethernet
+    /// header.
+    ControlPortTxStatus(Nl80211FrameTxStatus),
+}
+
+impl Nl80211FrameEvent {
+    /// Return `None` if specified message is not a `NL80211_CMD_FRAME`,
+    /// `NL80211_CMD_FRAME_TX_STATUS`, `NL80211_CMD_CONTROL_PORT_FRAME` or
+    /// `NL80211_CMD_CONTROL_PORT_FRAME_TX_STATUS` notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        match msg.cmd {
+            Nl80211Command::Frame => {
+                let mut ret = Nl80211FrameRx::default();
+                let mut has_frame = false;
+                let mut freq_offset = 0;
+                for attr in msg.attributes.as_slice() {
+                    match attr {
+                        Nl80211Attr::IfIndex(d) => {
+                            ret.if_index = Some(Nl80211IfIndex::new(*d))
+                        }
+                        Nl80211Attr::Wdev(d) => {
+                            ret.wdev = Some(Nl80211WdevId::new(*d))
+                        }
+                        Nl80211Attr::WiphyFreq(d) => ret.frequency = Some(*d),
+                        Nl80211Attr::WiphyFreqOffset(d) => freq_offset = *d,
+                        Nl80211Attr::RxSignalDbm(d) => {
+                            ret.signal_dbm = Some(*d)
+                        }
+                        Nl80211Attr::RxmgmtFlags(d) => ret.flags = *d,
+                        Nl80211Attr::Frame(v) => {
+                            has_frame = true;
+                            ret.frame = v.clone();
+                        }
+                        _ => (),
+                    }
+                }
+                ret.frequency = ret.frequency.map(|f| {
+                    Nl80211Freq::from_mhz_and_offset(f.mhz(), freq_offset)
+                });
+                // The reply of frame transmit request is also using
+                // NL80211_CMD_FRAME but only holds the cookie.
+                if has_frame {
+                    Some(Self::Received(ret))
+                } else {
+                    None
+                }
+            }
+            Nl80211Command::FrameTxStatus
+            | Nl80211Command::ControlPortFrameTxStatus => {
+                let mut ret = Nl80211FrameTxStatus::default();
+                let mut cookie = None;
+                for attr in msg.attributes.as_slice() {
+                    match attr {
+                        Nl80211Attr::IfIndex(d) => {
+                            ret.if_index = Some(Nl80211IfIndex::new(*d))
+                        }
+                        Nl80211Attr::Wdev(d) => {
+                            ret.wdev = Some(Nl80211WdevId::new(*d))
+                        }
+                        Nl80211Attr::Cookie(d) => cookie = Some(*d),
+                        Nl80211Attr::Ack => ret.ack = true,
+                        Nl80211Attr::Frame(v) => ret.frame = v.clone(),
+                        _ => (),
+                    }
+                }
+                ret.cookie = cookie?;
+                if msg.cmd == Nl80211Command::FrameTxStatus {
+                    Some(Self::TxStatus(ret))
+                } else {
+                    Some(Self::ControlPortTxStatus(ret))
+                }
+            }
+            Nl80211Command::ControlPortFrame => {
+                let mut ret = Nl80211ControlPortRx::default();
+                let mut has_frame = false;
+                for attr in msg.attributes.as_slice() {
+                    match attr {
+                        Nl80211Attr::IfIndex(d) => {
+                            ret.if_index = Some(Nl80211IfIndex::new(*d))
+                        }
+                        Nl80211Attr::Wdev(d) => {
+                            ret.wdev = Some(Nl80211WdevId::new(*d))
+                        }
+                        Nl80211Attr::Mac(s) => ret.src = Some(*s),
+                        Nl80211Attr::ControlPortEthertypeValue(d) => {
+                            ret.ethertype = Some(*d)
+                        }
+                        Nl80211Attr::ControlPortNoEncrypt => {
+                            ret.unencrypted = true
+                        }
+                        Nl80211Attr::Frame(v) => {
+                            has_frame = true;
+                            ret.payload = v.clone();
+                        }
+                        _ => (),
+                    }
+                }
+                // Like NL80211_CMD_FRAME, the reply of transmit request
+                // only holds the cookie.
+                if has_frame {
+                    Some(Self::ControlPortReceived(ret))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
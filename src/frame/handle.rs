@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::nla::Nla;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211ControlPortSendRequest,
+    Nl80211FrameRegisterRequest, Nl80211FrameSendRequest, Nl80211FrameType,
+    Nl80211Freq, Nl80211Handle, Nl80211IfIndex,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211FrameHandle(Nl80211Handle);
+
+impl Nl80211FrameHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211FrameHandle(handle)
+    }
+
+    /// Register for receiving management frames of specified type whose
+    /// body starts with `match_pattern`(empty for all frames of this type).
+    /// The registration is bound to the netlink socket of this handle, the
+    /// matched frames will be delivered as unicast `NL80211_CMD_FRAME`
+    /// messages to the unsolicited message receiver of the connection,
+    /// use [crate::Nl80211FrameEvent::from_message] to parse them.
+    /// The registration is removed when the socket is closed.
+    pub fn register(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        frame_type: Nl80211FrameType,
+        match_pattern: Vec<u8>,
+    ) -> Nl80211FrameRegisterRequest {
+        Nl80211FrameRegisterRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            frame_type,
+            match_pattern,
+        )
+    }
+
+    /// Transmit a management frame.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Frame].
+    /// Unless [Nl80211AttrsBuilder::dont_wait_for_ack()] is set, the reply
+    /// holds a [Nl80211Attr::Cookie] which will be included in the
+    /// `NL80211_CMD_FRAME_TX_STATUS` notification.
+    pub fn send(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211FrameSendRequest {
+        Nl80211FrameSendRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Transmit a control port frame(for example EAPOL) over nl80211, only
+    /// valid when [Nl80211Attr::ControlPortOverNl80211] was requested on
+    /// association by this socket.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211ControlPortFrame].
+    /// Unless [Nl80211AttrsBuilder::dont_wait_for_ack()] is set, the reply
+    /// holds a [Nl80211Attr::Cookie] which will be included in the
+    /// `NL80211_CMD_CONTROL_PORT_FRAME_TX_STATUS` notification.
+    pub fn control_port_send(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ControlPortSendRequest {
+        Nl80211ControlPortSendRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Transmit an action frame(including 802.11 header) on specified
+    /// frequency(MHz) which might differ from the operating channel of
+    /// interface, and wait for its TX status within `timeout`.
+    /// The wiphy capability decides whether the frame is transmitted
+    /// off-channel directly or after a remain on channel operation.
+    #[cfg(all(
+        feature = "events",
+        any(feature = "tokio_socket", feature = "smol_socket")
+    ))]
+    pub fn send_action_frame_offchannel(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        frequency: impl Into<Nl80211Freq>,
+        frame: Vec<u8>,
+        timeout: std::time::Duration,
+    ) -> crate::Nl80211FrameOffchannelSendRequest {
+        crate::Nl80211FrameOffchannelSendRequest::new(
+            self.0.clone(),
+            Nl80211Attr::IfIndex(if_index.get()),
+            frequency.into(),
+            frame,
+            timeout,
+        )
+    }
+
+    /// Similar to [Self::send_action_frame_offchannel()], but for wireless
+    /// device without network interface like P2P device.
+    #[cfg(all(
+        feature = "events",
+        any(feature = "tokio_socket", feature = "smol_socket")
+    ))]
+    pub fn send_action_frame_offchannel_wdev(
+        &mut self,
+        wdev: crate::Nl80211WdevId,
+        frequency: impl Into<Nl80211Freq>,
+        frame: Vec<u8>,
+        timeout: std::time::Duration,
+    ) -> crate::Nl80211FrameOffchannelSendRequest {
+        crate::Nl80211FrameOffchannelSendRequest::new(
+            self.0.clone(),
+            Nl80211Attr::Wdev(wdev.get()),
+            frequency.into(),
+            frame,
+            timeout,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Frame;
+
+impl Nl80211Frame {
+    /// Transmit management frame(including 802.11 header) on specified
+    /// interface
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        frame: Vec<u8>,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Frame(frame))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Frame> {
+    /// Frequency to transmit the frame on, a `u32` is taken as MHz. If the
+    /// interface is not operating on this channel,
+    /// [Self::offchannel_tx_ok()] is required.
+    pub fn frequency(self, freq: impl Into<Nl80211Freq>) -> Self {
+        self.set_frequency(freq)
+    }
+
+    /// Time in milliseconds to wait on the channel for response frame
+    /// after transmission when transmitting off-channel.
+    pub fn duration(self, value: u32) -> Self {
+        self.replace(Nl80211Attr::Duration(value))
+    }
+
+    /// Allow transmitting the frame off-channel when interface is operating
+    /// on other channel.
+    pub fn offchannel_tx_ok(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::OffchannelTxOk)
+        } else {
+            self.remove(Nl80211Attr::OffchannelTxOk.kind())
+        }
+    }
+
+    /// Do not wait for the ACK of transmitted frame, no cookie will be
+    /// returned and no TX status notification will be sent.
+    pub fn dont_wait_for_ack(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::DontWaitForAck)
+        } else {
+            self.remove(Nl80211Attr::DontWaitForAck.kind())
+        }
+    }
+
+    /// Do not transmit the frame at CCK rates(1, 2, 5.5 and 11 Mbps)
+    pub fn no_cck(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::TxNoCckRate)
+        } else {
+            self.remove(Nl80211Attr::TxNoCckRate.kind())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211ControlPortFrame;
+
+impl Nl80211ControlPortFrame {
+    /// Transmit control port frame payload(without ethernet header) of
+    /// specified ethertype to destination MAC address
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        dest: [u8; ETH_ALEN],
+        ethertype: u16,
+        payload: Vec<u8>,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Mac(dest))
+            .replace(Nl80211Attr::ControlPortEthertypeValue(ethertype))
+            .replace(Nl80211Attr::Frame(payload))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211ControlPortFrame> {
+    /// Transmit the frame unencrypted even when pairwise key is installed
+    pub fn no_encrypt(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::ControlPortNoEncrypt)
+        } else {
+            self.remove(Nl80211Attr::ControlPortNoEncrypt.kind())
+        }
+    }
+
+    /// Do not wait for the ACK of transmitted frame, no cookie will be
+    /// returned and no TX status notification will be sent.
+    pub fn dont_wait_for_ack(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::DontWaitForAck)
+        } else {
+            self.remove(Nl80211Attr::DontWaitForAck.kind())
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{DecodeError, Parseable};
+
+use crate::{
+    bytes::parse_u16_le, Nl80211BssCapabilities, Nl80211Element,
+    Nl80211Elements, Nl80211FrameTypeMgmt,
+};
+
+const ETH_ALEN: usize = 6;
+// Order bit of frame control field, indicating HT Control field present
+const FRAME_CONTROL_ORDER: u16 = 1 << 15;
+const HT_CONTROL_LEN: usize = 4;
+
+/// IEEE 802.11-2020 `9.3.3.2 Format of Management frames` header
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211MgmtFrameHeader {
+    pub subtype: Nl80211FrameTypeMgmt,
+    /// Raw frame control field
+    pub frame_control: u16,
+    pub duration: u16,
+    /// Destination address (Address 1)
+    pub da: [u8; ETH_ALEN],
+    /// Source address (Address 2)
+    pub sa: [u8; ETH_ALEN],
+    /// BSSID (Address 3)
+    pub bssid: [u8; ETH_ALEN],
+    /// Raw sequence control field
+    pub sequence_control: u16,
+    /// HT Control field, only present when the Order bit is set
+    pub ht_control: Option<u32>,
+}
+
+impl Nl80211MgmtFrameHeader {
+    pub const LENGTH: usize = 24;
+
+    /// Parse the header of management frame, for example
+    /// [crate::Nl80211FrameRx::frame]
+    pub fn parse(frame: &[u8]) -> Result<Self, DecodeError> {
+        let subtype =
+            Nl80211FrameTypeMgmt::from_frame(frame).ok_or_else(|| {
+                DecodeError::from(format!("Not a management frame: {frame:?}"))
+            })?;
+        if frame.len() < Self::LENGTH {
+            return Err(format!(
+                "Management frame header should be at least {} bytes, \
+                but got {frame:?}",
+                Self::LENGTH
+            )
+            .into());
+        }
+        let frame_control = parse_u16_le(&frame[0..2])?;
+        let ht_control = if frame_control & FRAME_CONTROL_ORDER > 0 {
+            let raw = frame
+                .get(Self::LENGTH..Self::LENGTH + HT_CONTROL_LEN)
+                .ok_or_else(|| {
+                    DecodeError::from(format!(
+                        "Management frame has Order bit set but no HT \
+                        Control field: {frame:?}"
+                    ))
+                })?;
+            Some(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+        } else {
+            None
+        };
+        let mut da = [0u8; ETH_ALEN];
+        let mut sa = [0u8; ETH_ALEN];
+        let mut bssid = [0u8; ETH_ALEN];
+        da.copy_from_slice(&frame[4..10]);
+        sa.copy_from_slice(&frame[10..16]);
+        bssid.copy_from_slice(&frame[16..22]);
+        Ok(Self {
+            subtype,
+            frame_control,
+            duration: parse_u16_le(&frame[2..4])?,
+            da,
+            sa,
+            bssid,
+            sequence_control: parse_u16_le(&frame[22..24])?,
+            ht_control,
+        })
+    }
+
+    /// Length of the header including the HT Control field if any, which is
+    /// the offset of frame body
+    pub fn header_len(&self) -> usize {
+        if self.ht_control.is_some() {
+            Self::LENGTH + HT_CONTROL_LEN
+        } else {
+            Self::LENGTH
+        }
+    }
+
+    /// Sequence number(12 bits) of the sequence control field
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_control >> 4
+    }
+
+    /// Fragment number(4 bits) of the sequence control field
+    pub fn fragment_number(&self) -> u8 {
+        (self.sequence_control & 0xf) as u8
+    }
+}
+
+/// Beacon or probe response frame, IEEE 802.11-2020 `9.3.3.3 Beacon frame
+/// format` and `9.3.3.10 Probe Response frame format`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211BeaconFrame {
+    pub header: Nl80211MgmtFrameHeader,
+    /// TSF timer of the transmitting BSS in microseconds
+    pub timestamp: u64,
+    /// Beacon interval in TU (1024 microseconds)
+    pub beacon_interval: u16,
+    pub capability: Nl80211BssCapabilities,
+    pub elements: Vec<Nl80211Element>,
+}
+
+impl Nl80211BeaconFrame {
+    // Timestamp, beacon interval and capability information
+    const FIXED_PARAMS_LEN: usize = 12;
+
+    /// Parse beacon or probe response frame including the 802.11 header.
+    /// Like [crate::Nl80211BssInfo::InformationElements], information
+    /// elements failed to decode are stored as [Nl80211Element::Other].
+    pub fn parse(frame: &[u8]) -> Result<Self, DecodeError> {
+        let header = Nl80211MgmtFrameHeader::parse(frame)?;
+        if !matches!(
+            header.subtype,
+            Nl80211FrameTypeMgmt::Beacon | Nl80211FrameTypeMgmt::ProbeResp
+        ) {
+            return Err(format!(
+                "Expecting beacon or probe response frame, but got {:?}",
+                header.subtype
+            )
+            .into());
+        }
+        let body = &frame[header.header_len()..];
+        if body.len() < Self::FIXED_PARAMS_LEN {
+            return Err(format!(
+                "Beacon frame body should be at least {} bytes, \
+                but got {body:?}",
+                Self::FIXED_PARAMS_LEN
+            )
+            .into());
+        }
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(&body[0..8]);
+        Ok(Self {
+            header,
+            timestamp: u64::from_le_bytes(timestamp),
+            beacon_interval: parse_u16_le(&body[8..10])?,
+            capability: Nl80211BssCapabilities::from_bits_retain(parse_u16_le(
+                &body[10..12],
+            )?),
+            elements: Nl80211Elements::parse(&body[Self::FIXED_PARAMS_LEN..])?
+                .into(),
+        })
+    }
+
+    /// Whether this is a probe response instead of beacon
+    pub fn is_probe_response(&self) -> bool {
+        self.header.subtype == Nl80211FrameTypeMgmt::ProbeResp
+    }
+}
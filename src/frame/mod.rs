@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+
+mod control_port;
+#[cfg(feature = "events")]
+mod event;
+mod handle;
+mod mgmt;
+#[cfg(all(
+    feature = "events",
+    any(feature = "tokio_socket", feature = "smol_socket")
+))]
+mod offchannel_send;
+mod register;
+mod rx_flags;
+mod send;
+
+pub use self::control_port::Nl80211ControlPortSendRequest;
+#[cfg(feature = "events")]
+pub use self::event::{
+    Nl80211ControlPortRx, Nl80211FrameEvent, Nl80211FrameRx,
+    Nl80211FrameTxStatus,
+};
+pub use self::handle::{
+    Nl80211ControlPortFrame, Nl80211Frame, Nl80211FrameHandle,
+};
+pub use self::mgmt::{Nl80211BeaconFrame, Nl80211MgmtFrameHeader};
+#[cfg(all(
+    feature = "events",
+    any(feature = "tokio_socket", feature = "smol_socket")
+))]
+pub use self::offchannel_send::Nl80211FrameOffchannelSendRequest;
+pub use self::register::Nl80211FrameRegisterRequest;
+pub use self::rx_flags::Nl80211RxMgmtFlags;
+pub use self::send::Nl80211FrameSendRequest;
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use futures::{Stream, TryStreamExt};
+use netlink_packet_core::NLM_F_REQUEST;
+
+use crate::{
+    nl80211_execute, runtime::wait_event, Nl80211Attr, Nl80211ChannelDef,
+    Nl80211Command, Nl80211Error, Nl80211FrameEvent, Nl80211FrameTxStatus,
+    Nl80211FrameTypeMgmt, Nl80211Freq, Nl80211Handle, Nl80211Message,
+    Nl80211RemainOnChannelEvent, Nl80211RemainOnChannelEventKind,
+    Nl80211WiphyGetRequest, Nl80211WiphyIndex,
+};
+
+const MIN_REMAIN_ON_CHANNEL_DURATION: u32 = 50;
+
+pub struct Nl80211FrameOffchannelSendRequest {
+    handle: Nl80211Handle,
+    // Nl80211Attr::IfIndex or Nl80211Attr::Wdev
+    target: Nl80211Attr,
+    frequency: Nl80211Freq,
+    frame: Vec<u8>,
+    wait: u32,
+    timeout: Duration,
+}
+
+impl Nl80211FrameOffchannelSendRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        target: Nl80211Attr,
+        frequency: Nl80211Freq,
+        frame: Vec<u8>,
+        timeout: Duration,
+    ) -> Self {
+        Nl80211FrameOffchannelSendRequest {
+            handle,
+            target,
+            frequency,
+            frame,
+            wait: 0,
+            timeout,
+        }
+    }
+
+    /// Time in milliseconds to stay on the channel after transmission for
+    /// receiving response frame, default is 0.
+    pub fn wait(mut self, ms: u32) -> Self {
+        self.wait = ms;
+        self
+    }
+
+    /// Transmit the action frame and wait for its TX status.
+    ///
+    /// The `events` could be generated by [Nl80211Handle::notifications()]
+    /// and should be created before invoking this function.
+    /// When the wiphy does not support [Nl80211Attr::OffchannelTxOk], a
+    /// remain on channel operation is started before transmission and
+    /// cancelled after TX status received, in which case the connection
+    /// socket should join the `mlme` multicast group for the remain on
+    /// channel notification, see [crate::subscribe_mlme()].
+    ///
+    /// Return [Nl80211Error::Timeout] if the TX status is not received
+    /// within the timeout.
+    pub async fn execute<S>(
+        self,
+        events: S,
+    ) -> Result<Nl80211FrameTxStatus, Nl80211Error>
+    where
+        S: Stream<Item = Nl80211Message>,
+    {
+        let deadline = Instant::now() + self.timeout;
+        if !matches!(
+            Nl80211FrameTypeMgmt::from_frame(self.frame.as_slice()),
+            Some(Nl80211FrameTypeMgmt::Action)
+                | Some(Nl80211FrameTypeMgmt::ActionNoAck)
+        ) {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "Frame is not an action frame: {:?}",
+                self.frame
+            )));
+        }
+        let Nl80211FrameOffchannelSendRequest {
+            mut handle,
+            target,
+            frequency,
+            frame,
+            wait,
+            timeout,
+        } = self;
+        futures::pin_mut!(events);
+        let device = device_name(&target);
+
+        let (offchannel_tx_ok, max_roc_duration) =
+            query_offchannel_capability(&mut handle, &target).await?;
+
+        let mut roc_cookie = None;
+        if !offchannel_tx_ok {
+            let mut duration = wait.max(MIN_REMAIN_ON_CHANNEL_DURATION);
+            if let Some(max) = max_roc_duration {
+                duration = duration.min(max);
+            }
+            let cookie = request_cookie(
+                &mut handle,
+                Nl80211Message {
+                    cmd: Nl80211Command::RemainOnChannel,
+                    attributes: [target.clone()]
+                        .into_iter()
+                        .chain(Nl80211ChannelDef::new(frequency).attributes())
+                        .chain([Nl80211Attr::Duration(duration)])
+                        .collect(),
+                },
+            )
+            .await?;
+            roc_cookie = Some(cookie);
+            let started = wait_event(&mut events, deadline, |msg| {
+                Nl80211RemainOnChannelEvent::from_message(msg).and_then(|e| {
+                    (e.cookie == cookie
+                        && e.kind == Nl80211RemainOnChannelEventKind::Started)
+                        .then_some(())
+                })
+            })
+            .await;
+            if started.is_err() {
+                cancel_remain_on_channel(&mut handle, target, cookie).await;
+                return Err(Nl80211Error::Timeout(format!(
+                    "The {device} did not switch to {frequency} within \
+                    {timeout:?}"
+                )));
+            }
+        }
+
+        let mut attributes = vec![target.clone()];
+        attributes.extend(Nl80211ChannelDef::new(frequency).attributes());
+        attributes.push(Nl80211Attr::Frame(frame));
+        if offchannel_tx_ok {
+            attributes.push(Nl80211Attr::OffchannelTxOk);
+            if wait > 0 {
+                attributes.push(Nl80211Attr::Duration(wait));
+            }
+        }
+        let result = match request_cookie(
+            &mut handle,
+            Nl80211Message {
+                cmd: Nl80211Command::Frame,
+                attributes,
+            },
+        )
+        .await
+        {
+            Ok(cookie) => wait_event(&mut events, deadline, |msg| {
+                match Nl80211FrameEvent::from_message(msg) {
+                    Some(Nl80211FrameEvent::TxStatus(s))
+                        if s.cookie == cookie =>
+                    {
+                        Some(s)
+                    }
+                    _ => None,
+                }
+            })
+            .await
+            .map_err(|_| {
+                Nl80211Error::Timeout(format!(
+                    "No TX status of frame sent on {device} within \
+                    {timeout:?}"
+                ))
+            }),
+            Err(e) => Err(e),
+        };
+
+        if let Some(cookie) = roc_cookie {
+            cancel_remain_on_channel(&mut handle, target, cookie).await;
+        }
+        result
+    }
+}
+
+async fn query_offchannel_capability(
+    handle: &mut Nl80211Handle,
+    target: &Nl80211Attr,
+) -> Result<(bool, Option<u32>), Nl80211Error> {
+    let nl80211_msg = Nl80211Message {
+        cmd: Nl80211Command::GetInterface,
+        attributes: vec![target.clone()],
+    };
+    let response = nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST).await;
+    futures::pin_mut!(response);
+    let mut wiphy_index = None;
+    while let Some(msg) = response.try_next().await? {
+        for attr in msg.payload.attributes {
+            if let Nl80211Attr::Wiphy(d) = attr {
+                wiphy_index = Some(Nl80211WiphyIndex::new(d));
+            }
+        }
+    }
+    let wiphy_index = wiphy_index.ok_or_else(|| {
+        Nl80211Error::RequestFailed(format!(
+            "No wiphy found for {}",
+            device_name(target)
+        ))
+    })?;
+    let caps = Nl80211WiphyGetRequest::new(handle.clone())
+        .wiphy_index(wiphy_index)
+        .capabilities()
+        .await?;
+    let cap = caps
+        .into_iter()
+        .find(|c| c.wiphy_index == wiphy_index)
+        .ok_or_else(|| {
+            Nl80211Error::RequestFailed(format!(
+                "Wiphy {wiphy_index} of {} not found",
+                device_name(target)
+            ))
+        })?;
+    Ok((cap.offchannel_tx_ok, cap.max_remain_on_channel_duration))
+}
+
+async fn request_cookie(
+    handle: &mut Nl80211Handle,
+    nl80211_msg: Nl80211Message,
+) -> Result<u64, Nl80211Error> {
+    let cmd = nl80211_msg.cmd;
+    let response = nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST).await;
+    futures::pin_mut!(response);
+    let mut cookie = None;
+    while let Some(msg) = response.try_next().await? {
+        for attr in msg.payload.attributes {
+            if let Nl80211Attr::Cookie(d) = attr {
+                cookie = Some(d);
+            }
+        }
+    }
+    cookie.ok_or_else(|| {
+        Nl80211Error::RequestFailed(format!(
+            "No cookie in the reply of {cmd:?}"
+        ))
+    })
+}
+
+async fn cancel_remain_on_channel(
+    handle: &mut Nl80211Handle,
+    target: Nl80211Attr,
+    cookie: u64,
+) {
+    let nl80211_msg = Nl80211Message {
+        cmd: Nl80211Command::CancelRemainOnChannel,
+        attributes: vec![target, Nl80211Attr::Cookie(cookie)],
+    };
+    let response = nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST).await;
+    futures::pin_mut!(response);
+    // The operation might already expired
+    loop {
+        match response.try_next().await {
+            Ok(Some(_)) => (),
+            Ok(None) => break,
+            Err(e) => {
+                log::debug!("Failed to cancel remain on channel {cookie}: {e}");
+                break;
+            }
+        }
+    }
+}
+
+fn device_name(target: &Nl80211Attr) -> String {
+    match target {
+        Nl80211Attr::Wdev(d) => format!("wireless device {d}"),
+        Nl80211Attr::IfIndex(d) => format!("interface {d}"),
+        _ => format!("{target:?}"),
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211FrameType, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211FrameRegisterRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    frame_type: Nl80211FrameType,
+    match_pattern: Vec<u8>,
+}
+
+impl Nl80211FrameRegisterRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frame_type: Nl80211FrameType,
+        match_pattern: Vec<u8>,
+    ) -> Self {
+        Nl80211FrameRegisterRequest {
+            handle,
+            if_index,
+            frame_type,
+            match_pattern,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211FrameRegisterRequest {
+            mut handle,
+            if_index,
+            frame_type,
+            match_pattern,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::RegisterFrame,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::FrameType(frame_type),
+                Nl80211Attr::FrameMatch(match_pattern),
+            ],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
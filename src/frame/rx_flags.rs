@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_RXMGMT_FLAG_ANSWERED: u32 = 1 << 0;
+const NL80211_RXMGMT_FLAG_EXTERNAL_AUTH: u32 = 1 << 1;
+
+bitflags::bitflags! {
+    /// Flags of received management frame
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211RxMgmtFlags: u32 {
+        /// Frame was already answered by device or driver, userspace should
+        /// not reply to it.
+        const Answered = NL80211_RXMGMT_FLAG_ANSWERED;
+        /// Host driver intends to offload the authentication to userspace,
+        /// the frame should be handled by the external authentication
+        /// implementation.
+        const ExternalAuth = NL80211_RXMGMT_FLAG_EXTERNAL_AUTH;
+        const _ = !0;
+    }
+}
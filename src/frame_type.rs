@@ -10,6 +10,7 @@ use netlink_packet_utils::{
 use crate::{bytes::write_u16, Nl80211InterfaceType};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Nl80211IfaceFrameType {
     pub iface_type: Nl80211InterfaceType,
@@ -67,6 +68,7 @@ const IEEE80211_FTYPE_DATA: u8 = 0x08;
 const IEEE80211_FTYPE_EXT: u8 = 0x0c;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211FrameType {
     Management(Nl80211FrameTypeMgmt),
@@ -144,8 +146,10 @@ const IEEE80211_STYPE_DISASSOC: u16 = 0x00A0;
 const IEEE80211_STYPE_AUTH: u16 = 0x00B0;
 const IEEE80211_STYPE_DEAUTH: u16 = 0x00C0;
 const IEEE80211_STYPE_ACTION: u16 = 0x00D0;
+const IEEE80211_STYPE_ACTION_NOACK: u16 = 0x00E0;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211FrameTypeMgmt {
     AssocReq,
@@ -160,9 +164,35 @@ pub enum Nl80211FrameTypeMgmt {
     Auth,
     Deauth,
     Action,
+    /// Action frame not requiring acknowledgement
+    ActionNoAck,
     Other(u16),
 }
 
+impl Nl80211FrameTypeMgmt {
+    /// The 4 bits subtype number used by `NL80211_ATTR_MGMT_SUBTYPE` and the
+    /// frame control field, for example 13 for action frame
+    pub fn subtype(&self) -> u8 {
+        ((u16::from(*self) >> 4) & 0xf) as u8
+    }
+
+    pub fn from_subtype(subtype: u8) -> Self {
+        Self::from(u16::from(subtype & 0xf) << 4)
+    }
+
+    /// Parse the subtype from the frame control field of frame including
+    /// 802.11 header, `None` if not a management frame.
+    pub fn from_frame(frame: &[u8]) -> Option<Self> {
+        let fc = *frame.first()?;
+        // Protocol version 0 and type 0(management)
+        if fc & 0x0f == IEEE80211_FTYPE_MGMT {
+            Some(Self::from_subtype(fc >> 4))
+        } else {
+            None
+        }
+    }
+}
+
 impl From<u16> for Nl80211FrameTypeMgmt {
     fn from(d: u16) -> Self {
         match d {
@@ -178,6 +208,7 @@ impl From<u16> for Nl80211FrameTypeMgmt {
             IEEE80211_STYPE_AUTH => Self::Auth,
             IEEE80211_STYPE_DEAUTH => Self::Deauth,
             IEEE80211_STYPE_ACTION => Self::Action,
+            IEEE80211_STYPE_ACTION_NOACK => Self::ActionNoAck,
             _ => Self::Other(d),
         }
     }
@@ -198,6 +229,7 @@ impl From<Nl80211FrameTypeMgmt> for u16 {
             Nl80211FrameTypeMgmt::Auth => IEEE80211_STYPE_AUTH,
             Nl80211FrameTypeMgmt::Deauth => IEEE80211_STYPE_DEAUTH,
             Nl80211FrameTypeMgmt::Action => IEEE80211_STYPE_ACTION,
+            Nl80211FrameTypeMgmt::ActionNoAck => IEEE80211_STYPE_ACTION_NOACK,
             Nl80211FrameTypeMgmt::Other(d) => d,
         }
     }
@@ -215,6 +247,7 @@ const IEEE80211_STYPE_CFEND: u16 = 0x00E0;
 const IEEE80211_STYPE_CFENDACK: u16 = 0x00F0;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211FrameTypeCtl {
     Trigger,
@@ -284,6 +317,7 @@ const IEEE80211_STYPE_QOS_CFPOLL: u16 = 0x00E0;
 const IEEE80211_STYPE_QOS_CFACKPOLL: u16 = 0x00F0;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211FrameTypeData {
     Data,
@@ -365,6 +399,7 @@ const IEEE80211_STYPE_DMG_BEACON: u16 = 0x0000;
 const IEEE80211_STYPE_S1G_BEACON: u16 = 0x0010;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211FrameTypeExt {
     DmgBeacon,
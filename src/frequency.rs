@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+const KHZ_PER_MHZ: u32 = 1000;
+
+/// Frequency stored in kHz.
+///
+/// Most nl80211 attributes carry frequencies in MHz while S1G channels
+/// require an additional kHz offset attribute, this type holds both in a
+/// single value to avoid mixing the units.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211Freq(u32);
+
+impl Nl80211Freq {
+    pub const fn from_mhz(mhz: u32) -> Self {
+        Self(mhz.saturating_mul(KHZ_PER_MHZ))
+    }
+
+    pub const fn from_khz(khz: u32) -> Self {
+        Self(khz)
+    }
+
+    /// Combine the MHz value with the kHz offset, for example
+    /// [crate::Nl80211Attr::WiphyFreq] with
+    /// [crate::Nl80211Attr::WiphyFreqOffset].
+    pub const fn from_mhz_and_offset(mhz: u32, offset_khz: u32) -> Self {
+        Self(mhz.saturating_mul(KHZ_PER_MHZ).saturating_add(offset_khz))
+    }
+
+    pub const fn khz(&self) -> u32 {
+        self.0
+    }
+
+    /// Integral MHz part, the remaining kHz is provided by
+    /// [Nl80211Freq::offset_khz()].
+    pub const fn mhz(&self) -> u32 {
+        self.0 / KHZ_PER_MHZ
+    }
+
+    /// The kHz offset on top of [Nl80211Freq::mhz()]
+    pub const fn offset_khz(&self) -> u32 {
+        self.0 % KHZ_PER_MHZ
+    }
+}
+
+/// Frequency in MHz
+impl From<u32> for Nl80211Freq {
+    fn from(mhz: u32) -> Self {
+        Self::from_mhz(mhz)
+    }
+}
+
+impl std::fmt::Display for Nl80211Freq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.offset_khz() == 0 {
+            write!(f, "{} MHz", self.mhz())
+        } else {
+            let offset = format!("{:03}", self.offset_khz());
+            write!(f, "{}.{} MHz", self.mhz(), offset.trim_end_matches('0'))
+        }
+    }
+}
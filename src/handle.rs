@@ -1,24 +1,94 @@
 // SPDX-License-Identifier: MIT
 
-use futures::{future::Either, FutureExt, Stream, StreamExt, TryStream};
+use std::sync::{Arc, Mutex};
+
+use futures::{
+    future::Either, FutureExt, Stream, StreamExt, TryStream, TryStreamExt,
+};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
 use genetlink::GenetlinkHandle;
-use netlink_packet_core::NetlinkMessage;
-use netlink_packet_generic::GenlMessage;
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_REQUEST,
+};
+#[cfg(feature = "events")]
+use netlink_packet_generic::{
+    ctrl::{
+        nlas::{GenlCtrlAttrs, McastGrpAttrs},
+        GenlCtrl, GenlCtrlCmd,
+    },
+    GenlFamily,
+};
+use netlink_packet_generic::{GenlHeader, GenlMessage};
 use netlink_packet_utils::DecodeError;
+#[cfg(feature = "events")]
+use netlink_packet_utils::ParseableParametrized;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
 
 use crate::{
-    try_nl80211, Nl80211Error, Nl80211InterfaceHandle, Nl80211Message,
-    Nl80211ScanHandle, Nl80211StationHandle, Nl80211WiphyHandle,
+    ext_ack::invalid_attribute_error, message::Nl80211RawMessage,
+    Nl80211ApHandle, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211FrameHandle, Nl80211InterfaceHandle, Nl80211KeyHandle,
+    Nl80211Message, Nl80211MlmeHandle, Nl80211MloHandle, Nl80211OcbHandle,
+    Nl80211OffchannelHandle, Nl80211PeerMeasurementHandle,
+    Nl80211ProtocolFeatures, Nl80211ScanHandle, Nl80211StationHandle,
+    Nl80211SurveyHandle, Nl80211VendorHandle, Nl80211WiphyHandle,
 };
 
+/// Handle for sending nl80211 requests through the connection returned by
+/// [crate::new_connection()], could be cloned and used concurrently.
+///
+/// # Cancellation safety
+///
+/// Dropping the future or reply stream of any request, for example on
+/// timeout, is safe and does not affect this handle or its clones:
+///  * Each request has its own netlink sequence number and reply channel.
+///    Replies arriving after the stream is dropped, including the rest of an
+///    interrupted dump, are discarded by the connection which forgets the
+///    request once kernel finishes the reply.
+///  * No lock is held across `.await` by this crate, the cached protocol
+///    features are only updated after the query succeeded.
+///
+/// Cancellation only stops waiting for kernel, the request already sent
+/// still takes effect. For example dropping
+/// `Nl80211FrameOffchannelSendRequest` leaves the remain on channel
+/// running until its duration expires.
+///
+/// The connection itself must keep being polled (e.g. spawned), otherwise
+/// every request hangs regardless of cancellation.
 #[derive(Clone, Debug)]
 pub struct Nl80211Handle {
     pub handle: GenetlinkHandle,
+    // Shared between cloned handles as they are using the same connection
+    protocol_features: Arc<Mutex<Option<Nl80211ProtocolFeatures>>>,
+    lenient_parse: bool,
 }
 
 impl Nl80211Handle {
     pub(crate) fn new(handle: GenetlinkHandle) -> Self {
-        Nl80211Handle { handle }
+        Nl80211Handle {
+            handle,
+            protocol_features: Arc::new(Mutex::new(None)),
+            lenient_parse: false,
+        }
+    }
+
+    /// Enable or disable the lenient parse mode for replies and
+    /// notifications decoded by this handle, disabled by default.
+    ///
+    /// In lenient mode, the top level nl80211 attribute failed to parse(e.g.
+    /// malformed nested attributes generated by odd vendor firmware) is
+    /// stored as [Nl80211Attr::MalformedNested] instead of failing the whole
+    /// message. The sub-handles(e.g. [Self::interface()]) and clones created
+    /// afterwards inherit this setting.
+    pub fn set_lenient_parse(&mut self, enabled: bool) {
+        self.lenient_parse = enabled;
+    }
+
+    /// Whether lenient parse mode is enabled, see [Self::set_lenient_parse()]
+    pub fn is_lenient_parse(&self) -> bool {
+        self.lenient_parse
     }
 
     // equivalent to `iw dev` command
@@ -41,6 +111,254 @@ impl Nl80211Handle {
         Nl80211ScanHandle::new(self.clone())
     }
 
+    // beaconing interface operations, for example channel switch
+    pub fn ap(&self) -> Nl80211ApHandle {
+        Nl80211ApHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE offchannel` command
+    pub fn offchannel(&self) -> Nl80211OffchannelHandle {
+        Nl80211OffchannelHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE ocb` command
+    pub fn ocb(&self) -> Nl80211OcbHandle {
+        Nl80211OcbHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE auth` and `iw dev DEVICE disconnect`
+    // commands
+    pub fn mlme(&self) -> Nl80211MlmeHandle {
+        Nl80211MlmeHandle::new(self.clone())
+    }
+
+    // key installation and default key selection
+    pub fn key(&self) -> Nl80211KeyHandle {
+        Nl80211KeyHandle::new(self.clone())
+    }
+
+    // peer measurement, for example FTM ranging
+    pub fn peer_measurement(&self) -> Nl80211PeerMeasurementHandle {
+        Nl80211PeerMeasurementHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE survey` command
+    pub fn survey(&self) -> Nl80211SurveyHandle {
+        Nl80211SurveyHandle::new(self.clone())
+    }
+
+    // equivalent to `iw dev DEVICE vendor` command
+    pub fn vendor(&self) -> Nl80211VendorHandle {
+        Nl80211VendorHandle::new(self.clone())
+    }
+
+    // management frame registration and transmission
+    pub fn frame(&self) -> Nl80211FrameHandle {
+        Nl80211FrameHandle::new(self.clone())
+    }
+
+    // multi-link operation, adding or removing links of MLD
+    pub fn mlo(&self) -> Nl80211MloHandle {
+        Nl80211MloHandle::new(self.clone())
+    }
+
+    /// Query the ID of nl80211 multicast group, for example `mlme`, `scan`,
+    /// `config` or `regulatory`. The notifications sent to this group will be
+    /// received by the unsolicited message receiver returned by
+    /// [crate::new_connection] once the socket of connection joined this
+    /// group via `add_membership()`. Please use [crate::subscribe()] for
+    /// joining the group before spawning the connection.
+    #[cfg(feature = "events")]
+    pub async fn multicast_group_id(
+        &mut self,
+        group_name: &str,
+    ) -> Result<u32, Nl80211Error> {
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(GenlCtrl {
+                cmd: GenlCtrlCmd::GetFamily,
+                nlas: vec![GenlCtrlAttrs::FamilyName(
+                    Nl80211Message::family_name().to_string(),
+                )],
+            }));
+        nl_msg.header.flags = NLM_F_REQUEST;
+
+        let mut response = self.handle.request(nl_msg).await.map_err(|e| {
+            Nl80211Error::RequestFailed(format!(
+                "Failed to query nl80211 multicast groups: {e}"
+            ))
+        })?;
+
+        while let Some(msg) = response.next().await {
+            let msg = msg?;
+            match msg.payload {
+                NetlinkPayload::InnerMessage(genl_msg) => {
+                    for nla in genl_msg.payload.nlas {
+                        if let GenlCtrlAttrs::McastGroups(groups) = nla {
+                            for group in groups {
+                                let mut id = None;
+                                let mut name = None;
+                                for attr in group {
+                                    match attr {
+                                        McastGrpAttrs::Id(d) => id = Some(d),
+                                        McastGrpAttrs::Name(s) => {
+                                            name = Some(s)
+                                        }
+                                    }
+                                }
+                                if let (Some(id), Some(name)) = (id, name) {
+                                    if name == group_name {
+                                        return Ok(id);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                NetlinkPayload::Error(e) => return Err(Nl80211Error::from(e)),
+                _ => (),
+            }
+        }
+        Err(Nl80211Error::RequestFailed(format!(
+            "nl80211 multicast group {group_name} not found"
+        )))
+    }
+
+    /// Decode nl80211 notifications from the unsolicited message receiver
+    /// returned by [crate::new_connection]. Messages of other netlink
+    /// families and messages failed to decode are discarded.
+    /// The connection socket should join the multicast group of interest
+    /// first, for example:
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::StreamExt;
+    ///
+    /// let (mut connection, handle, messages) =
+    ///     wl_nl80211::new_connection()?;
+    /// wl_nl80211::subscribe_mlme(&mut connection).await?;
+    /// tokio::spawn(connection);
+    ///
+    /// let mut notifications = handle.notifications(messages).await?;
+    /// while let Some(msg) = notifications.next().await {
+    ///     println!("{:?}", msg);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "events")]
+    pub async fn notifications<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211Message>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        let family_id = self
+            .handle
+            .resolve_family_id::<Nl80211Message>()
+            .await
+            .map_err(|e| {
+                Nl80211Error::RequestFailed(format!(
+                    "Failed to resolve nl80211 family ID: {e}"
+                ))
+            })?;
+        let lenient = self.lenient_parse;
+        Ok(messages.filter_map(move |(msg, _)| {
+            futures::future::ready(match msg.payload {
+                NetlinkPayload::InnerMessage(raw)
+                    if raw.family_id == family_id =>
+                {
+                    let ret = if lenient {
+                        Nl80211Message::parse_lenient(&raw.payload, raw.header)
+                    } else {
+                        Nl80211Message::parse_with_param(
+                            &raw.payload,
+                            raw.header,
+                        )
+                    };
+                    match ret {
+                        Ok(msg) => Some(msg),
+                        Err(e) => {
+                            log::warn!(
+                                "Failed to decode nl80211 notification: {e}"
+                            );
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            })
+        }))
+    }
+
+    /// The nl80211 protocol features supported by kernel.
+    /// The result of `NL80211_CMD_GET_PROTOCOL_FEATURES` is cached after the
+    /// first query and shared by all clones of this handle, use
+    /// [Self::refresh_protocol_features()] to query kernel again.
+    pub async fn protocol_features(
+        &mut self,
+    ) -> Result<Nl80211ProtocolFeatures, Nl80211Error> {
+        let cached = *self.protocol_features.lock().map_err(|e| {
+            Nl80211Error::Bug(format!(
+                "BUG: protocol features cache poisoned: {e}"
+            ))
+        })?;
+        match cached {
+            Some(features) => Ok(features),
+            None => self.refresh_protocol_features().await,
+        }
+    }
+
+    /// Query kernel for nl80211 protocol features and update the cache.
+    pub async fn refresh_protocol_features(
+        &mut self,
+    ) -> Result<Nl80211ProtocolFeatures, Nl80211Error> {
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetProtocolFeatures,
+            attributes: Vec::new(),
+        };
+        let response = nl80211_execute(self, nl80211_msg, NLM_F_REQUEST).await;
+        futures::pin_mut!(response);
+
+        // Kernel without any protocol feature omits the attribute
+        let mut features = Nl80211ProtocolFeatures::empty();
+        while let Some(msg) = response.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::ProtocolFeatures(d) = attr {
+                    features = d;
+                }
+            }
+        }
+        *self.protocol_features.lock().map_err(|e| {
+            Nl80211Error::Bug(format!(
+                "BUG: protocol features cache poisoned: {e}"
+            ))
+        })? = Some(features);
+        Ok(features)
+    }
+
+    /// Send the nl80211 message with specified generic netlink header and
+    /// netlink header flags(e.g. `NLM_F_REQUEST | NLM_F_ACK`), errors
+    /// replied by kernel are converted to [Nl80211Error].
+    /// See [Nl80211Message::into_genl_message_with_header()].
+    pub async fn execute_with_header(
+        &mut self,
+        nl80211_msg: Nl80211Message,
+        header: GenlHeader,
+        header_flags: u16,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        nl80211_execute_genl(
+            self,
+            nl80211_msg.into_genl_message_with_header(header),
+            header_flags,
+        )
+        .await
+    }
+
+    /// Send raw netlink message and return the stream of replies.
+    /// The generic netlink header of the message is sent as is, use
+    /// [Nl80211Message::into_genl_message_with_header()] to control the
+    /// `cmd` and `version` of it.
     pub async fn request(
         &mut self,
         message: NetlinkMessage<GenlMessage<Nl80211Message>>,
@@ -67,20 +385,112 @@ pub(crate) async fn nl80211_execute(
     nl80211_msg: Nl80211Message,
     header_flags: u16,
 ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error> {
-    let mut nl_msg =
-        NetlinkMessage::from(GenlMessage::from_payload(nl80211_msg));
+    nl80211_execute_genl(
+        handle,
+        GenlMessage::from_payload(nl80211_msg),
+        header_flags,
+    )
+    .await
+}
+
+/// Similar to [nl80211_execute()], but taking attributes which could be
+/// shared, for example `Arc<[Nl80211Attr]>` of request sent more than once.
+pub(crate) async fn nl80211_execute_shared<A>(
+    handle: &mut Nl80211Handle,
+    cmd: Nl80211Command,
+    attributes: A,
+    header_flags: u16,
+) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+where
+    A: AsRef<[Nl80211Attr]>,
+{
+    let header = GenlHeader {
+        cmd: cmd.into(),
+        version: Nl80211Message::GENL_VERSION,
+    };
+    nl80211_send(handle, header, 0, attributes, header_flags).await
+}
+
+async fn nl80211_execute_genl(
+    handle: &mut Nl80211Handle,
+    genl_msg: GenlMessage<Nl80211Message>,
+    header_flags: u16,
+) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error> {
+    let family_id = genl_msg.family_id();
+    let GenlMessage {
+        header, payload, ..
+    } = genl_msg;
+    nl80211_send(handle, header, family_id, payload.attributes, header_flags)
+        .await
+}
+
+// The attributes are kept for explaining the extended ACK of error reply
+async fn nl80211_send<A>(
+    handle: &mut Nl80211Handle,
+    header: GenlHeader,
+    family_id: u16,
+    attributes: A,
+    header_flags: u16,
+) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+where
+    A: AsRef<[Nl80211Attr]>,
+{
+    let lenient = handle.lenient_parse;
+    let mut nl_msg = NetlinkMessage::from(GenlMessage::new(
+        header,
+        Nl80211RawMessage::new(header, attributes.as_ref()),
+        family_id,
+    ));
 
     nl_msg.header.flags = header_flags;
 
-    match handle.request(nl_msg).await {
-        Ok(response) => {
-            Either::Left(response.map(move |msg| Ok(try_nl80211!(msg))))
-        }
+    match handle.handle.request(nl_msg).await {
+        Ok(response) => Either::Left(response.map(move |msg| {
+            if let Ok(NetlinkMessage {
+                header,
+                payload: NetlinkPayload::Error(err),
+                ..
+            }) = &msg
+            {
+                if let Some(e) = invalid_attribute_error(
+                    header.flags,
+                    err,
+                    attributes.as_ref(),
+                ) {
+                    return Err(e);
+                }
+            }
+            let (header, payload) = msg?.into_parts();
+            match payload {
+                NetlinkPayload::InnerMessage(genl_msg) => Ok(GenlMessage::new(
+                    genl_msg.header,
+                    genl_msg.payload.decode(lenient)?,
+                    genl_msg.family_id(),
+                )),
+                NetlinkPayload::Error(err) => Err(Nl80211Error::from(err)),
+                NetlinkPayload::Done(d) => {
+                    Err(unexpected_reply(header, NetlinkPayload::Done(d)))
+                }
+                NetlinkPayload::Overrun(d) => {
+                    Err(unexpected_reply(header, NetlinkPayload::Overrun(d)))
+                }
+                _ => Err(unexpected_reply(header, NetlinkPayload::Noop)),
+            }
+        })),
         Err(e) => Either::Right(
             futures::future::err::<GenlMessage<Nl80211Message>, Nl80211Error>(
-                e,
+                Nl80211Error::RequestFailed(format!(
+                    "BUG: Request failed with {e}"
+                )),
             )
             .into_stream(),
         ),
     }
 }
+
+fn unexpected_reply(
+    header: NetlinkHeader,
+    payload: NetlinkPayload<GenlMessage<Nl80211Message>>,
+) -> Nl80211Error {
+    Nl80211Error::UnexpectedMessage(NetlinkMessage::new(header, payload))
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+macro_rules! nl80211_id {
+    ($(#[$meta:meta])* $name:ident, $ty:ty) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default,
+        )]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        #[repr(transparent)]
+        pub struct $name($ty);
+
+        impl $name {
+            pub const fn new(value: $ty) -> Self {
+                Self(value)
+            }
+
+            pub const fn get(&self) -> $ty {
+                self.0
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(v: $name) -> $ty {
+                v.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+nl80211_id!(
+    /// Index of wireless physical device (wiphy), for example `0` of `phy0`
+    Nl80211WiphyIndex,
+    u32
+);
+
+nl80211_id!(
+    /// Index of network interface
+    Nl80211IfIndex,
+    u32
+);
+
+nl80211_id!(
+    /// Identifier of wireless device, unique across all wiphys, also
+    /// assigned to devices without network interface like P2P device
+    Nl80211WdevId,
+    u64
+);
@@ -7,7 +7,10 @@ use netlink_packet_utils::{
     DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
-use crate::{bytes::write_u32, Nl80211InterfaceType, Nl80211InterfaceTypes};
+use crate::{
+    bytes::write_u32, Nl80211ChannelWidth, Nl80211InterfaceType,
+    Nl80211InterfaceTypes,
+};
 
 const NL80211_IFACE_COMB_LIMITS: u16 = 1;
 const NL80211_IFACE_COMB_MAXNUM: u16 = 2;
@@ -18,6 +21,7 @@ const NL80211_IFACE_COMB_RADAR_DETECT_REGIONS: u16 = 6;
 const NL80211_IFACE_COMB_BI_MIN_GCD: u16 = 7;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Nl80211IfaceComb {
     pub index: u16,
@@ -38,6 +42,202 @@ impl Nla for Nl80211IfaceComb {
     }
 }
 
+impl Nl80211IfaceComb {
+    pub fn limits(&self) -> &[Nl80211IfaceCombLimit] {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211IfaceCombAttribute::Limits(v) => Some(v.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Maximum number of interfaces allowed in total in this combination
+    pub fn max_interfaces(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211IfaceCombAttribute::Maxnum(d) => Some(*d),
+            _ => None,
+        })
+    }
+
+    /// Maximum number of different channels allowed in this combination,
+    /// kernel always report this attribute, 1 is assumed when missing.
+    pub fn num_channels(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211IfaceCombAttribute::NumChannels(d) => Some(*d),
+                _ => None,
+            })
+            .unwrap_or(1)
+    }
+
+    /// Whether the beacon intervals between infrastructure(station) and AP
+    /// types must match.
+    pub fn sta_ap_bi_match(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|attr| matches!(attr, Nl80211IfaceCombAttribute::StaApBiMatch))
+    }
+
+    /// Minimum greatest common divisor(TU) of the beacon intervals of
+    /// beaconing interfaces. When present, beaconing interfaces may use
+    /// different beacon intervals as long as their GCD is not smaller than
+    /// this value, otherwise all beacon intervals must be the same.
+    pub fn bi_min_gcd(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211IfaceCombAttribute::BiMinGcd(d) => Some(*d),
+            _ => None,
+        })
+    }
+
+    /// Channel widths on which radar detection is supported in this
+    /// combination, empty if radar detection is not supported
+    pub fn radar_detect_widths(&self) -> Vec<Nl80211ChannelWidth> {
+        let bitmap = self
+            .attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211IfaceCombAttribute::RadarDetectWidths(d) => Some(*d),
+                _ => None,
+            })
+            .unwrap_or_default();
+        (0..u32::BITS)
+            .filter(|i| bitmap & (1 << i) > 0)
+            .map(Nl80211ChannelWidth::from)
+            .collect()
+    }
+
+    /// Bitmap of DFS regions(`1 << NL80211_DFS_*`) in which radar
+    /// detection is supported in this combination, 0 means all regions.
+    pub fn radar_detect_regions(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211IfaceCombAttribute::RadarDetectRegions(d) => Some(*d),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Check whether the interfaces described by `params` could be active
+    /// concurrently under this combination.
+    /// The [Nl80211IfaceCombParams::iftypes] should not contain interface
+    /// types not restricted by interface combinations, e.g. the
+    /// [crate::Nl80211WiphyCapabilities::software_iftypes].
+    pub fn allows(&self, params: &Nl80211IfaceCombParams) -> bool {
+        if let Some(max) = self.max_interfaces() {
+            if params.iftypes.len() > max as usize {
+                return false;
+            }
+        }
+        if params.num_channels > self.num_channels() {
+            return false;
+        }
+
+        let mut remains: Vec<u32> = self
+            .limits()
+            .iter()
+            .map(|limit| limit.max().unwrap_or_default())
+            .collect();
+        for iftype in &params.iftypes {
+            let Some(pos) = self
+                .limits()
+                .iter()
+                .position(|limit| limit.iftypes().contains(iftype))
+            else {
+                return false;
+            };
+            if remains[pos] == 0 {
+                return false;
+            }
+            remains[pos] -= 1;
+        }
+
+        if let Some(width) = params.radar_detect_width {
+            if !self.radar_detect_widths().contains(&width) {
+                return false;
+            }
+        }
+
+        if let Some(first) = params.beacon_intervals.first() {
+            // Without BI_MIN_GCD, kernel require all the beacon intervals to
+            // be the same
+            if let Some(min_gcd) = self.bi_min_gcd() {
+                let gcd = params
+                    .beacon_intervals
+                    .iter()
+                    .fold(0, |gcd, bi| greatest_common_divisor(gcd, *bi));
+                if gcd < min_gcd {
+                    return false;
+                }
+            } else if params.beacon_intervals.iter().any(|bi| bi != first) {
+                return false;
+            }
+            if let Some(sta_bi) = params.sta_beacon_interval {
+                if self.sta_ap_bi_match()
+                    && params.beacon_intervals.iter().any(|bi| *bi != sta_bi)
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Interfaces planned to be active concurrently, used by
+/// [Nl80211IfaceComb::allows()].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211IfaceCombParams {
+    /// Interface types, one entry for each interface
+    pub iftypes: Vec<Nl80211InterfaceType>,
+    /// Number of different channels in use by these interfaces
+    pub num_channels: u32,
+    /// Beacon intervals(TU) of beaconing interfaces, e.g. AP, P2P-GO, mesh
+    pub beacon_intervals: Vec<u32>,
+    /// Beacon interval(TU) of the BSS the station interface connected to
+    pub sta_beacon_interval: Option<u32>,
+    /// Channel width on which radar detection is required, for example
+    /// AP on DFS channel
+    pub radar_detect_width: Option<Nl80211ChannelWidth>,
+}
+
+impl Nl80211IfaceCombParams {
+    pub fn new(iftypes: Vec<Nl80211InterfaceType>, num_channels: u32) -> Self {
+        Self {
+            iftypes,
+            num_channels,
+            ..Default::default()
+        }
+    }
+
+    pub fn beacon_intervals(mut self, intervals: Vec<u32>) -> Self {
+        self.beacon_intervals = intervals;
+        self
+    }
+
+    pub fn sta_beacon_interval(mut self, interval: u32) -> Self {
+        self.sta_beacon_interval = Some(interval);
+        self
+    }
+
+    pub fn radar_detect_width(mut self, width: Nl80211ChannelWidth) -> Self {
+        self.radar_detect_width = Some(width);
+        self
+    }
+}
+
+fn greatest_common_divisor(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 impl<'a, T> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nl80211IfaceComb
 where
     T: AsRef<[u8]> + ?Sized,
@@ -59,16 +259,33 @@ where
     }
 }
 
+// Parse the nested array of interface combinations, `attr_name` is the name
+// of the attribute holding it.
+pub(crate) fn parse_iface_combinations(
+    payload: &[u8],
+    attr_name: &str,
+) -> Result<Vec<Nl80211IfaceComb>, DecodeError> {
+    let mut nlas = Vec::new();
+    for (index, nla) in NlasIterator::new(payload).enumerate() {
+        let err_msg = format!("Invalid {attr_name} value {nla:?}");
+        let nla = &nla.context(err_msg)?;
+        nlas.push(Nl80211IfaceComb::parse_with_param(nla, index as u16)?);
+    }
+    Ok(nlas)
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211IfaceCombAttribute {
     Limits(Vec<Nl80211IfaceCombLimit>),
     Maxnum(u32),
-    StaApiBiMatch,
+    StaApBiMatch,
     NumChannels(u32),
     RadarDetectWidths(u32),
-    RadarDetectRegins(u32),
+    RadarDetectRegions(u32),
     BiMinGcd(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -76,11 +293,11 @@ impl Nla for Nl80211IfaceCombAttribute {
     fn value_len(&self) -> usize {
         match self {
             Self::Limits(v) => v.as_slice().buffer_len(),
-            Self::StaApiBiMatch => 0,
+            Self::StaApBiMatch => 0,
             Self::Maxnum(_)
             | Self::NumChannels(_)
             | Self::RadarDetectWidths(_)
-            | Self::RadarDetectRegins(_)
+            | Self::RadarDetectRegions(_)
             | Self::BiMinGcd(_) => 4,
             Self::Other(attr) => attr.value_len(),
         }
@@ -90,12 +307,12 @@ impl Nla for Nl80211IfaceCombAttribute {
         match self {
             Self::Limits(_) => NL80211_IFACE_COMB_LIMITS,
             Self::Maxnum(_) => NL80211_IFACE_COMB_MAXNUM,
-            Self::StaApiBiMatch => NL80211_IFACE_COMB_STA_AP_BI_MATCH,
+            Self::StaApBiMatch => NL80211_IFACE_COMB_STA_AP_BI_MATCH,
             Self::NumChannels(_) => NL80211_IFACE_COMB_NUM_CHANNELS,
             Self::RadarDetectWidths(_) => {
                 NL80211_IFACE_COMB_RADAR_DETECT_WIDTHS
             }
-            Self::RadarDetectRegins(_) => {
+            Self::RadarDetectRegions(_) => {
                 NL80211_IFACE_COMB_RADAR_DETECT_REGIONS
             }
             Self::BiMinGcd(_) => NL80211_IFACE_COMB_BI_MIN_GCD,
@@ -108,9 +325,9 @@ impl Nla for Nl80211IfaceCombAttribute {
             Self::Maxnum(d)
             | Self::NumChannels(d)
             | Self::RadarDetectWidths(d)
-            | Self::RadarDetectRegins(d)
+            | Self::RadarDetectRegions(d)
             | Self::BiMinGcd(d) => write_u32(buffer, *d),
-            Self::StaApiBiMatch => (),
+            Self::StaApBiMatch => (),
             Self::Limits(v) => v.as_slice().emit(buffer),
             Self::Other(attr) => attr.emit(buffer),
         }
@@ -141,7 +358,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                     "Invalid NL80211_IFACE_COMB_MAXNUM {payload:?}"
                 ))?)
             }
-            NL80211_IFACE_COMB_STA_AP_BI_MATCH => Self::StaApiBiMatch,
+            NL80211_IFACE_COMB_STA_AP_BI_MATCH => Self::StaApBiMatch,
             NL80211_IFACE_COMB_NUM_CHANNELS => {
                 Self::NumChannels(parse_u32(payload).context(format!(
                     "Invalid NL80211_IFACE_COMB_NUM_CHANNELS {payload:?}"
@@ -154,10 +371,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                 ))?)
             }
             NL80211_IFACE_COMB_RADAR_DETECT_REGIONS => {
-                Self::RadarDetectRegins(parse_u32(payload).context(format!(
-                    "Invalid NL80211_IFACE_COMB_RADAR_DETECT_REGIONS \
+                Self::RadarDetectRegions(parse_u32(payload).context(
+                    format!(
+                        "Invalid NL80211_IFACE_COMB_RADAR_DETECT_REGIONS \
                     {payload:?}"
-                ))?)
+                    ),
+                )?)
             }
             NL80211_IFACE_COMB_BI_MIN_GCD => {
                 Self::BiMinGcd(parse_u32(payload).context(format!(
@@ -172,6 +391,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Nl80211IfaceCombLimit {
     pub index: u16,
@@ -192,6 +412,28 @@ impl Nla for Nl80211IfaceCombLimit {
     }
 }
 
+impl Nl80211IfaceCombLimit {
+    /// Maximum number of interfaces of these types
+    pub fn max(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211IfaceCombLimitAttribute::Max(d) => Some(*d),
+            _ => None,
+        })
+    }
+
+    pub fn iftypes(&self) -> &[Nl80211InterfaceType] {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211IfaceCombLimitAttribute::Iftypes(v) => {
+                    Some(v.as_slice())
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl<'a, T> ParseableParametrized<NlaBuffer<&'a T>, u16>
     for Nl80211IfaceCombLimit
 where
@@ -217,10 +459,12 @@ const NL80211_IFACE_LIMIT_MAX: u16 = 1;
 const NL80211_IFACE_LIMIT_TYPES: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211IfaceCombLimitAttribute {
     Max(u32),
     Iftypes(Vec<Nl80211InterfaceType>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const NL80211_CRIT_PROTO_UNSPEC: u16 = 0;
+const NL80211_CRIT_PROTO_DHCP: u16 = 1;
+const NL80211_CRIT_PROTO_EAPOL: u16 = 2;
+const NL80211_CRIT_PROTO_APIPA: u16 = 3;
+
+/// Protocol the critical period is requested for
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211CritProtocolId {
+    #[default]
+    Unspec,
+    Dhcp,
+    Eapol,
+    /// IPv4 link-local address autoconfiguration
+    Apipa,
+    Other(u16),
+}
+
+impl From<u16> for Nl80211CritProtocolId {
+    fn from(d: u16) -> Self {
+        match d {
+            NL80211_CRIT_PROTO_UNSPEC => Self::Unspec,
+            NL80211_CRIT_PROTO_DHCP => Self::Dhcp,
+            NL80211_CRIT_PROTO_EAPOL => Self::Eapol,
+            NL80211_CRIT_PROTO_APIPA => Self::Apipa,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CritProtocolId> for u16 {
+    fn from(v: Nl80211CritProtocolId) -> u16 {
+        match v {
+            Nl80211CritProtocolId::Unspec => NL80211_CRIT_PROTO_UNSPEC,
+            Nl80211CritProtocolId::Dhcp => NL80211_CRIT_PROTO_DHCP,
+            Nl80211CritProtocolId::Eapol => NL80211_CRIT_PROTO_EAPOL,
+            Nl80211CritProtocolId::Apipa => NL80211_CRIT_PROTO_APIPA,
+            Nl80211CritProtocolId::Other(d) => d,
+        }
+    }
+}
+
+pub struct Nl80211CritProtocolRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    // `None` for stopping the critical protocol period
+    start: Option<(Nl80211CritProtocolId, u16)>,
+}
+
+impl Nl80211CritProtocolRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        start: Option<(Nl80211CritProtocolId, u16)>,
+    ) -> Self {
+        Nl80211CritProtocolRequest {
+            handle,
+            if_index,
+            start,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211CritProtocolRequest {
+            mut handle,
+            if_index,
+            start,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        let cmd = if let Some((protocol, duration)) = start {
+            attributes.push(Nl80211Attr::CritProtId(protocol));
+            attributes.push(Nl80211Attr::MaxCritProtDuration(duration));
+            Nl80211Command::CritProtocolStart
+        } else {
+            Nl80211Command::CritProtocolStop
+        };
+
+        let nl80211_msg = Nl80211Message { cmd, attributes };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -1,6 +1,19 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211InterfaceGetRequest};
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+use crate::Nl80211InterfaceWaitAssociationRequest;
+#[cfg(feature = "events")]
+use crate::Nl80211RoamWatcher;
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211CritProtocolId,
+    Nl80211CritProtocolRequest, Nl80211Handle, Nl80211HwTimestampRequest,
+    Nl80211IfIndex, Nl80211InterfaceGetRequest, Nl80211InterfaceNewRequest,
+    Nl80211InterfaceSetRequest, Nl80211InterfaceSetTxBitrateRequest,
+    Nl80211InterfaceType, Nl80211MonitorFlag, Nl80211MulticastToUnicastRequest,
+    Nl80211NoackMapRequest, Nl80211P2pDeviceRequest,
+    Nl80211P2pPowerSaveRequest, Nl80211SetTidConfigRequest, Nl80211TidConfig,
+    Nl80211TxRateMask, Nl80211WdevId, Nl80211WiphyIndex,
+};
 
 pub struct Nl80211InterfaceHandle(Nl80211Handle);
 
@@ -14,4 +27,313 @@ impl Nl80211InterfaceHandle {
     pub fn get(&mut self) -> Nl80211InterfaceGetRequest {
         Nl80211InterfaceGetRequest::new(self.0.clone())
     }
+
+    /// Change type, 4-address mode, monitor flags, mesh ID or WDS peer of
+    /// existing wireless interface in one request
+    /// (equivalent to `iw dev DEV set type TYPE`).
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211InterfaceSet].
+    pub fn set(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211InterfaceSetRequest {
+        Nl80211InterfaceSetRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Create new wireless interface
+    /// (equivalent to `iw phy PHY interface add`).
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Interface].
+    pub fn add(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211InterfaceNewRequest {
+        Nl80211InterfaceNewRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Start the P2P device identified by `wdev`, the interface should be
+    /// created with [Nl80211InterfaceType::P2pDevice] type
+    /// (equivalent to `iw wdev WDEV p2p start`).
+    pub fn start_p2p_device(
+        &mut self,
+        wdev: Nl80211WdevId,
+    ) -> Nl80211P2pDeviceRequest {
+        Nl80211P2pDeviceRequest::new(self.0.clone(), wdev.get(), true)
+    }
+
+    /// Stop the P2P device identified by `wdev`
+    /// (equivalent to `iw wdev WDEV p2p stop`).
+    pub fn stop_p2p_device(
+        &mut self,
+        wdev: Nl80211WdevId,
+    ) -> Nl80211P2pDeviceRequest {
+        Nl80211P2pDeviceRequest::new(self.0.clone(), wdev.get(), false)
+    }
+
+    /// Configure the P2P GO power save of specified interface: the client
+    /// traffic window(CTWindow) in TUs and whether opportunistic power save
+    /// is enabled. CTWindow 0 means disabled.
+    pub fn set_p2p_power_save(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        ctwindow: u8,
+        opp_ps: bool,
+    ) -> Nl80211P2pPowerSaveRequest {
+        Nl80211P2pPowerSaveRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            ctwindow,
+            opp_ps,
+        )
+    }
+
+    /// Ask driver to prioritize the reliability of specified protocol, for
+    /// example by delaying background scan or power save, for at most
+    /// `duration_ms` milliseconds(up to 5000) or until
+    /// [Self::crit_protocol_stop()]. Useful during DHCP or EAPOL exchange
+    /// after connected.
+    pub fn crit_protocol_start(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        protocol: Nl80211CritProtocolId,
+        duration_ms: u16,
+    ) -> Nl80211CritProtocolRequest {
+        Nl80211CritProtocolRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            Some((protocol, duration_ms)),
+        )
+    }
+
+    /// Stop the critical protocol period started by
+    /// [Self::crit_protocol_start()]
+    pub fn crit_protocol_stop(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211CritProtocolRequest {
+        Nl80211CritProtocolRequest::new(self.0.clone(), if_index.get(), None)
+    }
+
+    /// Enable or disable converting multicast frames to unicast frames for
+    /// each associated station of specified AP interface, which improves
+    /// the reliability and airtime usage of multicast traffic like video
+    /// streaming to few stations.
+    pub fn set_multicast_to_unicast(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        enabled: bool,
+    ) -> Nl80211MulticastToUnicastRequest {
+        Nl80211MulticastToUnicastRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            enabled,
+        )
+    }
+
+    /// Set the per-TID no-ACK policy of QoS data frames sent by specified
+    /// interface: bit N of `noack_map` set means frames of TID N are sent
+    /// without requesting acknowledgment, 0 to restore normal ACK policy
+    /// for all TIDs.
+    pub fn set_noack_map(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        noack_map: u16,
+    ) -> Nl80211NoackMapRequest {
+        Nl80211NoackMapRequest::new(self.0.clone(), if_index.get(), noack_map)
+    }
+
+    /// Enable or disable hardware timestamping of frames exchanged with
+    /// specified peer, or with all peers when `peer` is `None`. The number
+    /// of peers supported concurrently is advertised by
+    /// [Nl80211Attr::MaxHwTimestampPeers] of wiphy.
+    pub fn set_hw_timestamp(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        peer: Option<[u8; 6]>,
+        enabled: bool,
+    ) -> Nl80211HwTimestampRequest {
+        Nl80211HwTimestampRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            peer,
+            enabled,
+        )
+    }
+
+    /// Build a [Nl80211RoamWatcher] tracking connect, roam and disconnect
+    /// transitions of specified interface in station mode
+    #[cfg(feature = "events")]
+    pub fn roam_watcher(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211RoamWatcher {
+        Nl80211RoamWatcher::new(self.0.clone(), if_index.get())
+    }
+
+    /// Wait until specified interface is associated (has SSID) or
+    /// `timeout` elapsed, which is reported as [crate::Nl80211Error::Timeout].
+    #[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+    pub fn wait_for_association(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        timeout: std::time::Duration,
+    ) -> Nl80211InterfaceWaitAssociationRequest {
+        Nl80211InterfaceWaitAssociationRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            timeout,
+        )
+    }
+
+    /// Limit the transmit bitrates of specified interface
+    /// (equivalent to `iw dev DEVICE set bitrates`).
+    /// Bands not included in `masks` are reset to allow all rates, hence
+    /// empty `masks` resets to default.
+    pub fn set_tx_bitrate(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        masks: Vec<Nl80211TxRateMask>,
+    ) -> Nl80211InterfaceSetTxBitrateRequest {
+        Nl80211InterfaceSetTxBitrateRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            masks,
+        )
+    }
+
+    /// Configure per-TID behavior (ACK policy, retry limits, aggregation,
+    /// RTS/CTS and transmit rate) of specified interface, or of the
+    /// specified `peer` station only
+    /// (equivalent to `iw dev DEVICE set tidconf`).
+    pub fn set_tid_config(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        peer: Option<[u8; 6]>,
+        configs: Vec<Nl80211TidConfig>,
+    ) -> Nl80211SetTidConfigRequest {
+        Nl80211SetTidConfigRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            peer,
+            configs,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Interface;
+
+impl Nl80211Interface {
+    /// Create interface with specified name and type on specified wiphy
+    pub fn new(
+        wiphy_index: Nl80211WiphyIndex,
+        name: &str,
+        iface_type: Nl80211InterfaceType,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .replace(Nl80211Attr::Wiphy(wiphy_index.get()))
+            .replace(Nl80211Attr::IfName(name.to_string()))
+            .replace(Nl80211Attr::IfType(iface_type))
+    }
+
+    /// Create monitor interface without any monitor flags, use
+    /// [Nl80211AttrsBuilder::monitor_flags()] to capture more frames
+    /// (equivalent to `iw phy PHY interface add NAME type monitor`).
+    pub fn monitor(
+        wiphy_index: Nl80211WiphyIndex,
+        name: &str,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Self::new(wiphy_index, name, Nl80211InterfaceType::Monitor)
+    }
+
+    /// Create station interface with 4-address mode disabled
+    /// (equivalent to `iw phy PHY interface add NAME type managed`).
+    pub fn station(
+        wiphy_index: Nl80211WiphyIndex,
+        name: &str,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Self::new(wiphy_index, name, Nl80211InterfaceType::Station)
+            .use_4addr(false)
+    }
+
+    /// Create AP interface with 4-address mode disabled
+    /// (equivalent to `iw phy PHY interface add NAME type __ap`).
+    pub fn ap(
+        wiphy_index: Nl80211WiphyIndex,
+        name: &str,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Self::new(wiphy_index, name, Nl80211InterfaceType::Ap).use_4addr(false)
+    }
+
+    /// Create mesh point interface joining specified mesh ID
+    /// (equivalent to
+    /// `iw phy PHY interface add NAME type mp mesh_id MESH_ID`).
+    pub fn mesh(
+        wiphy_index: Nl80211WiphyIndex,
+        name: &str,
+        mesh_id: &[u8],
+    ) -> Nl80211AttrsBuilder<Self> {
+        Self::new(wiphy_index, name, Nl80211InterfaceType::MeshPoint)
+            .replace(Nl80211Attr::MeshId(mesh_id.to_vec()))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Interface> {
+    /// Monitor configuration flags, only valid for
+    /// [Nl80211InterfaceType::Monitor]. For example, a sniffer capturing
+    /// everything in the air could use [Nl80211MonitorFlag::FcsFail],
+    /// [Nl80211MonitorFlag::PlcpFail], [Nl80211MonitorFlag::Control] and
+    /// [Nl80211MonitorFlag::OtherBss].
+    pub fn monitor_flags(self, flags: Vec<Nl80211MonitorFlag>) -> Self {
+        self.replace(Nl80211Attr::MonitorFlags(flags))
+    }
+
+    /// Use 4-address frame format, for example station interface added to
+    /// a bridge or WDS-like AP
+    pub fn use_4addr(self, value: bool) -> Self {
+        self.replace(Nl80211Attr::Use4Addr(value))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211InterfaceSet;
+
+impl Nl80211InterfaceSet {
+    /// Change specified interface to target interface type, the type is
+    /// unchanged if it is the current type of interface. Other attributes
+    /// are checked against the target type by
+    /// [crate::Nl80211InterfaceSetRequest::execute()].
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        iface_type: Nl80211InterfaceType,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::IfType(iface_type))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211InterfaceSet> {
+    /// Use 4-address frame format, enabling it is only valid for
+    /// [Nl80211InterfaceType::Station] and [Nl80211InterfaceType::ApVlan]
+    pub fn use_4addr(self, value: bool) -> Self {
+        self.replace(Nl80211Attr::Use4Addr(value))
+    }
+
+    /// Monitor configuration flags, only valid for
+    /// [Nl80211InterfaceType::Monitor]
+    pub fn monitor_flags(self, flags: Vec<Nl80211MonitorFlag>) -> Self {
+        self.replace(Nl80211Attr::MonitorFlags(flags))
+    }
+
+    /// Mesh ID, only valid for [Nl80211InterfaceType::MeshPoint]
+    pub fn mesh_id(self, mesh_id: &[u8]) -> Self {
+        self.replace(Nl80211Attr::MeshId(mesh_id.to_vec()))
+    }
+
+    /// MAC address of the WDS peer, only valid for
+    /// [Nl80211InterfaceType::Wds]
+    pub fn wds_peer(self, mac: [u8; 6]) -> Self {
+        self.replace(Nl80211Attr::Mac(mac))
+    }
 }
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211HwTimestampRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peer: Option<[u8; ETH_ALEN]>,
+    enabled: bool,
+}
+
+impl Nl80211HwTimestampRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        peer: Option<[u8; ETH_ALEN]>,
+        enabled: bool,
+    ) -> Self {
+        Nl80211HwTimestampRequest {
+            handle,
+            if_index,
+            peer,
+            enabled,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211HwTimestampRequest {
+            mut handle,
+            if_index,
+            peer,
+            enabled,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(peer) = peer {
+            attributes.push(Nl80211Attr::Mac(peer));
+        }
+        if enabled {
+            attributes.push(Nl80211Attr::HwTimestampEnabled);
+        }
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetHwTimestamp,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -9,6 +9,7 @@ use netlink_packet_utils::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Nl80211InterfaceTypes(pub(crate) Vec<Nl80211InterfaceType>);
 
 impl std::ops::Deref for Nl80211InterfaceTypes {
@@ -60,6 +61,7 @@ const NL80211_IFTYPE_NAN: u32 = 12;
 // Linux kernel data type `enum nl80211_iftype`
 /// (virtual) interface types
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211InterfaceType {
     /// unspecified type, driver decides
     Unspecified,
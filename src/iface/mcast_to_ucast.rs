@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211MulticastToUnicastRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    enabled: bool,
+}
+
+impl Nl80211MulticastToUnicastRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        enabled: bool,
+    ) -> Self {
+        Nl80211MulticastToUnicastRequest {
+            handle,
+            if_index,
+            enabled,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211MulticastToUnicastRequest {
+            mut handle,
+            if_index,
+            enabled,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        // Absence of the flag disables the conversion
+        if enabled {
+            attributes.push(Nl80211Attr::MulticastToUnicastEnabled);
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetMulticastToUnicast,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
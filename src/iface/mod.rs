@@ -1,16 +1,62 @@
 // SPDX-License-Identifier: MIT
 
 mod combination;
+mod crit_protocol;
 mod get;
 mod handle;
+mod hw_timestamp;
 mod iface_type;
+mod mcast_to_ucast;
+mod monitor;
+mod new;
+mod noack_map;
+mod p2p;
+#[cfg(feature = "events")]
+mod roam;
+mod set;
+mod set_tid_config;
+mod set_tx_bitrate;
+mod tid_config;
+mod tx_rate;
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+mod wait_assoc;
 
 pub use self::combination::{
     Nl80211IfaceComb, Nl80211IfaceCombAttribute, Nl80211IfaceCombLimit,
-    Nl80211IfaceCombLimitAttribute,
+    Nl80211IfaceCombLimitAttribute, Nl80211IfaceCombParams,
+};
+pub use self::crit_protocol::{
+    Nl80211CritProtocolId, Nl80211CritProtocolRequest,
 };
 pub use self::get::Nl80211InterfaceGetRequest;
-pub use self::handle::Nl80211InterfaceHandle;
+pub use self::handle::{
+    Nl80211Interface, Nl80211InterfaceHandle, Nl80211InterfaceSet,
+};
+pub use self::hw_timestamp::Nl80211HwTimestampRequest;
 pub use self::iface_type::Nl80211InterfaceType;
+pub use self::mcast_to_ucast::Nl80211MulticastToUnicastRequest;
+pub use self::monitor::Nl80211MonitorFlag;
+pub use self::new::Nl80211InterfaceNewRequest;
+pub use self::noack_map::Nl80211NoackMapRequest;
+pub use self::p2p::{Nl80211P2pDeviceRequest, Nl80211P2pPowerSaveRequest};
+#[cfg(feature = "events")]
+pub use self::roam::{
+    Nl80211RoamEntry, Nl80211RoamEventKind, Nl80211RoamWatcher,
+};
+pub use self::set::Nl80211InterfaceSetRequest;
+pub use self::set_tid_config::Nl80211SetTidConfigRequest;
+pub use self::set_tx_bitrate::Nl80211InterfaceSetTxBitrateRequest;
+pub use self::tid_config::{
+    Nl80211TidConfig, Nl80211TidConfigAttribute, Nl80211TidConfigState,
+    Nl80211TidConfigSupport, Nl80211TxRateSetting,
+};
+pub use self::tx_rate::{
+    Nl80211HeLtf, Nl80211TxRate, Nl80211TxRateGi, Nl80211TxRateMask,
+};
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+pub use self::wait_assoc::{
+    Nl80211AssociationInfo, Nl80211InterfaceWaitAssociationRequest,
+};
 
+pub(crate) use self::combination::parse_iface_combinations;
 pub(crate) use self::iface_type::Nl80211InterfaceTypes;
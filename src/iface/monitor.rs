@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    DecodeError, Emitable, Parseable,
+};
+
+const NL80211_MNTR_FLAG_FCSFAIL: u16 = 1;
+const NL80211_MNTR_FLAG_PLCPFAIL: u16 = 2;
+const NL80211_MNTR_FLAG_CONTROL: u16 = 3;
+const NL80211_MNTR_FLAG_OTHER_BSS: u16 = 4;
+const NL80211_MNTR_FLAG_COOK_FRAMES: u16 = 5;
+const NL80211_MNTR_FLAG_ACTIVE: u16 = 6;
+const NL80211_MNTR_FLAG_SKIP_TX: u16 = 7;
+
+/// Monitor configuration flags
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211MonitorFlag {
+    /// Pass frames with bad FCS
+    FcsFail,
+    /// Pass frames with bad PLCP
+    PlcpFail,
+    /// Pass control frames
+    Control,
+    /// Disable BSSID filtering
+    OtherBss,
+    /// Deprecated, will unconditionally be refused
+    CookFrames,
+    /// Use the configured MAC address and ACK incoming unicast packets
+    Active,
+    /// Do not pass local tx packets
+    SkipTx,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MonitorFlag {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => 0,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FcsFail => NL80211_MNTR_FLAG_FCSFAIL,
+            Self::PlcpFail => NL80211_MNTR_FLAG_PLCPFAIL,
+            Self::Control => NL80211_MNTR_FLAG_CONTROL,
+            Self::OtherBss => NL80211_MNTR_FLAG_OTHER_BSS,
+            Self::CookFrames => NL80211_MNTR_FLAG_COOK_FRAMES,
+            Self::Active => NL80211_MNTR_FLAG_ACTIVE,
+            Self::SkipTx => NL80211_MNTR_FLAG_SKIP_TX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        if let Self::Other(attr) = self {
+            attr.emit(buffer)
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MonitorFlag
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_MNTR_FLAG_FCSFAIL => Self::FcsFail,
+            NL80211_MNTR_FLAG_PLCPFAIL => Self::PlcpFail,
+            NL80211_MNTR_FLAG_CONTROL => Self::Control,
+            NL80211_MNTR_FLAG_OTHER_BSS => Self::OtherBss,
+            NL80211_MNTR_FLAG_COOK_FRAMES => Self::CookFrames,
+            NL80211_MNTR_FLAG_ACTIVE => Self::Active,
+            NL80211_MNTR_FLAG_SKIP_TX => Self::SkipTx,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
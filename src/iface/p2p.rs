@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211P2pDeviceRequest {
+    handle: Nl80211Handle,
+    wdev: u64,
+    start: bool,
+}
+
+impl Nl80211P2pDeviceRequest {
+    pub(crate) fn new(handle: Nl80211Handle, wdev: u64, start: bool) -> Self {
+        Nl80211P2pDeviceRequest {
+            handle,
+            wdev,
+            start,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211P2pDeviceRequest {
+            mut handle,
+            wdev,
+            start,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: if start {
+                Nl80211Command::StartP2PDevice
+            } else {
+                Nl80211Command::StopP2PDevice
+            },
+            attributes: vec![Nl80211Attr::Wdev(wdev)],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+pub struct Nl80211P2pPowerSaveRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    ctwindow: u8,
+    opp_ps: bool,
+}
+
+impl Nl80211P2pPowerSaveRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        ctwindow: u8,
+        opp_ps: bool,
+    ) -> Self {
+        Nl80211P2pPowerSaveRequest {
+            handle,
+            if_index,
+            ctwindow,
+            opp_ps,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211P2pPowerSaveRequest {
+            mut handle,
+            if_index,
+            ctwindow,
+            opp_ps,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetBss,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::P2pCtwindow(ctwindow),
+                Nl80211Attr::P2pOppps(opp_ps),
+            ],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
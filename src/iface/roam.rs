@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use futures::{Stream, StreamExt, TryStreamExt};
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle, Nl80211IfIndex,
+    Nl80211Message, Nl80211StationGetRequest, Nl80211StationInfo,
+};
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211RoamEventKind {
+    /// Connected to new BSS from disconnected state
+    Connected,
+    /// Connection attempt failed or timed out
+    ConnectFailed,
+    /// Moved to another BSS of the same ESS without disconnecting
+    Roamed,
+    Disconnected,
+}
+
+/// Single transition in the connection timeline of interface reported by
+/// [Nl80211RoamWatcher]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct Nl80211RoamEntry {
+    pub if_index: Nl80211IfIndex,
+    pub kind: Nl80211RoamEventKind,
+    /// When the notification was processed
+    pub time: Instant,
+    /// BSS connected before this transition
+    pub old_bssid: Option<[u8; ETH_ALEN]>,
+    /// BSS connected after this transition, `None` for
+    /// [Nl80211RoamEventKind::Disconnected] and
+    /// [Nl80211RoamEventKind::ConnectFailed]
+    pub new_bssid: Option<[u8; ETH_ALEN]>,
+    /// Time spent on [Self::old_bssid], `None` if the watcher did not see
+    /// the connection to it
+    pub duration: Option<Duration>,
+    /// IEEE 802.11 status code of connection attempt
+    pub status_code: Option<u16>,
+    /// IEEE 802.11 reason code of disconnection
+    pub reason_code: Option<u16>,
+    /// Disconnection was initiated by AP
+    pub by_ap: bool,
+    /// Station information of [Self::new_bssid] queried right after the
+    /// transition, empty if the query failed
+    pub station_info: Vec<Nl80211StationInfo>,
+}
+
+/// Track the connect, roam and disconnect notifications of specified
+/// interface in station mode and turn them into a timeline of
+/// [Nl80211RoamEntry].
+pub struct Nl80211RoamWatcher {
+    handle: Nl80211Handle,
+    if_index: u32,
+    // Current BSSID and when it was connected(`None` if connected before
+    // the watcher started)
+    current: Option<([u8; ETH_ALEN], Option<Instant>)>,
+}
+
+impl Nl80211RoamWatcher {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211RoamWatcher {
+            handle,
+            if_index,
+            current: None,
+        }
+    }
+
+    /// Stream of transitions found in `events`, which could be generated by
+    /// [Nl80211Handle::notifications()] with connection socket joined the
+    /// `mlme` multicast group.
+    /// The currently connected BSS(if any) is queried before processing
+    /// the first notification, so the first transition could report its
+    /// [Nl80211RoamEntry::old_bssid].
+    pub fn watch<S>(self, events: S) -> impl Stream<Item = Nl80211RoamEntry>
+    where
+        S: Stream<Item = Nl80211Message>,
+    {
+        let events = Box::pin(events);
+        futures::stream::unfold(
+            (self, events, false),
+            |(mut watcher, mut events, mut initialized)| async move {
+                if !initialized {
+                    initialized = true;
+                    match watcher.query_station(None).await {
+                        Ok(Some((bssid, _))) => {
+                            watcher.current = Some((bssid, None))
+                        }
+                        Ok(None) => (),
+                        Err(e) => log::debug!(
+                            "Failed to query current BSS of interface {}: {e}",
+                            watcher.if_index
+                        ),
+                    }
+                }
+                loop {
+                    let msg = events.next().await?;
+                    if let Some(entry) = watcher.process(&msg).await {
+                        return Some((entry, (watcher, events, initialized)));
+                    }
+                }
+            },
+        )
+    }
+
+    async fn process(
+        &mut self,
+        msg: &Nl80211Message,
+    ) -> Option<Nl80211RoamEntry> {
+        if !matches!(
+            msg.cmd,
+            Nl80211Command::Connect
+                | Nl80211Command::Roam
+                | Nl80211Command::Disconnect
+        ) || !msg
+            .attributes
+            .contains(&Nl80211Attr::IfIndex(self.if_index))
+        {
+            return None;
+        }
+        let now = Instant::now();
+        let mut bssid = None;
+        let mut status_code = None;
+        let mut reason_code = None;
+        let mut timed_out = false;
+        let mut by_ap = false;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Mac(s) => bssid = Some(*s),
+                Nl80211Attr::StatusCode(d) => status_code = Some(*d),
+                Nl80211Attr::ReasonCode(d) => reason_code = Some(*d),
+                Nl80211Attr::TimedOut => timed_out = true,
+                Nl80211Attr::DisconnectedByAp => by_ap = true,
+                _ => (),
+            }
+        }
+        let kind = match msg.cmd {
+            Nl80211Command::Connect
+                if !timed_out && status_code.unwrap_or_default() == 0 =>
+            {
+                Nl80211RoamEventKind::Connected
+            }
+            Nl80211Command::Connect => Nl80211RoamEventKind::ConnectFailed,
+            Nl80211Command::Roam => Nl80211RoamEventKind::Roamed,
+            _ => Nl80211RoamEventKind::Disconnected,
+        };
+        let new_bssid = match kind {
+            Nl80211RoamEventKind::Connected | Nl80211RoamEventKind::Roamed => {
+                bssid
+            }
+            _ => None,
+        };
+        let (old_bssid, since) = match self.current.take() {
+            Some((old, since)) => (Some(old), since),
+            None => (None, None),
+        };
+        self.current = new_bssid.map(|b| (b, Some(now)));
+
+        let station_info = if let Some(new_bssid) = new_bssid {
+            match self.query_station(Some(new_bssid)).await {
+                Ok(Some((_, info))) => info,
+                Ok(None) => Vec::new(),
+                Err(e) => {
+                    log::debug!(
+                        "Failed to query station {new_bssid:?} of \
+                        interface {}: {e}",
+                        self.if_index
+                    );
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        Some(Nl80211RoamEntry {
+            if_index: Nl80211IfIndex::new(self.if_index),
+            kind,
+            time: now,
+            old_bssid,
+            new_bssid,
+            duration: since.map(|s| now.duration_since(s)),
+            status_code,
+            reason_code,
+            by_ap,
+            station_info,
+        })
+    }
+
+    // Query specified station or the only station(the AP in station mode)
+    // of the interface
+    async fn query_station(
+        &mut self,
+        mac: Option<[u8; ETH_ALEN]>,
+    ) -> Result<Option<([u8; ETH_ALEN], Vec<Nl80211StationInfo>)>, Nl80211Error>
+    {
+        let response = Nl80211StationGetRequest::new(
+            self.handle.clone(),
+            self.if_index,
+            mac,
+        )
+        .execute()
+        .await;
+        futures::pin_mut!(response);
+        let mut stations = Vec::new();
+        while let Some(msg) = response.try_next().await? {
+            let mut station_mac = None;
+            let mut info = Vec::new();
+            for attr in msg.payload.attributes {
+                match attr {
+                    Nl80211Attr::Mac(s) => station_mac = Some(s),
+                    Nl80211Attr::StationInfo(v) => info = v,
+                    _ => (),
+                }
+            }
+            if let Some(station_mac) = station_mac {
+                stations.push((station_mac, info));
+            }
+        }
+        Ok(if stations.len() == 1 {
+            stations.pop()
+        } else {
+            None
+        })
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211InterfaceType, Nl80211Message,
+};
+
+pub struct Nl80211InterfaceSetRequest {
+    handle: Nl80211Handle,
+    attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211InterfaceSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211InterfaceSetRequest { handle, attributes }
+    }
+
+    /// Change the interface, the WDS peer if any is set by following
+    /// `NL80211_CMD_SET_WDS_PEER` once the interface type is changed.
+    ///
+    /// Return [Nl80211Error::RequestFailed] without sending anything when
+    /// the attributes are not compatible with the target interface type.
+    pub async fn execute(self) -> Result<(), Nl80211Error> {
+        let Nl80211InterfaceSetRequest {
+            mut handle,
+            attributes,
+        } = self;
+
+        validate(&attributes)?;
+
+        let mut if_index = None;
+        let mut wds_peer = None;
+        let mut set_attrs = Vec::new();
+        for attr in attributes {
+            match attr {
+                Nl80211Attr::Mac(mac) => wds_peer = Some(mac),
+                Nl80211Attr::IfIndex(i) => {
+                    if_index = Some(i);
+                    set_attrs.push(attr);
+                }
+                attr => set_attrs.push(attr),
+            }
+        }
+
+        // Nothing but interface index means only WDS peer is changed
+        if set_attrs.len() > 1 || wds_peer.is_none() {
+            execute_ack(&mut handle, Nl80211Command::SetInterface, set_attrs)
+                .await?;
+        }
+
+        if let Some(mac) = wds_peer {
+            let Some(if_index) = if_index else {
+                return Err(Nl80211Error::RequestFailed(
+                    "Interface index is required for setting WDS peer".into(),
+                ));
+            };
+            execute_ack(
+                &mut handle,
+                Nl80211Command::SetWdsPeer,
+                vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(mac)],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+async fn execute_ack(
+    handle: &mut Nl80211Handle,
+    cmd: Nl80211Command,
+    attributes: Vec<Nl80211Attr>,
+) -> Result<(), Nl80211Error> {
+    let nl80211_msg = Nl80211Message { cmd, attributes };
+    let response =
+        nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST | NLM_F_ACK).await;
+    futures::pin_mut!(response);
+    while response.try_next().await?.is_some() {}
+    Ok(())
+}
+
+// Mirror the checks of kernel `nl80211_set_interface()` so that the
+// incompatible request is refused with a descriptive error instead of
+// `EINVAL` or `EOPNOTSUPP`.
+fn validate(attributes: &[Nl80211Attr]) -> Result<(), Nl80211Error> {
+    let Some(iface_type) = attributes.iter().find_map(|attr| {
+        if let Nl80211Attr::IfType(t) = attr {
+            Some(*t)
+        } else {
+            None
+        }
+    }) else {
+        return Ok(());
+    };
+
+    for attr in attributes {
+        let required = match attr {
+            Nl80211Attr::MonitorFlags(_) => {
+                &[Nl80211InterfaceType::Monitor][..]
+            }
+            Nl80211Attr::MeshId(_) => &[Nl80211InterfaceType::MeshPoint][..],
+            Nl80211Attr::Mac(_) => &[Nl80211InterfaceType::Wds][..],
+            Nl80211Attr::Use4Addr(true) => {
+                &[Nl80211InterfaceType::Station, Nl80211InterfaceType::ApVlan][..]
+            }
+            _ => continue,
+        };
+        if !required.contains(&iface_type) {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "{attr:?} is not supported by interface type {iface_type:?}, \
+                 expecting one of {required:?}"
+            )));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211TidConfig,
+};
+
+pub struct Nl80211SetTidConfigRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    peer: Option<[u8; 6]>,
+    configs: Vec<Nl80211TidConfig>,
+}
+
+impl Nl80211SetTidConfigRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        peer: Option<[u8; 6]>,
+        configs: Vec<Nl80211TidConfig>,
+    ) -> Self {
+        Nl80211SetTidConfigRequest {
+            handle,
+            if_index,
+            peer,
+            configs,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SetTidConfigRequest {
+            mut handle,
+            if_index,
+            peer,
+            mut configs,
+        } = self;
+
+        for (index, config) in configs.iter_mut().enumerate() {
+            config.index = index as u16;
+        }
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        if let Some(mac) = peer {
+            attributes.push(Nl80211Attr::Mac(mac));
+        }
+        attributes.push(Nl80211Attr::TidConfig(configs));
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetTidConfig,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
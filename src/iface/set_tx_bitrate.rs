@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211TxRateMask,
+};
+
+pub struct Nl80211InterfaceSetTxBitrateRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    masks: Vec<Nl80211TxRateMask>,
+}
+
+impl Nl80211InterfaceSetTxBitrateRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        masks: Vec<Nl80211TxRateMask>,
+    ) -> Self {
+        Nl80211InterfaceSetTxBitrateRequest {
+            handle,
+            if_index,
+            masks,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211InterfaceSetTxBitrateRequest {
+            mut handle,
+            if_index,
+            masks,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetTxBitrateMask,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::TxRates(masks),
+            ],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,490 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::{write_u16, write_u64},
+    Nl80211TxRateMask,
+};
+
+const NL80211_TID_CONFIG_ATTR_PAD: u16 = 1;
+const NL80211_TID_CONFIG_ATTR_VIF_SUPP: u16 = 2;
+const NL80211_TID_CONFIG_ATTR_PEER_SUPP: u16 = 3;
+const NL80211_TID_CONFIG_ATTR_OVERRIDE: u16 = 4;
+const NL80211_TID_CONFIG_ATTR_TIDS: u16 = 5;
+const NL80211_TID_CONFIG_ATTR_NOACK: u16 = 6;
+const NL80211_TID_CONFIG_ATTR_RETRY_SHORT: u16 = 7;
+const NL80211_TID_CONFIG_ATTR_RETRY_LONG: u16 = 8;
+const NL80211_TID_CONFIG_ATTR_AMPDU_CTRL: u16 = 9;
+const NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL: u16 = 10;
+const NL80211_TID_CONFIG_ATTR_AMSDU_CTRL: u16 = 11;
+const NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE: u16 = 12;
+const NL80211_TID_CONFIG_ATTR_TX_RATE: u16 = 13;
+
+/// Per-TID configuration used by
+/// [crate::Nl80211InterfaceHandle::set_tid_config()]. Wiphy dump reports
+/// [Nl80211TidConfigSupport] instead.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211TidConfig {
+    pub index: u16,
+    pub attributes: Vec<Nl80211TidConfigAttribute>,
+}
+
+impl Nl80211TidConfig {
+    /// Configuration applied to TIDs in `tids` bitmap, bit N for TID N.
+    pub fn new(tids: u16) -> Self {
+        Self {
+            index: 0,
+            attributes: vec![Nl80211TidConfigAttribute::Tids(tids)],
+        }
+    }
+
+    /// Whether to disable ACK policy
+    pub fn noack(self, state: Nl80211TidConfigState) -> Self {
+        self.attr(Nl80211TidConfigAttribute::Noack(state))
+    }
+
+    /// Retry count for short frames, 0 is not allowed by kernel
+    pub fn retry_short(self, count: u8) -> Self {
+        self.attr(Nl80211TidConfigAttribute::RetryShort(count))
+    }
+
+    /// Retry count for long frames, 0 is not allowed by kernel
+    pub fn retry_long(self, count: u8) -> Self {
+        self.attr(Nl80211TidConfigAttribute::RetryLong(count))
+    }
+
+    /// Whether to allow aggregation in A-MPDU
+    pub fn ampdu(self, state: Nl80211TidConfigState) -> Self {
+        self.attr(Nl80211TidConfigAttribute::AmpduCtrl(state))
+    }
+
+    /// Whether to use RTS/CTS protection
+    pub fn rtscts(self, state: Nl80211TidConfigState) -> Self {
+        self.attr(Nl80211TidConfigAttribute::RtsctsCtrl(state))
+    }
+
+    /// Whether to allow aggregation in A-MSDU
+    pub fn amsdu(self, state: Nl80211TidConfigState) -> Self {
+        self.attr(Nl80211TidConfigAttribute::AmsduCtrl(state))
+    }
+
+    /// Transmit rate of these TIDs, the `masks` is ignored for
+    /// [Nl80211TxRateSetting::Automatic].
+    pub fn tx_rate(
+        self,
+        setting: Nl80211TxRateSetting,
+        masks: Vec<Nl80211TxRateMask>,
+    ) -> Self {
+        let ret = self.attr(Nl80211TidConfigAttribute::TxRateType(setting));
+        if setting == Nl80211TxRateSetting::Automatic {
+            ret
+        } else {
+            ret.attr(Nl80211TidConfigAttribute::TxRate(masks))
+        }
+    }
+
+    /// Apply this configuration to all peers overriding their peer specific
+    /// configurations. Ignored when configuring a specific peer.
+    pub fn override_peers(self) -> Self {
+        self.attr(Nl80211TidConfigAttribute::Override)
+    }
+
+    fn attr(mut self, attr: Nl80211TidConfigAttribute) -> Self {
+        self.attributes.retain(|a| a.kind() != attr.kind());
+        self.attributes.push(attr);
+        self
+    }
+
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        index: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_TID_CONFIG value {payload:?}");
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            attributes.push(Nl80211TidConfigAttribute::parse(nla)?);
+        }
+        Ok(Self { index, attributes })
+    }
+}
+
+impl Nla for Nl80211TidConfig {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TidConfigAttribute {
+    /// Override all peer specific configurations
+    Override,
+    /// Bitmap of TIDs this configuration applies to
+    Tids(u16),
+    Noack(Nl80211TidConfigState),
+    RetryShort(u8),
+    RetryLong(u8),
+    AmpduCtrl(Nl80211TidConfigState),
+    RtsctsCtrl(Nl80211TidConfigState),
+    AmsduCtrl(Nl80211TidConfigState),
+    TxRateType(Nl80211TxRateSetting),
+    TxRate(Vec<Nl80211TxRateMask>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TidConfigAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Override => 0,
+            Self::Tids(_) => 2,
+            Self::Noack(_)
+            | Self::RetryShort(_)
+            | Self::RetryLong(_)
+            | Self::AmpduCtrl(_)
+            | Self::RtsctsCtrl(_)
+            | Self::AmsduCtrl(_)
+            | Self::TxRateType(_) => 1,
+            Self::TxRate(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Override => NL80211_TID_CONFIG_ATTR_OVERRIDE,
+            Self::Tids(_) => NL80211_TID_CONFIG_ATTR_TIDS,
+            Self::Noack(_) => NL80211_TID_CONFIG_ATTR_NOACK,
+            Self::RetryShort(_) => NL80211_TID_CONFIG_ATTR_RETRY_SHORT,
+            Self::RetryLong(_) => NL80211_TID_CONFIG_ATTR_RETRY_LONG,
+            Self::AmpduCtrl(_) => NL80211_TID_CONFIG_ATTR_AMPDU_CTRL,
+            Self::RtsctsCtrl(_) => NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL,
+            Self::AmsduCtrl(_) => NL80211_TID_CONFIG_ATTR_AMSDU_CTRL,
+            Self::TxRateType(_) => NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE,
+            Self::TxRate(_) => NL80211_TID_CONFIG_ATTR_TX_RATE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Override => (),
+            Self::Tids(d) => write_u16(buffer, *d),
+            Self::RetryShort(d) | Self::RetryLong(d) => buffer[0] = *d,
+            Self::Noack(d)
+            | Self::AmpduCtrl(d)
+            | Self::RtsctsCtrl(d)
+            | Self::AmsduCtrl(d) => buffer[0] = (*d).into(),
+            Self::TxRateType(d) => buffer[0] = (*d).into(),
+            Self::TxRate(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TidConfigAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TID_CONFIG_ATTR_OVERRIDE => Self::Override,
+            NL80211_TID_CONFIG_ATTR_TIDS => {
+                let err_msg =
+                    format!("Invalid NL80211_TID_CONFIG_ATTR_TIDS {payload:?}");
+                Self::Tids(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_NOACK => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_NOACK {payload:?}"
+                );
+                Self::Noack(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_RETRY_SHORT => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RETRY_SHORT {payload:?}"
+                );
+                Self::RetryShort(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_RETRY_LONG => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RETRY_LONG {payload:?}"
+                );
+                Self::RetryLong(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_TID_CONFIG_ATTR_AMPDU_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_AMPDU_CTRL {payload:?}"
+                );
+                Self::AmpduCtrl(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_RTSCTS_CTRL {payload:?}"
+                );
+                Self::RtsctsCtrl(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_AMSDU_CTRL => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_AMSDU_CTRL {payload:?}"
+                );
+                Self::AmsduCtrl(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_TX_RATE_TYPE {payload:?}"
+                );
+                Self::TxRateType(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TID_CONFIG_ATTR_TX_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_TID_CONFIG_ATTR_TX_RATE {payload:?}"
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211TxRateMask::parse(nla)?);
+                }
+                Self::TxRate(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// TID configuration capabilities reported by wiphy dump in
+/// [crate::Nl80211Attr::TidConfigSupport], shares the attribute ID with
+/// [crate::Nl80211Attr::TidConfig].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211TidConfigSupport {
+    /// Bitmap of [Nl80211TidConfigAttribute] kinds supported for the
+    /// interface (VIF) configuration, bit N for attribute kind N
+    pub vif_support: u64,
+    /// Bitmap of [Nl80211TidConfigAttribute] kinds supported for the peer
+    /// configuration, bit N for attribute kind N
+    pub peer_support: u64,
+    /// Maximum retry count for short frames
+    pub max_retry_short: Option<u8>,
+    /// Maximum retry count for long frames
+    pub max_retry_long: Option<u8>,
+}
+
+impl Nl80211TidConfigSupport {
+    /// Whether specified attribute is supported for interface configuration
+    pub fn vif_supports(&self, attr: &Nl80211TidConfigAttribute) -> bool {
+        attr.kind() < 64 && self.vif_support & (1 << attr.kind()) > 0
+    }
+
+    /// Whether specified attribute is supported for peer configuration
+    pub fn peer_supports(&self, attr: &Nl80211TidConfigAttribute) -> bool {
+        attr.kind() < 64 && self.peer_support & (1 << attr.kind()) > 0
+    }
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let err_msg = format!("Invalid NL80211_ATTR_TID_CONFIG {payload:?}");
+        let mut ret = Self::default();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            let value = nla.value();
+            match nla.kind() {
+                NL80211_TID_CONFIG_ATTR_VIF_SUPP => {
+                    ret.vif_support = parse_u64(value).context(format!(
+                        "Invalid NL80211_TID_CONFIG_ATTR_VIF_SUPP {value:?}"
+                    ))?
+                }
+                NL80211_TID_CONFIG_ATTR_PEER_SUPP => {
+                    ret.peer_support = parse_u64(value).context(format!(
+                        "Invalid NL80211_TID_CONFIG_ATTR_PEER_SUPP {value:?}"
+                    ))?
+                }
+                NL80211_TID_CONFIG_ATTR_RETRY_SHORT => {
+                    ret.max_retry_short =
+                        Some(parse_u8(value).context(format!(
+                            "Invalid NL80211_TID_CONFIG_ATTR_RETRY_SHORT \
+                            {value:?}"
+                        ))?)
+                }
+                NL80211_TID_CONFIG_ATTR_RETRY_LONG => {
+                    ret.max_retry_long =
+                        Some(parse_u8(value).context(format!(
+                        "Invalid NL80211_TID_CONFIG_ATTR_RETRY_LONG {value:?}"
+                    ))?)
+                }
+                _ => (),
+            }
+        }
+        Ok(ret)
+    }
+
+    // The per-TID configurations are nested with attribute ID starting from
+    // 1, while the capabilities start with `NL80211_TID_CONFIG_ATTR_VIF_SUPP`
+    // or the empty `NL80211_TID_CONFIG_ATTR_PAD` for alignment.
+    pub(crate) fn is_support_payload(payload: &[u8]) -> bool {
+        match NlasIterator::new(payload).next() {
+            Some(Ok(nla)) => {
+                nla.kind() != NL80211_TID_CONFIG_ATTR_PAD
+                    || nla.value().is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    fn nlas(&self) -> Vec<Nl80211TidConfigSupportNla> {
+        let mut ret = vec![
+            Nl80211TidConfigSupportNla::U64(
+                NL80211_TID_CONFIG_ATTR_VIF_SUPP,
+                self.vif_support,
+            ),
+            Nl80211TidConfigSupportNla::U64(
+                NL80211_TID_CONFIG_ATTR_PEER_SUPP,
+                self.peer_support,
+            ),
+        ];
+        if let Some(d) = self.max_retry_short {
+            ret.push(Nl80211TidConfigSupportNla::U8(
+                NL80211_TID_CONFIG_ATTR_RETRY_SHORT,
+                d,
+            ));
+        }
+        if let Some(d) = self.max_retry_long {
+            ret.push(Nl80211TidConfigSupportNla::U8(
+                NL80211_TID_CONFIG_ATTR_RETRY_LONG,
+                d,
+            ));
+        }
+        ret
+    }
+}
+
+impl Emitable for Nl80211TidConfigSupport {
+    fn buffer_len(&self) -> usize {
+        self.nlas().as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.nlas().as_slice().emit(buffer)
+    }
+}
+
+enum Nl80211TidConfigSupportNla {
+    U64(u16, u64),
+    U8(u16, u8),
+}
+
+impl Nla for Nl80211TidConfigSupportNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::U64(..) => 8,
+            Self::U8(..) => 1,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::U64(kind, _) | Self::U8(kind, _) => *kind,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::U64(_, d) => write_u64(buffer, *d),
+            Self::U8(_, d) => buffer[0] = *d,
+        }
+    }
+}
+
+const NL80211_TID_CONFIG_ENABLE: u8 = 0;
+const NL80211_TID_CONFIG_DISABLE: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TidConfigState {
+    Enable,
+    Disable,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TidConfigState {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_TID_CONFIG_ENABLE => Self::Enable,
+            NL80211_TID_CONFIG_DISABLE => Self::Disable,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TidConfigState> for u8 {
+    fn from(v: Nl80211TidConfigState) -> u8 {
+        match v {
+            Nl80211TidConfigState::Enable => NL80211_TID_CONFIG_ENABLE,
+            Nl80211TidConfigState::Disable => NL80211_TID_CONFIG_DISABLE,
+            Nl80211TidConfigState::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_TX_RATE_AUTOMATIC: u8 = 0;
+const NL80211_TX_RATE_LIMITED: u8 = 1;
+const NL80211_TX_RATE_FIXED: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TxRateSetting {
+    /// Automatically determine transmit rate
+    Automatic,
+    /// Limit transmit rate to the rates in
+    /// [Nl80211TidConfigAttribute::TxRate]
+    Limited,
+    /// Fix transmit rate to the rate in [Nl80211TidConfigAttribute::TxRate]
+    Fixed,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TxRateSetting {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_TX_RATE_AUTOMATIC => Self::Automatic,
+            NL80211_TX_RATE_LIMITED => Self::Limited,
+            NL80211_TX_RATE_FIXED => Self::Fixed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TxRateSetting> for u8 {
+    fn from(v: Nl80211TxRateSetting) -> u8 {
+        match v {
+            Nl80211TxRateSetting::Automatic => NL80211_TX_RATE_AUTOMATIC,
+            Nl80211TxRateSetting::Limited => NL80211_TX_RATE_LIMITED,
+            Nl80211TxRateSetting::Fixed => NL80211_TX_RATE_FIXED,
+            Nl80211TxRateSetting::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{bytes::write_u16, Nl80211BandType, Nl80211HeGi};
+
+const NL80211_TXRATE_LEGACY: u16 = 1;
+const NL80211_TXRATE_HT: u16 = 2;
+const NL80211_TXRATE_VHT: u16 = 3;
+const NL80211_TXRATE_GI: u16 = 4;
+const NL80211_TXRATE_HE: u16 = 5;
+const NL80211_TXRATE_HE_GI: u16 = 6;
+const NL80211_TXRATE_HE_LTF: u16 = 7;
+// const NL80211_TXRATE_EHT:u16 = 8;
+// const NL80211_TXRATE_EHT_GI:u16 = 9;
+// const NL80211_TXRATE_EHT_LTF:u16 = 10;
+
+const NL80211_VHT_NSS_MAX: usize = 8;
+const NL80211_HE_NSS_MAX: usize = 8;
+
+/// Transmit rate mask of specified band used by
+/// [crate::Nl80211InterfaceHandle::set_tx_bitrate()]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211TxRateMask {
+    pub band: Nl80211BandType,
+    pub rates: Vec<Nl80211TxRate>,
+}
+
+impl Nl80211TxRateMask {
+    pub fn new(band: Nl80211BandType, rates: Vec<Nl80211TxRate>) -> Self {
+        Self { band, rates }
+    }
+}
+
+impl Nla for Nl80211TxRateMask {
+    fn value_len(&self) -> usize {
+        self.rates.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        u16::from(self.band)
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.rates.as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxRateMask
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let band = Nl80211BandType::from(buf.kind());
+        let err_msg = format!("Invalid NL80211_ATTR_TX_RATES {payload:?}");
+        let mut rates = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            rates.push(Nl80211TxRate::parse(nla)?);
+        }
+        Ok(Self { band, rates })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TxRate {
+    /// Legacy (non-MCS) rates allowed in unit of 500 Kbps, empty for none
+    Legacy(Vec<u8>),
+    /// HT MCS indexes allowed, empty for none
+    Ht(Vec<u8>),
+    /// VHT MCS bitmap for each spatial stream, index 0 is for 1 spatial
+    /// stream, bit N of the value allows MCS N
+    Vht([u16; NL80211_VHT_NSS_MAX]),
+    /// Guard interval for legacy, HT and VHT rates
+    Gi(Nl80211TxRateGi),
+    /// HE MCS bitmap for each spatial stream, index 0 is for 1 spatial
+    /// stream, bit N of the value allows MCS N
+    He([u16; NL80211_HE_NSS_MAX]),
+    /// Guard interval for HE rates
+    HeGi(Nl80211HeGi),
+    /// HE long training field
+    HeLtf(Nl80211HeLtf),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TxRate {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Legacy(v) | Self::Ht(v) => v.len(),
+            Self::Vht(_) => NL80211_VHT_NSS_MAX * 2,
+            Self::He(_) => NL80211_HE_NSS_MAX * 2,
+            Self::Gi(_) | Self::HeGi(_) | Self::HeLtf(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Legacy(_) => NL80211_TXRATE_LEGACY,
+            Self::Ht(_) => NL80211_TXRATE_HT,
+            Self::Vht(_) => NL80211_TXRATE_VHT,
+            Self::Gi(_) => NL80211_TXRATE_GI,
+            Self::He(_) => NL80211_TXRATE_HE,
+            Self::HeGi(_) => NL80211_TXRATE_HE_GI,
+            Self::HeLtf(_) => NL80211_TXRATE_HE_LTF,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Legacy(v) | Self::Ht(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_slice())
+            }
+            Self::Vht(mcs) | Self::He(mcs) => {
+                for (i, d) in mcs.iter().enumerate() {
+                    write_u16(&mut buffer[i * 2..], *d);
+                }
+            }
+            Self::Gi(d) => buffer[0] = (*d).into(),
+            Self::HeGi(d) => buffer[0] = (*d).into(),
+            Self::HeLtf(d) => buffer[0] = (*d).into(),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+fn parse_mcs_map(
+    payload: &[u8],
+    name: &str,
+) -> Result<[u16; NL80211_VHT_NSS_MAX], DecodeError> {
+    let mut ret = [0u16; NL80211_VHT_NSS_MAX];
+    if payload.len() < NL80211_VHT_NSS_MAX * 2 {
+        return Err(format!(
+            "Invalid {name}, expecting length {} but got {:?}",
+            NL80211_VHT_NSS_MAX * 2,
+            payload
+        )
+        .into());
+    }
+    for (i, d) in ret.iter_mut().enumerate() {
+        *d = parse_u16(&payload[i * 2..i * 2 + 2])
+            .context(format!("Invalid {name} {payload:?}"))?;
+    }
+    Ok(ret)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxRate
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TXRATE_LEGACY => Self::Legacy(payload.to_vec()),
+            NL80211_TXRATE_HT => Self::Ht(payload.to_vec()),
+            NL80211_TXRATE_VHT => {
+                Self::Vht(parse_mcs_map(payload, "NL80211_TXRATE_VHT")?)
+            }
+            NL80211_TXRATE_GI => {
+                let err_msg =
+                    format!("Invalid NL80211_TXRATE_GI value {:?}", payload);
+                Self::Gi(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TXRATE_HE => {
+                Self::He(parse_mcs_map(payload, "NL80211_TXRATE_HE")?)
+            }
+            NL80211_TXRATE_HE_GI => {
+                let err_msg =
+                    format!("Invalid NL80211_TXRATE_HE_GI value {:?}", payload);
+                Self::HeGi(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TXRATE_HE_LTF => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXRATE_HE_LTF value {:?}",
+                    payload
+                );
+                Self::HeLtf(parse_u8(payload).context(err_msg)?.into())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_TXRATE_DEFAULT_GI: u8 = 0;
+const NL80211_TXRATE_FORCE_SGI: u8 = 1;
+const NL80211_TXRATE_FORCE_LGI: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TxRateGi {
+    Default,
+    /// Force short guard interval
+    ForceShort,
+    /// Force long guard interval
+    ForceLong,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211TxRateGi {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_TXRATE_DEFAULT_GI => Self::Default,
+            NL80211_TXRATE_FORCE_SGI => Self::ForceShort,
+            NL80211_TXRATE_FORCE_LGI => Self::ForceLong,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TxRateGi> for u8 {
+    fn from(v: Nl80211TxRateGi) -> u8 {
+        match v {
+            Nl80211TxRateGi::Default => NL80211_TXRATE_DEFAULT_GI,
+            Nl80211TxRateGi::ForceShort => NL80211_TXRATE_FORCE_SGI,
+            Nl80211TxRateGi::ForceLong => NL80211_TXRATE_FORCE_LGI,
+            Nl80211TxRateGi::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_RATE_INFO_HE_1XLTF: u8 = 0;
+const NL80211_RATE_INFO_HE_2XLTF: u8 = 1;
+const NL80211_RATE_INFO_HE_4XLTF: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211HeLtf {
+    /// 3.2 usec
+    Ltf1x,
+    /// 6.4 usec
+    Ltf2x,
+    /// 12.8 usec
+    Ltf4x,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211HeLtf {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_RATE_INFO_HE_1XLTF => Self::Ltf1x,
+            NL80211_RATE_INFO_HE_2XLTF => Self::Ltf2x,
+            NL80211_RATE_INFO_HE_4XLTF => Self::Ltf4x,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211HeLtf> for u8 {
+    fn from(v: Nl80211HeLtf) -> u8 {
+        match v {
+            Nl80211HeLtf::Ltf1x => NL80211_RATE_INFO_HE_1XLTF,
+            Nl80211HeLtf::Ltf2x => NL80211_RATE_INFO_HE_2XLTF,
+            Nl80211HeLtf::Ltf4x => NL80211_RATE_INFO_HE_4XLTF,
+            Nl80211HeLtf::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, Instant};
+
+use futures::{
+    future::{self, Either},
+    Stream, StreamExt, TryStreamExt,
+};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+
+use crate::{
+    nl80211_execute, runtime::sleep, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211Handle, Nl80211IfIndex, Nl80211Message, Nl80211Ssid,
+};
+
+const ETH_ALEN: usize = 6;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Association state of interface reported by
+/// [Nl80211InterfaceWaitAssociationRequest]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211AssociationInfo {
+    pub if_index: Nl80211IfIndex,
+    pub ssid: Nl80211Ssid,
+    /// BSSID of the AP, `None` if kernel has no station entry for it yet
+    pub bssid: Option<[u8; ETH_ALEN]>,
+}
+
+pub struct Nl80211InterfaceWaitAssociationRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl Nl80211InterfaceWaitAssociationRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        timeout: Duration,
+    ) -> Self {
+        Nl80211InterfaceWaitAssociationRequest {
+            handle,
+            if_index,
+            timeout,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Interval of querying interface state, default is 500 milliseconds
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Poll the interface state until it is associated or timeout.
+    pub async fn execute(self) -> Result<Nl80211AssociationInfo, Nl80211Error> {
+        self.wait(futures::stream::pending()).await
+    }
+
+    /// Similar to [Self::execute()] but also query the interface state
+    /// immediately on `NL80211_CMD_CONNECT` or `NL80211_CMD_ASSOCIATE`
+    /// notification of this interface found in `events`, which could be
+    /// generated by [Nl80211Handle::notifications()] with connection
+    /// socket joined the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn execute_with_events<S>(
+        self,
+        events: S,
+    ) -> Result<Nl80211AssociationInfo, Nl80211Error>
+    where
+        S: Stream<Item = Nl80211Message>,
+    {
+        self.wait(events).await
+    }
+
+    async fn wait<S>(
+        mut self,
+        events: S,
+    ) -> Result<Nl80211AssociationInfo, Nl80211Error>
+    where
+        S: Stream<Item = Nl80211Message>,
+    {
+        let deadline = Instant::now() + self.timeout;
+        let if_index = self.if_index;
+        let events = events.filter(move |msg| {
+            future::ready(
+                matches!(
+                    msg.cmd,
+                    Nl80211Command::Connect | Nl80211Command::Associate
+                ) && msg.attributes.contains(&Nl80211Attr::IfIndex(if_index)),
+            )
+        });
+        futures::pin_mut!(events);
+        let mut events_ended = false;
+
+        loop {
+            if let Some(info) = self.query().await? {
+                return Ok(info);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Nl80211Error::Timeout(format!(
+                    "Interface {} not associated within {:?}",
+                    self.if_index, self.timeout
+                )));
+            }
+            let delay = Box::pin(sleep(self.poll_interval.min(deadline - now)));
+            if events_ended {
+                delay.await;
+            } else if let Either::Right((None, delay)) =
+                future::select(delay, events.next()).await
+            {
+                events_ended = true;
+                delay.await;
+            }
+        }
+    }
+
+    async fn query(
+        &mut self,
+    ) -> Result<Option<Nl80211AssociationInfo>, Nl80211Error> {
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetInterface,
+            attributes: vec![Nl80211Attr::IfIndex(self.if_index)],
+        };
+        let response =
+            nl80211_execute(&mut self.handle, nl80211_msg, NLM_F_REQUEST).await;
+        futures::pin_mut!(response);
+        let mut ssid = None;
+        while let Some(msg) = response.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Ssid(s) = attr {
+                    ssid = Some(s);
+                }
+            }
+        }
+        let Some(ssid) = ssid else {
+            return Ok(None);
+        };
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetStation,
+            attributes: vec![Nl80211Attr::IfIndex(self.if_index)],
+        };
+        let response = nl80211_execute(
+            &mut self.handle,
+            nl80211_msg,
+            NLM_F_REQUEST | NLM_F_DUMP,
+        )
+        .await;
+        futures::pin_mut!(response);
+        let mut bssid = None;
+        while let Some(msg) = response.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Mac(s) = attr {
+                    bssid.get_or_insert(s);
+                }
+            }
+        }
+        Ok(Some(Nl80211AssociationInfo {
+            if_index: Nl80211IfIndex::new(self.if_index),
+            ssid,
+            bssid,
+        }))
+    }
+}
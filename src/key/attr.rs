@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211CipherSuite;
+
+const NL80211_KEY_DATA: u16 = 1;
+const NL80211_KEY_IDX: u16 = 2;
+const NL80211_KEY_CIPHER: u16 = 3;
+const NL80211_KEY_SEQ: u16 = 4;
+const NL80211_KEY_DEFAULT: u16 = 5;
+const NL80211_KEY_DEFAULT_MGMT: u16 = 6;
+const NL80211_KEY_TYPE: u16 = 7;
+const NL80211_KEY_DEFAULT_TYPES: u16 = 8;
+const NL80211_KEY_MODE: u16 = 9;
+const NL80211_KEY_DEFAULT_BEACON: u16 = 10;
+
+/// Key attributes nested in [crate::Nl80211Attr::Key]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211KeyAttr {
+    /// Key data: 16 bytes for WEP, 32 bytes for TKIP, 16 bytes for CCMP,
+    /// etc.
+    Data(Vec<u8>),
+    /// Key ID: 0-3 for WEP, TKIP and CCMP pairwise/group keys, 4-5 for
+    /// management frame protection (IGTK), 6-7 for beacon protection (BIGTK)
+    Index(u8),
+    Cipher(Nl80211CipherSuite),
+    /// Transmit key sequence number (IV/PN) for TKIP and CCMP keys, each six
+    /// bytes in little endian
+    Seq(Vec<u8>),
+    /// Flag indicating default key
+    Default,
+    /// Flag indicating default management frame protection key
+    DefaultMgmt,
+    Type(Nl80211KeyType),
+    /// Default key types this key is used for, only valid along with
+    /// [Nl80211KeyAttr::Default]
+    DefaultTypes(Vec<Nl80211KeyDefaultType>),
+    Mode(Nl80211KeyMode),
+    /// Flag indicating default beacon protection key
+    DefaultBeacon,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211KeyAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Data(v) | Self::Seq(v) => v.len(),
+            Self::Index(_) | Self::Mode(_) => 1,
+            Self::Cipher(_) | Self::Type(_) => 4,
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => 0,
+            Self::DefaultTypes(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_KEY_DATA,
+            Self::Index(_) => NL80211_KEY_IDX,
+            Self::Cipher(_) => NL80211_KEY_CIPHER,
+            Self::Seq(_) => NL80211_KEY_SEQ,
+            Self::Default => NL80211_KEY_DEFAULT,
+            Self::DefaultMgmt => NL80211_KEY_DEFAULT_MGMT,
+            Self::Type(_) => NL80211_KEY_TYPE,
+            Self::DefaultTypes(_) => NL80211_KEY_DEFAULT_TYPES,
+            Self::Mode(_) => NL80211_KEY_MODE,
+            Self::DefaultBeacon => NL80211_KEY_DEFAULT_BEACON,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Data(v) | Self::Seq(v) => buffer.copy_from_slice(v),
+            Self::Index(d) => buffer[0] = *d,
+            Self::Mode(d) => buffer[0] = (*d).into(),
+            Self::Cipher(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Type(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::Default | Self::DefaultMgmt | Self::DefaultBeacon => (),
+            Self::DefaultTypes(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_KEY_DATA => Self::Data(payload.to_vec()),
+            NL80211_KEY_IDX => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_IDX value {:?}", payload);
+                Self::Index(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_KEY_CIPHER => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_CIPHER value {:?}", payload);
+                Self::Cipher(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_SEQ => Self::Seq(payload.to_vec()),
+            NL80211_KEY_DEFAULT => Self::Default,
+            NL80211_KEY_DEFAULT_MGMT => Self::DefaultMgmt,
+            NL80211_KEY_TYPE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_TYPE value {:?}", payload);
+                Self::Type(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_DEFAULT_TYPES => {
+                let err_msg = format!(
+                    "Invalid NL80211_KEY_DEFAULT_TYPES value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211KeyDefaultType::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::DefaultTypes(nlas)
+            }
+            NL80211_KEY_MODE => {
+                let err_msg =
+                    format!("Invalid NL80211_KEY_MODE value {:?}", payload);
+                Self::Mode(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_KEY_DEFAULT_BEACON => Self::DefaultBeacon,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_KEY_DEFAULT_TYPE_UNICAST: u16 = 1;
+const NL80211_KEY_DEFAULT_TYPE_MULTICAST: u16 = 2;
+
+/// Default key types
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211KeyDefaultType {
+    /// Key should be used as default unicast key
+    Unicast,
+    /// Key should be used as default multicast key
+    Multicast,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211KeyDefaultType {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => 0,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Unicast => NL80211_KEY_DEFAULT_TYPE_UNICAST,
+            Self::Multicast => NL80211_KEY_DEFAULT_TYPE_MULTICAST,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        if let Self::Other(attr) = self {
+            attr.emit(buffer)
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211KeyDefaultType
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            NL80211_KEY_DEFAULT_TYPE_UNICAST => Self::Unicast,
+            NL80211_KEY_DEFAULT_TYPE_MULTICAST => Self::Multicast,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_KEYTYPE_GROUP: u32 = 0;
+const NL80211_KEYTYPE_PAIRWISE: u32 = 1;
+const NL80211_KEYTYPE_PEERKEY: u32 = 2;
+
+/// Key type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211KeyType {
+    /// Group (broadcast/multicast) key
+    Group,
+    /// Pairwise (unicast/individual) key
+    Pairwise,
+    /// Peer key (DLS)
+    Peerkey,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211KeyType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_KEYTYPE_GROUP => Self::Group,
+            NL80211_KEYTYPE_PAIRWISE => Self::Pairwise,
+            NL80211_KEYTYPE_PEERKEY => Self::Peerkey,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyType> for u32 {
+    fn from(v: Nl80211KeyType) -> u32 {
+        match v {
+            Nl80211KeyType::Group => NL80211_KEYTYPE_GROUP,
+            Nl80211KeyType::Pairwise => NL80211_KEYTYPE_PAIRWISE,
+            Nl80211KeyType::Peerkey => NL80211_KEYTYPE_PEERKEY,
+            Nl80211KeyType::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_KEY_RX_TX: u8 = 0;
+const NL80211_KEY_NO_TX: u8 = 1;
+const NL80211_KEY_SET_TX: u8 = 2;
+
+/// Key installation mode, used for Extended Key ID support
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211KeyMode {
+    /// Key can be used for Rx and Tx immediately
+    RxTx,
+    /// Only allowed in combination with `NL80211_CMD_NEW_KEY`: Unicast key
+    /// can only be used for Rx, Tx not allowed, yet
+    NoTx,
+    /// Only allowed in combination with `NL80211_CMD_SET_KEY`: Switch Tx to
+    /// a unicast key installed with [Nl80211KeyMode::NoTx]
+    SetTx,
+    Other(u8),
+}
+
+impl From<u8> for Nl80211KeyMode {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_KEY_RX_TX => Self::RxTx,
+            NL80211_KEY_NO_TX => Self::NoTx,
+            NL80211_KEY_SET_TX => Self::SetTx,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211KeyMode> for u8 {
+    fn from(v: Nl80211KeyMode) -> u8 {
+        match v {
+            Nl80211KeyMode::RxTx => NL80211_KEY_RX_TX,
+            Nl80211KeyMode::NoTx => NL80211_KEY_NO_TX,
+            Nl80211KeyMode::SetTx => NL80211_KEY_SET_TX,
+            Nl80211KeyMode::Other(d) => d,
+        }
+    }
+}
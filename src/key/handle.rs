@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+use netlink_packet_utils::nla::Nla;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211CipherSuite, Nl80211Handle,
+    Nl80211IfIndex, Nl80211KeyAttr, Nl80211KeyDefaultType,
+    Nl80211KeyDelRequest, Nl80211KeyMode, Nl80211KeyNewRequest,
+    Nl80211KeySetRequest, Nl80211KeyType, Nl80211RekeyData,
+    Nl80211RekeyOffloadRequest,
+};
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211RekeyEvent};
+
+const ETH_ALEN: usize = 6;
+
+/// Key installation and default key selection, for userspace SME
+/// (station management entity) or authenticator.
+pub struct Nl80211KeyHandle(Nl80211Handle);
+
+impl Nl80211KeyHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211KeyHandle(handle)
+    }
+
+    /// Install a key.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Key].
+    pub fn add(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211KeyNewRequest {
+        Nl80211KeyNewRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Change key properties, for example set installed key as default
+    /// unicast, multicast, management or beacon protection key.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Key].
+    pub fn set(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211KeySetRequest {
+        Nl80211KeySetRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Delete a key.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Key].
+    pub fn del(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211KeyDelRequest {
+        Nl80211KeyDelRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Offload the GTK rekey handshake to driver or firmware, so the
+    /// connection could survive the group key rotation during system
+    /// suspend. The `kek` and `kck` are derived from PTK and `replay_ctr`
+    /// is the current EAPOL-Key replay counter.
+    pub fn set_rekey_offload(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        kek: Vec<u8>,
+        kck: Vec<u8>,
+        replay_ctr: [u8; 8],
+    ) -> Nl80211RekeyOffloadRequest {
+        Nl80211RekeyOffloadRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            vec![
+                Nl80211RekeyData::Kek(kek),
+                Nl80211RekeyData::Kck(kck),
+                Nl80211RekeyData::ReplayCtr(replay_ctr),
+            ],
+        )
+    }
+
+    /// Stream of GTK rekey notifications decoded from the unsolicited
+    /// message receiver of connection, see
+    /// [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn rekey_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211RekeyEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211RekeyEvent::from_message(&msg))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Key;
+
+impl Nl80211Key {
+    /// Key of specified index on specified interface
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        key_index: u8,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Key(vec![Nl80211KeyAttr::Index(key_index)]))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Key> {
+    fn key_attrs(&self) -> Vec<Nl80211KeyAttr> {
+        match self.get(Nl80211Attr::Key(Vec::new()).kind()) {
+            Some(Nl80211Attr::Key(attrs)) => attrs.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn replace_key_attr(self, attr: Nl80211KeyAttr) -> Self {
+        let mut attrs = self.key_attrs();
+        attrs.retain(|a| a.kind() != attr.kind());
+        attrs.push(attr);
+        self.replace(Nl80211Attr::Key(attrs))
+    }
+
+    /// Kernel only allows one of default, default management and default
+    /// beacon flag in a request.
+    fn set_default_flag(self, flag: Nl80211KeyAttr) -> Self {
+        let mut attrs = self.key_attrs();
+        attrs.retain(|a| {
+            !matches!(
+                a,
+                Nl80211KeyAttr::Default
+                    | Nl80211KeyAttr::DefaultMgmt
+                    | Nl80211KeyAttr::DefaultBeacon
+            ) && (flag == Nl80211KeyAttr::Default
+                || !matches!(a, Nl80211KeyAttr::DefaultTypes(_)))
+        });
+        attrs.push(flag);
+        self.replace(Nl80211Attr::Key(attrs))
+    }
+
+    fn add_default_type(self, default_type: Nl80211KeyDefaultType) -> Self {
+        let mut types = self
+            .key_attrs()
+            .into_iter()
+            .find_map(|a| {
+                if let Nl80211KeyAttr::DefaultTypes(v) = a {
+                    Some(v)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default();
+        if !types.contains(&default_type) {
+            types.push(default_type);
+        }
+        self.set_default_flag(Nl80211KeyAttr::Default)
+            .replace_key_attr(Nl80211KeyAttr::DefaultTypes(types))
+    }
+
+    /// Key material
+    pub fn data(self, data: Vec<u8>) -> Self {
+        self.replace_key_attr(Nl80211KeyAttr::Data(data))
+    }
+
+    pub fn cipher(self, cipher: Nl80211CipherSuite) -> Self {
+        self.replace_key_attr(Nl80211KeyAttr::Cipher(cipher))
+    }
+
+    /// Transmit key sequence number (IV/PN)
+    pub fn seq(self, seq: Vec<u8>) -> Self {
+        self.replace_key_attr(Nl80211KeyAttr::Seq(seq))
+    }
+
+    pub fn key_type(self, key_type: Nl80211KeyType) -> Self {
+        self.replace_key_attr(Nl80211KeyAttr::Type(key_type))
+    }
+
+    pub fn mode(self, mode: Nl80211KeyMode) -> Self {
+        self.replace_key_attr(Nl80211KeyAttr::Mode(mode))
+    }
+
+    /// MAC address of peer for pairwise key
+    pub fn mac(self, mac: [u8; ETH_ALEN]) -> Self {
+        self.replace(Nl80211Attr::Mac(mac))
+    }
+
+    /// Use this key as default unicast key. Could be combined with
+    /// [Self::default_multicast()]. Kernel treats default key without
+    /// default types as both default unicast and multicast key.
+    pub fn default_unicast(self) -> Self {
+        self.add_default_type(Nl80211KeyDefaultType::Unicast)
+    }
+
+    /// Use this key as default multicast key. Could be combined with
+    /// [Self::default_unicast()].
+    pub fn default_multicast(self) -> Self {
+        self.add_default_type(Nl80211KeyDefaultType::Multicast)
+    }
+
+    /// Use this key as default management frame protection key (IGTK).
+    /// This will override [Self::default_unicast()],
+    /// [Self::default_multicast()] and [Self::default_beacon()].
+    pub fn default_mgmt(self) -> Self {
+        self.set_default_flag(Nl80211KeyAttr::DefaultMgmt)
+    }
+
+    /// Use this key as default beacon protection key (BIGTK).
+    /// This will override [Self::default_unicast()],
+    /// [Self::default_multicast()] and [Self::default_mgmt()].
+    pub fn default_beacon(self) -> Self {
+        self.set_default_flag(Nl80211KeyAttr::DefaultBeacon)
+    }
+}
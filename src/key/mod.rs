@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod del;
+mod handle;
+mod new;
+mod rekey;
+#[cfg(feature = "events")]
+mod rekey_event;
+mod set;
+mod set_rekey;
+
+pub use self::attr::{
+    Nl80211KeyAttr, Nl80211KeyDefaultType, Nl80211KeyMode, Nl80211KeyType,
+};
+pub use self::del::Nl80211KeyDelRequest;
+pub use self::handle::{Nl80211Key, Nl80211KeyHandle};
+pub use self::new::Nl80211KeyNewRequest;
+pub use self::rekey::Nl80211RekeyData;
+#[cfg(feature = "events")]
+pub use self::rekey_event::Nl80211RekeyEvent;
+pub use self::set::Nl80211KeySetRequest;
+pub use self::set_rekey::Nl80211RekeyOffloadRequest;
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211AkmSuite;
+
+const NL80211_REKEY_DATA_KEK: u16 = 1;
+const NL80211_REKEY_DATA_KCK: u16 = 2;
+const NL80211_REKEY_DATA_REPLAY_CTR: u16 = 3;
+const NL80211_REKEY_DATA_AKM: u16 = 4;
+
+pub(crate) const NL80211_REPLAY_CTR_LEN: usize = 8;
+
+/// GTK rekey offload data nested in [crate::Nl80211Attr::RekeyData]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211RekeyData {
+    /// Key encryption key (KEK), 16 or 32 bytes depending on AKM
+    Kek(Vec<u8>),
+    /// Key confirmation key (KCK), 16 or 24 bytes depending on AKM
+    Kck(Vec<u8>),
+    /// EAPOL-Key replay counter
+    ReplayCtr([u8; NL80211_REPLAY_CTR_LEN]),
+    Akm(Nl80211AkmSuite),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211RekeyData {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Kek(v) | Self::Kck(v) => v.len(),
+            Self::ReplayCtr(_) => NL80211_REPLAY_CTR_LEN,
+            Self::Akm(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Kek(_) => NL80211_REKEY_DATA_KEK,
+            Self::Kck(_) => NL80211_REKEY_DATA_KCK,
+            Self::ReplayCtr(_) => NL80211_REKEY_DATA_REPLAY_CTR,
+            Self::Akm(_) => NL80211_REKEY_DATA_AKM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Kek(v) | Self::Kck(v) => buffer.copy_from_slice(v),
+            Self::ReplayCtr(v) => buffer.copy_from_slice(v),
+            Self::Akm(d) => NativeEndian::write_u32(buffer, d.suite_selector()),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211RekeyData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_REKEY_DATA_KEK => Self::Kek(payload.to_vec()),
+            NL80211_REKEY_DATA_KCK => Self::Kck(payload.to_vec()),
+            NL80211_REKEY_DATA_REPLAY_CTR => {
+                Self::ReplayCtr(if payload.len() == NL80211_REPLAY_CTR_LEN {
+                    let mut ret = [0u8; NL80211_REPLAY_CTR_LEN];
+                    ret.copy_from_slice(payload);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_REKEY_DATA_REPLAY_CTR, \
+                        expected length {} got {:?}",
+                        NL80211_REPLAY_CTR_LEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_REKEY_DATA_AKM => {
+                let err_msg = format!(
+                    "Invalid NL80211_REKEY_DATA_AKM value {:?}",
+                    payload
+                );
+                Self::Akm(Nl80211AkmSuite::from_suite_selector(
+                    parse_u32(payload).context(err_msg)?,
+                ))
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
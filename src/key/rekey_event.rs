@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use super::rekey::NL80211_REPLAY_CTR_LEN;
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message,
+    Nl80211RekeyData, Nl80211WiphyIndex,
+};
+
+const ETH_ALEN: usize = 6;
+
+/// Notification of GTK rekey done by the offloaded handshake, sent by
+/// kernel to the `mlme` multicast group. The supplicant should update its
+/// EAPOL-Key replay counter to [Self::replay_ctr].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211RekeyEvent {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    /// BSSID of the associated AP
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    pub replay_ctr: [u8; NL80211_REPLAY_CTR_LEN],
+}
+
+impl Nl80211RekeyEvent {
+    /// Return `None` if specified message is not a GTK rekey notification
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::SetRekeyOffload {
+            return None;
+        }
+        let mut if_index = None;
+        let mut wiphy = None;
+        let mut bssid = None;
+        let mut replay_ctr = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Mac(s) => bssid = Some(*s),
+                Nl80211Attr::RekeyData(nlas) => {
+                    replay_ctr = nlas.iter().find_map(|nla| match nla {
+                        Nl80211RekeyData::ReplayCtr(v) => Some(*v),
+                        _ => None,
+                    })
+                }
+                _ => (),
+            }
+        }
+        Some(Self {
+            if_index,
+            wiphy,
+            bssid,
+            replay_ctr: replay_ctr?,
+        })
+    }
+}
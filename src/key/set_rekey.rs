@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211AkmSuite, Nl80211Attr, Nl80211Command,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211RekeyData,
+};
+
+pub struct Nl80211RekeyOffloadRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    data: Vec<Nl80211RekeyData>,
+}
+
+impl Nl80211RekeyOffloadRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        data: Vec<Nl80211RekeyData>,
+    ) -> Self {
+        Nl80211RekeyOffloadRequest {
+            handle,
+            if_index,
+            data,
+        }
+    }
+
+    /// AKM suite negotiated for the connection, required by some drivers
+    /// to derive the key lengths
+    pub fn akm(mut self, akm: Nl80211AkmSuite) -> Self {
+        self.data.push(Nl80211RekeyData::Akm(akm));
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RekeyOffloadRequest {
+            mut handle,
+            if_index,
+            data,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetRekeyOffload,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::RekeyData(data),
+            ],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
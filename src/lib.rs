@@ -1,94 +1,299 @@
 // SPDX-License-Identifier: MIT
 
+mod ap;
 mod attr;
+#[cfg(feature = "blocking")]
+mod blocking;
 mod builder;
 mod channel;
 mod command;
 mod connection;
 mod element;
 mod error;
+mod ext_ack;
 mod ext_cap;
 mod feature;
+mod frame;
 mod frame_type;
+mod frequency;
 mod handle;
+mod ids;
 mod iface;
+mod key;
 mod macros;
 mod message;
+mod mlme;
 mod mlo;
+#[cfg(feature = "events")]
+mod multicast;
+mod ocb;
+mod offchannel;
+mod pattern;
+mod pmsr;
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+mod runtime;
 mod scan;
+#[cfg(feature = "serde")]
+mod serde_nla;
+mod ssid;
 mod station;
 mod stats;
+mod survey;
+mod timestamp;
+mod units;
+mod vendor;
 mod wifi4;
 mod wifi5;
 mod wifi6;
 mod wifi7;
+mod wifi_halow;
 mod wiphy;
 
 pub(crate) mod bytes;
 
+pub use self::ap::{
+    Nl80211ApHandle, Nl80211ApStartRequest, Nl80211ApStopRequest,
+    Nl80211BeaconData, Nl80211ChannelSwitch, Nl80211ChannelSwitchRequest,
+    Nl80211ColorChange, Nl80211ColorChangeRequest, Nl80211FilsDiscovery,
+    Nl80211RadarDetect, Nl80211RadarDetectRequest, Nl80211RadarEventKind,
+    Nl80211StartAp, Nl80211UnsolBcastProbeResp,
+};
 pub use self::attr::Nl80211Attr;
+#[cfg(feature = "blocking")]
+pub use self::blocking::Nl80211SyncHandle;
 pub use self::builder::Nl80211AttrsBuilder;
-pub use self::channel::Nl80211ChannelWidth;
+pub use self::channel::{
+    channel_def_to_operating_class, channel_to_frequency, frequency_to_band,
+    frequency_to_channel, operating_class_to_band, Nl80211ChannelDef,
+    Nl80211ChannelWidth,
+};
 pub use self::command::Nl80211Command;
+#[cfg(feature = "smol_socket")]
+pub use self::connection::new_smol_connection;
+#[cfg(feature = "async_global_executor")]
+pub use self::connection::new_smol_connection_spawned;
 #[cfg(feature = "tokio_socket")]
-pub use self::connection::new_connection;
-pub use self::connection::new_connection_with_socket;
-pub use self::element::Nl80211Element;
+pub use self::connection::{
+    new_connection, new_connection_in_netns, new_connection_in_netns_path,
+};
+pub use self::connection::{
+    new_connection_with_socket, new_connection_with_socket_in_netns,
+    new_connection_with_socket_in_netns_path,
+};
+pub use self::element::{
+    Nl80211AkmSuite, Nl80211Element, Nl80211ElementCountry,
+    Nl80211ElementCountryEnvironment, Nl80211ElementCountryTriplet,
+    Nl80211ElementExtension, Nl80211ElementIter, Nl80211ElementMobilityDomain,
+    Nl80211ElementOperating, Nl80211ElementRsn, Nl80211ElementSubBand,
+    Nl80211ElementSupportedOperatingClasses, Nl80211Pmkid,
+    Nl80211RateAndSelector, Nl80211RmEnabledCapabilities,
+    Nl80211RsnCapabilities, Nl80211RsnxCapabilities,
+};
 pub use self::error::Nl80211Error;
 pub use self::ext_cap::{
     Nl80211ExtendedCapability, Nl80211IfTypeExtCapa, Nl80211IfTypeExtCapas,
 };
-pub use self::feature::{Nl80211ExtFeature, Nl80211Features};
-pub use self::frame_type::{Nl80211FrameType, Nl80211IfaceFrameType};
+pub use self::feature::{
+    Nl80211ExtFeature, Nl80211Features, Nl80211ProtocolFeatures,
+};
+pub use self::frame::{
+    Nl80211BeaconFrame, Nl80211ControlPortFrame, Nl80211ControlPortSendRequest,
+    Nl80211Frame, Nl80211FrameHandle, Nl80211FrameRegisterRequest,
+    Nl80211FrameSendRequest, Nl80211MgmtFrameHeader, Nl80211RxMgmtFlags,
+};
+pub use self::frame_type::{
+    Nl80211FrameType, Nl80211FrameTypeMgmt, Nl80211IfaceFrameType,
+};
+pub use self::frequency::Nl80211Freq;
 pub use self::handle::Nl80211Handle;
+pub use self::ids::{Nl80211IfIndex, Nl80211WdevId, Nl80211WiphyIndex};
 pub use self::iface::{
-    Nl80211IfaceComb, Nl80211IfaceCombAttribute, Nl80211IfaceCombLimit,
-    Nl80211IfaceCombLimitAttribute, Nl80211InterfaceGetRequest,
-    Nl80211InterfaceHandle, Nl80211InterfaceType,
+    Nl80211CritProtocolId, Nl80211CritProtocolRequest, Nl80211HeLtf,
+    Nl80211HwTimestampRequest, Nl80211IfaceComb, Nl80211IfaceCombAttribute,
+    Nl80211IfaceCombLimit, Nl80211IfaceCombLimitAttribute,
+    Nl80211IfaceCombParams, Nl80211Interface, Nl80211InterfaceGetRequest,
+    Nl80211InterfaceHandle, Nl80211InterfaceNewRequest, Nl80211InterfaceSet,
+    Nl80211InterfaceSetRequest, Nl80211InterfaceSetTxBitrateRequest,
+    Nl80211InterfaceType, Nl80211MonitorFlag, Nl80211MulticastToUnicastRequest,
+    Nl80211NoackMapRequest, Nl80211P2pDeviceRequest,
+    Nl80211P2pPowerSaveRequest, Nl80211SetTidConfigRequest, Nl80211TidConfig,
+    Nl80211TidConfigAttribute, Nl80211TidConfigState, Nl80211TidConfigSupport,
+    Nl80211TxRate, Nl80211TxRateGi, Nl80211TxRateMask, Nl80211TxRateSetting,
+};
+pub use self::key::{
+    Nl80211Key, Nl80211KeyAttr, Nl80211KeyDefaultType, Nl80211KeyDelRequest,
+    Nl80211KeyHandle, Nl80211KeyMode, Nl80211KeyNewRequest,
+    Nl80211KeySetRequest, Nl80211KeyType, Nl80211RekeyData,
+    Nl80211RekeyOffloadRequest,
 };
 pub use self::message::Nl80211Message;
-pub use self::mlo::Nl80211MloLink;
+pub use self::mlme::{
+    Nl80211Associate, Nl80211AssociateRequest, Nl80211AuthType,
+    Nl80211Authenticate, Nl80211AuthenticateRequest,
+    Nl80211DeauthenticateRequest, Nl80211DisassociateRequest,
+    Nl80211ExternalAuth, Nl80211ExternalAuthAction, Nl80211ExternalAuthRequest,
+    Nl80211MlmeHandle, Nl80211UpdateConnectParams,
+    Nl80211UpdateConnectParamsRequest, Nl80211UpdateOweInfo,
+    Nl80211UpdateOweInfoRequest,
+};
+pub use self::mlo::{
+    Nl80211LinkStation, Nl80211MloHandle, Nl80211MloLink, Nl80211MloLinkRequest,
+};
+pub use self::ocb::{
+    Nl80211OcbHandle, Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest,
+};
+pub use self::offchannel::{
+    Nl80211OffchannelHandle, Nl80211RemainOnChannelCancelRequest,
+    Nl80211RemainOnChannelRequest,
+};
+pub use self::pattern::{Nl80211PacketPattern, Nl80211PatternSupport};
+pub use self::pmsr::{
+    Nl80211FtmResponderStats, Nl80211FtmResponderStatsRequest,
+    Nl80211PeerMeasurement, Nl80211PeerMeasurementAttr,
+    Nl80211PeerMeasurementHandle, Nl80211PeerMeasurementStartRequest,
+    Nl80211PmsrFtmCapa, Nl80211PmsrFtmFailureReason, Nl80211PmsrFtmReq,
+    Nl80211PmsrFtmResp, Nl80211PmsrPeerAttr, Nl80211PmsrReqAttr,
+    Nl80211PmsrReqData, Nl80211PmsrRespAttr, Nl80211PmsrRespData,
+    Nl80211PmsrStatus, Nl80211PmsrTypeCapa, Nl80211Preamble,
+};
 pub use self::scan::{
-    Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor, Nl80211Scan,
-    Nl80211ScanFlags, Nl80211ScanGetRequest, Nl80211ScanHandle,
-    Nl80211ScanScheduleRequest, Nl80211ScanScheduleStopRequest,
-    Nl80211ScanTriggerRequest, Nl80211SchedScanMatch, Nl80211SchedScanPlan,
+    Nl80211BssCannotUseReasons, Nl80211BssCapabilities, Nl80211BssDescription,
+    Nl80211BssEntry, Nl80211BssInfo, Nl80211BssSecurity, Nl80211BssUseFor,
+    Nl80211IeFingerprint, Nl80211Scan, Nl80211ScanAbortRequest,
+    Nl80211ScanBssListRequest, Nl80211ScanFlags, Nl80211ScanGetRequest,
+    Nl80211ScanHandle, Nl80211ScanScheduleRequest,
+    Nl80211ScanScheduleStopRequest, Nl80211ScanTriggerRequest,
+    Nl80211SchedScanMatch, Nl80211SchedScanPlan, Nl80211SchedScanRssiAdjust,
 };
+pub use self::ssid::{Nl80211Ssid, Nl80211SsidKind};
 pub use self::station::{
     Nl80211EhtGi, Nl80211EhtRuAllocation, Nl80211HeGi, Nl80211HeRuAllocation,
-    Nl80211MeshPowerMode, Nl80211PeerLinkState, Nl80211RateInfo,
-    Nl80211StationBssParam, Nl80211StationFlag, Nl80211StationFlagUpdate,
-    Nl80211StationGetRequest, Nl80211StationHandle, Nl80211StationInfo,
+    Nl80211MeshPowerMode, Nl80211PeerLinkState, Nl80211ProbeClientRequest,
+    Nl80211Rate, Nl80211RateInfo, Nl80211RateMode, Nl80211Station,
+    Nl80211StationAddress, Nl80211StationBssParam, Nl80211StationBssParams,
+    Nl80211StationCounters, Nl80211StationDelRequest,
+    Nl80211StationDumpAllRequest, Nl80211StationEntry, Nl80211StationFlag,
+    Nl80211StationFlagUpdate, Nl80211StationFlags, Nl80211StationGetRequest,
+    Nl80211StationHandle, Nl80211StationInfo, Nl80211StationSetRequest,
+    Nl80211StationSummary,
 };
 pub use self::stats::{
-    NestedNl80211TidStats, Nl80211TidStats, Nl80211TransmitQueueStat,
+    NestedNl80211TidStats, Nl80211CounterDelta, Nl80211CounterWidth,
+    Nl80211TidStats, Nl80211TidStatsTotal, Nl80211TransmitQueueStat,
+};
+pub use self::survey::{
+    Nl80211SurveyGetRequest, Nl80211SurveyHandle, Nl80211SurveyInfo,
+    Nl80211SurveyResult,
+};
+pub use self::timestamp::{Nl80211BootTime, Nl80211WallClockEstimate};
+pub use self::units::{dbm_to_mbm, mbm_to_dbm};
+pub use self::vendor::{
+    Nl80211Vendor, Nl80211VendorCmdInfo, Nl80211VendorHandle,
+    Nl80211VendorMessage, Nl80211VendorOui, Nl80211VendorPayload,
+    Nl80211VendorRegistry, Nl80211VendorRequest,
 };
 pub use self::wifi4::{
-    Nl80211ElementHtCap, Nl80211HtAMpduPara, Nl80211HtAselCaps,
-    Nl80211HtCapabilityMask, Nl80211HtCaps, Nl80211HtExtendedCap,
-    Nl80211HtMcsInfo, Nl80211HtTransmitBeamformingCaps, Nl80211HtTxParameter,
-    Nl80211HtWiphyChannelType,
+    Nl80211ElementHtCap, Nl80211ElementHtOperation, Nl80211HtAMpduPara,
+    Nl80211HtAselCaps, Nl80211HtCapabilityMask, Nl80211HtCaps,
+    Nl80211HtExtendedCap, Nl80211HtMcsInfo, Nl80211HtTransmitBeamformingCaps,
+    Nl80211HtTxParameter, Nl80211HtWiphyChannelType,
 };
 pub use self::wifi5::{
-    Nl80211VhtCapInfo, Nl80211VhtCapability, Nl80211VhtMcsInfo,
+    Nl80211ElementVhtOperation, Nl80211VhtCapInfo, Nl80211VhtCapability,
+    Nl80211VhtMcsInfo,
 };
 pub use self::wifi6::{
-    Nl80211He6GhzCapa, Nl80211HeMacCapInfo, Nl80211HeMcsNssSupp,
+    Nl80211ElementHeCap, Nl80211ElementHeOperation, Nl80211He6GhzCapa,
+    Nl80211He6GhzOperationInfo, Nl80211HeMacCapInfo, Nl80211HeMcsNssSupp,
     Nl80211HePhyCapInfo, Nl80211HePpeThreshold,
 };
 pub use self::wifi7::{
     Nl80211EhtMacCapInfo, Nl80211EhtMcsNssSupp,
     Nl80211EhtMcsNssSuppMoreThan20Mhz, Nl80211EhtMcsNssSuppOnly20Mhz,
-    Nl80211EhtPhyCapInfo, Nl80211EhtPpeThres,
+    Nl80211EhtPhyCapInfo, Nl80211EhtPpeThres, Nl80211ElementEhtCap,
+    Nl80211EmlCapability, Nl80211MldCapaAndOps,
+};
+pub use self::wifi_halow::{
+    Nl80211S1gCapInfo, Nl80211S1gMcsNssSet, Nl80211S1gMcsSupport,
 };
 pub use self::wiphy::{
-    Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211BandTypes,
-    Nl80211CipherSuit, Nl80211Frequency, Nl80211FrequencyInfo, Nl80211IfMode,
-    Nl80211WiphyGetRequest, Nl80211WiphyHandle, Nl80211WowlanTcpTrigerSupport,
+    Nl80211Ac, Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211BandTypes,
+    Nl80211CipherSuite, Nl80211CoalesceCondition, Nl80211CoalesceRule,
+    Nl80211CoalesceRuleAttr, Nl80211CoalesceSetRequest, Nl80211CoalesceSupport,
+    Nl80211Frequency, Nl80211FrequencyInfo, Nl80211IfMode, Nl80211SarAttr,
+    Nl80211SarSpec, Nl80211SarSpecAttr, Nl80211SarType,
+    Nl80211SetSarSpecsRequest, Nl80211TxPowerSetting, Nl80211TxqParams,
+    Nl80211Wiphy, Nl80211WiphyCapabilities, Nl80211WiphyGetRequest,
+    Nl80211WiphyHandle, Nl80211WiphyRadio, Nl80211WiphyRadioAttribute,
+    Nl80211WiphyRadioFreqRange, Nl80211WiphySetNetnsRequest,
+    Nl80211WiphySetRequest, Nl80211WowlanSetRequest, Nl80211WowlanTcp,
+    Nl80211WowlanTcpDataSeq, Nl80211WowlanTcpDataToken,
+    Nl80211WowlanTcpTriggerSupport, Nl80211WowlanTrigger,
+    Nl80211WowlanTriggerPatternSupport, Nl80211WowlanTriggersSupport,
+};
+// Misspelled names kept for compatibility
+#[allow(deprecated)]
+pub use self::wiphy::{
+    Nl80211CipherSuit, Nl80211WowlanTcpTrigerSupport,
     Nl80211WowlanTrigerPatternSupport, Nl80211WowlanTrigersSupport,
 };
 
+// Notification decoding and multicast subscription
+#[cfg(feature = "events")]
+pub use self::ap::{
+    Nl80211ColorEvent, Nl80211ColorEventKind, Nl80211RadarEvent,
+};
+#[cfg(feature = "events")]
+pub use self::frame::{
+    Nl80211ControlPortRx, Nl80211FrameEvent, Nl80211FrameRx,
+    Nl80211FrameTxStatus,
+};
+#[cfg(feature = "events")]
+pub use self::iface::{
+    Nl80211RoamEntry, Nl80211RoamEventKind, Nl80211RoamWatcher,
+};
+#[cfg(feature = "events")]
+pub use self::key::Nl80211RekeyEvent;
+#[cfg(feature = "events")]
+pub use self::mlme::{
+    Nl80211ExternalAuthEvent, Nl80211MlmeEvent, Nl80211MlmeEventInfo,
+    Nl80211OweInfoEvent,
+};
+#[cfg(feature = "events")]
+pub use self::mlo::Nl80211LinksRemovedEvent;
+#[cfg(feature = "events")]
+pub use self::multicast::{
+    subscribe, subscribe_config, subscribe_mlme, subscribe_regulatory,
+    subscribe_scan, Nl80211MulticastGroup,
+};
+#[cfg(feature = "events")]
+pub use self::offchannel::{
+    Nl80211RemainOnChannelEvent, Nl80211RemainOnChannelEventKind,
+};
+#[cfg(feature = "events")]
+pub use self::pmsr::{
+    Nl80211PeerMeasurementEvent, Nl80211PeerMeasurementEventInfo,
+    Nl80211PmsrResult,
+};
+#[cfg(feature = "events")]
+pub use self::scan::{Nl80211ScanEvent, Nl80211ScanEventInfo};
+#[cfg(feature = "events")]
+pub use self::station::{
+    Nl80211ProbeClientEvent, Nl80211StationEvent, Nl80211StationEventInfo,
+};
+
+// Waiting helpers relying on the timer of async runtime
+#[cfg(all(
+    feature = "events",
+    any(feature = "tokio_socket", feature = "smol_socket")
+))]
+pub use self::frame::Nl80211FrameOffchannelSendRequest;
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+pub use self::iface::{
+    Nl80211AssociationInfo, Nl80211InterfaceWaitAssociationRequest,
+};
+
 pub(crate) use self::element::Nl80211Elements;
 pub(crate) use self::feature::Nl80211ExtFeatures;
-pub(crate) use self::handle::nl80211_execute;
+pub(crate) use self::handle::{nl80211_execute, nl80211_execute_shared};
 pub(crate) use self::iface::Nl80211InterfaceTypes;
@@ -12,7 +12,7 @@ macro_rules! try_nl80211 {
                 match payload {
                     NetlinkPayload::InnerMessage(msg) => msg,
                     NetlinkPayload::Error(err) => {
-                        return Err(Nl80211Error::NetlinkError(err))
+                        return Err(Nl80211Error::from(err))
                     }
                     _ => {
                         return Err(Nl80211Error::UnexpectedMessage(
@@ -21,12 +21,7 @@ macro_rules! try_nl80211 {
                     }
                 }
             }
-            Err(e) => {
-                return Err(Nl80211Error::Bug(format!(
-                    "BUG: decode error {:?}",
-                    e
-                )))
-            }
+            Err(e) => return Err(Nl80211Error::from(e)),
         }
     }};
 }
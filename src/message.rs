@@ -1,26 +1,95 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::Context;
-use netlink_packet_generic::{GenlFamily, GenlHeader};
+use netlink_packet_generic::{GenlFamily, GenlHeader, GenlMessage};
 use netlink_packet_utils::{
     nla::NlasIterator, DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
-use crate::{Nl80211Attr, Nl80211Command};
+use crate::{
+    mbm_to_dbm, Nl80211Attr, Nl80211ChannelDef, Nl80211Command, Nl80211Error,
+    Nl80211IfIndex, Nl80211WdevId, Nl80211WiphyIndex,
+};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211Message {
     pub cmd: Nl80211Command,
     pub attributes: Vec<Nl80211Attr>,
 }
 
+impl Nl80211Message {
+    /// Version of nl80211 generic netlink family filled into the header by
+    /// default. Kernel(as of 6.x) does not check the header version of any
+    /// nl80211 command, so none of them needs a non-default version.
+    pub const GENL_VERSION: u8 = 1;
+
+    /// Wrap into generic netlink message with specified header instead of
+    /// the default one generated from [Self::cmd] and [Self::GENL_VERSION].
+    /// The `cmd` of the header is sent as is, which allows sending command
+    /// not defined in [Nl80211Command]. The family ID is resolved when
+    /// sending via [crate::Nl80211Handle::request()] or
+    /// [crate::Nl80211Handle::execute_with_header()].
+    pub fn into_genl_message_with_header(
+        self,
+        header: GenlHeader,
+    ) -> GenlMessage<Self> {
+        GenlMessage::new(header, self, 0)
+    }
+
+    /// Wiphy index stored in [Nl80211Attr::Wiphy]
+    pub fn wiphy_index(&self) -> Option<Nl80211WiphyIndex> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::Wiphy(d) => Some(Nl80211WiphyIndex::new(*d)),
+            _ => None,
+        })
+    }
+
+    /// Interface index stored in [Nl80211Attr::IfIndex]
+    pub fn if_index(&self) -> Option<Nl80211IfIndex> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::IfIndex(d) => Some(Nl80211IfIndex::new(*d)),
+            _ => None,
+        })
+    }
+
+    /// Wireless device identifier stored in [Nl80211Attr::Wdev]
+    pub fn wdev(&self) -> Option<Nl80211WdevId> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::Wdev(d) => Some(Nl80211WdevId::new(*d)),
+            _ => None,
+        })
+    }
+
+    /// Current transmit power in mBm(100 * dBm) stored in
+    /// [Nl80211Attr::WiphyTxPowerLevel], included in interface dump when
+    /// the driver can report it.
+    pub fn tx_power_mbm(&self) -> Option<i32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::WiphyTxPowerLevel(d) => Some(*d as i32),
+            _ => None,
+        })
+    }
+
+    /// Operating channel stored in interface dump, or the new channel of
+    /// channel switch notification
+    pub fn channel_def(&self) -> Option<Nl80211ChannelDef> {
+        Nl80211ChannelDef::from_attrs(&self.attributes)
+    }
+
+    /// Current transmit power in dBm, see [Self::tx_power_mbm()]
+    pub fn tx_power_dbm(&self) -> Option<f32> {
+        self.tx_power_mbm().map(mbm_to_dbm)
+    }
+}
+
 impl GenlFamily for Nl80211Message {
     fn family_name() -> &'static str {
         "nl80211"
     }
 
     fn version(&self) -> u8 {
-        1
+        Self::GENL_VERSION
     }
 
     fn command(&self) -> u8 {
@@ -38,23 +107,125 @@ impl Emitable for Nl80211Message {
     }
 }
 
-fn parse_nlas(buffer: &[u8]) -> Result<Vec<Nl80211Attr>, DecodeError> {
+fn parse_nlas(
+    buffer: &[u8],
+    lenient: bool,
+) -> Result<Vec<Nl80211Attr>, DecodeError> {
     let mut nlas = Vec::new();
     for nla in NlasIterator::new(buffer) {
         let error_msg = "Failed to parse nl80211 message attribute".to_string();
         let nla = &nla.context(error_msg.clone())?;
-        nlas.push(Nl80211Attr::parse(nla).context(error_msg)?);
+        match Nl80211Attr::parse(nla) {
+            Ok(attr) => nlas.push(attr),
+            Err(e) if lenient => {
+                log::warn!(
+                    "Failed to parse nl80211 attribute {}: {e}",
+                    nla.kind()
+                );
+                nlas.push(Nl80211Attr::MalformedNested(
+                    nla.kind(),
+                    nla.value().to_vec(),
+                    e.to_string(),
+                ));
+            }
+            Err(e) => return Err(e).context(error_msg)?,
+        }
     }
     Ok(nlas)
 }
 
+impl Nl80211Message {
+    /// Parse in lenient mode: the top level nl80211 attribute failed to
+    /// parse(e.g. malformed nested attributes generated by odd vendor
+    /// firmware) is stored as [Nl80211Attr::MalformedNested] instead of
+    /// failing the whole message.
+    /// See [crate::Nl80211Handle::set_lenient_parse()].
+    pub fn parse_lenient(
+        buffer: &[u8],
+        header: GenlHeader,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            cmd: Nl80211Command::from(header.cmd),
+            attributes: parse_nlas(buffer, true)?,
+        })
+    }
+}
+
 impl ParseableParametrized<[u8], GenlHeader> for Nl80211Message {
     fn parse_with_param(
         buffer: &[u8],
         header: GenlHeader,
     ) -> Result<Self, DecodeError> {
         let cmd = Nl80211Command::from(header.cmd);
-        let attributes = parse_nlas(buffer)?;
+        let attributes = parse_nlas(buffer, false)?;
         Ok(Self { cmd, attributes })
     }
 }
+
+// The nl80211 message with attributes kept as raw bytes, which are decoded
+// by the handle after received so that the decode error could point out the
+// offending attribute and the lenient parse mode is honored.
+#[derive(Debug)]
+pub(crate) struct Nl80211RawMessage {
+    header: GenlHeader,
+    attributes: Vec<u8>,
+}
+
+impl Nl80211RawMessage {
+    pub(crate) fn new(header: GenlHeader, attributes: &[Nl80211Attr]) -> Self {
+        let mut buffer = vec![0u8; attributes.buffer_len()];
+        attributes.emit(&mut buffer);
+        Self {
+            header,
+            attributes: buffer,
+        }
+    }
+
+    pub(crate) fn decode(
+        &self,
+        lenient: bool,
+    ) -> Result<Nl80211Message, Nl80211Error> {
+        let ret = if lenient {
+            Nl80211Message::parse_lenient(&self.attributes, self.header)
+        } else {
+            Nl80211Message::parse_with_param(&self.attributes, self.header)
+        };
+        ret.map_err(|e| Nl80211Error::decode_attributes(&self.attributes, e))
+    }
+}
+
+impl GenlFamily for Nl80211RawMessage {
+    fn family_name() -> &'static str {
+        Nl80211Message::family_name()
+    }
+
+    fn version(&self) -> u8 {
+        self.header.version
+    }
+
+    fn command(&self) -> u8 {
+        self.header.cmd
+    }
+}
+
+impl Emitable for Nl80211RawMessage {
+    fn buffer_len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer.copy_from_slice(&self.attributes)
+    }
+}
+
+impl ParseableParametrized<[u8], GenlHeader> for Nl80211RawMessage {
+    fn parse_with_param(
+        buffer: &[u8],
+        header: GenlHeader,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header,
+            attributes: buffer.to_vec(),
+        })
+    }
+}
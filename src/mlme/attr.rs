@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_AUTHTYPE_OPEN_SYSTEM: u32 = 0;
+const NL80211_AUTHTYPE_SHARED_KEY: u32 = 1;
+const NL80211_AUTHTYPE_FT: u32 = 2;
+const NL80211_AUTHTYPE_NETWORK_EAP: u32 = 3;
+const NL80211_AUTHTYPE_SAE: u32 = 4;
+const NL80211_AUTHTYPE_FILS_SK: u32 = 5;
+const NL80211_AUTHTYPE_FILS_SK_PFS: u32 = 6;
+const NL80211_AUTHTYPE_FILS_PK: u32 = 7;
+const NL80211_AUTHTYPE_AUTOMATIC: u32 = 8;
+
+/// Authentication type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211AuthType {
+    OpenSystem,
+    SharedKey,
+    /// Fast BSS Transition (IEEE 802.11r)
+    Ft,
+    /// Network EAP (some Cisco APs and mainly LEAP)
+    NetworkEap,
+    /// Simultaneous authentication of equals
+    Sae,
+    /// Fast Initial Link Setup shared key
+    FilsSk,
+    /// Fast Initial Link Setup shared key with PFS
+    FilsSkPfs,
+    /// Fast Initial Link Setup public key
+    FilsPk,
+    /// Determine automatically (if necessary by trying multiple times),
+    /// only valid for `NL80211_CMD_CONNECT`
+    Automatic,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211AuthType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_AUTHTYPE_OPEN_SYSTEM => Self::OpenSystem,
+            NL80211_AUTHTYPE_SHARED_KEY => Self::SharedKey,
+            NL80211_AUTHTYPE_FT => Self::Ft,
+            NL80211_AUTHTYPE_NETWORK_EAP => Self::NetworkEap,
+            NL80211_AUTHTYPE_SAE => Self::Sae,
+            NL80211_AUTHTYPE_FILS_SK => Self::FilsSk,
+            NL80211_AUTHTYPE_FILS_SK_PFS => Self::FilsSkPfs,
+            NL80211_AUTHTYPE_FILS_PK => Self::FilsPk,
+            NL80211_AUTHTYPE_AUTOMATIC => Self::Automatic,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211AuthType> for u32 {
+    fn from(v: Nl80211AuthType) -> u32 {
+        match v {
+            Nl80211AuthType::OpenSystem => NL80211_AUTHTYPE_OPEN_SYSTEM,
+            Nl80211AuthType::SharedKey => NL80211_AUTHTYPE_SHARED_KEY,
+            Nl80211AuthType::Ft => NL80211_AUTHTYPE_FT,
+            Nl80211AuthType::NetworkEap => NL80211_AUTHTYPE_NETWORK_EAP,
+            Nl80211AuthType::Sae => NL80211_AUTHTYPE_SAE,
+            Nl80211AuthType::FilsSk => NL80211_AUTHTYPE_FILS_SK,
+            Nl80211AuthType::FilsSkPfs => NL80211_AUTHTYPE_FILS_SK_PFS,
+            Nl80211AuthType::FilsPk => NL80211_AUTHTYPE_FILS_PK,
+            Nl80211AuthType::Automatic => NL80211_AUTHTYPE_AUTOMATIC,
+            Nl80211AuthType::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_EXTERNAL_AUTH_START: u32 = 0;
+const NL80211_EXTERNAL_AUTH_ABORT: u32 = 1;
+
+/// Action requested by driver for authentication offloaded to userspace
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211ExternalAuthAction {
+    /// Start the authentication with specified BSS
+    Start,
+    /// Abort the ongoing authentication
+    Abort,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211ExternalAuthAction {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_EXTERNAL_AUTH_START => Self::Start,
+            NL80211_EXTERNAL_AUTH_ABORT => Self::Abort,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211ExternalAuthAction> for u32 {
+    fn from(v: Nl80211ExternalAuthAction) -> u32 {
+        match v {
+            Nl80211ExternalAuthAction::Start => NL80211_EXTERNAL_AUTH_START,
+            Nl80211ExternalAuthAction::Abort => NL80211_EXTERNAL_AUTH_ABORT,
+            Nl80211ExternalAuthAction::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211AkmSuite, Nl80211Attr, Nl80211Command, Nl80211ExternalAuthAction,
+    Nl80211IfIndex, Nl80211Message, Nl80211Ssid, Nl80211WiphyIndex,
+};
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211MlmeEventInfo {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    /// Address of peer, only included in timeout notification
+    pub mac: Option<[u8; ETH_ALEN]>,
+    /// The received or transmitted management frame including 802.11 header
+    pub frame: Option<Vec<u8>>,
+    /// Authentication or association timed out
+    pub timed_out: bool,
+}
+
+/// MLME notifications sent by kernel to the `mlme` multicast group
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211MlmeEvent {
+    /// Holding the authentication frame from peer
+    Authenticate(Nl80211MlmeEventInfo),
+    /// Holding the (re)association response frame from peer
+    Associate(Nl80211MlmeEventInfo),
+    /// Holding the deauthentication frame sent or received
+    Deauthenticate(Nl80211MlmeEventInfo),
+    /// Holding the disassociation frame sent or received
+    Disassociate(Nl80211MlmeEventInfo),
+}
+
+impl Nl80211MlmeEvent {
+    /// Return `None` if specified message is not a MLME notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let variant = match msg.cmd {
+            Nl80211Command::Authenticate => Self::Authenticate,
+            Nl80211Command::Associate => Self::Associate,
+            Nl80211Command::Deauthenticate => Self::Deauthenticate,
+            Nl80211Command::Disassociate => Self::Disassociate,
+            _ => return None,
+        };
+        let mut info = Nl80211MlmeEventInfo::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    info.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    info.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Mac(s) => info.mac = Some(*s),
+                Nl80211Attr::Frame(v) => info.frame = Some(v.clone()),
+                Nl80211Attr::TimedOut => info.timed_out = true,
+                _ => (),
+            }
+        }
+        Some(variant(info))
+    }
+}
+
+/// Request from driver asking userspace to perform the authentication(for
+/// example SAE) on behalf of it. The result should be reported back by
+/// [crate::Nl80211MlmeHandle::external_auth()].
+///
+/// Kernel only sends this notification as unicast to the socket owning the
+/// connection, which requested `NL80211_ATTR_EXTERNAL_AUTH_SUPPORT`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ExternalAuthEvent {
+    pub action: Nl80211ExternalAuthAction,
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    pub ssid: Option<Nl80211Ssid>,
+    pub bssid: Option<[u8; ETH_ALEN]>,
+    pub akm: Option<Nl80211AkmSuite>,
+}
+
+impl Nl80211ExternalAuthEvent {
+    /// Return `None` if specified message is not a external authentication
+    /// request.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::ExternalAuth {
+            return None;
+        }
+        let mut action = None;
+        let mut ret = Self {
+            action: Nl80211ExternalAuthAction::Start,
+            if_index: None,
+            wiphy: None,
+            ssid: None,
+            bssid: None,
+            akm: None,
+        };
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::ExternalAuthAction(d) => action = Some(*d),
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    ret.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Ssid(s) => ret.ssid = Some(s.clone()),
+                Nl80211Attr::Bssid(s) => ret.bssid = Some(*s),
+                Nl80211Attr::AkmSuites(s) => ret.akm = s.first().copied(),
+                _ => (),
+            }
+        }
+        ret.action = action?;
+        Some(ret)
+    }
+}
+
+/// Diffie-Hellman information element of the peer station performing OWE
+/// (Opportunistic Wireless Encryption) association with AP offloading the
+/// association processing to driver. Userspace should reply with the result
+/// via [crate::Nl80211MlmeHandle::update_owe_info()].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211OweInfoEvent {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    /// Address of the peer station
+    pub peer: Option<[u8; ETH_ALEN]>,
+    /// Information elements from the association request of peer, holding
+    /// the OWE Diffie-Hellman parameter element
+    pub ie: Option<Vec<u8>>,
+}
+
+impl Nl80211OweInfoEvent {
+    /// Return `None` if specified message is not a OWE information update
+    /// notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::UpdateOweInfo {
+            return None;
+        }
+        let mut ret = Self::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    ret.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Mac(s) => ret.peer = Some(*s),
+                Nl80211Attr::Ie(v) => ret.ie = Some(v.clone()),
+                _ => (),
+            }
+        }
+        Some(ret)
+    }
+}
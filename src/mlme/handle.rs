@@ -0,0 +1,334 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use crate::{
+    Nl80211AssociateRequest, Nl80211Attr, Nl80211AttrsBuilder, Nl80211AuthType,
+    Nl80211AuthenticateRequest, Nl80211DeauthenticateRequest,
+    Nl80211DisassociateRequest, Nl80211ExternalAuthRequest, Nl80211Freq,
+    Nl80211Handle, Nl80211IfIndex, Nl80211Ssid,
+    Nl80211UpdateConnectParamsRequest, Nl80211UpdateOweInfoRequest,
+};
+
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211ExternalAuthEvent, Nl80211OweInfoEvent};
+
+const ETH_ALEN: usize = 6;
+
+/// MAC sublayer management entity (MLME) requests for userspace SME
+/// (station management entity), for example wpa_supplicant.
+pub struct Nl80211MlmeHandle(Nl80211Handle);
+
+impl Nl80211MlmeHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211MlmeHandle(handle)
+    }
+
+    /// Authenticate with specified BSS.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Authenticate].
+    /// The result is reported by `NL80211_CMD_AUTHENTICATE` notification
+    /// to `mlme` multicast group.
+    pub fn authenticate(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211AuthenticateRequest {
+        Nl80211AuthenticateRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Associate with specified BSS after authenticated.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Associate].
+    /// The result is reported by `NL80211_CMD_ASSOCIATE` notification
+    /// to `mlme` multicast group.
+    pub fn associate(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211AssociateRequest {
+        Nl80211AssociateRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Deauthenticate from specified BSS with IEEE 802.11 reason code
+    pub fn deauthenticate(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        bssid: [u8; ETH_ALEN],
+        reason_code: u16,
+    ) -> Nl80211DeauthenticateRequest {
+        Nl80211DeauthenticateRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            bssid,
+            reason_code,
+        )
+    }
+
+    /// Disassociate from specified BSS with IEEE 802.11 reason code
+    pub fn disassociate(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        bssid: [u8; ETH_ALEN],
+        reason_code: u16,
+    ) -> Nl80211DisassociateRequest {
+        Nl80211DisassociateRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            bssid,
+            reason_code,
+        )
+    }
+
+    /// Report the result of authentication offloaded to userspace by
+    /// [Nl80211ExternalAuthEvent].
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211ExternalAuth].
+    pub fn external_auth(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211ExternalAuthRequest {
+        Nl80211ExternalAuthRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Update the parameters of current connection without reconnecting,
+    /// used by following (re)association or FILS authentication.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211UpdateConnectParams].
+    pub fn update_connect_params(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211UpdateConnectParamsRequest {
+        Nl80211UpdateConnectParamsRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Report the result of OWE Diffie-Hellman processing requested by
+    /// [Nl80211OweInfoEvent] to driver.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211UpdateOweInfo].
+    pub fn update_owe_info(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211UpdateOweInfoRequest {
+        Nl80211UpdateOweInfoRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Stream of external authentication requests decoded from the
+    /// unsolicited message receiver of connection, see
+    /// [Nl80211Handle::notifications()].
+    /// Only the socket owning the connection will receive them.
+    #[cfg(feature = "events")]
+    pub async fn external_auth_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211ExternalAuthEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211ExternalAuthEvent::from_message(&msg))
+        }))
+    }
+
+    /// Stream of OWE information update requests decoded from the
+    /// unsolicited message receiver of connection subscribed to `mlme`
+    /// multicast group, see [Nl80211Handle::notifications()].
+    #[cfg(feature = "events")]
+    pub async fn owe_info_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211OweInfoEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211OweInfoEvent::from_message(&msg))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Authenticate;
+
+impl Nl80211Authenticate {
+    /// Authenticate with BSS on specified frequency, a `u32` is taken as
+    /// MHz.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        bssid: [u8; ETH_ALEN],
+        frequency: impl Into<Nl80211Freq>,
+        ssid: impl Into<Nl80211Ssid>,
+        auth_type: Nl80211AuthType,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Mac(bssid))
+            .set_frequency(frequency)
+            .ssid(ssid)
+            .replace(Nl80211Attr::AuthType(auth_type))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Authenticate> {
+    /// Extra information elements to be added into authentication frame
+    pub fn ie(self, ie: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::Ie(ie))
+    }
+
+    /// Only update local state without sending authentication frame
+    pub fn local_state_change(self) -> Self {
+        self.replace(Nl80211Attr::LocalStateChange)
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Associate;
+
+impl Nl80211Associate {
+    /// Associate with BSS on specified frequency, a `u32` is taken as MHz.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        bssid: [u8; ETH_ALEN],
+        frequency: impl Into<Nl80211Freq>,
+        ssid: impl Into<Nl80211Ssid>,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Mac(bssid))
+            .set_frequency(frequency)
+            .ssid(ssid)
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Associate> {
+    /// Information elements to be added into association request, for
+    /// example the RSN element
+    pub fn ie(self, ie: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::Ie(ie))
+    }
+
+    /// Use reassociation request frame with the BSSID of currently
+    /// associated AP
+    pub fn prev_bssid(self, bssid: [u8; ETH_ALEN]) -> Self {
+        self.replace(Nl80211Attr::PrevBssid(bssid))
+    }
+
+    /// Userspace will authorize the port after 802.1X or 4-way handshake
+    /// using frames of specified ethertype, for example `0x888e` for EAPOL
+    pub fn control_port(self, ethertype: u16) -> Self {
+        self.replace(Nl80211Attr::ControlPort)
+            .replace(Nl80211Attr::ControlPortEthertypeValue(ethertype))
+    }
+
+    /// Exchange control port frames over nl80211 with the socket sending
+    /// this request instead of the network interface, see
+    /// [crate::Nl80211FrameHandle::control_port_send()].
+    /// This socket also becomes the owner of the connection, which will be
+    /// torn down when the socket is closed.
+    pub fn control_port_over_nl80211(self) -> Self {
+        self.replace(Nl80211Attr::ControlPortOverNl80211)
+            .replace(Nl80211Attr::SocketOwner)
+    }
+
+    /// Do not deliver RSN pre-authentication frames over nl80211
+    pub fn control_port_no_preauth(self) -> Self {
+        self.replace(Nl80211Attr::ControlPortNoPreauth)
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211ExternalAuth;
+
+impl Nl80211ExternalAuth {
+    /// Result of external authentication with specified BSS, the
+    /// `status_code` is the IEEE 802.11 status code, 0 for success.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        bssid: [u8; ETH_ALEN],
+        ssid: impl Into<Nl80211Ssid>,
+        status_code: u16,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Bssid(bssid))
+            .ssid(ssid)
+            .replace(Nl80211Attr::StatusCode(status_code))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211ExternalAuth> {
+    /// PMKID derived by the authentication, used by driver for PMKSA
+    /// caching
+    pub fn pmkid(self, pmkid: [u8; 16]) -> Self {
+        self.replace(Nl80211Attr::Pmkid(pmkid))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211UpdateConnectParams;
+
+impl Nl80211UpdateConnectParams {
+    /// Update connection parameters of specified interface
+    pub fn new(if_index: Nl80211IfIndex) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new().if_index(if_index)
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211UpdateConnectParams> {
+    /// Information elements to be added into (re)association request
+    pub fn ie(self, ie: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::Ie(ie))
+    }
+
+    /// Authentication type for the following connection attempts, only FILS
+    /// related types are accepted by kernel
+    pub fn auth_type(self, auth_type: Nl80211AuthType) -> Self {
+        self.replace(Nl80211Attr::AuthType(auth_type))
+    }
+
+    /// FILS EAP re-authentication protocol(ERP) data, requires driver with
+    /// FILS shared key authentication offload
+    pub fn fils_erp(
+        self,
+        username: Vec<u8>,
+        realm: Vec<u8>,
+        next_seq_num: u16,
+        rrk: Vec<u8>,
+    ) -> Self {
+        self.replace(Nl80211Attr::FilsErpUsername(username))
+            .replace(Nl80211Attr::FilsErpRealm(realm))
+            .replace(Nl80211Attr::FilsErpNextSeqNum(next_seq_num))
+            .replace(Nl80211Attr::FilsErpRrk(rrk))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211UpdateOweInfo;
+
+impl Nl80211UpdateOweInfo {
+    /// Result of OWE processing for specified peer station, the
+    /// `status_code` is the IEEE 802.11 status code, 0 for success.
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        peer: [u8; ETH_ALEN],
+        status_code: u16,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Mac(peer))
+            .replace(Nl80211Attr::StatusCode(status_code))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211UpdateOweInfo> {
+    /// Information elements to be added into association response, holding
+    /// the Diffie-Hellman parameter element of AP
+    pub fn ie(self, ie: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::Ie(ie))
+    }
+}
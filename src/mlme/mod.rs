@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+mod assoc;
+mod attr;
+mod auth;
+#[cfg(feature = "events")]
+mod event;
+mod external_auth;
+mod handle;
+mod owe;
+mod update_connect;
+
+pub use self::assoc::{Nl80211AssociateRequest, Nl80211DisassociateRequest};
+pub use self::attr::{Nl80211AuthType, Nl80211ExternalAuthAction};
+pub use self::auth::{
+    Nl80211AuthenticateRequest, Nl80211DeauthenticateRequest,
+};
+#[cfg(feature = "events")]
+pub use self::event::{
+    Nl80211ExternalAuthEvent, Nl80211MlmeEvent, Nl80211MlmeEventInfo,
+    Nl80211OweInfoEvent,
+};
+pub use self::external_auth::Nl80211ExternalAuthRequest;
+pub use self::handle::{
+    Nl80211Associate, Nl80211Authenticate, Nl80211ExternalAuth,
+    Nl80211MlmeHandle, Nl80211UpdateConnectParams, Nl80211UpdateOweInfo,
+};
+pub use self::owe::Nl80211UpdateOweInfoRequest;
+pub use self::update_connect::Nl80211UpdateConnectParamsRequest;
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message, Nl80211WdevId,
+};
+
+/// Links removed from MLD interface by driver, for example after AP MLD
+/// reconfiguration, sent by kernel to the `mlme` multicast group
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211LinksRemovedEvent {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// IDs of the removed links
+    pub link_ids: Vec<u8>,
+}
+
+impl Nl80211LinksRemovedEvent {
+    /// Return `None` if specified message is not a
+    /// `NL80211_CMD_LINKS_REMOVED` notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::LinksRemoved {
+            return None;
+        }
+        let mut ret = Self::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => ret.wdev = Some(Nl80211WdevId::new(*d)),
+                Nl80211Attr::MloLinks(links) => {
+                    ret.link_ids = links.iter().map(|l| l.id).collect()
+                }
+                _ => (),
+            }
+        }
+        Some(ret)
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211Command, Nl80211Handle,
+    Nl80211IfIndex, Nl80211MloLinkRequest,
+};
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211LinksRemovedEvent};
+
+const ETH_ALEN: usize = 6;
+
+/// Multi-Link Operation(MLO) requests managing the links of AP MLD
+/// interface and its MLD stations
+pub struct Nl80211MloHandle(Nl80211Handle);
+
+impl Nl80211MloHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211MloHandle(handle)
+    }
+
+    /// Add link with specified link address to MLD interface, the link is
+    /// started by following [crate::Nl80211ApHandle] requests with
+    /// [Nl80211Attr::MloLinkId].
+    pub fn add_link(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        link_id: u8,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::AddLink,
+            vec![
+                Nl80211Attr::IfIndex(if_index.get()),
+                Nl80211Attr::MloLinkId(link_id),
+                Nl80211Attr::Mac(mac),
+            ],
+        )
+    }
+
+    /// Remove specified link from MLD interface
+    pub fn remove_link(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        link_id: u8,
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::RemoveLink,
+            vec![
+                Nl80211Attr::IfIndex(if_index.get()),
+                Nl80211Attr::MloLinkId(link_id),
+            ],
+        )
+    }
+
+    /// Add link to MLD station.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211LinkStation] with [Nl80211AttrsBuilder::link_mac()] and
+    /// [Nl80211AttrsBuilder::supported_rates()] set.
+    pub fn add_link_station(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::AddLinkSta,
+            attributes,
+        )
+    }
+
+    /// Modify link of MLD station.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211LinkStation].
+    pub fn modify_link_station(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::ModifyLinkSta,
+            attributes,
+        )
+    }
+
+    /// Remove specified link from MLD station identified by its MLD
+    /// address
+    pub fn remove_link_station(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        mld_addr: [u8; ETH_ALEN],
+        link_id: u8,
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::RemoveLinkSta,
+            vec![
+                Nl80211Attr::IfIndex(if_index.get()),
+                Nl80211Attr::MldAddr(mld_addr),
+                Nl80211Attr::MloLinkId(link_id),
+            ],
+        )
+    }
+
+    /// Negotiate TID-to-link mapping with the AP MLD the station interface
+    /// is associated with. Each element of `dlink` and `ulink` is the bitmap
+    /// of link IDs the TID of the same index is mapped to in downlink and
+    /// uplink direction.
+    pub fn set_tid_to_link_mapping(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        dlink: [u16; 8],
+        ulink: [u16; 8],
+    ) -> Nl80211MloLinkRequest {
+        Nl80211MloLinkRequest::new(
+            self.0.clone(),
+            Nl80211Command::SetTidToLinkMapping,
+            vec![
+                Nl80211Attr::IfIndex(if_index.get()),
+                Nl80211Attr::MloTtlmDlink(dlink),
+                Nl80211Attr::MloTtlmUlink(ulink),
+            ],
+        )
+    }
+
+    /// Stream of link removal notifications decoded from the unsolicited
+    /// message receiver of connection, see [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn links_removed_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211LinksRemovedEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211LinksRemovedEvent::from_message(&msg))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211LinkStation;
+
+impl Nl80211LinkStation {
+    /// Change specified link of MLD station identified by its MLD address
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        mld_addr: [u8; ETH_ALEN],
+        link_id: u8,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::MldAddr(mld_addr))
+            .replace(Nl80211Attr::MloLinkId(link_id))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211LinkStation> {
+    /// Link address of the station on this link, mandatory when adding
+    /// link
+    pub fn link_mac(self, mac: [u8; ETH_ALEN]) -> Self {
+        self.replace(Nl80211Attr::Mac(mac))
+    }
+
+    /// Supported rates in units of 500 kb/s, mandatory when adding link
+    pub fn supported_rates(self, rates: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::StaSupportedRates(rates))
+    }
+}
@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::write_u32, Nl80211ChannelDef, Nl80211ChannelWidth, Nl80211Freq,
+};
+
+const ETH_ALEN: usize = 6;
+const NL80211_ATTR_MAC: u16 = 6;
+const NL80211_ATTR_WIPHY_FREQ: u16 = 38;
+const NL80211_ATTR_WIPHY_TX_POWER_LEVEL: u16 = 98;
+const NL80211_ATTR_CHANNEL_WIDTH: u16 = 159;
+const NL80211_ATTR_CENTER_FREQ1: u16 = 160;
+const NL80211_ATTR_CENTER_FREQ2: u16 = 161;
+const NL80211_ATTR_MLO_LINK_ID: u16 = 313;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Nl80211MloLinkNla {
+    Id(u8),
+    Mac([u8; ETH_ALEN]),
+    Freq(u32),
+    TxPowerLevel(u32),
+    ChannelWidth(Nl80211ChannelWidth),
+    CenterFreq1(u32),
+    CenterFreq2(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211MloLinkNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 1,
+            Self::Mac(_) => ETH_ALEN,
+            Self::Freq(_)
+            | Self::TxPowerLevel(_)
+            | Self::ChannelWidth(_)
+            | Self::CenterFreq1(_)
+            | Self::CenterFreq2(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NL80211_ATTR_MLO_LINK_ID,
+            Self::Mac(_) => NL80211_ATTR_MAC,
+            Self::Freq(_) => NL80211_ATTR_WIPHY_FREQ,
+            Self::TxPowerLevel(_) => NL80211_ATTR_WIPHY_TX_POWER_LEVEL,
+            Self::ChannelWidth(_) => NL80211_ATTR_CHANNEL_WIDTH,
+            Self::CenterFreq1(_) => NL80211_ATTR_CENTER_FREQ1,
+            Self::CenterFreq2(_) => NL80211_ATTR_CENTER_FREQ2,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(d) => buffer[0] = *d,
+            Self::Mac(s) => buffer.copy_from_slice(s),
+            Self::Freq(d)
+            | Self::TxPowerLevel(d)
+            | Self::CenterFreq1(d)
+            | Self::CenterFreq2(d) => write_u32(buffer, *d),
+            Self::ChannelWidth(d) => write_u32(buffer, u32::from(*d)),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MloLinkNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_MLO_LINK_ID => {
+                let err_msg = format!(
+                    "Invalid NL80211_ATTR_MLO_LINK_ID value {:?}",
+                    payload
+                );
+                Self::Id(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_ATTR_MAC => Self::Mac(if payload.len() == ETH_ALEN {
+                let mut ret = [0u8; ETH_ALEN];
+                ret.copy_from_slice(&payload[..ETH_ALEN]);
+                ret
+            } else {
+                return Err(format!(
+                    "Invalid length of NL80211_ATTR_MAC, expected length {} got {:?}",
+                    ETH_ALEN, payload
+                )
+                .into());
+            }),
+            NL80211_ATTR_WIPHY_FREQ => Self::Freq(parse_u32(payload).context(
+                format!("Invalid NL80211_ATTR_WIPHY_FREQ value {:?}", payload),
+            )?),
+            NL80211_ATTR_WIPHY_TX_POWER_LEVEL => {
+                Self::TxPowerLevel(parse_u32(payload).context(format!(
+                    "Invalid NL80211_ATTR_WIPHY_TX_POWER_LEVEL value {:?}",
+                    payload
+                ))?)
+            }
+            NL80211_ATTR_CHANNEL_WIDTH => Self::ChannelWidth(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid NL80211_ATTR_CHANNEL_WIDTH value {:?}",
+                        payload
+                    ))?
+                    .into(),
+            ),
+            NL80211_ATTR_CENTER_FREQ1 => {
+                Self::CenterFreq1(parse_u32(payload).context(format!(
+                    "Invalid NL80211_ATTR_CENTER_FREQ1 value {:?}",
+                    payload
+                ))?)
+            }
+            NL80211_ATTR_CENTER_FREQ2 => {
+                Self::CenterFreq2(parse_u32(payload).context(format!(
+                    "Invalid NL80211_ATTR_CENTER_FREQ2 value {:?}",
+                    payload
+                ))?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Multi-Link Operation
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211MloLink {
+    pub id: u8,
+    /// Link address, not included in `NL80211_CMD_LINKS_REMOVED`
+    /// notification
+    pub mac: [u8; ETH_ALEN],
+    /// Operating frequency of this link, only included in interface dump
+    pub frequency: Option<Nl80211Freq>,
+    pub channel_width: Option<Nl80211ChannelWidth>,
+    pub center_freq1: Option<Nl80211Freq>,
+    pub center_freq2: Option<Nl80211Freq>,
+    /// Current transmit power of this link in mBm (100 * dBm)
+    pub tx_power_mbm: Option<i32>,
+}
+
+impl Nl80211MloLink {
+    /// Operating channel of this link, only available in interface dump
+    pub fn channel_def(&self) -> Option<Nl80211ChannelDef> {
+        Some(Nl80211ChannelDef {
+            frequency: self.frequency?,
+            width: self.channel_width,
+            center_freq1: self.center_freq1,
+            center_freq2: self.center_freq2,
+            ..Default::default()
+        })
+    }
+}
+
+impl Nla for Nl80211MloLink {
+    fn value_len(&self) -> usize {
+        Vec::<Nl80211MloLinkNla>::from(self).as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.id as u16 + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        Vec::<Nl80211MloLinkNla>::from(self).as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211MloLink
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_MLO_LINKS value {:?}", payload);
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211MloLinkNla::parse(nla).context(err_msg.clone())? {
+                Nl80211MloLinkNla::Id(d) => ret.id = d,
+                Nl80211MloLinkNla::Mac(s) => ret.mac = s,
+                Nl80211MloLinkNla::Freq(d) => {
+                    ret.frequency = Some(Nl80211Freq::from_mhz(d))
+                }
+                Nl80211MloLinkNla::TxPowerLevel(d) => {
+                    ret.tx_power_mbm = Some(d as i32)
+                }
+                Nl80211MloLinkNla::ChannelWidth(d) => {
+                    ret.channel_width = Some(d)
+                }
+                Nl80211MloLinkNla::CenterFreq1(d) => {
+                    ret.center_freq1 = Some(Nl80211Freq::from_mhz(d))
+                }
+                Nl80211MloLinkNla::CenterFreq2(d) => {
+                    ret.center_freq2 = Some(Nl80211Freq::from_mhz(d))
+                }
+                Nl80211MloLinkNla::Other(attr) => {
+                    log::warn!(
+                        "Got unsupported NL80211_ATTR_MLO_LINKS value {:?}",
+                        attr
+                    )
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl From<&Nl80211MloLink> for Vec<Nl80211MloLinkNla> {
+    fn from(link: &Nl80211MloLink) -> Self {
+        let mut nlas = vec![
+            Nl80211MloLinkNla::Id(link.id),
+            Nl80211MloLinkNla::Mac(link.mac),
+        ];
+        if let Some(f) = link.frequency {
+            nlas.push(Nl80211MloLinkNla::Freq(f.mhz()));
+        }
+        if let Some(w) = link.channel_width {
+            nlas.push(Nl80211MloLinkNla::ChannelWidth(w));
+        }
+        if let Some(f) = link.center_freq1 {
+            nlas.push(Nl80211MloLinkNla::CenterFreq1(f.mhz()));
+        }
+        if let Some(f) = link.center_freq2 {
+            nlas.push(Nl80211MloLinkNla::CenterFreq2(f.mhz()));
+        }
+        if let Some(d) = link.tx_power_mbm {
+            nlas.push(Nl80211MloLinkNla::TxPowerLevel(d as u32));
+        }
+        nlas
+    }
+}
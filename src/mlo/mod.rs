@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+mod event;
+mod handle;
+mod link;
+mod request;
+
+#[cfg(feature = "events")]
+pub use self::event::Nl80211LinksRemovedEvent;
+pub use self::handle::{Nl80211LinkStation, Nl80211MloHandle};
+pub use self::link::Nl80211MloLink;
+pub use self::request::Nl80211MloLinkRequest;
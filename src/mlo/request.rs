@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+/// Request adding or removing link of MLD interface or link of MLD station,
+/// or changing TID-to-link mapping, generated by [crate::Nl80211MloHandle].
+pub struct Nl80211MloLinkRequest {
+    handle: Nl80211Handle,
+    cmd: Nl80211Command,
+    attributes: Vec<Nl80211Attr>,
+}
+
+impl Nl80211MloLinkRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        cmd: Nl80211Command,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211MloLinkRequest {
+            handle,
+            cmd,
+            attributes,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211MloLinkRequest {
+            mut handle,
+            cmd,
+            attributes,
+        } = self;
+
+        let nl80211_msg = Nl80211Message { cmd, attributes };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
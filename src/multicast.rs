@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+
+use futures::future::{select, Either};
+use genetlink::message::RawGenlMessage;
+use netlink_proto::Connection;
+use netlink_sys::AsyncSocket;
+
+use crate::{new_connection_with_socket, Nl80211Error};
+
+const NL80211_MULTICAST_GROUP_CONFIG: &str = "config";
+const NL80211_MULTICAST_GROUP_SCAN: &str = "scan";
+const NL80211_MULTICAST_GROUP_REG: &str = "regulatory";
+const NL80211_MULTICAST_GROUP_MLME: &str = "mlme";
+const NL80211_MULTICAST_GROUP_VENDOR: &str = "vendor";
+const NL80211_MULTICAST_GROUP_NAN: &str = "nan";
+const NL80211_MULTICAST_GROUP_TESTMODE: &str = "testmode";
+
+/// Multicast groups of nl80211 generic netlink family
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211MulticastGroup {
+    /// Wiphy and interface configuration changes
+    Config,
+    /// Scan started, finished and aborted
+    Scan,
+    /// Regulatory domain changes
+    Regulatory,
+    /// MLME events, for example authentication, association, station
+    /// and management frame notifications
+    Mlme,
+    /// Vendor specific events
+    Vendor,
+    /// Neighbor Awareness Networking events
+    Nan,
+    Testmode,
+    Other(String),
+}
+
+impl Nl80211MulticastGroup {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Config => NL80211_MULTICAST_GROUP_CONFIG,
+            Self::Scan => NL80211_MULTICAST_GROUP_SCAN,
+            Self::Regulatory => NL80211_MULTICAST_GROUP_REG,
+            Self::Mlme => NL80211_MULTICAST_GROUP_MLME,
+            Self::Vendor => NL80211_MULTICAST_GROUP_VENDOR,
+            Self::Nan => NL80211_MULTICAST_GROUP_NAN,
+            Self::Testmode => NL80211_MULTICAST_GROUP_TESTMODE,
+            Self::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl From<&str> for Nl80211MulticastGroup {
+    fn from(s: &str) -> Self {
+        match s {
+            NL80211_MULTICAST_GROUP_CONFIG => Self::Config,
+            NL80211_MULTICAST_GROUP_SCAN => Self::Scan,
+            NL80211_MULTICAST_GROUP_REG => Self::Regulatory,
+            NL80211_MULTICAST_GROUP_MLME => Self::Mlme,
+            NL80211_MULTICAST_GROUP_VENDOR => Self::Vendor,
+            NL80211_MULTICAST_GROUP_NAN => Self::Nan,
+            NL80211_MULTICAST_GROUP_TESTMODE => Self::Testmode,
+            _ => Self::Other(s.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for Nl80211MulticastGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Join the socket of specified connection to nl80211 multicast group, so
+/// that the notifications sent to this group will be received by the
+/// unsolicited message receiver returned by [crate::new_connection].
+/// The group ID is resolved via a temporary connection, hence this
+/// function could be invoked before spawning the connection.
+/// Return the ID of multicast group. For example:
+/// ```no_run
+/// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures::StreamExt;
+///
+/// let (mut connection, handle, messages) = wl_nl80211::new_connection()?;
+/// wl_nl80211::subscribe_mlme(&mut connection).await?;
+/// tokio::spawn(connection);
+///
+/// let mut events = handle.station().events(messages).await?;
+/// while let Some(event) = events.next().await {
+///     println!("{:?}", event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn subscribe<S>(
+    connection: &mut Connection<RawGenlMessage, S>,
+    group: Nl80211MulticastGroup,
+) -> Result<u32, Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    let (query_conn, mut handle, _) = new_connection_with_socket::<S>()
+        .map_err(|e| {
+            Nl80211Error::RequestFailed(format!(
+                "Failed to create netlink connection: {e}"
+            ))
+        })?;
+    let query = handle.multicast_group_id(group.as_str());
+    futures::pin_mut!(query);
+    let group_id = match select(query_conn, query).await {
+        Either::Left(_) => {
+            return Err(Nl80211Error::RequestFailed(format!(
+                "Netlink connection closed while resolving nl80211 \
+                multicast group {group}"
+            )))
+        }
+        Either::Right((result, _)) => result?,
+    };
+    connection
+        .socket_mut()
+        .socket_mut()
+        .add_membership(group_id)
+        .map_err(|e| {
+            Nl80211Error::RequestFailed(format!(
+                "Failed to join nl80211 multicast group {group}: {e}"
+            ))
+        })?;
+    Ok(group_id)
+}
+
+/// Join the `config` multicast group, see [subscribe()]
+pub async fn subscribe_config<S>(
+    connection: &mut Connection<RawGenlMessage, S>,
+) -> Result<u32, Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    subscribe(connection, Nl80211MulticastGroup::Config).await
+}
+
+/// Join the `scan` multicast group, see [subscribe()]
+pub async fn subscribe_scan<S>(
+    connection: &mut Connection<RawGenlMessage, S>,
+) -> Result<u32, Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    subscribe(connection, Nl80211MulticastGroup::Scan).await
+}
+
+/// Join the `regulatory` multicast group, see [subscribe()]
+pub async fn subscribe_regulatory<S>(
+    connection: &mut Connection<RawGenlMessage, S>,
+) -> Result<u32, Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    subscribe(connection, Nl80211MulticastGroup::Regulatory).await
+}
+
+/// Join the `mlme` multicast group, see [subscribe()]
+pub async fn subscribe_mlme<S>(
+    connection: &mut Connection<RawGenlMessage, S>,
+) -> Result<u32, Nl80211Error>
+where
+    S: AsyncSocket,
+{
+    subscribe(connection, Nl80211MulticastGroup::Mlme).await
+}
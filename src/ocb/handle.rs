@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211ChannelWidth, Nl80211Freq, Nl80211Handle, Nl80211IfIndex,
+    Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest,
+};
+
+pub struct Nl80211OcbHandle(Nl80211Handle);
+
+impl Nl80211OcbHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211OcbHandle(handle)
+    }
+
+    /// Join the OCB(Outside the Context of a BSS, IEEE 802.11p) network on
+    /// specified control frequency and channel width centered on
+    /// `center_freq1` (a `u32` is taken as MHz). The width is usually
+    /// `Nl80211ChannelWidth::Mhz(10)` or `Nl80211ChannelWidth::Mhz(5)`
+    /// whose center frequency is the control frequency
+    /// (equivalent to `iw dev DEVICE ocb join FREQ 10MHz`).
+    /// The interface should be in [crate::Nl80211InterfaceType::Ocb] mode.
+    pub fn join(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        frequency: impl Into<Nl80211Freq>,
+        width: Nl80211ChannelWidth,
+        center_freq1: impl Into<Nl80211Freq>,
+    ) -> Nl80211OcbJoinRequest {
+        Nl80211OcbJoinRequest::new(
+            self.0.clone(),
+            if_index.get(),
+            frequency.into(),
+            width,
+            center_freq1.into(),
+        )
+    }
+
+    /// Leave the OCB network (equivalent to `iw dev DEVICE ocb leave`).
+    pub fn leave(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211OcbLeaveRequest {
+        Nl80211OcbLeaveRequest::new(self.0.clone(), if_index.get())
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211ChannelDef, Nl80211ChannelWidth,
+    Nl80211Command, Nl80211Error, Nl80211Freq, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211OcbJoinRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    frequency: Nl80211Freq,
+    width: Nl80211ChannelWidth,
+    center_freq1: Nl80211Freq,
+}
+
+impl Nl80211OcbJoinRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        frequency: Nl80211Freq,
+        width: Nl80211ChannelWidth,
+        center_freq1: Nl80211Freq,
+    ) -> Self {
+        Nl80211OcbJoinRequest {
+            handle,
+            if_index,
+            frequency,
+            width,
+            center_freq1,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211OcbJoinRequest {
+            mut handle,
+            if_index,
+            frequency,
+            width,
+            center_freq1,
+        } = self;
+
+        let channel =
+            Nl80211ChannelDef::with_width(frequency, width, center_freq1);
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        attributes.extend(channel.attributes());
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::JoinOcb,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+pub struct Nl80211OcbLeaveRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211OcbLeaveRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211OcbLeaveRequest { handle, if_index }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211OcbLeaveRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::LeaveOcb,
+            attributes: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
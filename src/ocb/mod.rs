@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+mod join;
+
+pub use self::handle::Nl80211OcbHandle;
+pub use self::join::{Nl80211OcbJoinRequest, Nl80211OcbLeaveRequest};
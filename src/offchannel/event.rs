@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211Freq, Nl80211IfIndex, Nl80211Message,
+    Nl80211WdevId,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211RemainOnChannelEventKind {
+    /// The device is now on the requested channel
+    Started,
+    /// The remain on channel duration expired or the operation was cancelled
+    Expired,
+}
+
+/// Remain on channel notification sent by kernel to the `mlme` multicast
+/// group. The unicast reply of [crate::Nl80211RemainOnChannelRequest] also uses
+/// the `NL80211_CMD_REMAIN_ON_CHANNEL` command but only holds the cookie.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211RemainOnChannelEvent {
+    pub kind: Nl80211RemainOnChannelEventKind,
+    pub cookie: u64,
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    pub frequency: Option<Nl80211Freq>,
+    /// Duration in milliseconds, only included in
+    /// [Nl80211RemainOnChannelEventKind::Started] event.
+    pub duration: Option<u32>,
+}
+
+impl Nl80211RemainOnChannelEvent {
+    /// Return `None` if specified message is not a remain on channel
+    /// notification or has no cookie.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let kind = match msg.cmd {
+            Nl80211Command::RemainOnChannel => {
+                Nl80211RemainOnChannelEventKind::Started
+            }
+            Nl80211Command::CancelRemainOnChannel => {
+                Nl80211RemainOnChannelEventKind::Expired
+            }
+            _ => return None,
+        };
+        let mut cookie = None;
+        let mut if_index = None;
+        let mut wdev = None;
+        let mut frequency = None;
+        let mut freq_offset = 0;
+        let mut duration = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Cookie(d) => cookie = Some(*d),
+                Nl80211Attr::IfIndex(d) => {
+                    if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => wdev = Some(Nl80211WdevId::new(*d)),
+                Nl80211Attr::WiphyFreq(d) => frequency = Some(*d),
+                Nl80211Attr::WiphyFreqOffset(d) => freq_offset = *d,
+                Nl80211Attr::Duration(d) => duration = Some(*d),
+                _ => (),
+            }
+        }
+        Some(Self {
+            kind,
+            cookie: cookie?,
+            if_index,
+            wdev,
+            frequency: frequency.map(|f| {
+                Nl80211Freq::from_mhz_and_offset(f.mhz(), freq_offset)
+            }),
+            duration,
+        })
+    }
+}
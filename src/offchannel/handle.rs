@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Freq, Nl80211Handle, Nl80211IfIndex,
+    Nl80211RemainOnChannelCancelRequest, Nl80211RemainOnChannelRequest,
+    Nl80211WdevId,
+};
+
+pub struct Nl80211OffchannelHandle(Nl80211Handle);
+
+impl Nl80211OffchannelHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211OffchannelHandle(handle)
+    }
+
+    /// Stay on the specified frequency (a `u32` is taken as MHz) for
+    /// `duration` milliseconds
+    /// (equivalent to `iw dev DEVICE offchannel FREQ DURATION`).
+    /// The reply of this request holds the [crate::Nl80211Attr::Cookie]
+    /// identifying this remain on channel operation, it could be used to
+    /// cancel the operation or to match the
+    /// [Nl80211RemainOnChannelEvent](crate::Nl80211RemainOnChannelEvent).
+    /// The maximum duration supported by the wiphy is reported by
+    /// [crate::Nl80211Attr::MaxRemainOnChannelDuration].
+    pub fn remain_on_channel(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        frequency: impl Into<Nl80211Freq>,
+        duration: u32,
+    ) -> Nl80211RemainOnChannelRequest {
+        Nl80211RemainOnChannelRequest::new(
+            self.0.clone(),
+            Nl80211Attr::IfIndex(if_index.get()),
+            frequency.into(),
+            duration,
+        )
+    }
+
+    /// Similar to [Self::remain_on_channel()], but for wireless device
+    /// without network interface like P2P device.
+    pub fn remain_on_channel_wdev(
+        &mut self,
+        wdev: Nl80211WdevId,
+        frequency: impl Into<Nl80211Freq>,
+        duration: u32,
+    ) -> Nl80211RemainOnChannelRequest {
+        Nl80211RemainOnChannelRequest::new(
+            self.0.clone(),
+            Nl80211Attr::Wdev(wdev.get()),
+            frequency.into(),
+            duration,
+        )
+    }
+
+    /// Cancel the pending remain on channel operation identified by the
+    /// `cookie`.
+    pub fn cancel_remain_on_channel(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        cookie: u64,
+    ) -> Nl80211RemainOnChannelCancelRequest {
+        Nl80211RemainOnChannelCancelRequest::new(
+            self.0.clone(),
+            Nl80211Attr::IfIndex(if_index.get()),
+            cookie,
+        )
+    }
+
+    /// Similar to [Self::cancel_remain_on_channel()], but for wireless
+    /// device without network interface like P2P device.
+    pub fn cancel_remain_on_channel_wdev(
+        &mut self,
+        wdev: Nl80211WdevId,
+        cookie: u64,
+    ) -> Nl80211RemainOnChannelCancelRequest {
+        Nl80211RemainOnChannelCancelRequest::new(
+            self.0.clone(),
+            Nl80211Attr::Wdev(wdev.get()),
+            cookie,
+        )
+    }
+}
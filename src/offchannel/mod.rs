@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+mod event;
+mod handle;
+mod remain;
+
+#[cfg(feature = "events")]
+pub use self::event::{
+    Nl80211RemainOnChannelEvent, Nl80211RemainOnChannelEventKind,
+};
+pub use self::handle::Nl80211OffchannelHandle;
+pub use self::remain::{
+    Nl80211RemainOnChannelCancelRequest, Nl80211RemainOnChannelRequest,
+};
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211ChannelDef, Nl80211Command,
+    Nl80211Error, Nl80211Freq, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211RemainOnChannelRequest {
+    handle: Nl80211Handle,
+    // Nl80211Attr::IfIndex or Nl80211Attr::Wdev
+    target: Nl80211Attr,
+    channel: Nl80211ChannelDef,
+    duration: u32,
+}
+
+impl Nl80211RemainOnChannelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        target: Nl80211Attr,
+        frequency: Nl80211Freq,
+        duration: u32,
+    ) -> Self {
+        Nl80211RemainOnChannelRequest {
+            handle,
+            target,
+            channel: Nl80211ChannelDef::new(frequency),
+            duration,
+        }
+    }
+
+    /// Remain on channel of specified width instead of the 20 MHz channel
+    /// of frequency defined at creation
+    pub fn channel_def(mut self, channel: Nl80211ChannelDef) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RemainOnChannelRequest {
+            mut handle,
+            target,
+            channel,
+            duration,
+        } = self;
+
+        let mut attributes = vec![target];
+        attributes.extend(channel.attributes());
+        attributes.push(Nl80211Attr::Duration(duration));
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::RemainOnChannel,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
+
+pub struct Nl80211RemainOnChannelCancelRequest {
+    handle: Nl80211Handle,
+    // Nl80211Attr::IfIndex or Nl80211Attr::Wdev
+    target: Nl80211Attr,
+    cookie: u64,
+}
+
+impl Nl80211RemainOnChannelCancelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        target: Nl80211Attr,
+        cookie: u64,
+    ) -> Self {
+        Nl80211RemainOnChannelCancelRequest {
+            handle,
+            target,
+            cookie,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211RemainOnChannelCancelRequest {
+            mut handle,
+            target,
+            cookie,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::CancelRemainOnChannel,
+            attributes: vec![target, Nl80211Attr::Cookie(cookie)],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
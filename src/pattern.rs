@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable,
+};
+
+use crate::bytes::write_u32;
+
+const NL80211_PKTPAT_MASK: u16 = 1;
+const NL80211_PKTPAT_PATTERN: u16 = 2;
+const NL80211_PKTPAT_OFFSET: u16 = 3;
+
+/// Packet pattern used by [crate::Nl80211WowlanTrigger::PktPattern] and
+/// [crate::Nl80211CoalesceRule].
+///
+/// The pattern is matched against the packet starting at `offset`, the
+/// `mask` is a bitmap where bit N (least significant bit first in each byte)
+/// indicates whether byte N of `pattern` should be matched.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211PacketPattern {
+    pub index: u16,
+    pub mask: Vec<u8>,
+    pub pattern: Vec<u8>,
+    pub offset: u32,
+}
+
+impl Nl80211PacketPattern {
+    pub fn new(offset: u32, pattern: Vec<u8>, mask: Vec<u8>) -> Self {
+        Self {
+            index: 0,
+            mask,
+            pattern,
+            offset,
+        }
+    }
+
+    /// Pattern matching all bytes of `pattern`
+    pub fn exact(offset: u32, pattern: Vec<u8>) -> Self {
+        let mut mask = vec![0u8; pattern.len().div_ceil(8)];
+        for i in 0..pattern.len() {
+            mask[i / 8] |= 1 << (i % 8);
+        }
+        Self::new(offset, pattern, mask)
+    }
+
+    /// Whether specified packet matches this pattern
+    pub fn matches(&self, packet: &[u8]) -> bool {
+        let offset = self.offset as usize;
+        self.pattern.iter().enumerate().all(|(i, byte)| {
+            let masked = self
+                .mask
+                .get(i / 8)
+                .map(|m| m & (1 << (i % 8)) > 0)
+                .unwrap_or_default();
+            !masked || packet.get(offset + i) == Some(byte)
+        })
+    }
+
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        index: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let mut ret = Self {
+            index,
+            ..Default::default()
+        };
+        for nla in NlasIterator::new(payload) {
+            let err_msg = format!("Invalid packet pattern {nla:?}");
+            let nla = &nla.context(err_msg)?;
+            match nla.kind() {
+                NL80211_PKTPAT_MASK => ret.mask = nla.value().to_vec(),
+                NL80211_PKTPAT_PATTERN => ret.pattern = nla.value().to_vec(),
+                NL80211_PKTPAT_OFFSET => {
+                    ret.offset = parse_u32(nla.value()).context(format!(
+                        "Invalid NL80211_PKTPAT_OFFSET {:?}",
+                        nla.value()
+                    ))?
+                }
+                _ => {
+                    log::debug!("Unknown packet pattern attribute {nla:?}");
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    fn attributes(&self) -> Vec<DefaultNla> {
+        let mut offset = vec![0u8; 4];
+        write_u32(&mut offset, self.offset);
+        vec![
+            DefaultNla::new(NL80211_PKTPAT_MASK, self.mask.clone()),
+            DefaultNla::new(NL80211_PKTPAT_PATTERN, self.pattern.clone()),
+            DefaultNla::new(NL80211_PKTPAT_OFFSET, offset),
+        ]
+    }
+
+    pub(crate) fn parse_list(
+        payload: &[u8],
+        name: &str,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut ret = Vec::new();
+        for (index, nla) in NlasIterator::new(payload).enumerate() {
+            let err_msg = format!("Invalid {name} value {nla:?}");
+            let nla = &nla.context(err_msg)?;
+            ret.push(Self::parse_with_param(nla, index as u16)?);
+        }
+        Ok(ret)
+    }
+}
+
+impl Nla for Nl80211PacketPattern {
+    fn value_len(&self) -> usize {
+        self.attributes().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes().as_slice().emit(buffer)
+    }
+}
+
+/// Pattern matching capabilities of WoWLAN and coalesce
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211PatternSupport {
+    pub max_patterns: u32,
+    pub min_pattern_len: u32,
+    pub max_pattern_len: u32,
+    pub max_pkt_offset: u32,
+}
+
+impl Nl80211PatternSupport {
+    pub(crate) const LENGTH: usize = 16;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid pattern support, expecting length {} but got {}: \
+                {payload:?}",
+                Self::LENGTH,
+                payload.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                max_patterns: parse_u32(&payload[..4])?,
+                min_pattern_len: parse_u32(&payload[4..8])?,
+                max_pattern_len: parse_u32(&payload[8..12])?,
+                max_pkt_offset: parse_u32(&payload[12..16])?,
+            })
+        }
+    }
+
+    /// Whether specified patterns are within the capabilities
+    pub fn allows(&self, patterns: &[Nl80211PacketPattern]) -> bool {
+        patterns.len() <= self.max_patterns as usize
+            && patterns.iter().all(|p| {
+                p.pattern.len() >= self.min_pattern_len as usize
+                    && p.pattern.len() <= self.max_pattern_len as usize
+                    && p.offset <= self.max_pkt_offset
+            })
+    }
+}
+
+impl Emitable for Nl80211PatternSupport {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        write_u32(&mut buffer[0..4], self.max_patterns);
+        write_u32(&mut buffer[4..8], self.min_pattern_len);
+        write_u32(&mut buffer[8..12], self.max_pattern_len);
+        write_u32(&mut buffer[12..16], self.max_pkt_offset);
+    }
+}
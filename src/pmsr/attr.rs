@@ -0,0 +1,720 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u64},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::{write_u32, write_u64},
+    Nl80211Attr, Nl80211PmsrFtmCapa, Nl80211PmsrFtmReq, Nl80211PmsrFtmResp,
+};
+
+const ETH_ALEN: usize = 6;
+
+const NL80211_PMSR_TYPE_FTM: u16 = 1;
+
+const NL80211_PMSR_ATTR_MAX_PEERS: u16 = 1;
+const NL80211_PMSR_ATTR_REPORT_AP_TSF: u16 = 2;
+const NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR: u16 = 3;
+const NL80211_PMSR_ATTR_TYPE_CAPA: u16 = 4;
+const NL80211_PMSR_ATTR_PEERS: u16 = 5;
+
+/// Peer measurement attributes, used both for the capabilities in wiphy
+/// dump and for the request and result of peer measurement
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PeerMeasurementAttr {
+    /// Maximum number of peers in one request, capability only
+    MaxPeers(u32),
+    /// Can report AP TSF of the associated AP, capability only
+    ReportApTsf,
+    /// Can randomize the MAC address used in measurement, capability only
+    RandomizeMacAddr,
+    /// Capabilities of each measurement type
+    TypeCapa(Vec<Nl80211PmsrTypeCapa>),
+    /// Peers to measure, or the results of the peers
+    Peers(Vec<Vec<Nl80211PmsrPeerAttr>>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PeerMeasurementAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MaxPeers(_) => 4,
+            Self::ReportApTsf | Self::RandomizeMacAddr => 0,
+            Self::TypeCapa(v) => v.as_slice().buffer_len(),
+            Self::Peers(v) => {
+                Nl80211PmsrPeer::new_list(v).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MaxPeers(_) => NL80211_PMSR_ATTR_MAX_PEERS,
+            Self::ReportApTsf => NL80211_PMSR_ATTR_REPORT_AP_TSF,
+            Self::RandomizeMacAddr => NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR,
+            Self::TypeCapa(_) => NL80211_PMSR_ATTR_TYPE_CAPA,
+            Self::Peers(_) => NL80211_PMSR_ATTR_PEERS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MaxPeers(d) => write_u32(buffer, *d),
+            Self::ReportApTsf | Self::RandomizeMacAddr => (),
+            Self::TypeCapa(v) => v.as_slice().emit(buffer),
+            Self::Peers(v) => {
+                Nl80211PmsrPeer::new_list(v).as_slice().emit(buffer)
+            }
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PeerMeasurementAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_ATTR_MAX_PEERS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_ATTR_MAX_PEERS value {:?}",
+                    payload
+                );
+                Self::MaxPeers(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_ATTR_REPORT_AP_TSF => Self::ReportApTsf,
+            NL80211_PMSR_ATTR_RANDOMIZE_MAC_ADDR => Self::RandomizeMacAddr,
+            NL80211_PMSR_ATTR_TYPE_CAPA => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_ATTR_TYPE_CAPA value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrTypeCapa::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::TypeCapa(nlas)
+            }
+            NL80211_PMSR_ATTR_PEERS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_ATTR_PEERS value {:?}",
+                    payload
+                );
+                let mut peers = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    peers.push(
+                        Nl80211PmsrPeer::parse(nla)
+                            .context(err_msg.clone())?
+                            .attributes,
+                    );
+                }
+                Self::Peers(peers)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Capabilities of peer measurement type
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrTypeCapa {
+    Ftm(Vec<Nl80211PmsrFtmCapa>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrTypeCapa {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrTypeCapa
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_TYPE_FTM value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrFtmCapa::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Ftm(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+// `NL80211_PMSR_ATTR_PEERS` is an array using index as NLA kind.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Nl80211PmsrPeer {
+    index: u16,
+    attributes: Vec<Nl80211PmsrPeerAttr>,
+}
+
+impl Nl80211PmsrPeer {
+    fn new_list(peers: &[Vec<Nl80211PmsrPeerAttr>]) -> Vec<Self> {
+        peers
+            .iter()
+            .enumerate()
+            .map(|(index, attributes)| Self {
+                index: index as u16,
+                attributes: attributes.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Nla for Nl80211PmsrPeer {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrPeer
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_PMSR_ATTR_PEERS value {:?}", payload);
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            attributes.push(
+                Nl80211PmsrPeerAttr::parse(nla).context(err_msg.clone())?,
+            );
+        }
+        Ok(Self {
+            index: buf.kind(),
+            attributes,
+        })
+    }
+}
+
+const NL80211_PMSR_PEER_ATTR_ADDR: u16 = 1;
+const NL80211_PMSR_PEER_ATTR_CHAN: u16 = 2;
+const NL80211_PMSR_PEER_ATTR_REQ: u16 = 3;
+const NL80211_PMSR_PEER_ATTR_RESP: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrPeerAttr {
+    /// MAC address of the peer
+    Addr([u8; ETH_ALEN]),
+    /// Channel of the peer, using [Nl80211Attr::WiphyFreq],
+    /// [Nl80211Attr::ChannelWidth], [Nl80211Attr::CenterFreq1] and
+    /// [Nl80211Attr::CenterFreq2]
+    Chan(Vec<Nl80211Attr>),
+    /// Measurement request of the peer
+    Req(Vec<Nl80211PmsrReqAttr>),
+    /// Measurement result of the peer
+    Resp(Vec<Nl80211PmsrRespAttr>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrPeerAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Addr(_) => ETH_ALEN,
+            Self::Chan(v) => v.as_slice().buffer_len(),
+            Self::Req(v) => v.as_slice().buffer_len(),
+            Self::Resp(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Addr(_) => NL80211_PMSR_PEER_ATTR_ADDR,
+            Self::Chan(_) => NL80211_PMSR_PEER_ATTR_CHAN,
+            Self::Req(_) => NL80211_PMSR_PEER_ATTR_REQ,
+            Self::Resp(_) => NL80211_PMSR_PEER_ATTR_RESP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Addr(s) => buffer.copy_from_slice(s),
+            Self::Chan(v) => v.as_slice().emit(buffer),
+            Self::Req(v) => v.as_slice().emit(buffer),
+            Self::Resp(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrPeerAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_PEER_ATTR_ADDR => {
+                Self::Addr(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_PMSR_PEER_ATTR_ADDR, \
+                        expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_PMSR_PEER_ATTR_CHAN => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_PEER_ATTR_CHAN value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211Attr::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::Chan(nlas)
+            }
+            NL80211_PMSR_PEER_ATTR_REQ => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_PEER_ATTR_REQ value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrReqAttr::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Req(nlas)
+            }
+            NL80211_PMSR_PEER_ATTR_RESP => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_PEER_ATTR_RESP value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrRespAttr::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Resp(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_PMSR_REQ_ATTR_DATA: u16 = 1;
+const NL80211_PMSR_REQ_ATTR_GET_AP_TSF: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrReqAttr {
+    /// Request data of each measurement type
+    Data(Vec<Nl80211PmsrReqData>),
+    /// Include the TSF of the associated AP in result
+    GetApTsf,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrReqAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Data(v) => v.as_slice().buffer_len(),
+            Self::GetApTsf => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_PMSR_REQ_ATTR_DATA,
+            Self::GetApTsf => NL80211_PMSR_REQ_ATTR_GET_AP_TSF,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Data(v) => v.as_slice().emit(buffer),
+            Self::GetApTsf => (),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrReqAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_REQ_ATTR_DATA => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_REQ_ATTR_DATA value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrReqData::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Data(nlas)
+            }
+            NL80211_PMSR_REQ_ATTR_GET_AP_TSF => Self::GetApTsf,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Request data of peer measurement type
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrReqData {
+    Ftm(Vec<Nl80211PmsrFtmReq>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrReqData {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrReqData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_TYPE_FTM value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrFtmReq::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Ftm(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_PMSR_STATUS_SUCCESS: u32 = 0;
+const NL80211_PMSR_STATUS_REFUSED: u32 = 1;
+const NL80211_PMSR_STATUS_TIMEOUT: u32 = 2;
+const NL80211_PMSR_STATUS_FAILURE: u32 = 3;
+
+/// Status of peer measurement
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrStatus {
+    Success,
+    /// Peer refused the measurement
+    Refused,
+    /// Measurement timed out
+    Timeout,
+    /// Measurement failed, the reason might be included in type specific
+    /// data, for example [Nl80211PmsrFtmResp::FailReason]
+    Failure,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PmsrStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PMSR_STATUS_SUCCESS => Self::Success,
+            NL80211_PMSR_STATUS_REFUSED => Self::Refused,
+            NL80211_PMSR_STATUS_TIMEOUT => Self::Timeout,
+            NL80211_PMSR_STATUS_FAILURE => Self::Failure,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PmsrStatus> for u32 {
+    fn from(v: Nl80211PmsrStatus) -> u32 {
+        match v {
+            Nl80211PmsrStatus::Success => NL80211_PMSR_STATUS_SUCCESS,
+            Nl80211PmsrStatus::Refused => NL80211_PMSR_STATUS_REFUSED,
+            Nl80211PmsrStatus::Timeout => NL80211_PMSR_STATUS_TIMEOUT,
+            Nl80211PmsrStatus::Failure => NL80211_PMSR_STATUS_FAILURE,
+            Nl80211PmsrStatus::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_PMSR_RESP_ATTR_DATA: u16 = 1;
+const NL80211_PMSR_RESP_ATTR_STATUS: u16 = 2;
+const NL80211_PMSR_RESP_ATTR_HOST_TIME: u16 = 3;
+const NL80211_PMSR_RESP_ATTR_AP_TSF: u16 = 4;
+const NL80211_PMSR_RESP_ATTR_FINAL: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrRespAttr {
+    /// Result data of each measurement type
+    Data(Vec<Nl80211PmsrRespData>),
+    Status(Nl80211PmsrStatus),
+    /// `CLOCK_BOOTTIME` in nanoseconds when the measurement was done, see
+    /// [crate::Nl80211BootTime]
+    HostTime(u64),
+    /// TSF of the associated AP when the measurement was done
+    ApTsf(u64),
+    /// This is the last result of the peer in this request
+    Final,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrRespAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Data(v) => v.as_slice().buffer_len(),
+            Self::Status(_) => 4,
+            Self::HostTime(_) | Self::ApTsf(_) => 8,
+            Self::Final => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Data(_) => NL80211_PMSR_RESP_ATTR_DATA,
+            Self::Status(_) => NL80211_PMSR_RESP_ATTR_STATUS,
+            Self::HostTime(_) => NL80211_PMSR_RESP_ATTR_HOST_TIME,
+            Self::ApTsf(_) => NL80211_PMSR_RESP_ATTR_AP_TSF,
+            Self::Final => NL80211_PMSR_RESP_ATTR_FINAL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Data(v) => v.as_slice().emit(buffer),
+            Self::Status(d) => write_u32(buffer, (*d).into()),
+            Self::HostTime(d) | Self::ApTsf(d) => write_u64(buffer, *d),
+            Self::Final => (),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRespAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_RESP_ATTR_DATA => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_DATA value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrRespData::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Data(nlas)
+            }
+            NL80211_PMSR_RESP_ATTR_STATUS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_STATUS value {:?}",
+                    payload
+                );
+                Self::Status(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_RESP_ATTR_HOST_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_HOST_TIME value {:?}",
+                    payload
+                );
+                Self::HostTime(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_RESP_ATTR_AP_TSF => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_RESP_ATTR_AP_TSF value {:?}",
+                    payload
+                );
+                Self::ApTsf(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_RESP_ATTR_FINAL => Self::Final,
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Result data of peer measurement type
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrRespData {
+    Ftm(Vec<Nl80211PmsrFtmResp>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrRespData {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ftm(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ftm(_) => NL80211_PMSR_TYPE_FTM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ftm(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrRespData
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_TYPE_FTM => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_TYPE_FTM value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211PmsrFtmResp::parse(nla)
+                            .context(err_msg.clone())?,
+                    );
+                }
+                Self::Ftm(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211BootTime, Nl80211Command, Nl80211IfIndex,
+    Nl80211Message, Nl80211PeerMeasurementAttr, Nl80211PmsrFtmResp,
+    Nl80211PmsrPeerAttr, Nl80211PmsrRespAttr, Nl80211PmsrRespData,
+    Nl80211PmsrStatus, Nl80211WdevId, Nl80211WiphyIndex,
+};
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211PeerMeasurementEventInfo {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Cookie returned when starting the measurement
+    pub cookie: Option<u64>,
+}
+
+/// Measurement result of a peer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211PmsrResult {
+    pub addr: Option<[u8; ETH_ALEN]>,
+    pub status: Option<Nl80211PmsrStatus>,
+    pub host_time: Option<Nl80211BootTime>,
+    pub ap_tsf: Option<u64>,
+    /// This is the last result of the peer in this measurement
+    pub is_final: bool,
+    /// FTM result data, empty if not FTM measurement
+    pub ftm: Vec<Nl80211PmsrFtmResp>,
+}
+
+impl Nl80211PmsrResult {
+    fn from_peer_attrs(attrs: &[Nl80211PmsrPeerAttr]) -> Self {
+        let mut ret = Self::default();
+        for attr in attrs {
+            match attr {
+                Nl80211PmsrPeerAttr::Addr(d) => ret.addr = Some(*d),
+                Nl80211PmsrPeerAttr::Resp(resp) => {
+                    for resp_attr in resp {
+                        match resp_attr {
+                            Nl80211PmsrRespAttr::Status(d) => {
+                                ret.status = Some(*d)
+                            }
+                            Nl80211PmsrRespAttr::HostTime(d) => {
+                                ret.host_time =
+                                    Some(Nl80211BootTime::from_nanos(*d))
+                            }
+                            Nl80211PmsrRespAttr::ApTsf(d) => {
+                                ret.ap_tsf = Some(*d)
+                            }
+                            Nl80211PmsrRespAttr::Final => ret.is_final = true,
+                            Nl80211PmsrRespAttr::Data(data) => {
+                                for d in data {
+                                    if let Nl80211PmsrRespData::Ftm(v) = d {
+                                        ret.ftm.extend(v.iter().cloned());
+                                    }
+                                }
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        ret
+    }
+
+    /// Average round trip time in picoseconds of FTM measurement
+    pub fn rtt_avg_ps(&self) -> Option<i64> {
+        self.ftm.iter().find_map(|attr| {
+            if let Nl80211PmsrFtmResp::RttAvg(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Average distance in millimeters of FTM measurement
+    pub fn dist_avg_mm(&self) -> Option<i64> {
+        self.ftm.iter().find_map(|attr| {
+            if let Nl80211PmsrFtmResp::DistAvg(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Peer measurement notifications, sent by kernel to the socket which
+/// started the measurement instead of multicast group
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PeerMeasurementEvent {
+    /// Results of some peers, the results might be split into multiple
+    /// events
+    Result(Nl80211PeerMeasurementEventInfo, Vec<Nl80211PmsrResult>),
+    /// Measurement finished, no more results of this cookie
+    Complete(Nl80211PeerMeasurementEventInfo),
+}
+
+impl Nl80211PeerMeasurementEvent {
+    /// Return `None` if specified message is not a peer measurement
+    /// notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::PeerMeasurementResult
+            && msg.cmd != Nl80211Command::PeerMeasurementComplete
+        {
+            return None;
+        }
+        let mut info = Nl80211PeerMeasurementEventInfo::default();
+        let mut results = Vec::new();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    info.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    info.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => {
+                    info.wdev = Some(Nl80211WdevId::new(*d))
+                }
+                Nl80211Attr::Cookie(d) => info.cookie = Some(*d),
+                Nl80211Attr::PeerMeasurements(attrs) => {
+                    for attr in attrs {
+                        if let Nl80211PeerMeasurementAttr::Peers(peers) = attr {
+                            results.extend(peers.iter().map(|p| {
+                                Nl80211PmsrResult::from_peer_attrs(p)
+                            }));
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        if msg.cmd == Nl80211Command::PeerMeasurementResult {
+            Some(Self::Result(info, results))
+        } else {
+            Some(Self::Complete(info))
+        }
+    }
+
+    pub fn info(&self) -> &Nl80211PeerMeasurementEventInfo {
+        match self {
+            Self::Result(i, _) | Self::Complete(i) => i,
+        }
+    }
+}
@@ -0,0 +1,812 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u16, parse_u32, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::{write_u16, write_u32},
+    Nl80211RateInfo,
+};
+
+const NL80211_PREAMBLE_LEGACY: u32 = 0;
+const NL80211_PREAMBLE_HT: u32 = 1;
+const NL80211_PREAMBLE_VHT: u32 = 2;
+const NL80211_PREAMBLE_DMG: u32 = 3;
+const NL80211_PREAMBLE_HE: u32 = 4;
+
+/// Frame preamble types
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211Preamble {
+    /// Legacy (HR/DSSS, OFDM, ERP PHY) preamble
+    Legacy,
+    Ht,
+    Vht,
+    /// DMG (60 GHz) preamble
+    Dmg,
+    He,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211Preamble {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PREAMBLE_LEGACY => Self::Legacy,
+            NL80211_PREAMBLE_HT => Self::Ht,
+            NL80211_PREAMBLE_VHT => Self::Vht,
+            NL80211_PREAMBLE_DMG => Self::Dmg,
+            NL80211_PREAMBLE_HE => Self::He,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Preamble> for u32 {
+    fn from(v: Nl80211Preamble) -> u32 {
+        match v {
+            Nl80211Preamble::Legacy => NL80211_PREAMBLE_LEGACY,
+            Nl80211Preamble::Ht => NL80211_PREAMBLE_HT,
+            Nl80211Preamble::Vht => NL80211_PREAMBLE_VHT,
+            Nl80211Preamble::Dmg => NL80211_PREAMBLE_DMG,
+            Nl80211Preamble::He => NL80211_PREAMBLE_HE,
+            Nl80211Preamble::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_PMSR_FTM_CAPA_ATTR_ASAP: u16 = 1;
+const NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP: u16 = 2;
+const NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI: u16 = 3;
+const NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC: u16 = 4;
+const NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES: u16 = 5;
+const NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS: u16 = 6;
+const NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT: u16 = 7;
+const NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST: u16 = 8;
+const NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED: u16 = 9;
+const NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED: u16 = 10;
+
+/// FTM (fine timing measurement) capabilities of wiphy
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrFtmCapa {
+    /// ASAP mode supported
+    Asap,
+    /// Non-ASAP mode supported
+    NonAsap,
+    /// Can request LCI (location configuration information) data
+    ReqLci,
+    /// Can request civic location data
+    ReqCivicloc,
+    /// Bitmap of supported [Nl80211Preamble], bit N for preamble of value N
+    Preambles(u32),
+    /// Bitmap of supported [crate::Nl80211ChannelWidth], bit N for width of
+    /// value N
+    Bandwidths(u32),
+    /// Maximum burst exponent, the number of bursts is `2^exponent`
+    MaxBurstsExponent(u32),
+    /// Maximum number of FTMs per burst
+    MaxFtmsPerBurst(u32),
+    /// Trigger based ranging measurement supported
+    TriggerBased,
+    /// Non trigger based ranging measurement supported
+    NonTriggerBased,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmCapa {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Asap
+            | Self::NonAsap
+            | Self::ReqLci
+            | Self::ReqCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased => 0,
+            Self::Preambles(_)
+            | Self::Bandwidths(_)
+            | Self::MaxBurstsExponent(_)
+            | Self::MaxFtmsPerBurst(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Asap => NL80211_PMSR_FTM_CAPA_ATTR_ASAP,
+            Self::NonAsap => NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP,
+            Self::ReqLci => NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI,
+            Self::ReqCivicloc => NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC,
+            Self::Preambles(_) => NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES,
+            Self::Bandwidths(_) => NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS,
+            Self::MaxBurstsExponent(_) => {
+                NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT
+            }
+            Self::MaxFtmsPerBurst(_) => {
+                NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST
+            }
+            Self::TriggerBased => NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED,
+            Self::NonTriggerBased => {
+                NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Asap
+            | Self::NonAsap
+            | Self::ReqLci
+            | Self::ReqCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased => (),
+            Self::Preambles(d)
+            | Self::Bandwidths(d)
+            | Self::MaxBurstsExponent(d)
+            | Self::MaxFtmsPerBurst(d) => write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmCapa
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_CAPA_ATTR_ASAP => Self::Asap,
+            NL80211_PMSR_FTM_CAPA_ATTR_NON_ASAP => Self::NonAsap,
+            NL80211_PMSR_FTM_CAPA_ATTR_REQ_LCI => Self::ReqLci,
+            NL80211_PMSR_FTM_CAPA_ATTR_REQ_CIVICLOC => Self::ReqCivicloc,
+            NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_PREAMBLES value {:?}",
+                    payload
+                );
+                Self::Preambles(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_BANDWIDTHS value {:?}",
+                    payload
+                );
+                Self::Bandwidths(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_MAX_BURSTS_EXPONENT \
+                    value {:?}",
+                    payload
+                );
+                Self::MaxBurstsExponent(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_CAPA_ATTR_MAX_FTMS_PER_BURST \
+                    value {:?}",
+                    payload
+                );
+                Self::MaxFtmsPerBurst(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_CAPA_ATTR_TRIGGER_BASED => Self::TriggerBased,
+            NL80211_PMSR_FTM_CAPA_ATTR_NON_TRIGGER_BASED => {
+                Self::NonTriggerBased
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_PMSR_FTM_REQ_ATTR_ASAP: u16 = 1;
+const NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE: u16 = 2;
+const NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP: u16 = 3;
+const NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD: u16 = 4;
+const NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION: u16 = 5;
+const NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST: u16 = 6;
+const NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES: u16 = 7;
+const NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI: u16 = 8;
+const NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC: u16 = 9;
+const NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED: u16 = 10;
+const NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED: u16 = 11;
+const NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK: u16 = 12;
+const NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR: u16 = 13;
+
+/// FTM request parameters of a peer, kernel uses the default value for
+/// omitted ones
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrFtmReq {
+    /// Request ASAP mode
+    Asap,
+    /// Preamble type, mandatory
+    Preamble(Nl80211Preamble),
+    /// Number of bursts exponent, the number of bursts is `2^exponent`,
+    /// 15 means no preference
+    NumBurstsExp(u8),
+    /// Interval between bursts in units of 100 ms, 0 means no preference
+    BurstPeriod(u16),
+    /// Burst duration as defined in IEEE 802.11-2016 9.4.2.168, 15 means no
+    /// preference
+    BurstDuration(u8),
+    /// Number of successful FTM frames requested per burst, 0 means no
+    /// preference
+    FtmsPerBurst(u8),
+    /// Number of FTMR frame retries
+    NumFtmrRetries(u8),
+    /// Request LCI data
+    RequestLci,
+    /// Request civic location data
+    RequestCivicloc,
+    /// Use trigger based ranging measurement
+    TriggerBased,
+    /// Use non trigger based ranging measurement
+    NonTriggerBased,
+    /// Negotiate for I2R LMR feedback, only valid for trigger based or non
+    /// trigger based ranging measurement
+    LmrFeedback,
+    /// BSS color of the responder, 0 means unknown
+    BssColor(u8),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmReq {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Asap
+            | Self::RequestLci
+            | Self::RequestCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased
+            | Self::LmrFeedback => 0,
+            Self::Preamble(_) => 4,
+            Self::BurstPeriod(_) => 2,
+            Self::NumBurstsExp(_)
+            | Self::BurstDuration(_)
+            | Self::FtmsPerBurst(_)
+            | Self::NumFtmrRetries(_)
+            | Self::BssColor(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Asap => NL80211_PMSR_FTM_REQ_ATTR_ASAP,
+            Self::Preamble(_) => NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE,
+            Self::NumBurstsExp(_) => NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP,
+            Self::BurstPeriod(_) => NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD,
+            Self::BurstDuration(_) => NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION,
+            Self::FtmsPerBurst(_) => NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST,
+            Self::NumFtmrRetries(_) => {
+                NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES
+            }
+            Self::RequestLci => NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI,
+            Self::RequestCivicloc => NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC,
+            Self::TriggerBased => NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED,
+            Self::NonTriggerBased => {
+                NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED
+            }
+            Self::LmrFeedback => NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK,
+            Self::BssColor(_) => NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Asap
+            | Self::RequestLci
+            | Self::RequestCivicloc
+            | Self::TriggerBased
+            | Self::NonTriggerBased
+            | Self::LmrFeedback => (),
+            Self::Preamble(d) => write_u32(buffer, (*d).into()),
+            Self::BurstPeriod(d) => write_u16(buffer, *d),
+            Self::NumBurstsExp(d)
+            | Self::BurstDuration(d)
+            | Self::FtmsPerBurst(d)
+            | Self::NumFtmrRetries(d)
+            | Self::BssColor(d) => buffer[0] = *d,
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmReq
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_REQ_ATTR_ASAP => Self::Asap,
+            NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_PREAMBLE value {:?}",
+                    payload
+                );
+                Self::Preamble(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_NUM_BURSTS_EXP \
+                    value {:?}",
+                    payload
+                );
+                Self::NumBurstsExp(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BURST_PERIOD value {:?}",
+                    payload
+                );
+                Self::BurstPeriod(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BURST_DURATION \
+                    value {:?}",
+                    payload
+                );
+                Self::BurstDuration(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_FTMS_PER_BURST \
+                    value {:?}",
+                    payload
+                );
+                Self::FtmsPerBurst(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_NUM_FTMR_RETRIES \
+                    value {:?}",
+                    payload
+                );
+                Self::NumFtmrRetries(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_REQUEST_LCI => Self::RequestLci,
+            NL80211_PMSR_FTM_REQ_ATTR_REQUEST_CIVICLOC => Self::RequestCivicloc,
+            NL80211_PMSR_FTM_REQ_ATTR_TRIGGER_BASED => Self::TriggerBased,
+            NL80211_PMSR_FTM_REQ_ATTR_NON_TRIGGER_BASED => {
+                Self::NonTriggerBased
+            }
+            NL80211_PMSR_FTM_REQ_ATTR_LMR_FEEDBACK => Self::LmrFeedback,
+            NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_REQ_ATTR_BSS_COLOR value {:?}",
+                    payload
+                );
+                Self::BssColor(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_PMSR_FTM_FAILURE_UNSPECIFIED: u32 = 0;
+const NL80211_PMSR_FTM_FAILURE_NO_RESPONSE: u32 = 1;
+const NL80211_PMSR_FTM_FAILURE_REJECTED: u32 = 2;
+const NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL: u32 = 3;
+const NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE: u32 = 4;
+const NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP: u32 = 5;
+const NL80211_PMSR_FTM_FAILURE_PEER_BUSY: u32 = 6;
+const NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS: u32 = 7;
+
+/// Reason of FTM measurement failure
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrFtmFailureReason {
+    Unspecified,
+    /// No response from the FTM responder
+    NoResponse,
+    /// FTM responder rejected the request
+    Rejected,
+    /// Responder is on a different channel
+    WrongChannel,
+    /// Peer is not capable of FTM
+    PeerNotCapable,
+    /// Invalid timestamp was reported
+    InvalidTimestamp,
+    /// Responder is busy, retry after
+    /// [Nl80211PmsrFtmResp::BusyRetryTime]
+    PeerBusy,
+    /// Responder changed the parameters in an unacceptable way
+    BadChangedParams,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211PmsrFtmFailureReason {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_PMSR_FTM_FAILURE_UNSPECIFIED => Self::Unspecified,
+            NL80211_PMSR_FTM_FAILURE_NO_RESPONSE => Self::NoResponse,
+            NL80211_PMSR_FTM_FAILURE_REJECTED => Self::Rejected,
+            NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL => Self::WrongChannel,
+            NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE => Self::PeerNotCapable,
+            NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP => {
+                Self::InvalidTimestamp
+            }
+            NL80211_PMSR_FTM_FAILURE_PEER_BUSY => Self::PeerBusy,
+            NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS => {
+                Self::BadChangedParams
+            }
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211PmsrFtmFailureReason> for u32 {
+    fn from(v: Nl80211PmsrFtmFailureReason) -> u32 {
+        match v {
+            Nl80211PmsrFtmFailureReason::Unspecified => {
+                NL80211_PMSR_FTM_FAILURE_UNSPECIFIED
+            }
+            Nl80211PmsrFtmFailureReason::NoResponse => {
+                NL80211_PMSR_FTM_FAILURE_NO_RESPONSE
+            }
+            Nl80211PmsrFtmFailureReason::Rejected => {
+                NL80211_PMSR_FTM_FAILURE_REJECTED
+            }
+            Nl80211PmsrFtmFailureReason::WrongChannel => {
+                NL80211_PMSR_FTM_FAILURE_WRONG_CHANNEL
+            }
+            Nl80211PmsrFtmFailureReason::PeerNotCapable => {
+                NL80211_PMSR_FTM_FAILURE_PEER_NOT_CAPABLE
+            }
+            Nl80211PmsrFtmFailureReason::InvalidTimestamp => {
+                NL80211_PMSR_FTM_FAILURE_INVALID_TIMESTAMP
+            }
+            Nl80211PmsrFtmFailureReason::PeerBusy => {
+                NL80211_PMSR_FTM_FAILURE_PEER_BUSY
+            }
+            Nl80211PmsrFtmFailureReason::BadChangedParams => {
+                NL80211_PMSR_FTM_FAILURE_BAD_CHANGED_PARAMS
+            }
+            Nl80211PmsrFtmFailureReason::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON: u16 = 1;
+const NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX: u16 = 2;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS: u16 = 3;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES: u16 = 4;
+const NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME: u16 = 5;
+const NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP: u16 = 6;
+const NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION: u16 = 7;
+const NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST: u16 = 8;
+const NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG: u16 = 9;
+const NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD: u16 = 10;
+const NL80211_PMSR_FTM_RESP_ATTR_TX_RATE: u16 = 11;
+const NL80211_PMSR_FTM_RESP_ATTR_RX_RATE: u16 = 12;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG: u16 = 13;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE: u16 = 14;
+const NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD: u16 = 15;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG: u16 = 16;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE: u16 = 17;
+const NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD: u16 = 18;
+const NL80211_PMSR_FTM_RESP_ATTR_LCI: u16 = 19;
+const NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC: u16 = 20;
+
+/// FTM measurement result of a peer
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211PmsrFtmResp {
+    /// Only included when the measurement failed
+    FailReason(Nl80211PmsrFtmFailureReason),
+    /// Index of the burst this result belongs to
+    BurstIndex(u32),
+    /// Number of FTM request frames transmitted
+    NumFtmrAttempts(u32),
+    /// Number of FTM request frames acknowledged
+    NumFtmrSuccesses(u32),
+    /// Seconds to wait before retrying when the responder is busy
+    BusyRetryTime(u32),
+    /// Actual number of bursts exponent negotiated
+    NumBurstsExp(u8),
+    /// Actual burst duration negotiated
+    BurstDuration(u8),
+    /// Actual number of FTMs per burst negotiated
+    FtmsPerBurst(u8),
+    /// Average RSSI in units of 0.5 dBm
+    RssiAvg(i32),
+    /// RSSI spread in units of 0.5 dB
+    RssiSpread(u32),
+    /// TX rate of the FTM frames
+    TxRate(Vec<Nl80211RateInfo>),
+    /// RX rate of the FTM frames
+    RxRate(Vec<Nl80211RateInfo>),
+    /// Average round trip time in picoseconds
+    RttAvg(i64),
+    /// Round trip time variance in picoseconds squared
+    RttVariance(u64),
+    /// Round trip time spread in picoseconds
+    RttSpread(u64),
+    /// Average distance in millimeters
+    DistAvg(i64),
+    /// Distance variance in millimeters squared
+    DistVariance(u64),
+    /// Distance spread in millimeters
+    DistSpread(u64),
+    /// LCI data from the measurement report element
+    Lci(Vec<u8>),
+    /// Civic location data from the measurement report element
+    Civicloc(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211PmsrFtmResp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::FailReason(_)
+            | Self::BurstIndex(_)
+            | Self::NumFtmrAttempts(_)
+            | Self::NumFtmrSuccesses(_)
+            | Self::BusyRetryTime(_)
+            | Self::RssiAvg(_)
+            | Self::RssiSpread(_) => 4,
+            Self::NumBurstsExp(_)
+            | Self::BurstDuration(_)
+            | Self::FtmsPerBurst(_) => 1,
+            Self::TxRate(nlas) | Self::RxRate(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::RttAvg(_)
+            | Self::RttVariance(_)
+            | Self::RttSpread(_)
+            | Self::DistAvg(_)
+            | Self::DistVariance(_)
+            | Self::DistSpread(_) => 8,
+            Self::Lci(v) | Self::Civicloc(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FailReason(_) => NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON,
+            Self::BurstIndex(_) => NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX,
+            Self::NumFtmrAttempts(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS
+            }
+            Self::NumFtmrSuccesses(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES
+            }
+            Self::BusyRetryTime(_) => {
+                NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME
+            }
+            Self::NumBurstsExp(_) => NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP,
+            Self::BurstDuration(_) => NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION,
+            Self::FtmsPerBurst(_) => NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST,
+            Self::RssiAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG,
+            Self::RssiSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD,
+            Self::TxRate(_) => NL80211_PMSR_FTM_RESP_ATTR_TX_RATE,
+            Self::RxRate(_) => NL80211_PMSR_FTM_RESP_ATTR_RX_RATE,
+            Self::RttAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG,
+            Self::RttVariance(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE,
+            Self::RttSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD,
+            Self::DistAvg(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG,
+            Self::DistVariance(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE,
+            Self::DistSpread(_) => NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD,
+            Self::Lci(_) => NL80211_PMSR_FTM_RESP_ATTR_LCI,
+            Self::Civicloc(_) => NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::FailReason(d) => write_u32(buffer, (*d).into()),
+            Self::BurstIndex(d)
+            | Self::NumFtmrAttempts(d)
+            | Self::NumFtmrSuccesses(d)
+            | Self::BusyRetryTime(d)
+            | Self::RssiSpread(d) => write_u32(buffer, *d),
+            Self::RssiAvg(d) => NativeEndian::write_i32(buffer, *d),
+            Self::NumBurstsExp(d)
+            | Self::BurstDuration(d)
+            | Self::FtmsPerBurst(d) => buffer[0] = *d,
+            Self::TxRate(nlas) | Self::RxRate(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::RttAvg(d) | Self::DistAvg(d) => {
+                NativeEndian::write_i64(buffer, *d)
+            }
+            Self::RttVariance(d)
+            | Self::RttSpread(d)
+            | Self::DistVariance(d)
+            | Self::DistSpread(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Lci(v) | Self::Civicloc(v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211PmsrFtmResp
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_FAIL_REASON value {:?}",
+                    payload
+                );
+                Self::FailReason(parse_u32(payload).context(err_msg)?.into())
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BURST_INDEX value {:?}",
+                    payload
+                );
+                Self::BurstIndex(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_ATTEMPTS \
+                    value {:?}",
+                    payload
+                );
+                Self::NumFtmrAttempts(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_FTMR_SUCCESSES \
+                    value {:?}",
+                    payload
+                );
+                Self::NumFtmrSuccesses(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BUSY_RETRY_TIME \
+                    value {:?}",
+                    payload
+                );
+                Self::BusyRetryTime(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_NUM_BURSTS_EXP \
+                    value {:?}",
+                    payload
+                );
+                Self::NumBurstsExp(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_BURST_DURATION \
+                    value {:?}",
+                    payload
+                );
+                Self::BurstDuration(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_FTMS_PER_BURST \
+                    value {:?}",
+                    payload
+                );
+                Self::FtmsPerBurst(parse_u8(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RSSI_AVG value {:?}",
+                    payload
+                );
+                Self::RssiAvg(parse_i32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RSSI_SPREAD value {:?}",
+                    payload
+                );
+                Self::RssiSpread(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_TX_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_TX_RATE value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211RateInfo::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::TxRate(nlas)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RX_RATE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RX_RATE value {:?}",
+                    payload
+                );
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(
+                        Nl80211RateInfo::parse(nla).context(err_msg.clone())?,
+                    );
+                }
+                Self::RxRate(nlas)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_AVG value {:?}",
+                    payload
+                );
+                Self::RttAvg(i64::from_ne_bytes(
+                    payload.try_into().context(err_msg)?,
+                ))
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_VARIANCE value {:?}",
+                    payload
+                );
+                Self::RttVariance(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_RTT_SPREAD value {:?}",
+                    payload
+                );
+                Self::RttSpread(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_AVG value {:?}",
+                    payload
+                );
+                Self::DistAvg(i64::from_ne_bytes(
+                    payload.try_into().context(err_msg)?,
+                ))
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_VARIANCE \
+                    value {:?}",
+                    payload
+                );
+                Self::DistVariance(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD => {
+                let err_msg = format!(
+                    "Invalid NL80211_PMSR_FTM_RESP_ATTR_DIST_SPREAD value {:?}",
+                    payload
+                );
+                Self::DistSpread(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_PMSR_FTM_RESP_ATTR_LCI => Self::Lci(payload.to_vec()),
+            NL80211_PMSR_FTM_RESP_ATTR_CIVICLOC => {
+                Self::Civicloc(payload.to_vec())
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+use netlink_packet_utils::nla::Nla;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211FtmResponderStatsRequest,
+    Nl80211Handle, Nl80211IfIndex, Nl80211PeerMeasurementAttr,
+    Nl80211PeerMeasurementStartRequest, Nl80211PmsrFtmReq, Nl80211PmsrPeerAttr,
+    Nl80211PmsrReqAttr, Nl80211PmsrReqData,
+};
+
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211PeerMeasurementEvent};
+
+const ETH_ALEN: usize = 6;
+
+/// Peer measurement, for example FTM (fine timing measurement) ranging
+#[derive(Debug, Clone)]
+pub struct Nl80211PeerMeasurementHandle(Nl80211Handle);
+
+impl Nl80211PeerMeasurementHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211PeerMeasurementHandle(handle)
+    }
+
+    /// Start peer measurement. The results are sent to the socket of this
+    /// connection, see [Self::events()]. Kernel aborts the measurement
+    /// when the socket is closed.
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211PeerMeasurement].
+    pub fn start(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211PeerMeasurementStartRequest {
+        Nl80211PeerMeasurementStartRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Retrieve FTM responder statistics of AP interface
+    pub fn ftm_responder_stats(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211FtmResponderStatsRequest {
+        Nl80211FtmResponderStatsRequest::new(self.0.clone(), if_index.get())
+    }
+
+    /// Stream of peer measurement results decoded from the unsolicited
+    /// message receiver of connection, see [Nl80211Handle::notifications()].
+    /// No multicast group subscription is required.
+    #[cfg(feature = "events")]
+    pub async fn events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211PeerMeasurementEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211PeerMeasurementEvent::from_message(
+                &msg,
+            ))
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211PeerMeasurement;
+
+impl Nl80211PeerMeasurement {
+    /// Peer measurement on specified interface
+    pub fn new(if_index: Nl80211IfIndex) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new().if_index(if_index)
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211PeerMeasurement> {
+    fn peers(&self) -> Vec<Vec<Nl80211PmsrPeerAttr>> {
+        match self.get(Nl80211Attr::PeerMeasurements(Vec::new()).kind()) {
+            Some(Nl80211Attr::PeerMeasurements(attrs)) => attrs
+                .iter()
+                .find_map(|attr| {
+                    if let Nl80211PeerMeasurementAttr::Peers(peers) = attr {
+                        Some(peers.clone())
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Add a peer to measure
+    pub fn peer(self, attributes: Vec<Nl80211PmsrPeerAttr>) -> Self {
+        let mut peers = self.peers();
+        peers.push(attributes);
+        self.replace(Nl80211Attr::PeerMeasurements(vec![
+            Nl80211PeerMeasurementAttr::Peers(peers),
+        ]))
+    }
+
+    /// Add a peer for FTM ranging. The `channel` should hold
+    /// [Nl80211Attr::WiphyFreq] and [Nl80211Attr::ChannelWidth], and
+    /// [Nl80211Attr::CenterFreq1] for channel wider than 20 MHz.
+    /// The `ftm` should at least contain [Nl80211PmsrFtmReq::Preamble].
+    pub fn ftm_peer(
+        self,
+        addr: [u8; ETH_ALEN],
+        channel: Vec<Nl80211Attr>,
+        ftm: Vec<Nl80211PmsrFtmReq>,
+    ) -> Self {
+        self.peer(vec![
+            Nl80211PmsrPeerAttr::Addr(addr),
+            Nl80211PmsrPeerAttr::Chan(channel),
+            Nl80211PmsrPeerAttr::Req(vec![Nl80211PmsrReqAttr::Data(vec![
+                Nl80211PmsrReqData::Ftm(ftm),
+            ])]),
+        ])
+    }
+
+    /// Abort the measurement after specified milliseconds
+    pub fn timeout(self, ms: u32) -> Self {
+        self.replace(Nl80211Attr::Timeout(ms))
+    }
+
+    /// Use random source address in measurement, the bits set in `mask`
+    /// are taken from `mac`. Requires
+    /// [Nl80211PeerMeasurementAttr::RandomizeMacAddr] capability.
+    pub fn random_mac(self, mac: [u8; ETH_ALEN], mask: [u8; ETH_ALEN]) -> Self {
+        self.replace(Nl80211Attr::Mac(mac))
+            .replace(Nl80211Attr::MacMask(mask))
+    }
+}
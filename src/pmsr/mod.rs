@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+#[cfg(feature = "events")]
+mod event;
+mod ftm;
+mod handle;
+mod responder_stats;
+mod start;
+
+pub use self::attr::{
+    Nl80211PeerMeasurementAttr, Nl80211PmsrPeerAttr, Nl80211PmsrReqAttr,
+    Nl80211PmsrReqData, Nl80211PmsrRespAttr, Nl80211PmsrRespData,
+    Nl80211PmsrStatus, Nl80211PmsrTypeCapa,
+};
+#[cfg(feature = "events")]
+pub use self::event::{
+    Nl80211PeerMeasurementEvent, Nl80211PeerMeasurementEventInfo,
+    Nl80211PmsrResult,
+};
+pub use self::ftm::{
+    Nl80211PmsrFtmCapa, Nl80211PmsrFtmFailureReason, Nl80211PmsrFtmReq,
+    Nl80211PmsrFtmResp, Nl80211Preamble,
+};
+pub use self::handle::{Nl80211PeerMeasurement, Nl80211PeerMeasurementHandle};
+pub use self::responder_stats::{
+    Nl80211FtmResponderStats, Nl80211FtmResponderStatsRequest,
+};
+pub use self::start::Nl80211PeerMeasurementStartRequest;
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use futures::{future, TryStream, TryStreamExt};
+use netlink_packet_core::NLM_F_REQUEST;
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::{write_u32, write_u64},
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const NL80211_FTM_STATS_SUCCESS_NUM: u16 = 1;
+const NL80211_FTM_STATS_PARTIAL_NUM: u16 = 2;
+const NL80211_FTM_STATS_FAILED_NUM: u16 = 3;
+const NL80211_FTM_STATS_ASAP_NUM: u16 = 4;
+const NL80211_FTM_STATS_NON_ASAP_NUM: u16 = 5;
+const NL80211_FTM_STATS_TOTAL_DURATION_MSEC: u16 = 6;
+const NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM: u16 = 7;
+const NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM: u16 = 8;
+const NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM: u16 = 9;
+
+/// Statistics of FTM responder running on AP interface
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211FtmResponderStats {
+    /// Number of FTM sessions in which all frames were successfully answered
+    SuccessNum(u32),
+    /// Number of FTM sessions in which part of frames were successfully
+    /// answered
+    PartialNum(u32),
+    /// Number of failed FTM sessions
+    FailedNum(u32),
+    /// Number of ASAP FTM sessions
+    AsapNum(u32),
+    /// Number of non-ASAP FTM sessions
+    NonAsapNum(u32),
+    /// Total sessions durations in milliseconds
+    TotalDurationMsec(u64),
+    /// Number of unknown FTM triggers, triggers from initiators that did
+    /// not finish successful negotiation
+    UnknownTriggersNum(u32),
+    /// Number of FTM reschedule requests that initiator sent after
+    /// successful negotiation
+    RescheduleRequestsNum(u32),
+    /// Number of FTM triggers out of scheduled window
+    OutOfWindowTriggersNum(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211FtmResponderStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::TotalDurationMsec(_) => 8,
+            Self::SuccessNum(_)
+            | Self::PartialNum(_)
+            | Self::FailedNum(_)
+            | Self::AsapNum(_)
+            | Self::NonAsapNum(_)
+            | Self::UnknownTriggersNum(_)
+            | Self::RescheduleRequestsNum(_)
+            | Self::OutOfWindowTriggersNum(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SuccessNum(_) => NL80211_FTM_STATS_SUCCESS_NUM,
+            Self::PartialNum(_) => NL80211_FTM_STATS_PARTIAL_NUM,
+            Self::FailedNum(_) => NL80211_FTM_STATS_FAILED_NUM,
+            Self::AsapNum(_) => NL80211_FTM_STATS_ASAP_NUM,
+            Self::NonAsapNum(_) => NL80211_FTM_STATS_NON_ASAP_NUM,
+            Self::TotalDurationMsec(_) => NL80211_FTM_STATS_TOTAL_DURATION_MSEC,
+            Self::UnknownTriggersNum(_) => {
+                NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM
+            }
+            Self::RescheduleRequestsNum(_) => {
+                NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM
+            }
+            Self::OutOfWindowTriggersNum(_) => {
+                NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::TotalDurationMsec(d) => write_u64(buffer, *d),
+            Self::SuccessNum(d)
+            | Self::PartialNum(d)
+            | Self::FailedNum(d)
+            | Self::AsapNum(d)
+            | Self::NonAsapNum(d)
+            | Self::UnknownTriggersNum(d)
+            | Self::RescheduleRequestsNum(d)
+            | Self::OutOfWindowTriggersNum(d) => write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211FtmResponderStats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_FTM_STATS_SUCCESS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_SUCCESS_NUM value {:?}",
+                    payload
+                );
+                Self::SuccessNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_PARTIAL_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_PARTIAL_NUM value {:?}",
+                    payload
+                );
+                Self::PartialNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_FAILED_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_FAILED_NUM value {:?}",
+                    payload
+                );
+                Self::FailedNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_ASAP_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_ASAP_NUM value {:?}",
+                    payload
+                );
+                Self::AsapNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_NON_ASAP_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_NON_ASAP_NUM value {:?}",
+                    payload
+                );
+                Self::NonAsapNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_TOTAL_DURATION_MSEC => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_TOTAL_DURATION_MSEC value {:?}",
+                    payload
+                );
+                Self::TotalDurationMsec(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_UNKNOWN_TRIGGERS_NUM value {:?}",
+                    payload
+                );
+                Self::UnknownTriggersNum(parse_u32(payload).context(err_msg)?)
+            }
+            NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_RESCHEDULE_REQUESTS_NUM \
+                    value {:?}",
+                    payload
+                );
+                Self::RescheduleRequestsNum(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM => {
+                let err_msg = format!(
+                    "Invalid NL80211_FTM_STATS_OUT_OF_WINDOW_TRIGGERS_NUM \
+                    value {:?}",
+                    payload
+                );
+                Self::OutOfWindowTriggersNum(
+                    parse_u32(payload).context(err_msg)?,
+                )
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub struct Nl80211FtmResponderStatsRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211FtmResponderStatsRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211FtmResponderStatsRequest { handle, if_index }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211FtmResponderStatsRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetFtmResponderStats,
+            attributes: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        let flags = NLM_F_REQUEST;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+
+    /// Execute the request and extract the
+    /// [Nl80211Attr::FtmResponderStats] of reply
+    pub async fn stats(
+        self,
+    ) -> impl TryStream<Ok = Vec<Nl80211FtmResponderStats>, Error = Nl80211Error>
+    {
+        self.execute().await.try_filter_map(|msg| {
+            future::ready(Ok(msg.payload.attributes.into_iter().find_map(
+                |attr| {
+                    if let Nl80211Attr::FtmResponderStats(stats) = attr {
+                        Some(stats)
+                    } else {
+                        None
+                    }
+                },
+            )))
+        })
+    }
+}
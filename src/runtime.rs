@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+#[cfg(feature = "events")]
+use std::time::Instant;
+
+#[cfg(feature = "events")]
+use futures::{
+    future::{self, Either},
+    Stream, StreamExt,
+};
+
+#[cfg(feature = "tokio_socket")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+// The `async-io` timer is driven by its own reactor thread when not running
+// inside `async_io::block_on()`, hence works with any executor.
+#[cfg(all(feature = "smol_socket", not(feature = "tokio_socket")))]
+pub(crate) async fn sleep(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+// Return `Err(())` on timeout or end of events
+#[cfg(feature = "events")]
+pub(crate) async fn wait_event<S, I, T, F>(
+    events: &mut std::pin::Pin<&mut S>,
+    deadline: Instant,
+    mut matcher: F,
+) -> Result<T, ()>
+where
+    S: Stream<Item = I>,
+    F: FnMut(&I) -> Option<T>,
+{
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(());
+        }
+        let delay = Box::pin(sleep(deadline - now));
+        match future::select(delay, events.next()).await {
+            Either::Left(_) | Either::Right((None, _)) => return Err(()),
+            Either::Right((Some(msg), _)) => {
+                if let Some(ret) = matcher(&msg) {
+                    return Ok(ret);
+                }
+            }
+        }
+    }
+}
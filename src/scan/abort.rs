@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+pub struct Nl80211ScanAbortRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+}
+
+impl Nl80211ScanAbortRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Nl80211ScanAbortRequest { handle, if_index }
+    }
+
+    /// Kernel replies `ENOENT` when no scan is running on the interface,
+    /// which is reported as [Nl80211Error::NotFound], while `EBUSY` is
+    /// reported as [Nl80211Error::Busy].
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211ScanAbortRequest {
+            mut handle,
+            if_index,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::AbortScan,
+            attributes: vec![Nl80211Attr::IfIndex(if_index)],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -4,23 +4,24 @@ use anyhow::Context;
 
 use netlink_packet_utils::{
     nla::{Nla, NlasIterator},
-    parsers::{parse_string, parse_u32},
+    parsers::parse_u32,
     DecodeError, Emitable, Parseable,
 };
 
 use crate::bytes::write_u32;
 #[cfg(doc)]
 use crate::Nl80211Attr;
+use crate::{Nl80211Freq, Nl80211Ssid};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Nla80211ScanSsidNla {
     index: u16,
-    ssid: String,
+    ssid: Nl80211Ssid,
 }
 
 impl Nla for Nla80211ScanSsidNla {
     fn value_len(&self) -> usize {
-        self.ssid.len()
+        self.ssid.as_bytes().len()
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
@@ -44,13 +45,13 @@ impl std::ops::Deref for Nla80211ScanSsidNlas {
     }
 }
 
-impl From<&Vec<String>> for Nla80211ScanSsidNlas {
-    fn from(ssids: &Vec<String>) -> Self {
+impl From<&Vec<Nl80211Ssid>> for Nla80211ScanSsidNlas {
+    fn from(ssids: &Vec<Nl80211Ssid>) -> Self {
         let mut nlas = Vec::new();
         for (i, ssid) in ssids.iter().enumerate() {
             let nla = Nla80211ScanSsidNla {
                 index: i as u16,
-                ssid: ssid.to_string(),
+                ssid: ssid.clone(),
             };
             nlas.push(nla);
         }
@@ -58,7 +59,7 @@ impl From<&Vec<String>> for Nla80211ScanSsidNlas {
     }
 }
 
-impl From<Nla80211ScanSsidNlas> for Vec<String> {
+impl From<Nla80211ScanSsidNlas> for Vec<Nl80211Ssid> {
     fn from(ssids: Nla80211ScanSsidNlas) -> Self {
         let mut ssids = ssids;
         ssids.0.drain(..).map(|c| c.ssid).collect()
@@ -71,8 +72,7 @@ impl Nla80211ScanSsidNlas {
         for (index, nla) in NlasIterator::new(payload).enumerate() {
             let error_msg = format!("Invalid NL80211_ATTR_SCAN_SSIDS: {nla:?}");
             let nla = &nla.context(error_msg.clone())?;
-            let ssid = parse_string(nla.value())
-                .context(format!("Invalid NL80211_ATTR_SCAN_SSIDS: {nla:?}"))?;
+            let ssid = Nl80211Ssid::from(nla.value());
             ssids.push(Nla80211ScanSsidNla {
                 index: index as u16,
                 ssid,
@@ -86,6 +86,7 @@ bitflags::bitflags! {
     /// Scan request control flags
     // Kernel data type: enum nl80211_scan_flags
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211ScanFlags: u32 {
         /// Scan request has low priority
@@ -189,7 +190,7 @@ impl Emitable for Nl80211ScanFlags {
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Nla80211ScanFreqNla {
     index: u16,
-    freq: u32,
+    freq: Nl80211Freq,
 }
 
 impl Nla for Nla80211ScanFreqNla {
@@ -198,7 +199,7 @@ impl Nla for Nla80211ScanFreqNla {
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
-        write_u32(buffer, self.freq)
+        write_u32(buffer, self.freq.mhz())
     }
 
     fn kind(&self) -> u16 {
@@ -216,8 +217,8 @@ impl std::ops::Deref for Nla80211ScanFreqNlas {
     }
 }
 
-impl From<&Vec<u32>> for Nla80211ScanFreqNlas {
-    fn from(freqs: &Vec<u32>) -> Self {
+impl From<&Vec<Nl80211Freq>> for Nla80211ScanFreqNlas {
+    fn from(freqs: &Vec<Nl80211Freq>) -> Self {
         let mut nlas = Vec::new();
         for (i, freq) in freqs.iter().enumerate() {
             let nla = Nla80211ScanFreqNla {
@@ -230,7 +231,7 @@ impl From<&Vec<u32>> for Nla80211ScanFreqNlas {
     }
 }
 
-impl From<Nla80211ScanFreqNlas> for Vec<u32> {
+impl From<Nla80211ScanFreqNlas> for Vec<Nl80211Freq> {
     fn from(freqs: Nla80211ScanFreqNlas) -> Self {
         let mut freqs = freqs;
         freqs.0.drain(..).map(|c| c.freq).collect()
@@ -249,7 +250,7 @@ impl Nla80211ScanFreqNlas {
             ))?;
             freqs.push(Nla80211ScanFreqNla {
                 index: index as u16,
-                freq,
+                freq: Nl80211Freq::from_mhz(freq),
             });
         }
         Ok(Self(freqs))
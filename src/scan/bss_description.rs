@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::Emitable;
+
+use crate::{
+    frequency_to_band, frequency_to_channel, mbm_to_dbm, Nl80211AkmSuite,
+    Nl80211BandType, Nl80211BootTime, Nl80211BssCapabilities, Nl80211BssEntry,
+    Nl80211BssInfo, Nl80211Element, Nl80211ElementRsn, Nl80211Elements,
+    Nl80211Freq, Nl80211IeFingerprint, Nl80211Ssid,
+};
+
+const ETH_ALEN: usize = 6;
+// Microsoft OUI with vendor type 1 used by WPA (pre-RSN) element
+const WPA_VENDOR_PREFIX: [u8; 4] = [0x00, 0x50, 0xf2, 0x01];
+
+/// Security type of BSS guessed from RSN element, WPA vendor element and
+/// privacy capability bit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211BssSecurity {
+    #[default]
+    Open,
+    Wep,
+    /// Pre-RSN WPA (WPA1)
+    Wpa,
+    /// RSN with PSK authentication
+    Wpa2Personal,
+    /// RSN with both PSK and SAE authentication
+    Wpa2Wpa3Personal,
+    /// RSN with SAE authentication
+    Wpa3Personal,
+    /// RSN with IEEE 802.1X authentication
+    Enterprise,
+    /// Opportunistic Wireless Encryption
+    Owe,
+    /// RSN with AKM suites not covered by other variants
+    Other,
+}
+
+/// Typed view of the [Nl80211BssInfo] list of a scan result.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211BssDescription {
+    pub bssid: [u8; ETH_ALEN],
+    pub ssid: Option<Nl80211Ssid>,
+    /// Channel frequency including the kHz offset if any
+    pub frequency: Option<Nl80211Freq>,
+    /// Signal strength in mBm (100 * dBm)
+    pub signal_mbm: Option<i32>,
+    /// Signal strength in unspecified unit, scaled to 0..100
+    pub signal_unspec: Option<u8>,
+    pub capability: Nl80211BssCapabilities,
+    /// Beacon interval in TU (1024 microseconds)
+    pub beacon_interval: Option<u16>,
+    /// TSF timer(microseconds) of the BSS when the frame providing
+    /// [Self::elements] was received
+    pub tsf: Option<u64>,
+    /// TSF timer(microseconds) of the BSS in last received beacon
+    pub beacon_tsf: Option<u64>,
+    /// Whether [Self::elements] and [Self::tsf] are known to come from a
+    /// probe response, otherwise they might come from either beacon or
+    /// probe response
+    pub from_probe_response: bool,
+    pub security: Nl80211BssSecurity,
+    pub rsn: Option<Nl80211ElementRsn>,
+    /// Age of this BSS entry in milliseconds
+    pub seen_ms_ago: Option<u32>,
+    /// When this entry was last updated by a received frame, accurate to
+    /// about 10ms
+    pub last_seen: Option<Nl80211BootTime>,
+    pub status: Option<u32>,
+    /// Information elements from probe response or beacon
+    pub elements: Vec<Nl80211Element>,
+    /// The information elements of most recent probe response or beacon
+    /// emitted back to wire format, for hashing or forwarding. Elements
+    /// failed to parse or unknown to this crate are kept byte for byte,
+    /// the others keep their ID and length but might be normalized, for
+    /// example 5.5 Mb/s rate is emitted as 5 Mb/s.
+    pub raw_information_elements: Option<Vec<u8>>,
+}
+
+impl Nl80211BssDescription {
+    /// Signal strength in dBm, the fractional part is truncated, use
+    /// [Self::signal_dbm_f32()] for the precise value.
+    pub fn signal_dbm(&self) -> Option<i32> {
+        self.signal_dbm_f32().map(|d| d as i32)
+    }
+
+    /// Signal strength in dBm
+    pub fn signal_dbm_f32(&self) -> Option<f32> {
+        self.signal_mbm.map(mbm_to_dbm)
+    }
+
+    /// IEEE 802.11 channel number of [Self::frequency]
+    pub fn channel(&self) -> Option<u32> {
+        self.frequency.and_then(frequency_to_channel)
+    }
+
+    /// Band of [Self::frequency]
+    pub fn band(&self) -> Option<Nl80211BandType> {
+        self.frequency.and_then(frequency_to_band)
+    }
+
+    /// Fingerprint of [Self::raw_information_elements] for identifying
+    /// the AP model, see [Nl80211IeFingerprint]
+    pub fn ie_fingerprint(&self) -> Option<Nl80211IeFingerprint> {
+        self.raw_information_elements
+            .as_deref()
+            .map(Nl80211IeFingerprint::from_ies)
+    }
+
+    /// BSSID in `xx:xx:xx:xx:xx:xx` format
+    pub fn bssid_str(&self) -> String {
+        self.bssid
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<Vec<String>>()
+            .join(":")
+    }
+
+    /// SSID safe for display, see [Nl80211Ssid::escaped()]
+    pub fn ssid_escaped(&self) -> Option<String> {
+        self.ssid.as_ref().map(|s| s.escaped())
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.security != Nl80211BssSecurity::Open
+    }
+
+    fn guess_security(&self) -> Nl80211BssSecurity {
+        if let Some(rsn) = self.rsn.as_ref() {
+            let mut psk = false;
+            let mut sae = false;
+            let mut eap = false;
+            let mut owe = false;
+            for akm in rsn.akm_suites.as_slice() {
+                match akm {
+                    Nl80211AkmSuite::Psk
+                    | Nl80211AkmSuite::FtPsk
+                    | Nl80211AkmSuite::PskSha256
+                    | Nl80211AkmSuite::PskSha384
+                    | Nl80211AkmSuite::FtPskSha384 => psk = true,
+                    Nl80211AkmSuite::Sae
+                    | Nl80211AkmSuite::FtSae
+                    | Nl80211AkmSuite::SaeGroupDependentHash
+                    | Nl80211AkmSuite::FtSaeGroupDependentHash => sae = true,
+                    Nl80211AkmSuite::Ieee8021x
+                    | Nl80211AkmSuite::FtIeee8021x
+                    | Nl80211AkmSuite::Ieee8021xSha256
+                    | Nl80211AkmSuite::Ieee8021xSuiteB
+                    | Nl80211AkmSuite::Ieee8021xCnsa
+                    | Nl80211AkmSuite::FtIeee8021xSha384 => eap = true,
+                    Nl80211AkmSuite::Owe => owe = true,
+                    _ => (),
+                }
+            }
+            match (psk, sae, eap, owe) {
+                (_, _, true, _) => Nl80211BssSecurity::Enterprise,
+                (true, true, _, _) => Nl80211BssSecurity::Wpa2Wpa3Personal,
+                (false, true, _, _) => Nl80211BssSecurity::Wpa3Personal,
+                (true, false, _, _) => Nl80211BssSecurity::Wpa2Personal,
+                (_, _, _, true) => Nl80211BssSecurity::Owe,
+                _ => Nl80211BssSecurity::Other,
+            }
+        } else if self.elements.iter().any(|e| {
+            matches!(e, Nl80211Element::Vendor(v)
+                if v.starts_with(&WPA_VENDOR_PREFIX))
+        }) {
+            Nl80211BssSecurity::Wpa
+        } else if self.capability.contains(Nl80211BssCapabilities::Privacy) {
+            Nl80211BssSecurity::Wep
+        } else {
+            Nl80211BssSecurity::Open
+        }
+    }
+}
+
+impl From<&[Nl80211BssInfo]> for Nl80211BssDescription {
+    fn from(infos: &[Nl80211BssInfo]) -> Self {
+        let mut ret = Self::default();
+        let mut freq_mhz = None;
+        let mut offset_khz = 0;
+        let mut ies = None;
+        let mut beacon_ies = None;
+        for info in infos {
+            match info {
+                Nl80211BssInfo::Bssid(v) => ret.bssid = *v,
+                Nl80211BssInfo::Frequency(d) => freq_mhz = Some(*d),
+                Nl80211BssInfo::FrequencyOffset(d) => offset_khz = *d,
+                Nl80211BssInfo::SignalMbm(d) => ret.signal_mbm = Some(*d),
+                Nl80211BssInfo::SignalUnspec(d) => ret.signal_unspec = Some(*d),
+                Nl80211BssInfo::Capability(d) => ret.capability = *d,
+                Nl80211BssInfo::BeaconInterval(d) => {
+                    ret.beacon_interval = Some(*d)
+                }
+                Nl80211BssInfo::Tsf(d) => ret.tsf = Some(*d),
+                Nl80211BssInfo::BeaconTsf(d) => ret.beacon_tsf = Some(*d),
+                Nl80211BssInfo::ProbeResponseInformationElements(_) => {
+                    ret.from_probe_response = true
+                }
+                Nl80211BssInfo::SeenMsAgo(d) => ret.seen_ms_ago = Some(*d),
+                Nl80211BssInfo::Status(d) => ret.status = Some(*d),
+                Nl80211BssInfo::LastSeenBootTime(d) => {
+                    ret.last_seen = Some(Nl80211BootTime::from(*d))
+                }
+                Nl80211BssInfo::InformationElements(v) => ies = Some(v),
+                Nl80211BssInfo::BeaconInformationElements(v) => {
+                    beacon_ies = Some(v)
+                }
+                _ => (),
+            }
+        }
+        ret.frequency = freq_mhz
+            .map(|mhz| Nl80211Freq::from_mhz_and_offset(mhz, offset_khz));
+        ret.raw_information_elements = ies.map(|v| {
+            let elements = Nl80211Elements::from(v);
+            let mut buffer = vec![0u8; elements.buffer_len()];
+            elements.emit(&mut buffer);
+            buffer
+        });
+        ret.elements = ies.or(beacon_ies).cloned().unwrap_or_default();
+        for element in ret.elements.as_slice() {
+            match element {
+                Nl80211Element::Ssid(s) => ret.ssid = Some(s.clone()),
+                Nl80211Element::Rsn(v) => ret.rsn = Some(v.clone()),
+                _ => (),
+            }
+        }
+        ret.security = ret.guess_security();
+        ret
+    }
+}
+
+impl From<&Vec<Nl80211BssInfo>> for Nl80211BssDescription {
+    fn from(infos: &Vec<Nl80211BssInfo>) -> Self {
+        Self::from(infos.as_slice())
+    }
+}
+
+impl From<&Nl80211BssEntry> for Nl80211BssDescription {
+    fn from(entry: &Nl80211BssEntry) -> Self {
+        Self::from(entry.bss.as_slice())
+    }
+}
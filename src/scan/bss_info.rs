@@ -47,6 +47,7 @@ use crate::{
 bitflags::bitflags! {
     /// IEEE 802.11-202, 9.4.1.4 Capability Information field
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211BssCapabilities: u16 {
         const Ess = 1 << 0;
@@ -88,6 +89,7 @@ impl Emitable for Nl80211BssCapabilities {
 
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211BssUseFor: u32 {
         const Normal = 1 << 0;
@@ -119,6 +121,46 @@ impl Emitable for Nl80211BssUseFor {
     }
 }
 
+const NL80211_BSS_CANNOT_USE_NSTR_NONPRIMARY: u64 = 1 << 0;
+const NL80211_BSS_CANNOT_USE_6GHZ_PWR_MISMATCH: u64 = 1 << 1;
+
+bitflags::bitflags! {
+    /// Reasons why the BSS cannot be used for normal connection
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211BssCannotUseReasons: u64 {
+        /// NSTR nonprimary links aren't supported by the device
+        const NstrNonprimary = NL80211_BSS_CANNOT_USE_NSTR_NONPRIMARY;
+        /// 6 GHz power type mismatch between the BSS and the client
+        const Pwr6GhzMismatch = NL80211_BSS_CANNOT_USE_6GHZ_PWR_MISMATCH;
+        const _ = !0;
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211BssCannotUseReasons {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf: &[u8] = buf.as_ref();
+        Ok(Self::from_bits_retain(parse_u64(buf).context(format!(
+            "Invalid Nl80211BssCannotUseReasons payload {buf:?}"
+        ))?))
+    }
+}
+
+impl Nl80211BssCannotUseReasons {
+    pub const LENGTH: usize = 8;
+}
+
+impl Emitable for Nl80211BssCannotUseReasons {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer.copy_from_slice(&self.bits().to_ne_bytes())
+    }
+}
+
 const ETH_ALEN: usize = 6;
 
 const NL80211_BSS_BSSID: u16 = 1;
@@ -144,15 +186,17 @@ const NL80211_BSS_FREQUENCY_OFFSET: u16 = 20;
 //NL80211_BSS_MLO_LINK_ID 21,
 //NL80211_BSS_MLD_ADDR 22 ,
 const NL80211_BSS_USE_FOR: u16 = 23;
-//NL80211_BSS_CANNOT_USE_REASONS 24,
+const NL80211_BSS_CANNOT_USE_REASONS: u16 = 24;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211BssInfo {
     Bssid([u8; ETH_ALEN]),
     /// Frequency in MHz
     Frequency(u32),
     /// Timing Synchronization Function (TSF) of received probe response/beacon
-    /// in microsecond(μs).
+    /// in microsecond(μs). This is the timer of the BSS, not related to any
+    /// clock of local system.
     Tsf(u64),
     /// Beacon interval of the (I)BSS
     BeaconInterval(u16),
@@ -165,14 +209,22 @@ pub enum Nl80211BssInfo {
     BeaconInformationElements(Vec<Nl80211Element>),
     ChanWidth(u32),
     BeaconTsf(u64),
+    /// Kernel sends this as flag indicating
+    /// [Self::InformationElements] and [Self::Tsf] are known to come from a
+    /// probe response, hence the list is normally empty.
     ProbeResponseInformationElements(Vec<Nl80211Element>),
     /// `CLOCK_BOOTTIME` timestamp when this entry was last updated by a
     /// received frame. The value is expected to be accurate to about 10ms.
-    /// (u64, nanoseconds)
+    /// (u64, nanoseconds), could be converted to wall-clock time via
+    /// [crate::Nl80211BootTime].
     LastSeenBootTime(u64),
     /// Frequency offset in KHz
     FrequencyOffset(u32),
     UseFor(Nl80211BssUseFor),
+    /// Only included when requested by
+    /// [crate::Nl80211ScanGetRequest::include_use_data()]
+    CannotUseReasons(Nl80211BssCannotUseReasons),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -196,6 +248,7 @@ impl Nla for Nl80211BssInfo {
             }
             Self::Capability(_) => Nl80211BssCapabilities::LENGTH,
             Self::UseFor(_) => Nl80211BssUseFor::LENGTH,
+            Self::CannotUseReasons(_) => Nl80211BssCannotUseReasons::LENGTH,
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -219,6 +272,7 @@ impl Nla for Nl80211BssInfo {
             Self::LastSeenBootTime(_) => NL80211_BSS_LAST_SEEN_BOOTTIME,
             Self::FrequencyOffset(_) => NL80211_BSS_FREQUENCY_OFFSET,
             Self::UseFor(_) => NL80211_BSS_USE_FOR,
+            Self::CannotUseReasons(_) => NL80211_BSS_CANNOT_USE_REASONS,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -244,6 +298,7 @@ impl Nla for Nl80211BssInfo {
             }
             Self::Capability(v) => v.emit(buffer),
             Self::UseFor(v) => v.emit(buffer),
+            Self::CannotUseReasons(v) => v.emit(buffer),
             Self::Other(ref attr) => attr.emit(buffer),
         }
     }
@@ -355,6 +410,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
             NL80211_BSS_USE_FOR => {
                 Self::UseFor(Nl80211BssUseFor::parse(payload)?)
             }
+            NL80211_BSS_CANNOT_USE_REASONS => Self::CannotUseReasons(
+                Nl80211BssCannotUseReasons::parse(payload)?,
+            ),
             _ => Self::Other(
                 DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
             ),
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future, TryStream, TryStreamExt};
+
+use crate::{
+    Nl80211Attr, Nl80211BssInfo, Nl80211Error, Nl80211Handle, Nl80211IfIndex,
+    Nl80211Message, Nl80211ScanGetRequest, Nl80211WdevId,
+};
+
+/// BSS reported by `NL80211_CMD_GET_SCAN` dump, annotated with the interface
+/// it was discovered on.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211BssEntry {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Scan generation, changes whenever the BSS list of the interface
+    /// changed during the dump
+    pub generation: Option<u32>,
+    pub bss: Vec<Nl80211BssInfo>,
+}
+
+impl Nl80211BssEntry {
+    /// Return `None` if specified message has no [Nl80211Attr::Bss].
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let mut entry = Self::default();
+        let mut bss = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    entry.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => {
+                    entry.wdev = Some(Nl80211WdevId::new(*d))
+                }
+                Nl80211Attr::Generation(d) => entry.generation = Some(*d),
+                Nl80211Attr::Bss(v) => bss = Some(v.clone()),
+                _ => (),
+            }
+        }
+        entry.bss = bss?;
+        Some(entry)
+    }
+}
+
+pub struct Nl80211ScanBssListRequest {
+    request: Nl80211ScanGetRequest,
+}
+
+impl Nl80211ScanBssListRequest {
+    pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
+        Self {
+            request: Nl80211ScanGetRequest::new(handle, if_index),
+        }
+    }
+
+    /// Please refer to [Nl80211ScanGetRequest::include_use_data()].
+    pub fn include_use_data(mut self) -> Self {
+        self.request = self.request.include_use_data();
+        self
+    }
+
+    /// Stream of BSS entries found in all the parts of the dump reply.
+    /// Use [TryStreamExt::try_collect()] to gather them into a
+    /// `Vec<Nl80211BssEntry>`.
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = Nl80211BssEntry, Error = Nl80211Error> {
+        self.request.execute().await.try_filter_map(|msg| {
+            future::ready(Ok(Nl80211BssEntry::from_message(&msg.payload)))
+        })
+    }
+}
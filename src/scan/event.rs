@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message, Nl80211WdevId,
+    Nl80211WiphyIndex,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ScanEventInfo {
+    pub if_index: Option<Nl80211IfIndex>,
+    pub wiphy: Option<Nl80211WiphyIndex>,
+    pub wdev: Option<Nl80211WdevId>,
+    /// Request ID of scheduled scan, only included when wiphy supports
+    /// multiple concurrent scheduled scans
+    pub cookie: Option<u64>,
+}
+
+/// Scan notifications sent by kernel to the `scan` multicast group
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211ScanEvent {
+    /// Scan started
+    Triggered(Nl80211ScanEventInfo),
+    /// Scan finished, results could be retrieved by
+    /// [crate::Nl80211ScanHandle::dump()]
+    NewResults(Nl80211ScanEventInfo),
+    Aborted(Nl80211ScanEventInfo),
+    ScheduleStarted(Nl80211ScanEventInfo),
+    /// Scheduled scan found new results, could be retrieved by
+    /// [crate::Nl80211ScanHandle::dump()]
+    ScheduleResults(Nl80211ScanEventInfo),
+    /// Scheduled scan stopped by kernel or driver, or by request
+    ScheduleStopped(Nl80211ScanEventInfo),
+}
+
+impl Nl80211ScanEvent {
+    /// Return `None` if specified message is not a scan notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let variant = match msg.cmd {
+            Nl80211Command::TriggerScan => Self::Triggered,
+            Nl80211Command::NewScanResults => Self::NewResults,
+            Nl80211Command::ScanAborted => Self::Aborted,
+            Nl80211Command::StartSchedScan => Self::ScheduleStarted,
+            Nl80211Command::SchedScanResults => Self::ScheduleResults,
+            Nl80211Command::SchedScanStopped => Self::ScheduleStopped,
+            _ => return None,
+        };
+        let mut info = Nl80211ScanEventInfo::default();
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    info.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Wiphy(d) => {
+                    info.wiphy = Some(Nl80211WiphyIndex::new(*d))
+                }
+                Nl80211Attr::Wdev(d) => {
+                    info.wdev = Some(Nl80211WdevId::new(*d))
+                }
+                Nl80211Attr::Cookie(d) => info.cookie = Some(*d),
+                _ => (),
+            }
+        }
+        Some(variant(info))
+    }
+
+    pub fn info(&self) -> &Nl80211ScanEventInfo {
+        match self {
+            Self::Triggered(i)
+            | Self::NewResults(i)
+            | Self::Aborted(i)
+            | Self::ScheduleStarted(i)
+            | Self::ScheduleResults(i)
+            | Self::ScheduleStopped(i) => i,
+        }
+    }
+}
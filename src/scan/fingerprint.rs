@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+const ELEMENT_ID_SSID: u8 = 0;
+const ELEMENT_ID_TIM: u8 = 5;
+const ELEMENT_ID_VENDOR: u8 = 221;
+const ELEMENT_ID_EXTENSION: u8 = 255;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Fingerprint of the information elements of a BSS, identifying the AP
+/// model or firmware rather than the network.
+///
+/// The signature lists elements in the received order, comma separated:
+///  * `ID:LENGTH` for most elements, for example `45:26` for HT capabilities,
+///  * `255.EXT_ID:LENGTH` for extension elements,
+///  * `221.OUI-TYPE:LENGTH` for vendor specific elements, for example
+///    `221.0050f2-04:14` for WPS,
+///  * `0` for SSID whose length depends on network rather than device,
+///  * `5` for TIM whose length varies with the buffered traffic.
+///
+/// A truncated trailing element is noted as `ID!`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211IeFingerprint(String);
+
+impl Nl80211IeFingerprint {
+    /// Build from raw information elements, for example
+    /// [crate::Nl80211BssDescription::raw_information_elements]
+    pub fn from_ies(ies: &[u8]) -> Self {
+        let mut ret = String::new();
+        let mut offset = 0;
+        while offset < ies.len() {
+            if !ret.is_empty() {
+                ret.push(',');
+            }
+            let id = ies[offset];
+            let Some(len) = ies.get(offset + 1).map(|l| *l as usize) else {
+                let _ = write!(ret, "{id}!");
+                break;
+            };
+            let Some(payload) = ies.get(offset + 2..offset + 2 + len) else {
+                let _ = write!(ret, "{id}!");
+                break;
+            };
+            let _ = match (id, payload) {
+                (ELEMENT_ID_SSID | ELEMENT_ID_TIM, _) => write!(ret, "{id}"),
+                (ELEMENT_ID_VENDOR, [o1, o2, o3, t, ..]) => {
+                    write!(ret, "{id}.{o1:02x}{o2:02x}{o3:02x}-{t:02x}:{len}")
+                }
+                (ELEMENT_ID_EXTENSION, [ext_id, ..]) => {
+                    write!(ret, "{id}.{ext_id}:{len}")
+                }
+                _ => write!(ret, "{id}:{len}"),
+            };
+            offset += 2 + len;
+        }
+        Self(ret)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// 64 bits FNV-1a hash of the signature, stable across platforms and
+    /// releases hence suitable for storing in database
+    pub fn hash64(&self) -> u64 {
+        self.0.bytes().fold(FNV_OFFSET_BASIS, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
+impl std::fmt::Display for Nl80211IeFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
@@ -12,11 +12,26 @@ use crate::{
 pub struct Nl80211ScanGetRequest {
     handle: Nl80211Handle,
     if_index: u32,
+    include_use_data: bool,
 }
 
 impl Nl80211ScanGetRequest {
     pub(crate) fn new(handle: Nl80211Handle, if_index: u32) -> Self {
-        Nl80211ScanGetRequest { handle, if_index }
+        Nl80211ScanGetRequest {
+            handle,
+            if_index,
+            include_use_data: false,
+        }
+    }
+
+    /// Include BSSes that can only be used for restricted purpose and
+    /// their [crate::Nl80211BssInfo::UseFor] and
+    /// [crate::Nl80211BssInfo::CannotUseReasons] in the dump.
+    /// Without this flag, kernel only includes BSSes usable for normal
+    /// connection.
+    pub fn include_use_data(mut self) -> Self {
+        self.include_use_data = true;
+        self
     }
 
     pub async fn execute(
@@ -26,9 +41,13 @@ impl Nl80211ScanGetRequest {
         let Nl80211ScanGetRequest {
             mut handle,
             if_index,
+            include_use_data,
         } = self;
 
-        let attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+        if include_use_data {
+            attributes.push(Nl80211Attr::BssDumpIncludeUseData);
+        }
         let nl80211_msg = Nl80211Message {
             cmd: Nl80211Command::GetScan,
             attributes,
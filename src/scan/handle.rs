@@ -1,14 +1,29 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
 use netlink_packet_utils::nla::Nla;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
 
 use crate::{
-    Nl80211Attr, Nl80211AttrsBuilder, Nl80211Handle, Nl80211ScanFlags,
-    Nl80211ScanGetRequest, Nl80211ScanScheduleRequest,
-    Nl80211ScanScheduleStopRequest, Nl80211ScanTriggerRequest,
-    Nl80211SchedScanMatch, Nl80211SchedScanPlan,
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211BandType, Nl80211Freq,
+    Nl80211Handle, Nl80211IfIndex, Nl80211ScanAbortRequest,
+    Nl80211ScanBssListRequest, Nl80211ScanFlags, Nl80211ScanGetRequest,
+    Nl80211ScanScheduleRequest, Nl80211ScanScheduleStopRequest,
+    Nl80211ScanTriggerRequest, Nl80211SchedScanMatch, Nl80211SchedScanPlan,
+    Nl80211SchedScanRssiAdjust, Nl80211Ssid,
 };
 
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211ScanEvent};
+
+const ETH_ALEN: usize = 6;
+
 #[derive(Debug, Clone)]
 pub struct Nl80211ScanHandle(Nl80211Handle);
 
@@ -19,8 +34,22 @@ impl Nl80211ScanHandle {
 
     /// Retrieve the current scan data
     /// (equivalent to `iw dev DEVICE scan dump`)
-    pub fn dump(&mut self, if_index: u32) -> Nl80211ScanGetRequest {
-        Nl80211ScanGetRequest::new(self.0.clone(), if_index)
+    pub fn dump(&mut self, if_index: Nl80211IfIndex) -> Nl80211ScanGetRequest {
+        Nl80211ScanGetRequest::new(self.0.clone(), if_index.get())
+    }
+
+    /// Retrieve the BSS list of the current scan data. Unlike [Self::dump()],
+    /// the [Nl80211Attr::Bss] of each dump reply is extracted and annotated
+    /// with the interface index and wireless device identifier.
+    /// For example:
+    /// ```no_run
+    #[doc = include_str!("../../examples/dump_nl80211_scan.rs")]
+    /// ```
+    pub fn get_bss_list(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211ScanBssListRequest {
+        Nl80211ScanBssListRequest::new(self.0.clone(), if_index.get())
     }
 
     /// Trigger a scan (equivalent to `iw dev DEVICE scan trigger`)
@@ -38,6 +67,15 @@ impl Nl80211ScanHandle {
         Nl80211ScanTriggerRequest::new(self.0.clone(), attributes)
     }
 
+    /// Abort the scan running on specified interface
+    /// (equivalent to `iw dev DEVICE scan abort`)
+    pub fn abort(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211ScanAbortRequest {
+        Nl80211ScanAbortRequest::new(self.0.clone(), if_index.get())
+    }
+
     /// Start a scan schedule (equivalent to `iw dev DEVICE scan sched_start`)
     pub fn schedule_start(
         &mut self,
@@ -46,6 +84,47 @@ impl Nl80211ScanHandle {
         Nl80211ScanScheduleRequest::new(self.0.clone(), attributes)
     }
 
+    /// Stream of scan notifications decoded from the unsolicited message
+    /// receiver of connection, see [Nl80211Handle::notifications()].
+    /// The connection socket should join the `scan` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211ScanEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211ScanEvent::from_message(&msg))
+        }))
+    }
+
+    /// Similar to [Self::events()], but only yields
+    /// [Nl80211ScanEvent::ScheduleResults] and
+    /// [Nl80211ScanEvent::ScheduleStopped] of specified interface. The stream
+    /// is not ended by [Nl80211ScanEvent::ScheduleStopped], hence it could be
+    /// reused after scheduled scan restarted.
+    #[cfg(feature = "events")]
+    pub async fn schedule_events<S>(
+        &self,
+        if_index: Nl80211IfIndex,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211ScanEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.events(messages).await?.filter(move |event| {
+            futures::future::ready(
+                matches!(
+                    event,
+                    Nl80211ScanEvent::ScheduleResults(_)
+                        | Nl80211ScanEvent::ScheduleStopped(_)
+                ) && event.info().if_index == Some(if_index),
+            )
+        }))
+    }
+
     /// Stop all scan schedule (equivalent to `iw dev DEVICE scan sched_stop`)
     pub fn schedule_stop_all(&mut self) -> Nl80211ScanScheduleStopRequest {
         Nl80211ScanScheduleStopRequest::new(self.0.clone(), Vec::new())
@@ -57,45 +136,118 @@ pub struct Nl80211Scan;
 
 impl Nl80211Scan {
     /// Perform active scan on specified interface
-    pub fn new(if_index: u32) -> Nl80211AttrsBuilder<Self> {
+    pub fn new(if_index: Nl80211IfIndex) -> Nl80211AttrsBuilder<Self> {
         Nl80211AttrsBuilder::<Self>::new()
             .if_index(if_index)
-            .ssids(vec!["".to_string()])
+            .ssids(vec![Nl80211Ssid::default()])
     }
 }
 
 impl Nl80211AttrsBuilder<Nl80211Scan> {
     /// SSIDs to send probe request during active scan.
-    /// `vec!["".to_string()]` means wildcard.
-    pub fn ssids(self, ssids: Vec<String>) -> Self {
-        self.replace(Nl80211Attr::ScanSsids(ssids))
+    /// Empty SSID means wildcard.
+    pub fn ssids<T: Into<Nl80211Ssid>>(self, ssids: Vec<T>) -> Self {
+        self.replace(Nl80211Attr::ScanSsids(
+            ssids.into_iter().map(Into::into).collect(),
+        ))
     }
 
     pub fn scan_flags(self, flags: Nl80211ScanFlags) -> Self {
         self.replace(Nl80211Attr::ScanFlags(flags))
     }
 
+    /// Use specified MAC address as the source address of probe requests of
+    /// this scan instead of the interface address.
+    /// Linux kernel only honors [Nl80211Attr::Mac] of scan request with
+    /// [Nl80211ScanFlags::RandomAddr], hence this function sets the flag
+    /// along with a all-ones [Nl80211Attr::MacMask] preserving every bit of
+    /// specified address, so no randomisation happens.
+    /// Requires [crate::Nl80211Features::ScanRandomMacAddr] (or
+    /// [crate::Nl80211Features::SchedScanRandomMacAddr] for schedule scan)
+    /// advertised by the wiphy.
+    /// The [Self::scan_flags()] invoked after this function will override the
+    /// [Nl80211ScanFlags::RandomAddr] flag.
+    pub fn source_mac(self, mac: [u8; ETH_ALEN]) -> Self {
+        self.random_mac(mac, [0xff; ETH_ALEN])
+    }
+
+    /// Randomize the source address of probe requests of this scan.
+    /// The bits set in `mask` are taken from `mac`, the others are
+    /// randomized by kernel, for example a mask of
+    /// `[0xff, 0xff, 0xff, 0, 0, 0]` preserves the OUI of `mac`.
+    /// This function also sets [Nl80211ScanFlags::RandomAddr], the
+    /// [Self::scan_flags()] invoked after this function will override it.
+    /// Requires [crate::Nl80211Features::ScanRandomMacAddr] (or
+    /// [crate::Nl80211Features::SchedScanRandomMacAddr] for schedule scan)
+    /// advertised by the wiphy.
+    pub fn random_mac(self, mac: [u8; ETH_ALEN], mask: [u8; ETH_ALEN]) -> Self {
+        let flags = match self
+            .get(Nl80211Attr::ScanFlags(Nl80211ScanFlags::empty()).kind())
+        {
+            Some(Nl80211Attr::ScanFlags(flags)) => *flags,
+            _ => Nl80211ScanFlags::empty(),
+        };
+        self.replace(Nl80211Attr::ScanFlags(
+            flags | Nl80211ScanFlags::RandomAddr,
+        ))
+        .replace(Nl80211Attr::Mac(mac))
+        .replace(Nl80211Attr::MacMask(mask))
+    }
+
+    /// Extra information elements appended to the probe requests sent
+    /// during active scan, for example vendor specific elements.
+    pub fn ie(self, value: Vec<u8>) -> Self {
+        self.replace(Nl80211Attr::Ie(value))
+    }
+
+    /// Only scan for specified BSSID, the probe requests will be sent to
+    /// this BSSID instead of broadcast address.
+    pub fn bssid(self, value: [u8; ETH_ALEN]) -> Self {
+        self.replace(Nl80211Attr::Bssid(value))
+    }
+
     /// Enable passive scan or active scan.
     /// During an active scan, the client radio transmits a probe request and
     /// listens for a probe response from an AP. With a passive scan, the client
     /// radio listens on each channel for beacons sent periodically by an AP.
     /// This method will override existing ssids defined before:
     ///  * Enable passive scan will remove all SSIDs
-    ///  * Disable passive scan will replace existing SSIDs with
-    ///    `vec!["".to_string()]`
+    ///  * Disable passive scan will replace existing SSIDs with wildcard SSID
     pub fn passive(self, value: bool) -> Self {
         if value {
             self.remove(Nl80211Attr::ScanSsids(Vec::new()).kind())
         } else {
-            self.replace(Nl80211Attr::ScanSsids(vec!["".to_string()]))
+            self.replace(Nl80211Attr::ScanSsids(vec![Nl80211Ssid::default()]))
+        }
+    }
+
+    /// Do not send probe requests at CCK rates(1, 2, 5.5 and 11 Mbps),
+    /// required by P2P and reducing airtime of scan on 2.4 GHz band.
+    pub fn no_cck(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::TxNoCckRate)
+        } else {
+            self.remove(Nl80211Attr::TxNoCckRate.kind())
         }
     }
 
-    /// Duration in unit of TU(1024 microseconds(µs)
+    /// Duration in unit of TU(1024 microseconds(µs)) of scanning each
+    /// channel
     pub fn duration(self, value: u16) -> Self {
         self.replace(Nl80211Attr::MeasurementDuration(value))
     }
 
+    /// Whether the [Self::duration()] is mandatory. When enabled, the kernel
+    /// rejects the scan if the driver cannot scan each channel for exactly
+    /// the requested duration, otherwise the duration is a hint only.
+    pub fn duration_mandatory(self, value: bool) -> Self {
+        if value {
+            self.replace(Nl80211Attr::MeasurementDurationMandatory)
+        } else {
+            self.remove(Nl80211Attr::MeasurementDurationMandatory.kind())
+        }
+    }
+
     /// Scan interval in millisecond(ms), only available for schedule scan
     pub fn interval(self, value: u32) -> Self {
         self.replace(Nl80211Attr::SchedScanInterval(value))
@@ -109,8 +261,15 @@ impl Nl80211AttrsBuilder<Nl80211Scan> {
     }
 
     /// Scan frequencies in MHz.
+    pub fn scan_frequencies(self, freqs: Vec<u32>) -> Self {
+        self.replace(Nl80211Attr::ScanFrequencies(
+            freqs.into_iter().map(Nl80211Freq::from_mhz).collect(),
+        ))
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use scan_frequencies")]
     pub fn scan_frequncies(self, freqs: Vec<u32>) -> Self {
-        self.replace(Nl80211Attr::ScanFrequencies(freqs))
+        self.scan_frequencies(freqs)
     }
 
     /// Sets of attributes to match during scheduled scans. Only BSSs
@@ -130,6 +289,21 @@ impl Nl80211AttrsBuilder<Nl80211Scan> {
         self.replace(Nl80211Attr::SchedScanMatch(matches))
     }
 
+    /// Only report BSSes with RSSI better than the current connected BSS by
+    /// `value` dB, only available for schedule scan while connected.
+    pub fn relative_rssi(self, value: i8) -> Self {
+        self.replace(Nl80211Attr::SchedScanRelativeRssi(value))
+    }
+
+    /// Adjust RSSI of BSSes on specified band by `delta` dB before
+    /// comparing with [Self::relative_rssi()], for example to prefer 5 GHz
+    /// BSS.
+    pub fn rssi_adjust(self, band: Nl80211BandType, delta: i8) -> Self {
+        self.replace(Nl80211Attr::SchedScanRssiAdjust(
+            Nl80211SchedScanRssiAdjust::new(band, delta),
+        ))
+    }
+
     /// A list of scan plans for scheduled scan. Each scan plan defines the
     /// number of scan iterations and the interval between scans. The last scan
     /// plan will always run infinitely, thus it must not specify the number of
@@ -1,21 +1,34 @@
 // SPDX-License-Identifier: MIT
 
+mod abort;
 mod attr;
+mod bss_description;
 mod bss_info;
+mod bss_list;
+#[cfg(feature = "events")]
+mod event;
+mod fingerprint;
 mod get;
 mod handle;
 mod schedule;
 mod trigger;
 
+pub use self::abort::Nl80211ScanAbortRequest;
 pub use self::attr::Nl80211ScanFlags;
+pub use self::bss_description::{Nl80211BssDescription, Nl80211BssSecurity};
 pub use self::bss_info::{
-    Nl80211BssCapabilities, Nl80211BssInfo, Nl80211BssUseFor,
+    Nl80211BssCannotUseReasons, Nl80211BssCapabilities, Nl80211BssInfo,
+    Nl80211BssUseFor,
 };
+pub use self::bss_list::{Nl80211BssEntry, Nl80211ScanBssListRequest};
+#[cfg(feature = "events")]
+pub use self::event::{Nl80211ScanEvent, Nl80211ScanEventInfo};
+pub use self::fingerprint::Nl80211IeFingerprint;
 pub use self::get::Nl80211ScanGetRequest;
 pub use self::handle::{Nl80211Scan, Nl80211ScanHandle};
 pub use self::schedule::{
     Nl80211ScanScheduleRequest, Nl80211ScanScheduleStopRequest,
-    Nl80211SchedScanMatch, Nl80211SchedScanPlan,
+    Nl80211SchedScanMatch, Nl80211SchedScanPlan, Nl80211SchedScanRssiAdjust,
 };
 pub use self::trigger::Nl80211ScanTriggerRequest;
 
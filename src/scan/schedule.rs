@@ -6,14 +6,14 @@ use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
-    parsers::{parse_i32, parse_string, parse_u32},
+    parsers::{parse_i32, parse_u32},
     DecodeError, Emitable, Parseable,
 };
 
 use crate::{
     bytes::{write_i32, write_u32},
-    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    nl80211_execute, Nl80211Attr, Nl80211BandType, Nl80211Command,
+    Nl80211Error, Nl80211Handle, Nl80211Message, Nl80211Ssid,
 };
 
 #[derive(Debug, Clone)]
@@ -94,10 +94,11 @@ const NL80211_SCHED_SCAN_MATCH_ATTR_BSSID: u16 = 5;
 // const NL80211_SCHED_SCAN_MATCH_PER_BAND_RSSI: u16 = 6;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211SchedScanMatch {
     /// SSID to be used for matching. Cannot use with
     /// [Nl80211SchedScanMatch::Bssid].
-    Ssid(String),
+    Ssid(Nl80211Ssid),
     /// RSSI threshold (in dBm) for reporting a BSS in scan results. Filtering
     /// is turned off if not specified. Note that if this attribute is in a
     /// match set of its own, then it is treated as the default value for all
@@ -110,13 +111,14 @@ pub enum Nl80211SchedScanMatch {
     /// BSSID to be used for matching. Cannot use with
     /// [Nl80211SchedScanMatch::Ssid].
     Bssid([u8; ETH_ALEN]),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
 impl Nla for Nl80211SchedScanMatch {
     fn value_len(&self) -> usize {
         match self {
-            Self::Ssid(v) => v.len(),
+            Self::Ssid(v) => v.as_bytes().len(),
             Self::Bssid(_) => ETH_ALEN,
             Self::Rssi(_) => 4,
             Self::Other(v) => v.value_len(),
@@ -149,11 +151,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         let payload = buf.value();
         Ok(match buf.kind() {
             NL80211_SCHED_SCAN_MATCH_ATTR_SSID => {
-                let err_msg = format!(
-                    "Invalid NL80211_SCHED_SCAN_MATCH_ATTR_SSID value {:?}",
-                    payload
-                );
-                Self::Ssid(parse_string(payload).context(err_msg)?)
+                Self::Ssid(Nl80211Ssid::from(payload))
             }
             NL80211_SCHED_SCAN_MATCH_ATTR_RSSI => {
                 let err_msg = format!(
@@ -185,6 +183,7 @@ const NL80211_SCHED_SCAN_PLAN_INTERVAL: u16 = 1;
 const NL80211_SCHED_SCAN_PLAN_ITERATIONS: u16 = 2;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211SchedScanPlan {
     /// Interval between scan iterations in seconds.
     Interval(u32),
@@ -192,6 +191,7 @@ pub enum Nl80211SchedScanPlan {
     /// must not specify this attribute because it will run infinitely. A value
     /// of zero is invalid as it will make the scan plan meaningless.
     Iterations(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -246,3 +246,50 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         })
     }
 }
+
+/// RSSI adjustment of BSSes on specified band when comparing against
+/// [crate::Nl80211Attr::SchedScanRelativeRssi]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211SchedScanRssiAdjust {
+    pub band: Nl80211BandType,
+    /// RSSI delta in dB added to the BSSes on this band
+    pub delta: i8,
+}
+
+impl Nl80211SchedScanRssiAdjust {
+    pub const LENGTH: usize = 2;
+
+    pub fn new(band: Nl80211BandType, delta: i8) -> Self {
+        Self { band, delta }
+    }
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid NL80211_ATTR_SCHED_SCAN_RSSI_ADJUST, \
+                expecting length {} but got {}: {payload:?}",
+                Self::LENGTH,
+                payload.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                band: Nl80211BandType::from(payload[0] as u16),
+                delta: payload[1] as i8,
+            })
+        }
+    }
+}
+
+impl Emitable for Nl80211SchedScanRssiAdjust {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = u16::from(self.band) as u8;
+        buffer[1] = self.delta as u8;
+    }
+}
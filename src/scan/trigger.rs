@@ -1,12 +1,12 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use futures::{TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
-    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    nl80211_execute_shared, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211Handle, Nl80211Message,
 };
 
 pub struct Nl80211ScanTriggerRequest {
@@ -22,6 +22,8 @@ impl Nl80211ScanTriggerRequest {
         Nl80211ScanTriggerRequest { handle, attributes }
     }
 
+    /// The `EBUSY` reply of kernel, which means another scan is running on
+    /// the wiphy, is reported as [Nl80211Error::ScanInProgress].
     pub async fn execute(
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
@@ -31,12 +33,156 @@ impl Nl80211ScanTriggerRequest {
             attributes,
         } = self;
 
-        let nl80211_msg = Nl80211Message {
-            cmd: Nl80211Command::TriggerScan,
+        trigger_scan(&mut handle, attributes).await
+    }
+
+    /// Trigger the scan, and when another scan is in progress, wait for the
+    /// scan on the same wiphy to finish or abort and retry once. Return
+    /// [Nl80211Error::ScanInProgress] if the scan is still refused after
+    /// retry or [Nl80211Error::Timeout] if the running scan did not finish
+    /// within `timeout`.
+    ///
+    /// The `events` could be generated by [crate::Nl80211ScanHandle::events()]
+    /// and should be created before invoking this function, the connection
+    /// socket should join the `scan` multicast group, see
+    /// [crate::subscribe_scan()].
+    #[cfg(all(
+        feature = "events",
+        any(feature = "tokio_socket", feature = "smol_socket")
+    ))]
+    pub async fn execute_retry_on_busy<S>(
+        self,
+        events: S,
+        timeout: std::time::Duration,
+    ) -> Result<Vec<GenlMessage<Nl80211Message>>, Nl80211Error>
+    where
+        S: futures::Stream<Item = crate::Nl80211ScanEvent>,
+    {
+        use crate::{
+            Nl80211IfIndex, Nl80211ScanEvent, Nl80211ScanEventInfo,
+            Nl80211WdevId,
+        };
+
+        let deadline = std::time::Instant::now() + timeout;
+        let Nl80211ScanTriggerRequest {
+            mut handle,
             attributes,
+        } = self;
+        // Shared by the retry instead of cloning every attribute
+        let attributes: std::sync::Arc<[Nl80211Attr]> = attributes.into();
+        let if_index = attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::IfIndex(d) => Some(Nl80211IfIndex::new(*d)),
+            _ => None,
+        });
+        let wdev = attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::Wdev(d) => Some(Nl80211WdevId::new(*d)),
+            _ => None,
+        });
+        futures::pin_mut!(events);
+
+        match trigger_scan(&mut handle, attributes.clone())
+            .await
+            .try_collect()
+            .await
+        {
+            Err(Nl80211Error::ScanInProgress(_)) => {
+                log::debug!(
+                    "Scan in progress on interface {if_index:?}, waiting for \
+                    it to finish"
+                );
+            }
+            result => return result,
+        }
+
+        // The running scan might be triggered on other interface of the same
+        // wiphy, hence scan completion of any interface of that wiphy is
+        // accepted.
+        let wiphy = get_wiphy_index(&mut handle, if_index, wdev).await?;
+        let is_same_device = |info: &Nl80211ScanEventInfo| {
+            (wiphy.is_some() && info.wiphy == wiphy)
+                || (if_index.is_some() && info.if_index == if_index)
+                || (wdev.is_some() && info.wdev == wdev)
         };
-        let flags = NLM_F_REQUEST | NLM_F_ACK;
+        crate::runtime::wait_event(
+            &mut events,
+            deadline,
+            |event| match event {
+                Nl80211ScanEvent::NewResults(info)
+                | Nl80211ScanEvent::Aborted(info)
+                    if is_same_device(info) =>
+                {
+                    Some(())
+                }
+                _ => None,
+            },
+        )
+        .await
+        .map_err(|_| {
+            Nl80211Error::Timeout(format!(
+                "Scan in progress on interface {if_index:?} did not finish \
+                within {timeout:?}"
+            ))
+        })?;
+
+        trigger_scan(&mut handle, attributes)
+            .await
+            .try_collect()
+            .await
+    }
+}
+
+async fn trigger_scan<A>(
+    handle: &mut Nl80211Handle,
+    attributes: A,
+) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+where
+    A: AsRef<[Nl80211Attr]>,
+{
+    let flags = NLM_F_REQUEST | NLM_F_ACK;
 
-        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    nl80211_execute_shared(
+        handle,
+        Nl80211Command::TriggerScan,
+        attributes,
+        flags,
+    )
+    .await
+    .map_err(|e| match e {
+        Nl80211Error::Busy(e) => Nl80211Error::ScanInProgress(e),
+        e => e,
+    })
+}
+
+// Wiphy of the interface or wireless device
+#[cfg(all(
+    feature = "events",
+    any(feature = "tokio_socket", feature = "smol_socket")
+))]
+async fn get_wiphy_index(
+    handle: &mut Nl80211Handle,
+    if_index: Option<crate::Nl80211IfIndex>,
+    wdev: Option<crate::Nl80211WdevId>,
+) -> Result<Option<crate::Nl80211WiphyIndex>, Nl80211Error> {
+    let mut attributes = Vec::new();
+    if let Some(if_index) = if_index {
+        attributes.push(Nl80211Attr::IfIndex(if_index.get()));
+    }
+    if let Some(wdev) = wdev {
+        attributes.push(Nl80211Attr::Wdev(wdev.get()));
+    }
+    if attributes.is_empty() {
+        return Ok(None);
+    }
+    let nl80211_msg = Nl80211Message {
+        cmd: Nl80211Command::GetInterface,
+        attributes,
+    };
+    let response =
+        crate::nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST).await;
+    futures::pin_mut!(response);
+    let mut ret = None;
+    while let Some(msg) = response.try_next().await? {
+        ret = ret.or(msg.payload.wiphy_index());
     }
+    Ok(ret)
 }
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+// `DefaultNla` of netlink-packet-utils does not implement serde traits,
+// store it as its kind and raw payload instead.
+// Used via `#[serde(with = "crate::serde_nla")]` on `Other(DefaultNla)`.
+
+use netlink_packet_utils::nla::{DefaultNla, Nla};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct RawNla {
+    kind: u16,
+    value: Vec<u8>,
+}
+
+pub(crate) fn serialize<S>(
+    nla: &DefaultNla,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut value = vec![0u8; nla.value_len()];
+    nla.emit_value(&mut value);
+    RawNla {
+        kind: nla.kind(),
+        value,
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<DefaultNla, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = RawNla::deserialize(deserializer)?;
+    Ok(DefaultNla::new(raw.kind, raw.value))
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+/// Classification of SSID bytes for display
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211SsidKind {
+    /// Only printable ASCII characters, safe to display as it is
+    Printable,
+    /// Valid UTF-8 but containing non-ASCII or control characters
+    Utf8,
+    /// Not valid UTF-8
+    Binary,
+}
+
+/// SSID as raw bytes, which is not required to be UTF-8 by IEEE 802.11.
+///
+/// The [std::fmt::Display] implementation prints the escaped form of
+/// [Self::escaped()], hence hostile SSIDs from scan results cannot inject
+/// control characters into logs or terminal.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211Ssid(Vec<u8>);
+
+impl Nl80211Ssid {
+    pub fn new(ssid: Vec<u8>) -> Self {
+        Self(ssid)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Return `None` if SSID is not valid UTF-8
+    pub fn as_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.0.as_slice()).ok()
+    }
+
+    pub fn kind(&self) -> Nl80211SsidKind {
+        if self.0.iter().all(|c| c.is_ascii_graphic() || *c == b' ') {
+            Nl80211SsidKind::Printable
+        } else if self.as_str().is_some() {
+            Nl80211SsidKind::Utf8
+        } else {
+            Nl80211SsidKind::Binary
+        }
+    }
+
+    /// Escape the SSID the same way as `iw` does: printable ASCII characters
+    /// are kept, backslash, non-printable bytes and leading or trailing
+    /// space are shown as `\xNN`.
+    pub fn escaped(&self) -> String {
+        let mut ret = String::new();
+        let len = self.0.len();
+        for (i, c) in self.0.iter().enumerate() {
+            if c.is_ascii_graphic() && *c != b'\\' {
+                ret.push(*c as char);
+            } else if *c == b' ' && i != 0 && i != len - 1 {
+                ret.push(' ');
+            } else {
+                write!(ret, "\\x{c:02x}").ok();
+            }
+        }
+        ret
+    }
+}
+
+impl std::fmt::Display for Nl80211Ssid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.escaped())
+    }
+}
+
+impl From<Vec<u8>> for Nl80211Ssid {
+    fn from(v: Vec<u8>) -> Self {
+        Self(v)
+    }
+}
+
+impl From<&[u8]> for Nl80211Ssid {
+    fn from(v: &[u8]) -> Self {
+        Self(v.to_vec())
+    }
+}
+
+impl From<&str> for Nl80211Ssid {
+    fn from(s: &str) -> Self {
+        Self(s.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for Nl80211Ssid {
+    fn from(s: String) -> Self {
+        Self(s.into_bytes())
+    }
+}
+
+impl From<Nl80211Ssid> for Vec<u8> {
+    fn from(v: Nl80211Ssid) -> Vec<u8> {
+        v.0
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Nl80211Attr, Nl80211Message};
+
+const ETH_ALEN: usize = 6;
+
+/// Address space of station entry. For MLD(Multi-Link Device) station
+/// connected to MLD AP, kernel identifies the station by its MLD address
+/// instead of the address of affiliated link.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211StationAddress {
+    /// MAC address of non-MLD station
+    Link([u8; ETH_ALEN]),
+    /// MLD address of multi-link station
+    Mld([u8; ETH_ALEN]),
+}
+
+impl Nl80211StationAddress {
+    /// Return `None` if specified message holds no station address.
+    /// The message could be the reply of
+    /// [crate::Nl80211StationHandle::dump()] or station notification.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let mut mac = None;
+        let mut mld_addr = None;
+        let mut is_mld = false;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::Mac(s) => mac = Some(*s),
+                Nl80211Attr::MldAddr(s) => mld_addr = Some(*s),
+                Nl80211Attr::MloLinkId(_) | Nl80211Attr::MloLinks(_) => {
+                    is_mld = true
+                }
+                _ => (),
+            }
+        }
+        if let Some(addr) = mld_addr {
+            Some(Self::Mld(addr))
+        } else if is_mld {
+            mac.map(Self::Mld)
+        } else {
+            mac.map(Self::Link)
+        }
+    }
+
+    pub fn mac(&self) -> [u8; ETH_ALEN] {
+        match self {
+            Self::Link(s) | Self::Mld(s) => *s,
+        }
+    }
+
+    pub fn is_mld(&self) -> bool {
+        matches!(self, Self::Mld(_))
+    }
+}
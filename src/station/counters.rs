@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    stats::counter_delta, Nl80211CounterDelta, Nl80211CounterWidth,
+    Nl80211StationInfo,
+};
+
+/// Accumulating counters of a station extracted from
+/// [crate::Nl80211Attr::StationInfo], used to calculate the increments
+/// between two snapshots via [Self::delta()].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211StationCounters {
+    /// Time in seconds since the station is last connected
+    pub connected_time: Option<u32>,
+    /// `CLOCK_BOOTTIME` timestamp (nanoseconds) of station's association
+    pub association_at_boottime: Option<u64>,
+    pub tx_bytes: Option<u64>,
+    pub rx_bytes: Option<u64>,
+    /// [Nl80211CounterWidth::U32] if `tx_bytes` is taken from
+    /// [Nl80211StationInfo::TxBytes] as kernel does not report
+    /// [Nl80211StationInfo::TxBytes64]
+    pub tx_bytes_width: Nl80211CounterWidth,
+    /// [Nl80211CounterWidth::U32] if `rx_bytes` is taken from
+    /// [Nl80211StationInfo::RxBytes] as kernel does not report
+    /// [Nl80211StationInfo::RxBytes64]
+    pub rx_bytes_width: Nl80211CounterWidth,
+    pub tx_packets: Option<u64>,
+    pub rx_packets: Option<u64>,
+    pub rx_mpdus: Option<u64>,
+    pub tx_retries: Option<u64>,
+    pub tx_failed: Option<u64>,
+    pub rx_drop_misc: Option<u64>,
+    pub fcs_error_count: Option<u64>,
+    pub beacon_rx: Option<u64>,
+    pub beacon_loss: Option<u64>,
+    /// Aggregate PPDU duration (usec) for all frames sent to the station
+    pub tx_duration: Option<u64>,
+    /// Aggregate PPDU duration (usec) for all frames received from the
+    /// station
+    pub rx_duration: Option<u64>,
+}
+
+impl Nl80211StationCounters {
+    pub fn from_station_info(infos: &[Nl80211StationInfo]) -> Self {
+        let mut ret = Self::default();
+        for info in infos {
+            match info {
+                Nl80211StationInfo::ConnectedTime(d) => {
+                    ret.connected_time = Some(*d)
+                }
+                Nl80211StationInfo::AssociationAtBoottime(d) => {
+                    ret.association_at_boottime = Some(*d)
+                }
+                // Prefer the 64 bits counters
+                Nl80211StationInfo::TxBytes(d) if ret.tx_bytes.is_none() => {
+                    ret.tx_bytes = Some((*d).into());
+                    ret.tx_bytes_width = Nl80211CounterWidth::U32;
+                }
+                Nl80211StationInfo::RxBytes(d) if ret.rx_bytes.is_none() => {
+                    ret.rx_bytes = Some((*d).into());
+                    ret.rx_bytes_width = Nl80211CounterWidth::U32;
+                }
+                Nl80211StationInfo::TxBytes64(d) => {
+                    ret.tx_bytes = Some(*d);
+                    ret.tx_bytes_width = Nl80211CounterWidth::U64;
+                }
+                Nl80211StationInfo::RxBytes64(d) => {
+                    ret.rx_bytes = Some(*d);
+                    ret.rx_bytes_width = Nl80211CounterWidth::U64;
+                }
+                Nl80211StationInfo::TxPackets(d) => {
+                    ret.tx_packets = Some((*d).into())
+                }
+                Nl80211StationInfo::RxPackets(d) => {
+                    ret.rx_packets = Some((*d).into())
+                }
+                Nl80211StationInfo::RxMpdus(d) => {
+                    ret.rx_mpdus = Some((*d).into())
+                }
+                Nl80211StationInfo::TxRetries(d) => {
+                    ret.tx_retries = Some((*d).into())
+                }
+                Nl80211StationInfo::TxFailed(d) => {
+                    ret.tx_failed = Some((*d).into())
+                }
+                Nl80211StationInfo::RxDropMisc(d) => {
+                    ret.rx_drop_misc = Some(*d)
+                }
+                Nl80211StationInfo::FcsErrorCount(d) => {
+                    ret.fcs_error_count = Some((*d).into())
+                }
+                Nl80211StationInfo::BeaconRx(d) => ret.beacon_rx = Some(*d),
+                Nl80211StationInfo::BeaconLoss(d) => {
+                    ret.beacon_loss = Some((*d).into())
+                }
+                Nl80211StationInfo::TxDuration(d) => ret.tx_duration = Some(*d),
+                Nl80211StationInfo::RxDuration(d) => ret.rx_duration = Some(*d),
+                _ => (),
+            }
+        }
+        ret
+    }
+
+    /// Compare with previous snapshot of the same station, return the
+    /// increments of all the counters or [Nl80211CounterDelta::Reset] if
+    /// the station reassociated (association timestamp changed or connected
+    /// time decreased) or any 64 bits counter decreased. Decrease of 32 bits
+    /// counter is treated as wrap around.
+    /// The `connected_time`, `association_at_boottime` and counter widths of
+    /// increments are copied from current snapshot.
+    pub fn delta(&self, previous: &Self) -> Nl80211CounterDelta<Self> {
+        use Nl80211CounterWidth::{U32, U64};
+
+        if self.association_at_boottime.is_some()
+            && previous.association_at_boottime.is_some()
+            && self.association_at_boottime != previous.association_at_boottime
+        {
+            return Nl80211CounterDelta::Reset;
+        }
+        if let (Some(cur), Some(prev)) =
+            (self.connected_time, previous.connected_time)
+        {
+            if cur < prev {
+                return Nl80211CounterDelta::Reset;
+            }
+        }
+        // Byte counters switched between 32 and 64 bits are not comparable
+        if (self.tx_bytes.is_some()
+            && previous.tx_bytes.is_some()
+            && self.tx_bytes_width != previous.tx_bytes_width)
+            || (self.rx_bytes.is_some()
+                && previous.rx_bytes.is_some()
+                && self.rx_bytes_width != previous.rx_bytes_width)
+        {
+            return Nl80211CounterDelta::Reset;
+        }
+        let delta = || -> Result<Self, ()> {
+            Ok(Self {
+                connected_time: self.connected_time,
+                association_at_boottime: self.association_at_boottime,
+                tx_bytes_width: self.tx_bytes_width,
+                rx_bytes_width: self.rx_bytes_width,
+                tx_bytes: counter_delta(
+                    self.tx_bytes,
+                    previous.tx_bytes,
+                    self.tx_bytes_width,
+                )?,
+                rx_bytes: counter_delta(
+                    self.rx_bytes,
+                    previous.rx_bytes,
+                    self.rx_bytes_width,
+                )?,
+                tx_packets: counter_delta(
+                    self.tx_packets,
+                    previous.tx_packets,
+                    U32,
+                )?,
+                rx_packets: counter_delta(
+                    self.rx_packets,
+                    previous.rx_packets,
+                    U32,
+                )?,
+                rx_mpdus: counter_delta(self.rx_mpdus, previous.rx_mpdus, U32)?,
+                tx_retries: counter_delta(
+                    self.tx_retries,
+                    previous.tx_retries,
+                    U32,
+                )?,
+                tx_failed: counter_delta(
+                    self.tx_failed,
+                    previous.tx_failed,
+                    U32,
+                )?,
+                rx_drop_misc: counter_delta(
+                    self.rx_drop_misc,
+                    previous.rx_drop_misc,
+                    U64,
+                )?,
+                fcs_error_count: counter_delta(
+                    self.fcs_error_count,
+                    previous.fcs_error_count,
+                    U32,
+                )?,
+                beacon_rx: counter_delta(
+                    self.beacon_rx,
+                    previous.beacon_rx,
+                    U64,
+                )?,
+                beacon_loss: counter_delta(
+                    self.beacon_loss,
+                    previous.beacon_loss,
+                    U32,
+                )?,
+                tx_duration: counter_delta(
+                    self.tx_duration,
+                    previous.tx_duration,
+                    U64,
+                )?,
+                rx_duration: counter_delta(
+                    self.rx_duration,
+                    previous.rx_duration,
+                    U64,
+                )?,
+            })
+        };
+        match delta() {
+            Ok(d) => Nl80211CounterDelta::Delta(d),
+            Err(()) => Nl80211CounterDelta::Reset,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error,
+    Nl80211FrameTypeMgmt, Nl80211Handle, Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211StationDelRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; ETH_ALEN],
+    reason_code: Option<u16>,
+    subtype: Option<Nl80211FrameTypeMgmt>,
+}
+
+impl Nl80211StationDelRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+    ) -> Self {
+        Nl80211StationDelRequest {
+            handle,
+            if_index,
+            mac,
+            reason_code: None,
+            subtype: None,
+        }
+    }
+
+    /// IEEE 802.11 reason code used in the deauthentication frame sent to
+    /// the station
+    pub fn reason_code(mut self, reason_code: u16) -> Self {
+        self.reason_code = Some(reason_code);
+        self
+    }
+
+    /// Send disassociation([Nl80211FrameTypeMgmt::Disassoc]) instead of
+    /// the default deauthentication([Nl80211FrameTypeMgmt::Deauth]) frame
+    /// to the station, other subtypes are rejected by kernel
+    pub fn mgmt_subtype(mut self, subtype: Nl80211FrameTypeMgmt) -> Self {
+        self.subtype = Some(subtype);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211StationDelRequest {
+            mut handle,
+            if_index,
+            mac,
+            reason_code,
+            subtype,
+        } = self;
+
+        let mut attributes =
+            vec![Nl80211Attr::IfIndex(if_index), Nl80211Attr::Mac(mac)];
+        if let Some(d) = reason_code {
+            attributes.push(Nl80211Attr::ReasonCode(d));
+        }
+        if let Some(d) = subtype {
+            attributes.push(Nl80211Attr::MgmtSubtype(d));
+        }
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::DelStation,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use std::collections::BTreeMap;
+
+use futures::{future::join_all, TryStreamExt};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211IfIndex, Nl80211InterfaceType, Nl80211Message,
+    Nl80211StationGetRequest,
+};
+
+pub struct Nl80211StationDumpAllRequest {
+    handle: Nl80211Handle,
+    if_indexes: Option<Vec<Nl80211IfIndex>>,
+}
+
+impl Nl80211StationDumpAllRequest {
+    pub(crate) fn new(handle: Nl80211Handle) -> Self {
+        Nl80211StationDumpAllRequest {
+            handle,
+            if_indexes: None,
+        }
+    }
+
+    /// Only dump stations of specified interfaces instead of all the
+    /// wireless interfaces
+    pub fn interfaces(mut self, if_indexes: Vec<Nl80211IfIndex>) -> Self {
+        self.if_indexes = Some(if_indexes);
+        self
+    }
+
+    /// Dump stations of all interfaces concurrently and group the replies
+    /// by interface index.
+    /// When interfaces are not specified by [Self::interfaces()], monitor
+    /// interfaces are skipped and the interfaces failed to dump (e.g.
+    /// removed during the dump) are ignored, otherwise the first failure is
+    /// returned.
+    pub async fn execute(
+        self,
+    ) -> Result<BTreeMap<Nl80211IfIndex, Vec<Nl80211Message>>, Nl80211Error>
+    {
+        let Nl80211StationDumpAllRequest {
+            mut handle,
+            if_indexes,
+        } = self;
+
+        let (if_indexes, ignore_error) = match if_indexes {
+            Some(v) => (v, false),
+            None => (get_if_indexes(&mut handle, None).await?, true),
+        };
+
+        let results = join_all(if_indexes.into_iter().map(|if_index| {
+            let handle = handle.clone();
+            async move {
+                let response =
+                    Nl80211StationGetRequest::new(handle, if_index.get(), None)
+                        .execute()
+                        .await;
+                let result = response
+                    .map_ok(|msg| msg.payload)
+                    .try_collect::<Vec<_>>()
+                    .await;
+                (if_index, result)
+            }
+        }))
+        .await;
+
+        let mut ret = BTreeMap::new();
+        for (if_index, result) in results {
+            match result {
+                Ok(msgs) => {
+                    ret.insert(if_index, msgs);
+                }
+                Err(e) if ignore_error => {
+                    log::debug!(
+                        "Ignoring failure of dumping stations of interface \
+                        {if_index}: {e}"
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(ret)
+    }
+}
+
+// Index of non-monitor interfaces, only of specified wiphy if any
+pub(super) async fn get_if_indexes(
+    handle: &mut Nl80211Handle,
+    wiphy: Option<u32>,
+) -> Result<Vec<Nl80211IfIndex>, Nl80211Error> {
+    let nl80211_msg = Nl80211Message {
+        cmd: Nl80211Command::GetInterface,
+        attributes: vec![],
+    };
+    let response =
+        nl80211_execute(handle, nl80211_msg, NLM_F_REQUEST | NLM_F_DUMP).await;
+    futures::pin_mut!(response);
+    let mut ret = Vec::new();
+    while let Some(msg) = response.try_next().await? {
+        let mut if_index = None;
+        let mut is_monitor = false;
+        if wiphy.is_some() && msg.payload.wiphy_index().map(u32::from) != wiphy
+        {
+            continue;
+        }
+        for attr in msg.payload.attributes {
+            match attr {
+                Nl80211Attr::IfIndex(d) => if_index = Some(d),
+                Nl80211Attr::IfType(Nl80211InterfaceType::Monitor) => {
+                    is_monitor = true
+                }
+                _ => (),
+            }
+        }
+        // P2P device has no interface index
+        if let Some(if_index) = if_index {
+            if !is_monitor {
+                ret.push(Nl80211IfIndex::new(if_index));
+            }
+        }
+    }
+    Ok(ret)
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211IfIndex, Nl80211Message, Nl80211MloLink,
+    Nl80211StationAddress, Nl80211StationInfo, Nl80211StationSummary,
+};
+
+/// Station reported by `NL80211_CMD_GET_STATION`, annotated with the
+/// interface it is associated to.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211StationEntry {
+    pub if_index: Option<Nl80211IfIndex>,
+    /// MAC address, or MLD address for multi-link station
+    pub address: Option<Nl80211StationAddress>,
+    /// Station list generation, changes whenever the station list of the
+    /// interface changed during the dump
+    pub generation: Option<u32>,
+    /// Link of MLD AP the station is associated with when reported for
+    /// single link
+    pub link_id: Option<u8>,
+    /// Affiliated links of multi-link station
+    pub links: Vec<Nl80211MloLink>,
+    pub info: Vec<Nl80211StationInfo>,
+}
+
+impl Nl80211StationEntry {
+    /// Return `None` if specified message has no
+    /// [Nl80211Attr::StationInfo].
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let mut entry = Self {
+            address: Nl80211StationAddress::from_message(msg),
+            ..Default::default()
+        };
+        let mut info = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    entry.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Generation(d) => entry.generation = Some(*d),
+                Nl80211Attr::MloLinkId(d) => entry.link_id = Some(*d),
+                Nl80211Attr::MloLinks(v) => entry.links.clone_from(v),
+                Nl80211Attr::StationInfo(v) => info = Some(v.clone()),
+                _ => (),
+            }
+        }
+        entry.info = info?;
+        Some(entry)
+    }
+
+    /// Common values folded from [Self::info]
+    pub fn summary(&self) -> Nl80211StationSummary {
+        Nl80211StationSummary::from_station_info(&self.info)
+    }
+
+    /// Whether the station is associated via specified link of MLD AP
+    pub fn uses_link(&self, link_id: u8) -> bool {
+        self.link_id == Some(link_id)
+            || self.links.iter().any(|link| link.id == link_id)
+    }
+}
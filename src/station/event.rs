@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message,
+    Nl80211StationInfo,
+};
+
+const ETH_ALEN: usize = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211StationEventInfo {
+    pub if_index: Option<Nl80211IfIndex>,
+    /// MAC address of the station
+    pub mac: [u8; ETH_ALEN],
+    pub generation: Option<u32>,
+    /// MLD address of multi-link station
+    pub mld_addr: Option<[u8; ETH_ALEN]>,
+    /// The link used for association of multi-link station
+    pub assoc_link_id: Option<u8>,
+    /// Station information when provided by kernel, usually only included
+    /// in [Nl80211StationEvent::New] and final statistics in
+    /// [Nl80211StationEvent::Del]
+    pub station_info: Vec<Nl80211StationInfo>,
+}
+
+/// Station notifications sent by kernel to the `mlme` multicast group, for
+/// example when client associated to or disassociated from AP interface.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211StationEvent {
+    New(Nl80211StationEventInfo),
+    Del(Nl80211StationEventInfo),
+}
+
+impl Nl80211StationEvent {
+    /// Return `None` if specified message is not a `NL80211_CMD_NEW_STATION`
+    /// or `NL80211_CMD_DEL_STATION` notification or has no MAC address.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let variant = match msg.cmd {
+            Nl80211Command::NewStation => Self::New,
+            Nl80211Command::DelStation => Self::Del,
+            _ => return None,
+        };
+        let mut info = Nl80211StationEventInfo::default();
+        let mut mac = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    info.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Mac(s) => mac = Some(*s),
+                Nl80211Attr::Generation(d) => info.generation = Some(*d),
+                Nl80211Attr::MldAddr(s) => info.mld_addr = Some(*s),
+                Nl80211Attr::MloLinkId(d) => info.assoc_link_id = Some(*d),
+                Nl80211Attr::StationInfo(v) => info.station_info = v.clone(),
+                _ => (),
+            }
+        }
+        info.mac = mac?;
+        Some(variant(info))
+    }
+}
+
+/// Result of probing associated station by
+/// [crate::Nl80211StationHandle::probe_client()], sent by kernel to the
+/// `mlme` multicast group
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211ProbeClientEvent {
+    pub if_index: Option<Nl80211IfIndex>,
+    /// MAC address of the probed station
+    pub mac: [u8; ETH_ALEN],
+    /// Cookie returned by the probe request
+    pub cookie: u64,
+    /// Whether the probe was acknowledged by the station
+    pub ack: bool,
+    /// Signal strength(dBm) of the ACK frame when supported by driver
+    pub ack_signal: Option<i32>,
+}
+
+impl Nl80211ProbeClientEvent {
+    /// Return `None` if specified message is not a `NL80211_CMD_PROBE_CLIENT`
+    /// notification. The reply of probe request also uses this command but
+    /// only holds the cookie, hence is ignored.
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        if msg.cmd != Nl80211Command::ProbeClient {
+            return None;
+        }
+        let mut ret = Self::default();
+        let mut mac = None;
+        let mut cookie = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::Mac(s) => mac = Some(*s),
+                Nl80211Attr::Cookie(d) => cookie = Some(*d),
+                Nl80211Attr::Ack => ret.ack = true,
+                Nl80211Attr::AckSignal(d) => ret.ack_signal = Some(*d),
+                _ => (),
+            }
+        }
+        ret.mac = mac?;
+        ret.cookie = cookie?;
+        Some(ret)
+    }
+}
@@ -1,20 +1,27 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use futures::{future, stream, StreamExt, TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
     nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    Nl80211Message, Nl80211StationEntry,
 };
 
 const ETH_ALEN: usize = 6;
 
+#[derive(Debug, Clone, Copy)]
+enum StationScope {
+    Interface(u32),
+    Wiphy(u32),
+}
+
 pub struct Nl80211StationGetRequest {
     handle: Nl80211Handle,
-    if_index: u32,
+    scope: StationScope,
     mac_address: Option<[u8; ETH_ALEN]>,
+    link_id: Option<u8>,
 }
 
 impl Nl80211StationGetRequest {
@@ -25,33 +32,115 @@ impl Nl80211StationGetRequest {
     ) -> Self {
         Nl80211StationGetRequest {
             handle,
-            if_index,
+            scope: StationScope::Interface(if_index),
             mac_address,
+            link_id: None,
+        }
+    }
+
+    pub(crate) fn new_wiphy(handle: Nl80211Handle, wiphy: u32) -> Self {
+        Nl80211StationGetRequest {
+            handle,
+            scope: StationScope::Wiphy(wiphy),
+            mac_address: None,
+            link_id: None,
         }
     }
 
+    /// Only retrieve the station of specified MAC address (or MLD address
+    /// for multi-link station) instead of dumping all. When querying all
+    /// interfaces of wiphy, the interfaces not holding this station are
+    /// skipped.
+    pub fn mac(mut self, mac: [u8; ETH_ALEN]) -> Self {
+        self.mac_address = Some(mac);
+        self
+    }
+
+    /// Only include the stations associated via specified link of MLD AP.
+    /// Kernel has no such filter, hence the filtering is done after the
+    /// reply is received, replies not holding a station are dropped too.
+    pub fn link_id(mut self, link_id: u8) -> Self {
+        self.link_id = Some(link_id);
+        self
+    }
+
     pub async fn execute(
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
         let Nl80211StationGetRequest {
             mut handle,
-            if_index,
+            scope,
             mac_address,
+            link_id,
         } = self;
 
-        let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
-        if let Some(arr) = mac_address {
-            attributes.push(Nl80211Attr::Mac(arr))
-        }
+        let if_indexes = match scope {
+            StationScope::Interface(if_index) => vec![Ok(if_index)],
+            StationScope::Wiphy(wiphy) => {
+                match super::dump_all::get_if_indexes(&mut handle, Some(wiphy))
+                    .await
+                {
+                    Ok(v) => v.into_iter().map(|i| Ok(i.get())).collect(),
+                    Err(e) => vec![Err(e)],
+                }
+            }
+        };
+        let is_wiphy = matches!(scope, StationScope::Wiphy(_));
 
-        let nl80211_msg = Nl80211Message {
-            cmd: Nl80211Command::GetStation,
-            attributes,
+        // Kernel ignores the MAC address in dump request
+        let flags = if mac_address.is_some() {
+            NLM_F_REQUEST
+        } else {
+            NLM_F_REQUEST | NLM_F_DUMP
         };
 
-        let flags = NLM_F_REQUEST | NLM_F_DUMP;
+        // Boxed to keep the stream `Unpin` like other requests
+        Box::pin(
+            stream::iter(if_indexes)
+                .and_then(move |if_index| {
+                    let mut handle = handle.clone();
+                    let mut attributes = vec![Nl80211Attr::IfIndex(if_index)];
+                    if let Some(arr) = mac_address {
+                        attributes.push(Nl80211Attr::Mac(arr))
+                    }
+                    let nl80211_msg = Nl80211Message {
+                        cmd: Nl80211Command::GetStation,
+                        attributes,
+                    };
+                    async move {
+                        Ok(nl80211_execute(&mut handle, nl80211_msg, flags)
+                            .await)
+                    }
+                })
+                .try_flatten()
+                .filter(move |result| {
+                    future::ready(!matches!(
+                        result,
+                        Err(Nl80211Error::NotFound(_))
+                            if is_wiphy && mac_address.is_some()
+                    ))
+                })
+                .try_filter(move |msg| {
+                    future::ready(match link_id {
+                        Some(link_id) => {
+                            Nl80211StationEntry::from_message(&msg.payload)
+                                .map(|entry| entry.uses_link(link_id))
+                                .unwrap_or(false)
+                        }
+                        None => true,
+                    })
+                }),
+        )
+    }
 
-        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    /// Similar to [Self::execute()] but decodes each station into
+    /// [Nl80211StationEntry].
+    pub async fn entries(
+        self,
+    ) -> impl TryStream<Ok = Nl80211StationEntry, Error = Nl80211Error> {
+        self.execute().await.try_filter_map(|msg| {
+            future::ready(Ok(Nl80211StationEntry::from_message(&msg.payload)))
+        })
     }
 }
@@ -1,6 +1,27 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211StationGetRequest};
+#[cfg(feature = "events")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "events")]
+use genetlink::message::RawGenlMessage;
+#[cfg(feature = "events")]
+use netlink_packet_core::NetlinkMessage;
+#[cfg(feature = "events")]
+use netlink_sys::SocketAddr;
+
+use netlink_packet_utils::nla::Nla;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211Handle, Nl80211IfIndex,
+    Nl80211ProbeClientRequest, Nl80211StationDelRequest,
+    Nl80211StationDumpAllRequest, Nl80211StationFlagUpdate,
+    Nl80211StationGetRequest, Nl80211StationSetRequest, Nl80211TxPowerSetting,
+    Nl80211WiphyIndex,
+};
+#[cfg(feature = "events")]
+use crate::{Nl80211Error, Nl80211ProbeClientEvent, Nl80211StationEvent};
+
+const ETH_ALEN: usize = 6;
 
 pub struct Nl80211StationHandle(Nl80211Handle);
 
@@ -11,7 +32,145 @@ impl Nl80211StationHandle {
 
     /// Retrieve the stations
     /// (equivalent to `iw dev DEV station dump`)
-    pub fn dump(&mut self, if_index: u32) -> Nl80211StationGetRequest {
-        Nl80211StationGetRequest::new(self.0.clone(), if_index, None)
+    pub fn dump(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211StationGetRequest {
+        Nl80211StationGetRequest::new(self.0.clone(), if_index.get(), None)
+    }
+
+    /// Retrieve specified station
+    /// (equivalent to `iw dev DEV station get MAC`).
+    /// For MLD station, the `mac` should be its MLD address, see
+    /// [crate::Nl80211StationAddress].
+    pub fn get(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211StationGetRequest {
+        Nl80211StationGetRequest::new(self.0.clone(), if_index.get(), Some(mac))
+    }
+
+    /// Retrieve the stations of all interfaces belonging to specified
+    /// wiphy, monitor interfaces are skipped
+    pub fn dump_wiphy(
+        &mut self,
+        wiphy: Nl80211WiphyIndex,
+    ) -> Nl80211StationGetRequest {
+        Nl80211StationGetRequest::new_wiphy(self.0.clone(), wiphy.get())
+    }
+
+    /// Remove specified station
+    /// (equivalent to `iw dev DEV station del MAC`).
+    /// For MLD station, the `mac` should be its MLD address.
+    pub fn del(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211StationDelRequest {
+        Nl80211StationDelRequest::new(self.0.clone(), if_index.get(), mac)
+    }
+
+    /// Probe whether specified station associated to AP interface is still
+    /// alive by sending null data frame to it, the ACK status is reported
+    /// by [crate::Nl80211ProbeClientEvent] carrying the cookie returned by
+    /// [Nl80211ProbeClientRequest::execute()].
+    pub fn probe_client(
+        &mut self,
+        if_index: Nl80211IfIndex,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211ProbeClientRequest {
+        Nl80211ProbeClientRequest::new(self.0.clone(), if_index.get(), mac)
+    }
+
+    /// Retrieve the stations of all wireless interfaces concurrently,
+    /// grouped by interface index
+    pub fn dump_all(&mut self) -> Nl80211StationDumpAllRequest {
+        Nl80211StationDumpAllRequest::new(self.0.clone())
+    }
+
+    /// Stream of station notifications decoded from the unsolicited message
+    /// receiver of connection, see [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211StationEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211StationEvent::from_message(&msg))
+        }))
+    }
+
+    /// Stream of probe results decoded from the unsolicited message
+    /// receiver of connection, see [Nl80211Handle::notifications()].
+    /// The connection socket should join the `mlme` multicast group.
+    #[cfg(feature = "events")]
+    pub async fn probe_client_events<S>(
+        &self,
+        messages: S,
+    ) -> Result<impl Stream<Item = Nl80211ProbeClientEvent>, Nl80211Error>
+    where
+        S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+    {
+        Ok(self.0.notifications(messages).await?.filter_map(|msg| {
+            futures::future::ready(Nl80211ProbeClientEvent::from_message(&msg))
+        }))
+    }
+
+    /// Change station parameters
+    /// (equivalent to `iw dev DEV station set`).
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Station].
+    pub fn set(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211StationSetRequest {
+        Nl80211StationSetRequest::new(self.0.clone(), attributes)
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Station;
+
+impl Nl80211Station {
+    /// Change parameters of specified station on specified interface
+    pub fn new(
+        if_index: Nl80211IfIndex,
+        mac: [u8; ETH_ALEN],
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .if_index(if_index)
+            .replace(Nl80211Attr::Mac(mac))
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Station> {
+    /// Station flags to change, for example:
+    /// `Nl80211StationFlagUpdate::new().set(Nl80211StationFlags::Authorized)`
+    pub fn flags(self, update: Nl80211StationFlagUpdate) -> Self {
+        self.replace(Nl80211Attr::StationFlags2(update))
+    }
+
+    /// Only change the parameters of specified link of MLD station, the
+    /// station should be identified by its MLD address
+    pub fn link_id(self, link_id: u8) -> Self {
+        self.replace(Nl80211Attr::MloLinkId(link_id))
+    }
+
+    /// Transmit power of station in mBm (100 * dBm), requires driver
+    /// supporting `NL80211_EXT_FEATURE_STA_TX_PWR`. The `mbm` level is
+    /// ignored for [Nl80211TxPowerSetting::Automatic].
+    /// Use [crate::dbm_to_mbm()] to convert from dBm.
+    pub fn tx_power(self, setting: Nl80211TxPowerSetting, mbm: i16) -> Self {
+        let builder = self.replace(Nl80211Attr::StaTxPowerSetting(setting));
+        if setting == Nl80211TxPowerSetting::Automatic {
+            builder.remove(Nl80211Attr::StaTxPower(0).kind())
+        } else {
+            builder.replace(Nl80211Attr::StaTxPower(mbm))
+        }
     }
 }
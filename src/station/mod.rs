@@ -1,17 +1,40 @@
 // SPDX-License-Identifier: MIT
 
+mod address;
+mod counters;
+mod del;
+mod dump_all;
+mod entry;
+#[cfg(feature = "events")]
+mod event;
 mod get;
 mod handle;
+mod probe;
 mod rate_info;
+mod set;
 mod station_info;
+mod summary;
 
+pub use self::address::Nl80211StationAddress;
+pub use self::counters::Nl80211StationCounters;
+pub use self::del::Nl80211StationDelRequest;
+pub use self::dump_all::Nl80211StationDumpAllRequest;
+pub use self::entry::Nl80211StationEntry;
+#[cfg(feature = "events")]
+pub use self::event::{
+    Nl80211ProbeClientEvent, Nl80211StationEvent, Nl80211StationEventInfo,
+};
 pub use self::get::Nl80211StationGetRequest;
-pub use self::handle::Nl80211StationHandle;
+pub use self::handle::{Nl80211Station, Nl80211StationHandle};
+pub use self::probe::Nl80211ProbeClientRequest;
 pub use self::rate_info::{
     Nl80211EhtGi, Nl80211EhtRuAllocation, Nl80211HeGi, Nl80211HeRuAllocation,
-    Nl80211RateInfo,
+    Nl80211Rate, Nl80211RateInfo, Nl80211RateMode,
 };
+pub use self::set::Nl80211StationSetRequest;
 pub use self::station_info::{
     Nl80211MeshPowerMode, Nl80211PeerLinkState, Nl80211StationBssParam,
-    Nl80211StationFlag, Nl80211StationFlagUpdate, Nl80211StationInfo,
+    Nl80211StationBssParams, Nl80211StationFlag, Nl80211StationFlagUpdate,
+    Nl80211StationFlags, Nl80211StationInfo,
 };
+pub use self::summary::Nl80211StationSummary;
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStreamExt;
+use netlink_packet_core::NLM_F_REQUEST;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+const ETH_ALEN: usize = 6;
+
+pub struct Nl80211ProbeClientRequest {
+    handle: Nl80211Handle,
+    if_index: u32,
+    mac: [u8; ETH_ALEN],
+}
+
+impl Nl80211ProbeClientRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        if_index: u32,
+        mac: [u8; ETH_ALEN],
+    ) -> Self {
+        Nl80211ProbeClientRequest {
+            handle,
+            if_index,
+            mac,
+        }
+    }
+
+    /// Send the probe and return the cookie identifying it in the
+    /// following `NL80211_CMD_PROBE_CLIENT` notification.
+    pub async fn execute(self) -> Result<u64, Nl80211Error> {
+        let Nl80211ProbeClientRequest {
+            mut handle,
+            if_index,
+            mac,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::ProbeClient,
+            attributes: vec![
+                Nl80211Attr::IfIndex(if_index),
+                Nl80211Attr::Mac(mac),
+            ],
+        };
+
+        let response =
+            nl80211_execute(&mut handle, nl80211_msg, NLM_F_REQUEST).await;
+        futures::pin_mut!(response);
+        let mut cookie = None;
+        while let Some(msg) = response.try_next().await? {
+            for attr in msg.payload.attributes {
+                if let Nl80211Attr::Cookie(d) = attr {
+                    cookie = Some(d);
+                }
+            }
+        }
+        cookie.ok_or_else(|| {
+            Nl80211Error::RequestFailed(
+                "No cookie in the reply of NL80211_CMD_PROBE_CLIENT".into(),
+            )
+        })
+    }
+}
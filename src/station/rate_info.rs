@@ -39,6 +39,7 @@ pub const NL80211_RATE_INFO_8_MHZ_WIDTH: u16 = 28;
 pub const NL80211_RATE_INFO_16_MHZ_WIDTH: u16 = 29;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211RateInfo {
     /// Total bitrate, 100kb/s
     Bitrate(u16),
@@ -80,6 +81,7 @@ pub enum Nl80211RateInfo {
     /// See [`Nl80211EhtRuAllocation`]
     EhtRuAlloc(Nl80211EhtRuAllocation),
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -314,11 +316,127 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     }
 }
 
+/// PHY mode of the rate
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211RateMode {
+    /// Legacy (CCK or OFDM) rate
+    #[default]
+    Legacy,
+    /// 802.11n
+    Ht,
+    /// 802.11ac
+    Vht,
+    /// 802.11ax
+    He,
+    /// 802.11be
+    Eht,
+    /// 802.11ah
+    S1g,
+}
+
+/// Summary of the [Nl80211RateInfo] list regardless of PHY mode, created by
+/// [Nl80211Rate::from_rate_info()]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211Rate {
+    mode: Nl80211RateMode,
+    bitrate: Option<u32>,
+    mcs: Option<u8>,
+    nss: Option<u8>,
+    bandwidth_mhz: u32,
+}
+
+impl Nl80211Rate {
+    pub fn from_rate_info(infos: &[Nl80211RateInfo]) -> Self {
+        let mut ret = Self {
+            bandwidth_mhz: 20,
+            ..Default::default()
+        };
+        for info in infos {
+            match info {
+                Nl80211RateInfo::Bitrate(d) => {
+                    ret.bitrate = ret.bitrate.or(Some((*d).into()))
+                }
+                // Kernel only includes the 16 bits bitrate when fits
+                Nl80211RateInfo::Bitrate32(d) => ret.bitrate = Some(*d),
+                Nl80211RateInfo::MhzWidth(d) => ret.bandwidth_mhz = *d,
+                Nl80211RateInfo::MhzWidth80Plus80 => ret.bandwidth_mhz = 160,
+                Nl80211RateInfo::Mcs(d) => {
+                    ret.mode = Nl80211RateMode::Ht;
+                    ret.mcs = Some(*d);
+                    // MCS 0-31 are using equal modulation on 1-4 streams,
+                    // MCS 32 is 1 stream duplicated on 40 MHz, MCS 33-76
+                    // are unequal modulation on 2-4 streams as IEEE
+                    // 802.11-2020 Table 19-39.
+                    ret.nss = match *d {
+                        0..=31 => Some(*d / 8 + 1),
+                        32 => Some(1),
+                        33..=38 => Some(2),
+                        39..=52 => Some(3),
+                        53..=76 => Some(4),
+                        _ => None,
+                    };
+                }
+                Nl80211RateInfo::VhtMcs(d) => {
+                    ret.mode = Nl80211RateMode::Vht;
+                    ret.mcs = Some(*d);
+                }
+                Nl80211RateInfo::VhtNss(d) => ret.nss = Some(*d),
+                Nl80211RateInfo::HeMcs(d) => {
+                    ret.mode = Nl80211RateMode::He;
+                    ret.mcs = Some(*d);
+                }
+                Nl80211RateInfo::HeNss(d) => ret.nss = Some(*d),
+                Nl80211RateInfo::EhtMcs(d) => {
+                    ret.mode = Nl80211RateMode::Eht;
+                    ret.mcs = Some(*d);
+                }
+                Nl80211RateInfo::EhtNss(d) => ret.nss = Some(*d),
+                Nl80211RateInfo::S1gMcs(d) => {
+                    ret.mode = Nl80211RateMode::S1g;
+                    ret.mcs = Some(*d);
+                }
+                Nl80211RateInfo::S1gNss(d) => ret.nss = Some(*d),
+                _ => (),
+            }
+        }
+        ret
+    }
+
+    pub fn mode(&self) -> Nl80211RateMode {
+        self.mode
+    }
+
+    /// Total bitrate in 100 kbit/s
+    pub fn bitrate(&self) -> Option<u32> {
+        self.bitrate
+    }
+
+    /// MCS index, for HT it is the index(0-76) covering all the spatial
+    /// streams. `None` for legacy rate.
+    pub fn mcs(&self) -> Option<u8> {
+        self.mcs
+    }
+
+    /// Number of spatial streams. `None` for legacy rate.
+    pub fn nss(&self) -> Option<u8> {
+        self.nss
+    }
+
+    /// Channel bandwidth in MHz, 80+80 MHz is reported as 160 MHz
+    pub fn bandwidth_mhz(&self) -> u32 {
+        self.bandwidth_mhz
+    }
+}
+
 const NL80211_RATE_INFO_HE_GI_0_8: u8 = 0;
 const NL80211_RATE_INFO_HE_GI_1_6: u8 = 1;
 const NL80211_RATE_INFO_HE_GI_3_2: u8 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211HeGi {
     /// 0.8 usec
     Usec0_8,
@@ -361,6 +479,7 @@ const NL80211_RATE_INFO_HE_RU_ALLOC_996: u8 = 5;
 const NL80211_RATE_INFO_HE_RU_ALLOC_2X996: u8 = 6;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211HeRuAllocation {
     Tone(u32),
     Tone2x996,
@@ -417,6 +536,7 @@ const NL80211_RATE_INFO_EHT_GI_1_6: u8 = 1;
 const NL80211_RATE_INFO_EHT_GI_3_2: u8 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211EhtGi {
     /// 0.8 usec
     Usec0_8,
@@ -469,6 +589,7 @@ const NL80211_RATE_INFO_EHT_RU_ALLOC_4X996: u8 = 15;
 
 /// EHT RU allocation values
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211EhtRuAllocation {
     Tone(u32),
     Tone52Plus26,
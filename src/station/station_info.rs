@@ -7,7 +7,7 @@ use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
     parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
-    Emitable, Parseable,
+    DecodeError, Emitable, Parseable,
 };
 
 use std::fmt::Debug;
@@ -66,6 +66,7 @@ const NL80211_STA_INFO_CONNECTED_TO_AS: u16 = 43;
 /// These attribute types are used with [`Nl80211Attr::StationInfo`]
 /// when getting information about a station.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211StationInfo {
     /// Time since last activity (msecs)
     InactiveTime(u32),
@@ -155,11 +156,13 @@ pub enum Nl80211StationInfo {
     AirtimeWeight(u16),
     /// Airtime link metric for mesh station
     AirtimeLinkMetric(u16),
-    /// Timestamp (nanoseconds) of station's association
+    /// `CLOCK_BOOTTIME` timestamp (nanoseconds) of station's association,
+    /// could be converted to wall-clock time via [crate::Nl80211BootTime].
     AssociationAtBoottime(u64),
     /// Set to true if the station has a path to an authentication server
     ConnectedToAuthServer(bool),
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -333,10 +336,7 @@ impl Nla for Nl80211StationInfo {
             }
             Nl80211StationInfo::PeerLinkState(d) => buffer[0] = (*d).into(),
             Nl80211StationInfo::BssParam(nlas) => nlas.as_slice().emit(buffer),
-            Nl80211StationInfo::StationFlags(d) => {
-                NativeEndian::write_u32(&mut buffer[0..4], (&d.mask).into());
-                NativeEndian::write_u32(&mut buffer[4..8], (&d.set).into());
-            }
+            Nl80211StationInfo::StationFlags(d) => d.emit(buffer),
             Nl80211StationInfo::LocalPowerMode(d)
             | Nl80211StationInfo::PeerPowerMode(d)
             | Nl80211StationInfo::NonPeerPowerMode(d) => {
@@ -500,27 +500,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                 );
                 Self::ConnectedTime(parse_u32(payload).context(err_msg)?)
             }
-            NL80211_STA_INFO_STA_FLAGS => {
-                Self::StationFlags(if payload.len() == 8 {
-                    let err_msg = format!(
-                        "Invalid NL80211_STA_INFO_STA_FLAGS value {:?}",
-                        payload
-                    );
-                    let mask =
-                        parse_u32(&payload[0..4]).context(err_msg.clone())?;
-                    let set = parse_u32(&payload[4..8]).context(err_msg)?;
-                    Nl80211StationFlagUpdate {
-                        mask: mask.into(),
-                        set: set.into(),
-                    }
-                } else {
-                    return Err(format!(
-                    "Invalid length of NL80211_STA_INFO_STA_FLAGS, expected length {} got {:?}",
-                    8, payload
-                )
-                .into());
-                })
-            }
+            NL80211_STA_INFO_STA_FLAGS => Self::StationFlags(
+                Nl80211StationFlagUpdate::parse(payload)
+                    .context("Invalid NL80211_STA_INFO_STA_FLAGS")?,
+            ),
             NL80211_STA_INFO_BEACON_LOSS => {
                 let err_msg = format!(
                     "Invalid NL80211_STA_INFO_BEACON_LOSS value {:?}",
@@ -724,6 +707,7 @@ pub const NL80211_PLINK_BLOCKED: u8 = 6;
 
 /// State of a mesh peer link finite state machine
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211PeerLinkState {
     /// Initial state, considered the implicit state of non existent mesh peer
     /// links
@@ -785,6 +769,7 @@ const NL80211_STA_BSS_PARAM_BEACON_INTERVAL: u16 = 5;
 /// These attribute types are used with [`Nl80211StationInfo::BssParam`]
 /// when getting information about the bitrate of a station.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211StationBssParam {
     CtsProtection,
     ShortPreamble,
@@ -793,6 +778,7 @@ pub enum Nl80211StationBssParam {
     DtimPeriod(u8),
     BeaconInterval(u16),
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -861,12 +847,45 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Nl80211StationFlagUpdate {
-    /// Mask of station flags to set
-    mask: VecNl80211StationFlag,
-    /// Which values to set them to
-    set: VecNl80211StationFlag,
+/// BSS parameters of [Nl80211StationInfo::BssParam] folded into single
+/// struct
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211StationBssParams {
+    pub cts_protection: bool,
+    pub short_preamble: bool,
+    pub short_slot_time: bool,
+    pub dtim_period: Option<u8>,
+    /// Beacon interval in TU
+    pub beacon_interval: Option<u16>,
+}
+
+impl Nl80211StationBssParams {
+    pub fn from_bss_params(params: &[Nl80211StationBssParam]) -> Self {
+        let mut ret = Self::default();
+        for param in params {
+            match param {
+                Nl80211StationBssParam::CtsProtection => {
+                    ret.cts_protection = true
+                }
+                Nl80211StationBssParam::ShortPreamble => {
+                    ret.short_preamble = true
+                }
+                Nl80211StationBssParam::ShortSlotTime => {
+                    ret.short_slot_time = true
+                }
+                Nl80211StationBssParam::DtimPeriod(d) => {
+                    ret.dtim_period = Some(*d)
+                }
+                Nl80211StationBssParam::BeaconInterval(d) => {
+                    ret.beacon_interval = Some(*d)
+                }
+                _ => (),
+            }
+        }
+        ret
+    }
 }
 
 pub const NL80211_STA_FLAG_AUTHORIZED: u32 = 1;
@@ -877,15 +896,128 @@ pub const NL80211_STA_FLAG_AUTHENTICATED: u32 = 5;
 pub const NL80211_STA_FLAG_TDLS_PEER: u32 = 6;
 pub const NL80211_STA_FLAG_ASSOCIATED: u32 = 7;
 
-/// Station flags
-///
-/// When a station is added to an AP interface, it is assumed to
-/// be already associated (and hence authenticated.)
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub(crate) struct VecNl80211StationFlag(pub Vec<Nl80211StationFlag>);
+bitflags::bitflags! {
+    /// Station flags
+    ///
+    /// When a station is added to an AP interface, it is assumed to
+    /// be already associated (and hence authenticated.)
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub struct Nl80211StationFlags: u32 {
+        /// Station is authorized (802.1X)
+        const Authorized = 1 << NL80211_STA_FLAG_AUTHORIZED;
+        /// Station is capable of receiving frames with short barker preamble
+        const ShortPreamble = 1 << NL80211_STA_FLAG_SHORT_PREAMBLE;
+        /// Station is WME/QoS capable
+        const Wme = 1 << NL80211_STA_FLAG_WME;
+        /// Station uses management frame protection
+        const Mfp = 1 << NL80211_STA_FLAG_MFP;
+        /// Station is authenticated
+        const Authenticated = 1 << NL80211_STA_FLAG_AUTHENTICATED;
+        /// Station is a TDLS peer. This flag should only be used in managed
+        /// mode (even in the flags mask). Note that the flag can't be
+        /// changed, it is only valid while adding a station, and attempts to
+        /// change it will silently be ignored (rather than rejected as
+        /// errors.)
+        const TdlsPeer = 1 << NL80211_STA_FLAG_TDLS_PEER;
+        /// station is associated; used with drivers that support
+        /// [crate::Nl80211Features::FullApClientState] to transition a
+        /// previously added station into associated state
+        const Associated = 1 << NL80211_STA_FLAG_ASSOCIATED;
+        const _ = !0;
+    }
+}
+
+impl From<Nl80211StationFlag> for Nl80211StationFlags {
+    fn from(v: Nl80211StationFlag) -> Self {
+        match u32::from(v) {
+            d if d < u32::BITS => Self::from_bits_retain(1 << d),
+            _ => Self::empty(),
+        }
+    }
+}
+
+/// Station flags update: only flags included in the mask are changed to the
+/// corresponding values in the set.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211StationFlagUpdate {
+    /// Mask of station flags to set
+    mask: Nl80211StationFlags,
+    /// Which values to set them to
+    set: Nl80211StationFlags,
+}
+
+impl Nl80211StationFlagUpdate {
+    pub(crate) const LENGTH: usize = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable specified flags
+    pub fn set(self, flags: Nl80211StationFlags) -> Self {
+        Self {
+            mask: self.mask | flags,
+            set: self.set | flags,
+        }
+    }
+
+    /// Disable specified flags
+    pub fn clear(self, flags: Nl80211StationFlags) -> Self {
+        Self {
+            mask: self.mask | flags,
+            set: self.set & !flags,
+        }
+    }
+
+    /// Flags this update is changing
+    pub fn mask(&self) -> Nl80211StationFlags {
+        self.mask
+    }
+
+    /// Flags enabled by this update, always a subset of [Self::mask()]
+    pub fn flags(&self) -> Nl80211StationFlags {
+        self.set & self.mask
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() == Self::LENGTH {
+            let err_msg =
+                format!("Invalid nl80211_sta_flag_update value {:?}", payload);
+            let mask = parse_u32(&payload[0..4]).context(err_msg.clone())?;
+            let set = parse_u32(&payload[4..8]).context(err_msg)?;
+            Ok(Self {
+                mask: Nl80211StationFlags::from_bits_retain(mask),
+                set: Nl80211StationFlags::from_bits_retain(set),
+            })
+        } else {
+            Err(format!(
+                "Invalid length of nl80211_sta_flag_update, expected length \
+                {} got {:?}",
+                Self::LENGTH,
+                payload
+            )
+            .into())
+        }
+    }
+}
+
+impl Emitable for Nl80211StationFlagUpdate {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.mask.bits());
+        NativeEndian::write_u32(&mut buffer[4..8], self.set.bits());
+    }
+}
 
 #[non_exhaustive]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211StationFlag {
     /// Station is authorized (802.1X)
     Authorized,
@@ -957,51 +1089,13 @@ impl std::fmt::Display for Nl80211StationFlag {
     }
 }
 
-const ALL_STATION_FLAGS: [Nl80211StationFlag; 7] = [
-    Nl80211StationFlag::Associated,
-    Nl80211StationFlag::Authenticated,
-    Nl80211StationFlag::Authorized,
-    Nl80211StationFlag::Mfp,
-    Nl80211StationFlag::ShortPreamble,
-    Nl80211StationFlag::TdlsPeer,
-    Nl80211StationFlag::Wme,
-];
-
-impl From<u32> for VecNl80211StationFlag {
-    fn from(d: u32) -> Self {
-        let mut got: u32 = 0;
-        let mut ret = Vec::new();
-
-        for flag in ALL_STATION_FLAGS {
-            if (d & u32::from(flag)) > 0 {
-                ret.push(flag);
-                got += u32::from(flag);
-            }
-        }
-        if got != d {
-            ret.push(Nl80211StationFlag::Other(d - got));
-        }
-
-        Self(ret)
-    }
-}
-
-impl From<&VecNl80211StationFlag> for u32 {
-    fn from(v: &VecNl80211StationFlag) -> u32 {
-        let mut d: u32 = 0;
-        for flag in &v.0 {
-            d += u32::from(*flag);
-        }
-        d
-    }
-}
-
 pub const NL80211_MESH_POWER_UNKNOWN: u32 = 0;
 pub const NL80211_MESH_POWER_ACTIVE: u32 = 1;
 pub const NL80211_MESH_POWER_LIGHT_SLEEP: u32 = 2;
 pub const NL80211_MESH_POWER_DEEP_SLEEP: u32 = 3;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211MeshPowerMode {
     Unknown,
     Active,
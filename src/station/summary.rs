@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Rate, Nl80211StationBssParams, Nl80211StationCounters,
+    Nl80211StationInfo, Nl80211TidStatsTotal,
+};
+
+/// Commonly monitored values folded from [crate::Nl80211Attr::StationInfo]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211StationSummary {
+    /// Accumulating counters, 64 bits byte counters are preferred
+    pub counters: Nl80211StationCounters,
+    /// Time in milliseconds since last activity
+    pub inactive_time: Option<u32>,
+    /// Signal strength of last received PPDU in dBm
+    pub signal: Option<i8>,
+    /// Average signal strength in dBm
+    pub signal_avg: Option<i8>,
+    /// Current unicast transmit rate
+    pub tx_rate: Option<Nl80211Rate>,
+    /// Last unicast receive rate
+    pub rx_rate: Option<Nl80211Rate>,
+    /// Expected throughput in kbit/s
+    pub expected_throughput: Option<u32>,
+    pub bss_params: Option<Nl80211StationBssParams>,
+    /// Sum of the per-TID statistics, `None` if not reported
+    pub tid_stats: Option<Nl80211TidStatsTotal>,
+}
+
+impl Nl80211StationSummary {
+    pub fn from_station_info(infos: &[Nl80211StationInfo]) -> Self {
+        let mut ret = Self {
+            counters: Nl80211StationCounters::from_station_info(infos),
+            ..Default::default()
+        };
+        for info in infos {
+            match info {
+                Nl80211StationInfo::InactiveTime(d) => {
+                    ret.inactive_time = Some(*d)
+                }
+                Nl80211StationInfo::Signal(d) => ret.signal = Some(*d),
+                Nl80211StationInfo::SignalAvg(d) => ret.signal_avg = Some(*d),
+                Nl80211StationInfo::TxBitrate(v) => {
+                    ret.tx_rate = Some(Nl80211Rate::from_rate_info(v))
+                }
+                Nl80211StationInfo::RxBitrate(v) => {
+                    ret.rx_rate = Some(Nl80211Rate::from_rate_info(v))
+                }
+                Nl80211StationInfo::ExpectedThroughput(d) => {
+                    ret.expected_throughput = Some(*d)
+                }
+                Nl80211StationInfo::BssParam(v) => {
+                    ret.bss_params =
+                        Some(Nl80211StationBssParams::from_bss_params(v))
+                }
+                Nl80211StationInfo::TidStats(v) => {
+                    ret.tid_stats =
+                        Some(Nl80211TidStatsTotal::from_tid_stats(v))
+                }
+                _ => (),
+            }
+        }
+        ret
+    }
+
+    /// Ratio of failed transmissions to transmitted packets, `None` if
+    /// the counters are not reported or no packet transmitted
+    pub fn tx_loss_rate(&self) -> Option<f64> {
+        ratio(self.counters.tx_failed?, self.counters.tx_packets?)
+    }
+
+    /// Ratio of transmission retries to transmitted packets, `None` if
+    /// the counters are not reported or no packet transmitted
+    pub fn tx_retry_rate(&self) -> Option<f64> {
+        ratio(self.counters.tx_retries?, self.counters.tx_packets?)
+    }
+
+    /// Ratio of lost beacons to expected beacons, `None` if the counters
+    /// are not reported or no beacon expected
+    pub fn beacon_loss_rate(&self) -> Option<f64> {
+        let loss = self.counters.beacon_loss?;
+        ratio(loss, self.counters.beacon_rx? + loss)
+    }
+}
+
+fn ratio(count: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| count as f64 / total as f64)
+}
@@ -9,19 +9,64 @@ use netlink_packet_utils::{
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct NestedNl80211TidStats(Vec<Nl80211TidStats>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NestedNl80211TidStats {
+    tid: u16,
+    stats: Vec<Nl80211TidStats>,
+}
+
+impl NestedNl80211TidStats {
+    /// TID of these statistics, 16 for non-QoS traffic
+    pub fn tid(&self) -> u16 {
+        self.tid
+    }
+
+    /// Statistics of single TID, kernel omits the TIDs without any
+    /// statistics
+    pub fn stats(&self) -> &[Nl80211TidStats] {
+        self.stats.as_slice()
+    }
+}
+
+/// Sum of the MSDU counters of all TIDs in
+/// [crate::Nl80211StationInfo::TidStats]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211TidStatsTotal {
+    pub rx_msdu: u64,
+    pub tx_msdu: u64,
+    pub tx_msdu_retries: u64,
+    pub tx_msdu_failed: u64,
+}
+
+impl Nl80211TidStatsTotal {
+    pub fn from_tid_stats(tids: &[NestedNl80211TidStats]) -> Self {
+        let mut ret = Self::default();
+        for stat in tids.iter().flat_map(|tid| tid.stats()) {
+            match stat {
+                Nl80211TidStats::RxMsdu(d) => ret.rx_msdu += d,
+                Nl80211TidStats::TxMsdu(d) => ret.tx_msdu += d,
+                Nl80211TidStats::TxMsduRetries(d) => ret.tx_msdu_retries += d,
+                Nl80211TidStats::TxMsduFailed(d) => ret.tx_msdu_failed += d,
+                _ => (),
+            }
+        }
+        ret
+    }
+}
 
 impl Nla for NestedNl80211TidStats {
     fn value_len(&self) -> usize {
-        self.0.as_slice().buffer_len()
+        self.stats.as_slice().buffer_len()
     }
 
     fn kind(&self) -> u16 {
-        unimplemented!("Variable between 0-16")
+        self.tid + 1
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
-        self.0.as_slice().emit(buffer);
+        self.stats.as_slice().emit(buffer);
     }
 }
 
@@ -32,13 +77,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         let payload = buf.value();
         let err_msg =
             format!("Invalid NestedNl80211TidStats value {:?}", payload);
+        // The nested attribute kind is TID + 1
+        let tid = buf.kind().checked_sub(1).ok_or_else(|| {
+            DecodeError::from(format!(
+                "Invalid NestedNl80211TidStats kind {}",
+                buf.kind()
+            ))
+        })?;
         let mut nlas = Vec::new();
 
         for nla in NlasIterator::new(payload) {
             let nla = &nla.context(err_msg.clone())?;
             nlas.push(Nl80211TidStats::parse(nla).context(err_msg.clone())?);
         }
-        Ok(Self(nlas))
+        Ok(Self { tid, stats: nlas })
     }
 }
 
@@ -49,6 +101,7 @@ const NL80211_TID_STATS_TX_MSDU_FAILED: u16 = 4;
 const NL80211_TID_STATS_TXQ_STATS: u16 = 6;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211TidStats {
     /// Number of MSDUs received
     RxMsdu(u64),
@@ -61,6 +114,7 @@ pub enum Nl80211TidStats {
     TxMsduFailed(u64),
     TransmitQueueStats(Vec<Nl80211TransmitQueueStat>),
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -70,7 +124,7 @@ impl Nla for Nl80211TidStats {
             Self::RxMsdu(_)
             | Self::TxMsdu(_)
             | Self::TxMsduRetries(_)
-            | Self::TxMsduFailed(_) => 4,
+            | Self::TxMsduFailed(_) => 8,
             Self::TransmitQueueStats(nlas) => nlas.as_slice().buffer_len(),
             Self::Other(attr) => attr.value_len(),
         }
@@ -168,6 +222,7 @@ const NL80211_TXQ_STATS_TX_PACKETS: u16 = 10;
 const NL80211_TXQ_STATS_MAX_FLOWS: u16 = 11;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211TransmitQueueStat {
     BacklogBytes(u32),
     BacklogPackets(u32),
@@ -180,6 +235,7 @@ pub enum Nl80211TransmitQueueStat {
     TxBytes(u32),
     TxPackets(u32),
     MaxFlows(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -323,3 +379,56 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         })
     }
 }
+
+/// Difference between two snapshots of accumulating counters
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211CounterDelta<T> {
+    /// Counters increased since previous snapshot, holding the increments
+    Delta(T),
+    /// Counters have been reset since previous snapshot, for example due to
+    /// driver reload or reassociation, the increments are unknown
+    Reset,
+}
+
+impl<T> Nl80211CounterDelta<T> {
+    pub fn is_reset(&self) -> bool {
+        matches!(self, Self::Reset)
+    }
+
+    /// Return `None` on counter reset
+    pub fn delta(self) -> Option<T> {
+        match self {
+            Self::Delta(d) => Some(d),
+            Self::Reset => None,
+        }
+    }
+}
+
+/// Width of the counter reported by kernel. The 32 bits counters wrap
+/// around on long running links, hence decrease of them is treated as
+/// wrap instead of reset.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211CounterWidth {
+    U32,
+    #[default]
+    U64,
+}
+
+// Return `Err(())` if 64 bits counter decreased
+pub(crate) fn counter_delta(
+    current: Option<u64>,
+    previous: Option<u64>,
+    width: Nl80211CounterWidth,
+) -> Result<Option<u64>, ()> {
+    match (current, previous, width) {
+        (Some(cur), Some(prev), Nl80211CounterWidth::U32) => {
+            Ok(Some((cur as u32).wrapping_sub(prev as u32).into()))
+        }
+        (Some(cur), Some(prev), Nl80211CounterWidth::U64) => {
+            cur.checked_sub(prev).map(Some).ok_or(())
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future, TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Freq,
+    Nl80211Handle, Nl80211Message, Nl80211SurveyResult,
+};
+
+pub struct Nl80211SurveyGetRequest {
+    handle: Nl80211Handle,
+    attributes: Vec<Nl80211Attr>,
+    frequency: Option<Nl80211Freq>,
+}
+
+impl Nl80211SurveyGetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Self {
+        Nl80211SurveyGetRequest {
+            handle,
+            attributes,
+            frequency: None,
+        }
+    }
+
+    /// Request overall radio statistics to be returned along with other
+    /// survey data. Kernel might include an entry without channel frequency
+    /// holding the global radio statistics.
+    pub fn radio_stats(mut self) -> Self {
+        if !self.attributes.contains(&Nl80211Attr::SurveyRadioStats) {
+            self.attributes.push(Nl80211Attr::SurveyRadioStats);
+        }
+        self
+    }
+
+    /// Only include the survey data of specified channel frequency.
+    /// Kernel always dumps all channels, hence the filtering is done after
+    /// the reply is received. Entries without channel frequency (global
+    /// radio statistics) are not filtered out.
+    pub fn frequency(mut self, freq: Nl80211Freq) -> Self {
+        self.frequency = Some(freq);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SurveyGetRequest {
+            mut handle,
+            attributes,
+            frequency,
+        } = self;
+
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::GetSurvey,
+            attributes,
+        };
+
+        let flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags)
+            .await
+            .try_filter(move |msg| {
+                future::ready(match frequency {
+                    Some(freq) => {
+                        Nl80211SurveyResult::from_message(&msg.payload)
+                            .map(|result| {
+                                result.frequency.is_none()
+                                    || result.frequency == Some(freq)
+                            })
+                            .unwrap_or(true)
+                    }
+                    None => true,
+                })
+            })
+    }
+
+    /// Similar to [Self::execute()] but decodes each survey entry into
+    /// [Nl80211SurveyResult].
+    pub async fn results(
+        self,
+    ) -> impl TryStream<Ok = Nl80211SurveyResult, Error = Nl80211Error> {
+        self.execute().await.try_filter_map(|msg| {
+            future::ready(Ok(Nl80211SurveyResult::from_message(&msg.payload)))
+        })
+    }
+}
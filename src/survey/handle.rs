@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Nl80211Attr, Nl80211Handle, Nl80211IfIndex, Nl80211SurveyGetRequest,
+    Nl80211WdevId,
+};
+
+#[derive(Debug, Clone)]
+pub struct Nl80211SurveyHandle(Nl80211Handle);
+
+impl Nl80211SurveyHandle {
+    pub fn new(handle: Nl80211Handle) -> Self {
+        Nl80211SurveyHandle(handle)
+    }
+
+    /// Retrieve the channel survey data of specified interface
+    /// (equivalent to `iw dev DEVICE survey dump`)
+    pub fn dump(
+        &mut self,
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211SurveyGetRequest {
+        Nl80211SurveyGetRequest::new(
+            self.0.clone(),
+            vec![Nl80211Attr::IfIndex(if_index.get())],
+        )
+    }
+
+    /// Retrieve the channel survey data of specified wireless device,
+    /// useful for devices without network interface like P2P device.
+    pub fn dump_wdev(
+        &mut self,
+        wdev: Nl80211WdevId,
+    ) -> Nl80211SurveyGetRequest {
+        Nl80211SurveyGetRequest::new(
+            self.0.clone(),
+            vec![Nl80211Attr::Wdev(wdev.get())],
+        )
+    }
+}
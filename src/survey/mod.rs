@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod get;
+mod handle;
+mod result;
+mod survey_info;
+
+pub use self::get::Nl80211SurveyGetRequest;
+pub use self::handle::Nl80211SurveyHandle;
+pub use self::result::Nl80211SurveyResult;
+pub use self::survey_info::Nl80211SurveyInfo;
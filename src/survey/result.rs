@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    stats::counter_delta, Nl80211Attr, Nl80211CounterDelta,
+    Nl80211CounterWidth, Nl80211Freq, Nl80211IfIndex, Nl80211Message,
+    Nl80211SurveyInfo,
+};
+
+/// Survey data of a channel, or of the whole radio when `frequency` is
+/// `None` (only reported when requested by
+/// [crate::Nl80211SurveyGetRequest::radio_stats()]).
+/// All the time values are in milliseconds.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211SurveyResult {
+    pub if_index: Option<Nl80211IfIndex>,
+    /// Channel frequency including the kHz offset if any
+    pub frequency: Option<Nl80211Freq>,
+    /// Noise level in dBm
+    pub noise: Option<i8>,
+    /// Channel is currently being used
+    pub in_use: bool,
+    pub time: Option<u64>,
+    pub time_busy: Option<u64>,
+    pub time_ext_busy: Option<u64>,
+    pub time_rx: Option<u64>,
+    pub time_tx: Option<u64>,
+    pub time_scan: Option<u64>,
+    pub time_bss_rx: Option<u64>,
+}
+
+impl Nl80211SurveyResult {
+    /// Return `None` if specified message has no
+    /// [Nl80211Attr::SurveyInfo].
+    pub fn from_message(msg: &Nl80211Message) -> Option<Self> {
+        let mut ret = Self::default();
+        let mut infos = None;
+        for attr in msg.attributes.as_slice() {
+            match attr {
+                Nl80211Attr::IfIndex(d) => {
+                    ret.if_index = Some(Nl80211IfIndex::new(*d))
+                }
+                Nl80211Attr::SurveyInfo(v) => infos = Some(v),
+                _ => (),
+            }
+        }
+        let mut offset_khz = 0;
+        for info in infos? {
+            match info {
+                Nl80211SurveyInfo::Frequency(d) => ret.frequency = Some(*d),
+                Nl80211SurveyInfo::FrequencyOffset(d) => offset_khz = *d,
+                Nl80211SurveyInfo::Noise(d) => ret.noise = Some(*d),
+                Nl80211SurveyInfo::InUse => ret.in_use = true,
+                Nl80211SurveyInfo::Time(d) => ret.time = Some(*d),
+                Nl80211SurveyInfo::TimeBusy(d) => ret.time_busy = Some(*d),
+                Nl80211SurveyInfo::TimeExtBusy(d) => {
+                    ret.time_ext_busy = Some(*d)
+                }
+                Nl80211SurveyInfo::TimeRx(d) => ret.time_rx = Some(*d),
+                Nl80211SurveyInfo::TimeTx(d) => ret.time_tx = Some(*d),
+                Nl80211SurveyInfo::TimeScan(d) => ret.time_scan = Some(*d),
+                Nl80211SurveyInfo::TimeBssRx(d) => ret.time_bss_rx = Some(*d),
+                _ => (),
+            }
+        }
+        if let Some(freq) = ret.frequency {
+            ret.frequency =
+                Some(Nl80211Freq::from_mhz_and_offset(freq.mhz(), offset_khz));
+        }
+        Some(ret)
+    }
+
+    /// Compare with previous snapshot of the same channel, return the
+    /// increments of all the time counters or [Nl80211CounterDelta::Reset]
+    /// if any counter decreased or the channel differs.
+    /// Counters missing from either snapshot are `None` in the increments.
+    pub fn delta(&self, previous: &Self) -> Nl80211CounterDelta<Self> {
+        if self.frequency != previous.frequency {
+            return Nl80211CounterDelta::Reset;
+        }
+        use Nl80211CounterWidth::U64;
+        let delta = || -> Result<Self, ()> {
+            Ok(Self {
+                time: counter_delta(self.time, previous.time, U64)?,
+                time_busy: counter_delta(
+                    self.time_busy,
+                    previous.time_busy,
+                    U64,
+                )?,
+                time_ext_busy: counter_delta(
+                    self.time_ext_busy,
+                    previous.time_ext_busy,
+                    U64,
+                )?,
+                time_rx: counter_delta(self.time_rx, previous.time_rx, U64)?,
+                time_tx: counter_delta(self.time_tx, previous.time_tx, U64)?,
+                time_scan: counter_delta(
+                    self.time_scan,
+                    previous.time_scan,
+                    U64,
+                )?,
+                time_bss_rx: counter_delta(
+                    self.time_bss_rx,
+                    previous.time_bss_rx,
+                    U64,
+                )?,
+                ..self.clone()
+            })
+        };
+        match delta() {
+            Ok(d) => Nl80211CounterDelta::Delta(d),
+            Err(()) => Nl80211CounterDelta::Reset,
+        }
+    }
+}
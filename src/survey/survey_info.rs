@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::Nl80211Freq;
+
+const NL80211_SURVEY_INFO_FREQUENCY: u16 = 1;
+const NL80211_SURVEY_INFO_NOISE: u16 = 2;
+const NL80211_SURVEY_INFO_IN_USE: u16 = 3;
+const NL80211_SURVEY_INFO_TIME: u16 = 4;
+const NL80211_SURVEY_INFO_TIME_BUSY: u16 = 5;
+const NL80211_SURVEY_INFO_TIME_EXT_BUSY: u16 = 6;
+const NL80211_SURVEY_INFO_TIME_RX: u16 = 7;
+const NL80211_SURVEY_INFO_TIME_TX: u16 = 8;
+const NL80211_SURVEY_INFO_TIME_SCAN: u16 = 9;
+// const NL80211_SURVEY_INFO_PAD: u16 = 10;
+const NL80211_SURVEY_INFO_TIME_BSS_RX: u16 = 11;
+const NL80211_SURVEY_INFO_FREQUENCY_OFFSET: u16 = 12;
+
+/// Survey information of a channel
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211SurveyInfo {
+    /// Center frequency of channel, kernel reports it in MHz
+    Frequency(Nl80211Freq),
+    /// Noise level of channel in dBm
+    Noise(i8),
+    /// Channel is currently being used
+    InUse,
+    /// Amount of time (in ms) that the radio was turned on (on channel or
+    /// globally)
+    Time(u64),
+    /// Amount of time (in ms) the primary channel was sensed busy (either
+    /// due to activity or energy detect)
+    TimeBusy(u64),
+    /// Amount of time (in ms) the extension channel was sensed busy
+    TimeExtBusy(u64),
+    /// Amount of time (in ms) the radio spent receiving data (on channel or
+    /// globally)
+    TimeRx(u64),
+    /// Amount of time (in ms) the radio spent transmitting data (on channel
+    /// or globally)
+    TimeTx(u64),
+    /// Time (in ms) the radio spent for scan (on this channel or globally)
+    TimeScan(u64),
+    /// Amount of time (in ms) the radio spent receiving data on a local
+    /// BSS
+    TimeBssRx(u64),
+    /// Offset of the center frequency in kHz
+    FrequencyOffset(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211SurveyInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Frequency(_) | Self::FrequencyOffset(_) => 4,
+            Self::Noise(_) => 1,
+            Self::InUse => 0,
+            Self::Time(_)
+            | Self::TimeBusy(_)
+            | Self::TimeExtBusy(_)
+            | Self::TimeRx(_)
+            | Self::TimeTx(_)
+            | Self::TimeScan(_)
+            | Self::TimeBssRx(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Frequency(_) => NL80211_SURVEY_INFO_FREQUENCY,
+            Self::Noise(_) => NL80211_SURVEY_INFO_NOISE,
+            Self::InUse => NL80211_SURVEY_INFO_IN_USE,
+            Self::Time(_) => NL80211_SURVEY_INFO_TIME,
+            Self::TimeBusy(_) => NL80211_SURVEY_INFO_TIME_BUSY,
+            Self::TimeExtBusy(_) => NL80211_SURVEY_INFO_TIME_EXT_BUSY,
+            Self::TimeRx(_) => NL80211_SURVEY_INFO_TIME_RX,
+            Self::TimeTx(_) => NL80211_SURVEY_INFO_TIME_TX,
+            Self::TimeScan(_) => NL80211_SURVEY_INFO_TIME_SCAN,
+            Self::TimeBssRx(_) => NL80211_SURVEY_INFO_TIME_BSS_RX,
+            Self::FrequencyOffset(_) => NL80211_SURVEY_INFO_FREQUENCY_OFFSET,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Frequency(d) => NativeEndian::write_u32(buffer, d.mhz()),
+            Self::FrequencyOffset(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Noise(d) => buffer[0] = *d as u8,
+            Self::InUse => (),
+            Self::Time(d)
+            | Self::TimeBusy(d)
+            | Self::TimeExtBusy(d)
+            | Self::TimeRx(d)
+            | Self::TimeTx(d)
+            | Self::TimeScan(d)
+            | Self::TimeBssRx(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SurveyInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_SURVEY_INFO_FREQUENCY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_FREQUENCY value {:?}",
+                    payload
+                );
+                Self::Frequency(Nl80211Freq::from_mhz(
+                    parse_u32(payload).context(err_msg)?,
+                ))
+            }
+            NL80211_SURVEY_INFO_NOISE => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_NOISE value {:?}",
+                    payload
+                );
+                Self::Noise(parse_u8(payload).context(err_msg)? as i8)
+            }
+            NL80211_SURVEY_INFO_IN_USE => Self::InUse,
+            NL80211_SURVEY_INFO_TIME => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME value {:?}",
+                    payload
+                );
+                Self::Time(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_BUSY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_BUSY value {:?}",
+                    payload
+                );
+                Self::TimeBusy(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_EXT_BUSY => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_EXT_BUSY value {:?}",
+                    payload
+                );
+                Self::TimeExtBusy(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_RX value {:?}",
+                    payload
+                );
+                Self::TimeRx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_TX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_TX value {:?}",
+                    payload
+                );
+                Self::TimeTx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_SCAN => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_SCAN value {:?}",
+                    payload
+                );
+                Self::TimeScan(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_TIME_BSS_RX => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_TIME_BSS_RX value {:?}",
+                    payload
+                );
+                Self::TimeBssRx(parse_u64(payload).context(err_msg)?)
+            }
+            NL80211_SURVEY_INFO_FREQUENCY_OFFSET => {
+                let err_msg = format!(
+                    "Invalid NL80211_SURVEY_INFO_FREQUENCY_OFFSET value {:?}",
+                    payload
+                );
+                Self::FrequencyOffset(parse_u32(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, SystemTime};
+
+/// Timestamp of `CLOCK_BOOTTIME` in nanoseconds, the monotonic clock
+/// including time spent in suspend, used by kernel for fields like
+/// [crate::Nl80211BssInfo::LastSeenBootTime] and
+/// [crate::Nl80211StationInfo::AssociationAtBoottime].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211BootTime(u64);
+
+/// Wall-clock time estimated from a [Nl80211BootTime]. The `uncertainty`
+/// only covers the error of sampling the two clocks, it does not include
+/// the accuracy of the kernel timestamp itself or wall-clock adjustment (for
+/// example NTP or manual change) happened after the event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211WallClockEstimate<T> {
+    pub time: T,
+    pub uncertainty: Duration,
+}
+
+impl Nl80211BootTime {
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    pub const fn as_nanos(&self) -> u64 {
+        self.0
+    }
+
+    /// Current `CLOCK_BOOTTIME`
+    pub fn now() -> Self {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `ts` is valid for write and CLOCK_BOOTTIME is supported by
+        // all Linux kernels providing nl80211.
+        unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut ts) };
+        Self(ts.tv_sec as u64 * 1_000_000_000 + ts.tv_nsec as u64)
+    }
+
+    /// Time elapsed since this timestamp, saturated to zero if it is in
+    /// the future.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(Self::now().0.saturating_sub(self.0))
+    }
+
+    /// Estimate the wall-clock time of this timestamp by comparing with the
+    /// current `CLOCK_BOOTTIME` and system time.
+    pub fn to_system_time(&self) -> Nl80211WallClockEstimate<SystemTime> {
+        let before = Self::now().0;
+        let wall_now = SystemTime::now();
+        let after = Self::now().0;
+        let boot_now = before + (after - before) / 2;
+        let time = if boot_now >= self.0 {
+            wall_now - Duration::from_nanos(boot_now - self.0)
+        } else {
+            wall_now + Duration::from_nanos(self.0 - boot_now)
+        };
+        Nl80211WallClockEstimate {
+            time,
+            uncertainty: Duration::from_nanos((after - before).div_ceil(2)),
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(
+        &self,
+    ) -> Nl80211WallClockEstimate<chrono::DateTime<chrono::Utc>> {
+        let estimate = self.to_system_time();
+        Nl80211WallClockEstimate {
+            time: estimate.time.into(),
+            uncertainty: estimate.uncertainty,
+        }
+    }
+
+    #[cfg(feature = "time")]
+    pub fn to_offset_date_time(
+        &self,
+    ) -> Nl80211WallClockEstimate<time::OffsetDateTime> {
+        let estimate = self.to_system_time();
+        Nl80211WallClockEstimate {
+            time: estimate.time.into(),
+            uncertainty: estimate.uncertainty,
+        }
+    }
+}
+
+impl From<u64> for Nl80211BootTime {
+    fn from(nanos: u64) -> Self {
+        Self(nanos)
+    }
+}
+
+impl From<Nl80211BootTime> for u64 {
+    fn from(v: Nl80211BootTime) -> u64 {
+        v.0
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+//! Conversion between the units used by nl80211 power and signal
+//! attributes.
+
+/// Convert mBm(100 * dBm), used by TX power and BSS signal attributes, to
+/// dBm
+pub fn mbm_to_dbm(mbm: i32) -> f32 {
+    mbm as f32 / 100.0
+}
+
+/// Convert dBm to mBm(100 * dBm), rounded to the nearest integer
+pub fn dbm_to_mbm(dbm: f32) -> i32 {
+    (dbm * 100.0).round() as i32
+}
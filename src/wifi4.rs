@@ -17,6 +17,7 @@ const NL80211_CHAN_HT40PLUS: u32 = 3;
 
 // kernel data type: `enum nl80211_channel_type`
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211HtWiphyChannelType {
     NoHt,
@@ -69,6 +70,7 @@ const IEEE80211_HT_CAP_LSIG_TXOP_PROT: u16 = 0x8000;
 // For linux kernel `struct  ieee80211_ht_cap.cap_info`
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211HtCaps: u16 {
         /// ndicates support for receiving LDPC coded packets
@@ -133,6 +135,7 @@ const NL80211_BAND_MCS_INFO_LEN: usize = 16;
 
 // kernel data type: `struct ieee80211_mcs_info`
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HtMcsInfo {
     pub rx_mask: [u8; IEEE80211_HT_MCS_MASK_LEN],
     /// The Rx Highest Supported Data Rate in Mb/s. The 0 means STA does not
@@ -199,6 +202,7 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211HtMcsInfo {
 const NL80211_HT_CAPABILITY_LEN: usize = 26;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HtCapabilityMask(pub [u8; NL80211_HT_CAPABILITY_LEN]);
 
 impl Nl80211HtCapabilityMask {
@@ -234,6 +238,7 @@ impl Emitable for Nl80211HtCapabilityMask {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211ElementHtCap {
     /// HT Capabilities Info
     pub caps: Nl80211HtCaps,
@@ -314,22 +319,25 @@ impl Emitable for Nl80211ElementHtCap {
             );
             return;
         }
+        // Some fields expect buffer of exact size
         let mut offset = 0;
-        self.caps.emit(buffer);
-        offset += self.caps.buffer_len();
-        self.a_mpdu_para.emit(&mut buffer[offset..]);
-        offset += self.a_mpdu_para.buffer_len();
-        self.mcs_set.emit(&mut buffer[offset..]);
-        offset += self.mcs_set.buffer_len();
-        self.ht_ext_cap.emit(&mut buffer[offset..]);
-        offset += self.ht_ext_cap.buffer_len();
-        self.transmit_beamforming_cap.emit(&mut buffer[offset..]);
-        offset += self.transmit_beamforming_cap.buffer_len();
-        self.asel_cap.emit(&mut buffer[offset..]);
+        for field in [
+            &self.caps as &dyn Emitable,
+            &self.a_mpdu_para,
+            &self.mcs_set,
+            &self.ht_ext_cap,
+            &self.transmit_beamforming_cap,
+            &self.asel_cap,
+        ] {
+            let len = field.buffer_len();
+            field.emit(&mut buffer[offset..offset + len]);
+            offset += len;
+        }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HtAMpduPara {
     /// The maximum length of A-MPDU that the STA can receive.
     /// The length equal to: 2 ** (13 + max_len_exponent) - 1
@@ -389,6 +397,7 @@ impl Nl80211HtAMpduPara {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HtTxParameter {
     pub mcs_set_defined: bool,
     pub tx_rx_mcs_set_not_equal: bool,
@@ -418,6 +427,7 @@ impl From<Nl80211HtTxParameter> for u8 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HtExtendedCap {
     pub pco: bool,
     pub pco_trans_time: u8,
@@ -479,6 +489,7 @@ impl From<Nl80211HtExtendedCap> for [u8; 2] {
 
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211HtTransmitBeamformingCaps: u32 {
         /// Indicates this STA can receive Transmit Beamforming steered
@@ -633,6 +644,7 @@ impl Emitable for Nl80211HtTransmitBeamformingCaps {
 
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211HtAselCaps : u8 {
         /// Indicates this STA supports ASEL
@@ -678,3 +690,104 @@ impl Emitable for Nl80211HtAselCaps {
         buffer.copy_from_slice(&self.bits().to_ne_bytes())
     }
 }
+
+const HT_OPERATION_INFO_LEN: usize = 5;
+
+/// HT Operation element
+///
+/// IEEE 802.11-2020 section 9.4.2.56
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementHtOperation {
+    /// Channel number of the primary channel
+    pub primary_channel: u8,
+    /// HT Operation Information
+    pub info: [u8; HT_OPERATION_INFO_LEN],
+    /// Basic HT-MCS Set
+    pub basic_mcs_set: Nl80211HtMcsInfo,
+}
+
+impl Nl80211ElementHtOperation {
+    pub const LENGTH: usize = 22;
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::LENGTH {
+            return Err(format!(
+                "Nl80211ElementHtOperation buffer size is smaller than \
+                required size {}: {buf:?}",
+                Self::LENGTH
+            )
+            .into());
+        }
+        let mut info = [0u8; HT_OPERATION_INFO_LEN];
+        info.copy_from_slice(&buf[1..1 + HT_OPERATION_INFO_LEN]);
+        Ok(Self {
+            primary_channel: buf[0],
+            info,
+            basic_mcs_set: Nl80211HtMcsInfo::parse(
+                &buf[1 + HT_OPERATION_INFO_LEN..],
+            )?,
+        })
+    }
+
+    /// Offset of the secondary channel relative to the primary channel:
+    /// 1 for above, 3 for below, 0 for no secondary channel.
+    pub fn secondary_channel_offset(&self) -> u8 {
+        get_bits_as_u8(&self.info, 0, 1)
+    }
+
+    /// Whether any channel width in the STA's Supported Channel Width Set is
+    /// allowed, otherwise only 20 MHz.
+    pub fn sta_channel_width_any(&self) -> bool {
+        get_bit(&self.info, 2)
+    }
+
+    /// Whether use of reduced interframe space is permitted.
+    pub fn rifs_mode(&self) -> bool {
+        get_bit(&self.info, 3)
+    }
+
+    /// HT Protection: 0 for no protection mode, 1 for nonmember protection
+    /// mode, 2 for 20 MHz protection mode, 3 for non-HT mixed mode.
+    pub fn ht_protection(&self) -> u8 {
+        get_bits_as_u8(&self.info, 8, 9)
+    }
+
+    /// Whether HT STAs that are not HT-greenfield capable are associated.
+    pub fn nongreenfield_sta_present(&self) -> bool {
+        get_bit(&self.info, 10)
+    }
+
+    /// Whether the use of protection for non-HT STAs by overlapping BSSs is
+    /// determined to be desirable.
+    pub fn obss_non_ht_sta_present(&self) -> bool {
+        get_bit(&self.info, 12)
+    }
+
+    /// Channel Center Frequency Segment 2 used for VHT 160 MHz and 80+80 MHz
+    /// operation with reduced NSS.
+    pub fn channel_center_freq_seg2(&self) -> u8 {
+        get_bits_as_u8(&self.info, 13, 20)
+    }
+}
+
+impl Emitable for Nl80211ElementHtOperation {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        if buffer.len() < Self::LENGTH {
+            log::error!(
+                "Nl80211ElementHtOperation buffer size is smaller than \
+                required size {}: {buffer:?}",
+                Self::LENGTH
+            );
+            return;
+        }
+        buffer[0] = self.primary_channel;
+        buffer[1..1 + HT_OPERATION_INFO_LEN].copy_from_slice(&self.info);
+        self.basic_mcs_set
+            .emit(&mut buffer[1 + HT_OPERATION_INFO_LEN..Self::LENGTH]);
+    }
+}
@@ -14,6 +14,7 @@ const NL80211_BAND_VHT_MCS_INFO_LEN: usize = 8;
 // We cannot use buffer! macro here as these u16 are all little endian while
 // The `buffer!` does not support little endian yet.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211VhtMcsInfo {
     pub rx_mcs_map: u16,
     pub rx_highest: u16,
@@ -107,6 +108,7 @@ const IEEE80211_VHT_CAP_EXT_NSS_BW_MASK: u32 = 0xc0000000;
 
 bitflags::bitflags! {
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211VhtCapInfo: u32 {
         const MaxMpduLength3895 = IEEE80211_VHT_CAP_MAX_MPDU_LENGTH_3895;
@@ -171,6 +173,7 @@ impl Emitable for Nl80211VhtCapInfo {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211VhtCapability {
     pub cap_info: Nl80211VhtCapInfo,
     pub mcs_info: Nl80211VhtMcsInfo,
@@ -224,3 +227,67 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211VhtCapability {
         }
     }
 }
+
+/// VHT Operation element
+///
+/// IEEE 802.11-2020 section 9.4.2.158
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementVhtOperation {
+    /// Channel Width: 0 for 20 MHz or 40 MHz, 1 for 80 MHz, 160 MHz or
+    /// 80+80 MHz, 2 and 3 are deprecated 160 MHz and 80+80 MHz.
+    pub channel_width: u8,
+    /// Channel Center Frequency Segment 0
+    pub center_freq_seg0: u8,
+    /// Channel Center Frequency Segment 1
+    pub center_freq_seg1: u8,
+    /// Basic VHT-MCS And NSS Set, 2 bits for each stream.
+    pub basic_mcs_nss: u16,
+}
+
+impl Nl80211ElementVhtOperation {
+    pub const LENGTH: usize = 5;
+}
+
+impl Emitable for Nl80211ElementVhtOperation {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        if buffer.len() < Self::LENGTH {
+            log::error!(
+                "Buffer size {} is smaller than desired size {}",
+                buffer.len(),
+                Self::LENGTH,
+            );
+            return;
+        }
+        buffer[0] = self.channel_width;
+        buffer[1] = self.center_freq_seg0;
+        buffer[2] = self.center_freq_seg1;
+        write_u16_le(&mut buffer[3..5], self.basic_mcs_nss);
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for Nl80211ElementVhtOperation {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf: &[u8] = buf.as_ref();
+        if buf.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid length of payload for Nl80211ElementVhtOperation, \
+                expecting {}, but got {}",
+                Self::LENGTH,
+                buf.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                channel_width: buf[0],
+                center_freq_seg0: buf[1],
+                center_freq_seg1: buf[2],
+                basic_mcs_nss: u16::from_le_bytes([buf[3], buf[4]]),
+            })
+        }
+    }
+}
@@ -15,6 +15,7 @@ const HE_MAC_CAP_INFO_LEN: usize = 6;
 ///
 /// IEEE 802.11ax-2021 section 9.4.2.248.2
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HeMacCapInfo(pub [u8; HE_MAC_CAP_INFO_LEN]);
 
 impl Nl80211HeMacCapInfo {
@@ -30,115 +31,325 @@ impl Nl80211HeMacCapInfo {
         Self(data)
     }
 
-    pub fn tc_he_support(&self) -> bool {
+    pub fn htc_he_support(&self) -> bool {
         get_bit(&self.0, 0)
     }
-    pub fn wt_requester_support(&self) -> bool {
+    pub fn twt_requester_support(&self) -> bool {
         get_bit(&self.0, 1)
     }
-    pub fn wt_responder_support(&self) -> bool {
+    pub fn twt_responder_support(&self) -> bool {
         get_bit(&self.0, 2)
     }
-    pub fn ynamic_fragmentation_support(&self) -> u8 {
+    pub fn dynamic_fragmentation_support(&self) -> u8 {
         get_bits_as_u8(&self.0, 3, 4)
     }
-    pub fn ax_fragmented_msdu_amsdu(&self) -> u8 {
+    pub fn max_fragmented_msdu_amsdu(&self) -> u8 {
         get_bits_as_u8(&self.0, 5, 7)
     }
-    pub fn in_fragment_size(&self) -> u8 {
+    pub fn min_fragment_size(&self) -> u8 {
         get_bits_as_u8(&self.0, 8, 9)
     }
-    pub fn rigger_frame_mac_pending_duration(&self) -> u8 {
+    pub fn trigger_frame_mac_pending_duration(&self) -> u8 {
         get_bits_as_u8(&self.0, 10, 11)
     }
-    pub fn ulti_tid_aggregation_rx_support(&self) -> u8 {
+    pub fn multi_tid_aggregation_rx_support(&self) -> u8 {
         get_bits_as_u8(&self.0, 12, 14)
     }
-    pub fn e_link_adapttion_support(&self) -> u8 {
+    pub fn he_link_adaptation_support(&self) -> u8 {
         get_bits_as_u8(&self.0, 15, 16)
     }
-    pub fn ll_ack_support(&self) -> bool {
+    pub fn all_ack_support(&self) -> bool {
         get_bit(&self.0, 17)
     }
-    pub fn ts_support(&self) -> bool {
+    pub fn trs_support(&self) -> bool {
         get_bit(&self.0, 18)
     }
-    pub fn sr_support(&self) -> bool {
+    pub fn bsr_support(&self) -> bool {
         get_bit(&self.0, 19)
     }
-    pub fn roadcast_twt_support(&self) -> bool {
+    pub fn broadcast_twt_support(&self) -> bool {
         get_bit(&self.0, 20)
     }
-    pub fn upport_32_bit_ba_bitmap(&self) -> bool {
+    pub fn support_32_bit_ba_bitmap(&self) -> bool {
         get_bit(&self.0, 21)
     }
-    pub fn u_cascading_support(&self) -> bool {
+    pub fn mu_cascading_support(&self) -> bool {
         get_bit(&self.0, 22)
     }
-    pub fn ck_enabled_aggregation_support(&self) -> bool {
+    pub fn ack_enabled_aggregation_support(&self) -> bool {
         get_bit(&self.0, 23)
     }
     // bit 24 is reserved
-    pub fn m_control_support(&self) -> bool {
+    pub fn om_control_support(&self) -> bool {
         get_bit(&self.0, 25)
     }
-    pub fn fdma_ra_support(&self) -> bool {
+    pub fn ofdma_ra_support(&self) -> bool {
         get_bit(&self.0, 26)
     }
-    pub fn ax_a_mpdu_length_exponent_extentsion(&self) -> u8 {
+    pub fn max_a_mpdu_length_exponent_extension(&self) -> u8 {
         get_bits_as_u8(&self.0, 27, 28)
     }
-    pub fn _msdu_fragmentation_support(&self) -> bool {
+    pub fn a_msdu_fragmentation_support(&self) -> bool {
         get_bit(&self.0, 29)
     }
-    pub fn lexible_twt_scheduler_support(&self) -> bool {
+    pub fn flexible_twt_scheduler_support(&self) -> bool {
         get_bit(&self.0, 30)
     }
-    pub fn x_control_frame_to_multibss(&self) -> bool {
+    pub fn rx_control_frame_to_multibss(&self) -> bool {
         get_bit(&self.0, 31)
     }
-    pub fn srp_bqrp_a_mpdu_aggregation(&self) -> bool {
+    pub fn bsrp_bqrp_a_mpdu_aggregation(&self) -> bool {
         get_bit(&self.0, 32)
     }
-    pub fn tp_support(&self) -> bool {
+    pub fn qtp_support(&self) -> bool {
         get_bit(&self.0, 33)
     }
-    pub fn qr_support(&self) -> bool {
+    pub fn bqr_support(&self) -> bool {
         get_bit(&self.0, 34)
     }
-    pub fn sr_responder(&self) -> bool {
+    pub fn psr_responder(&self) -> bool {
         get_bit(&self.0, 35)
     }
-    pub fn dp_feedback_report_support(&self) -> bool {
+    pub fn ndp_feedback_report_support(&self) -> bool {
         get_bit(&self.0, 36)
     }
-    pub fn ps_support(&self) -> bool {
+    pub fn ops_support(&self) -> bool {
         get_bit(&self.0, 37)
     }
-    pub fn _msdu_not_under_ba_in_ack_enabled_a_mpdu_support(&self) -> bool {
+    pub fn a_msdu_not_under_ba_in_ack_enabled_a_mpdu_support(&self) -> bool {
         get_bit(&self.0, 38)
     }
-    pub fn ulti_tid_aggregation_tx_support(&self) -> u8 {
+    pub fn multi_tid_aggregation_tx_support(&self) -> u8 {
         get_bits_as_u8(&self.0, 39, 41)
     }
-    pub fn e_subchannel_selective_ransmission_support(&self) -> bool {
+    pub fn he_subchannel_selective_transmission_support(&self) -> bool {
         get_bit(&self.0, 42)
     }
-    pub fn l_2x_996_tone_ru_support(&self) -> bool {
+    pub fn ul_2x_996_tone_ru_support(&self) -> bool {
         get_bit(&self.0, 43)
     }
-    pub fn m_control_ul_mu_data_disable_rx_support(&self) -> bool {
+    pub fn om_control_ul_mu_data_disable_rx_support(&self) -> bool {
         get_bit(&self.0, 44)
     }
-    pub fn e_dyanmic_sm_power_save(&self) -> bool {
+    pub fn he_dynamic_sm_power_save(&self) -> bool {
         get_bit(&self.0, 45)
     }
-    pub fn unctured_sounding_support(&self) -> bool {
+    pub fn punctured_sounding_support(&self) -> bool {
         get_bit(&self.0, 46)
     }
-    pub fn t_and_vht_trigger_frame_rx_support(&self) -> bool {
+    pub fn ht_and_vht_trigger_frame_rx_support(&self) -> bool {
         get_bit(&self.0, 47)
     }
+
+    #[deprecated(since = "0.3.0", note = "Use htc_he_support()")]
+    pub fn tc_he_support(&self) -> bool {
+        self.htc_he_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use twt_requester_support()")]
+    pub fn wt_requester_support(&self) -> bool {
+        self.twt_requester_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use twt_responder_support()")]
+    pub fn wt_responder_support(&self) -> bool {
+        self.twt_responder_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use dynamic_fragmentation_support()")]
+    pub fn ynamic_fragmentation_support(&self) -> u8 {
+        self.dynamic_fragmentation_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use max_fragmented_msdu_amsdu()")]
+    pub fn ax_fragmented_msdu_amsdu(&self) -> u8 {
+        self.max_fragmented_msdu_amsdu()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use min_fragment_size()")]
+    pub fn in_fragment_size(&self) -> u8 {
+        self.min_fragment_size()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use trigger_frame_mac_pending_duration()"
+    )]
+    pub fn rigger_frame_mac_pending_duration(&self) -> u8 {
+        self.trigger_frame_mac_pending_duration()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use multi_tid_aggregation_rx_support()"
+    )]
+    pub fn ulti_tid_aggregation_rx_support(&self) -> u8 {
+        self.multi_tid_aggregation_rx_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use he_link_adaptation_support()")]
+    pub fn e_link_adapttion_support(&self) -> u8 {
+        self.he_link_adaptation_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use all_ack_support()")]
+    pub fn ll_ack_support(&self) -> bool {
+        self.all_ack_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use trs_support()")]
+    pub fn ts_support(&self) -> bool {
+        self.trs_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use bsr_support()")]
+    pub fn sr_support(&self) -> bool {
+        self.bsr_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use broadcast_twt_support()")]
+    pub fn roadcast_twt_support(&self) -> bool {
+        self.broadcast_twt_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use support_32_bit_ba_bitmap()")]
+    pub fn upport_32_bit_ba_bitmap(&self) -> bool {
+        self.support_32_bit_ba_bitmap()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use mu_cascading_support()")]
+    pub fn u_cascading_support(&self) -> bool {
+        self.mu_cascading_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use ack_enabled_aggregation_support()"
+    )]
+    pub fn ck_enabled_aggregation_support(&self) -> bool {
+        self.ack_enabled_aggregation_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use om_control_support()")]
+    pub fn m_control_support(&self) -> bool {
+        self.om_control_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use ofdma_ra_support()")]
+    pub fn fdma_ra_support(&self) -> bool {
+        self.ofdma_ra_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use max_a_mpdu_length_exponent_extension()"
+    )]
+    pub fn ax_a_mpdu_length_exponent_extentsion(&self) -> u8 {
+        self.max_a_mpdu_length_exponent_extension()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use a_msdu_fragmentation_support()")]
+    pub fn _msdu_fragmentation_support(&self) -> bool {
+        self.a_msdu_fragmentation_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use flexible_twt_scheduler_support()"
+    )]
+    pub fn lexible_twt_scheduler_support(&self) -> bool {
+        self.flexible_twt_scheduler_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use rx_control_frame_to_multibss()")]
+    pub fn x_control_frame_to_multibss(&self) -> bool {
+        self.rx_control_frame_to_multibss()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use bsrp_bqrp_a_mpdu_aggregation()")]
+    pub fn srp_bqrp_a_mpdu_aggregation(&self) -> bool {
+        self.bsrp_bqrp_a_mpdu_aggregation()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use qtp_support()")]
+    pub fn tp_support(&self) -> bool {
+        self.qtp_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use bqr_support()")]
+    pub fn qr_support(&self) -> bool {
+        self.bqr_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use psr_responder()")]
+    pub fn sr_responder(&self) -> bool {
+        self.psr_responder()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use ndp_feedback_report_support()")]
+    pub fn dp_feedback_report_support(&self) -> bool {
+        self.ndp_feedback_report_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use ops_support()")]
+    pub fn ps_support(&self) -> bool {
+        self.ops_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use a_msdu_not_under_ba_in_ack_enabled_a_mpdu_support()"
+    )]
+    pub fn _msdu_not_under_ba_in_ack_enabled_a_mpdu_support(&self) -> bool {
+        self.a_msdu_not_under_ba_in_ack_enabled_a_mpdu_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use multi_tid_aggregation_tx_support()"
+    )]
+    pub fn ulti_tid_aggregation_tx_support(&self) -> u8 {
+        self.multi_tid_aggregation_tx_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use he_subchannel_selective_transmission_support()"
+    )]
+    pub fn e_subchannel_selective_ransmission_support(&self) -> bool {
+        self.he_subchannel_selective_transmission_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use ul_2x_996_tone_ru_support()")]
+    pub fn l_2x_996_tone_ru_support(&self) -> bool {
+        self.ul_2x_996_tone_ru_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use om_control_ul_mu_data_disable_rx_support()"
+    )]
+    pub fn m_control_ul_mu_data_disable_rx_support(&self) -> bool {
+        self.om_control_ul_mu_data_disable_rx_support()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use he_dynamic_sm_power_save()")]
+    pub fn e_dyanmic_sm_power_save(&self) -> bool {
+        self.he_dynamic_sm_power_save()
+    }
+
+    #[deprecated(since = "0.3.0", note = "Use punctured_sounding_support()")]
+    pub fn unctured_sounding_support(&self) -> bool {
+        self.punctured_sounding_support()
+    }
+
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use ht_and_vht_trigger_frame_rx_support()"
+    )]
+    pub fn t_and_vht_trigger_frame_rx_support(&self) -> bool {
+        self.ht_and_vht_trigger_frame_rx_support()
+    }
 }
 
 impl Emitable for Nl80211HeMacCapInfo {
@@ -164,6 +375,7 @@ const HE_PHY_CAP_INFO_LEN: usize = 11;
 ///
 /// IEEE 802.11ax-2021 section 9.4.2.248.3
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HePhyCapInfo(pub [u8; HE_PHY_CAP_INFO_LEN]);
 
 impl Nl80211HePhyCapInfo {
@@ -179,11 +391,269 @@ impl Nl80211HePhyCapInfo {
         Self(data)
     }
 
+    /// Bitmap of supported channel widths:
+    /// * bit 0: 40 MHz in 2.4 GHz band
+    /// * bit 1: 40 MHz and 80 MHz in 5 GHz and 6 GHz band
+    /// * bit 2: 160 MHz in 5 GHz and 6 GHz band
+    /// * bit 3: 160 MHz and 80+80 MHz in 5 GHz and 6 GHz band
+    /// * bit 4: 242-tone RUs in 2.4 GHz band
+    /// * bit 5: 242-tone RUs in 5 GHz and 6 GHz band
     pub fn supported_channel_width_set(&self) -> u8 {
         get_bits_as_u8(&self.0, 1, 7)
     }
 
-    // TODO: Add all fields as functions by checking 802.11ax-2021
+    /// Bitmap of supported punctured preamble reception
+    pub fn punctured_preamble_rx(&self) -> u8 {
+        get_bits_as_u8(&self.0, 8, 11)
+    }
+
+    /// Whether the device is class A(otherwise class B), only meaningful for
+    /// non-AP STA
+    pub fn device_class_a(&self) -> bool {
+        get_bit(&self.0, 12)
+    }
+
+    pub fn ldpc_coding_in_payload(&self) -> bool {
+        get_bit(&self.0, 13)
+    }
+
+    /// Whether HE SU PPDU with 1x HE-LTF and 0.8 µs GI is supported
+    pub fn su_ppdu_1x_ltf_0_8us_gi(&self) -> bool {
+        get_bit(&self.0, 14)
+    }
+
+    pub fn midamble_tx_rx_max_nsts(&self) -> u8 {
+        get_bits_as_u8(&self.0, 15, 16)
+    }
+
+    /// Whether NDP with 4x HE-LTF and 3.2 µs GI is supported
+    pub fn ndp_4x_ltf_3_2us_gi(&self) -> bool {
+        get_bit(&self.0, 17)
+    }
+
+    /// Whether STBC transmission in 80 MHz or less is supported
+    pub fn stbc_tx_le_80mhz(&self) -> bool {
+        get_bit(&self.0, 18)
+    }
+
+    /// Whether STBC reception in 80 MHz or less is supported
+    pub fn stbc_rx_le_80mhz(&self) -> bool {
+        get_bit(&self.0, 19)
+    }
+
+    pub fn doppler_tx(&self) -> bool {
+        get_bit(&self.0, 20)
+    }
+
+    pub fn doppler_rx(&self) -> bool {
+        get_bit(&self.0, 21)
+    }
+
+    pub fn full_bandwidth_ul_mu_mimo(&self) -> bool {
+        get_bit(&self.0, 22)
+    }
+
+    pub fn partial_bandwidth_ul_mu_mimo(&self) -> bool {
+        get_bit(&self.0, 23)
+    }
+
+    /// Maximum constellation of dual carrier modulation(DCM) for transmission:
+    /// 0 for not supported, 1 for BPSK, 2 for QPSK, 3 for 16-QAM
+    pub fn dcm_max_constellation_tx(&self) -> u8 {
+        get_bits_as_u8(&self.0, 24, 25)
+    }
+
+    /// Whether DCM transmission of 2 spatial streams is supported
+    pub fn dcm_max_nss_tx(&self) -> bool {
+        get_bit(&self.0, 26)
+    }
+
+    /// Maximum constellation of DCM for reception, same encoding as
+    /// [Self::dcm_max_constellation_tx()]
+    pub fn dcm_max_constellation_rx(&self) -> u8 {
+        get_bits_as_u8(&self.0, 27, 28)
+    }
+
+    /// Whether DCM reception of 2 spatial streams is supported
+    pub fn dcm_max_nss_rx(&self) -> bool {
+        get_bit(&self.0, 29)
+    }
+
+    pub fn rx_partial_bw_su_in_20mhz_mu_ppdu(&self) -> bool {
+        get_bit(&self.0, 30)
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        get_bit(&self.0, 31)
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        get_bit(&self.0, 32)
+    }
+
+    pub fn mu_beamformer(&self) -> bool {
+        get_bit(&self.0, 33)
+    }
+
+    /// Maximum number of space-time streams minus 1 the beamformee could
+    /// receive in 80 MHz or less
+    pub fn beamformee_sts_le_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 34, 36)
+    }
+
+    /// Maximum number of space-time streams minus 1 the beamformee could
+    /// receive in 160 MHz or 80+80 MHz
+    pub fn beamformee_sts_gt_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 37, 39)
+    }
+
+    /// Number of sounding dimensions minus 1 of beamformer in 80 MHz or less
+    pub fn sounding_dimensions_le_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 40, 42)
+    }
+
+    /// Number of sounding dimensions minus 1 of beamformer in 160 MHz or 80+80
+    /// MHz
+    pub fn sounding_dimensions_gt_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 43, 45)
+    }
+
+    pub fn ng16_su_feedback(&self) -> bool {
+        get_bit(&self.0, 46)
+    }
+
+    pub fn ng16_mu_feedback(&self) -> bool {
+        get_bit(&self.0, 47)
+    }
+
+    pub fn codebook_size_4_2_su_feedback(&self) -> bool {
+        get_bit(&self.0, 48)
+    }
+
+    pub fn codebook_size_7_5_mu_feedback(&self) -> bool {
+        get_bit(&self.0, 49)
+    }
+
+    pub fn triggered_su_beamforming_feedback(&self) -> bool {
+        get_bit(&self.0, 50)
+    }
+
+    pub fn triggered_mu_beamforming_partial_bw_feedback(&self) -> bool {
+        get_bit(&self.0, 51)
+    }
+
+    pub fn triggered_cqi_feedback(&self) -> bool {
+        get_bit(&self.0, 52)
+    }
+
+    pub fn partial_bandwidth_extended_range(&self) -> bool {
+        get_bit(&self.0, 53)
+    }
+
+    pub fn partial_bandwidth_dl_mu_mimo(&self) -> bool {
+        get_bit(&self.0, 54)
+    }
+
+    /// Whether the PPE Thresholds field is present, see [Nl80211HePpeThreshold]
+    pub fn ppe_threshold_present(&self) -> bool {
+        get_bit(&self.0, 55)
+    }
+
+    pub fn psr_based_sr_support(&self) -> bool {
+        get_bit(&self.0, 56)
+    }
+
+    pub fn power_boost_factor_support(&self) -> bool {
+        get_bit(&self.0, 57)
+    }
+
+    /// Whether HE SU PPDU and HE MU PPDU with 4x HE-LTF and 0.8 µs GI are
+    /// supported
+    pub fn su_mu_ppdu_4x_ltf_0_8us_gi(&self) -> bool {
+        get_bit(&self.0, 58)
+    }
+
+    /// Maximum number of columns minus 1 in the compressed beamforming feedback
+    pub fn max_nc(&self) -> u8 {
+        get_bits_as_u8(&self.0, 59, 61)
+    }
+
+    /// Whether STBC transmission in more than 80 MHz is supported
+    pub fn stbc_tx_gt_80mhz(&self) -> bool {
+        get_bit(&self.0, 62)
+    }
+
+    /// Whether STBC reception in more than 80 MHz is supported
+    pub fn stbc_rx_gt_80mhz(&self) -> bool {
+        get_bit(&self.0, 63)
+    }
+
+    pub fn er_su_ppdu_4x_ltf_0_8us_gi(&self) -> bool {
+        get_bit(&self.0, 64)
+    }
+
+    /// Whether 20 MHz operating non-AP STA could receive 20 MHz PPDU in 40 MHz
+    /// HE PPDU on 2.4 GHz band
+    pub fn ppdu_20mhz_in_40mhz_2g(&self) -> bool {
+        get_bit(&self.0, 65)
+    }
+
+    pub fn ppdu_20mhz_in_160mhz(&self) -> bool {
+        get_bit(&self.0, 66)
+    }
+
+    pub fn ppdu_80mhz_in_160mhz(&self) -> bool {
+        get_bit(&self.0, 67)
+    }
+
+    pub fn er_su_ppdu_1x_ltf_0_8us_gi(&self) -> bool {
+        get_bit(&self.0, 68)
+    }
+
+    pub fn midamble_tx_rx_2x_1x_ltf(&self) -> bool {
+        get_bit(&self.0, 69)
+    }
+
+    /// Maximum RU of DCM: 0 for 242-tone, 1 for 484-tone, 2 for 996-tone, 3 for
+    /// 2x996-tone
+    pub fn dcm_max_ru(&self) -> u8 {
+        get_bits_as_u8(&self.0, 70, 71)
+    }
+
+    pub fn longer_than_16_sigb_ofdm_symbols(&self) -> bool {
+        get_bit(&self.0, 72)
+    }
+
+    pub fn non_triggered_cqi_feedback(&self) -> bool {
+        get_bit(&self.0, 73)
+    }
+
+    /// Whether transmitting 1024-QAM in RU smaller than 242-tone is supported
+    pub fn tx_1024_qam_lt_242_tone_ru(&self) -> bool {
+        get_bit(&self.0, 74)
+    }
+
+    /// Whether receiving 1024-QAM in RU smaller than 242-tone is supported
+    pub fn rx_1024_qam_lt_242_tone_ru(&self) -> bool {
+        get_bit(&self.0, 75)
+    }
+
+    pub fn rx_full_bw_su_compressed_sigb(&self) -> bool {
+        get_bit(&self.0, 76)
+    }
+
+    pub fn rx_full_bw_su_non_compressed_sigb(&self) -> bool {
+        get_bit(&self.0, 77)
+    }
+
+    /// Nominal packet padding: 0 for 0 µs, 1 for 8 µs, 2 for 16 µs
+    pub fn nominal_packet_padding(&self) -> u8 {
+        get_bits_as_u8(&self.0, 78, 79)
+    }
+
+    pub fn mu_ppdu_more_than_one_ru_rx_max_n_ltf(&self) -> bool {
+        get_bit(&self.0, 80)
+    }
 }
 
 impl Emitable for Nl80211HePhyCapInfo {
@@ -210,6 +680,7 @@ const NL80211_HE_MCS_NSS_SUPP_LEN: usize = 12;
 /// The released 802.11ax-2021 has no `Tx/Rx HE MCS NSS Support` section, this
 /// struct is merely copy of linux kernel `struct ieee80211_he_mcs_nss_supp`.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct Nl80211HeMcsNssSupp {
     /// Rx MCS map 2 bits for each stream, total 8 streams, for channel widths
@@ -299,6 +770,7 @@ const IEEE80211_HE_PPE_THRES_MAX_LEN: usize = 25;
 /// IEEE 802.11ax-2021 section 9.4.2.248.5
 /// "PPE Thresholds field"
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211HePpeThreshold(pub [u8; IEEE80211_HE_PPE_THRES_MAX_LEN]);
 
 impl Nl80211HePpeThreshold {
@@ -357,6 +829,7 @@ const IEEE80211_HE_6GHZ_CAP_LEN: usize = 2;
 ///
 /// IEEE 802.11ax-2021 section 9.4.2.263
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211He6GhzCapa(pub [u8; IEEE80211_HE_6GHZ_CAP_LEN]);
 
 impl Nl80211He6GhzCapa {
@@ -417,3 +890,404 @@ impl Emitable for Nl80211He6GhzCapa {
         buffer[..IEEE80211_HE_6GHZ_CAP_LEN].copy_from_slice(&self.0)
     }
 }
+
+// Bits of `Supported Channel Width Set` in HE PHY Capabilities Information
+// indicating the presence of optional Tx/Rx HE MCS NSS Support fields.
+const HE_CHANNEL_WIDTH_SET_160MHZ: u8 = 1 << 2;
+const HE_CHANNEL_WIDTH_SET_80P80MHZ: u8 = 1 << 3;
+// "PPE Thresholds Present" bit in HE PHY Capabilities Information
+const HE_PHY_CAP_PPE_THRESHOLD_PRESENT: usize = 55;
+// MCS map value indicating none of the spatial streams are supported
+const HE_MCS_NOT_SUPPORTED: u16 = 0xffff;
+
+/// "HE Capabilities element"
+///
+/// IEEE 802.11ax-2021 section 9.4.2.248
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementHeCap {
+    pub mac_cap_info: Nl80211HeMacCapInfo,
+    pub phy_cap_info: Nl80211HePhyCapInfo,
+    /// The 160 MHz and 80+80 MHz maps are set to `0xffff`(not supported) if
+    /// absent as indicated by `phy_cap_info`.
+    pub mcs_nss_supp: Nl80211HeMcsNssSupp,
+    /// Raw PPE Thresholds field, empty if not present.
+    pub ppe_thresholds: Vec<u8>,
+}
+
+impl Nl80211ElementHeCap {
+    fn has_mcs_160(&self) -> bool {
+        self.phy_cap_info.supported_channel_width_set()
+            & HE_CHANNEL_WIDTH_SET_160MHZ
+            > 0
+    }
+
+    fn has_mcs_80p80(&self) -> bool {
+        self.phy_cap_info.supported_channel_width_set()
+            & HE_CHANNEL_WIDTH_SET_80P80MHZ
+            > 0
+    }
+
+    fn mcs_nss_supp_len(&self) -> usize {
+        let mut len = 4;
+        if self.has_mcs_160() {
+            len += 4;
+        }
+        if self.has_mcs_80p80() {
+            len += 4;
+        }
+        len
+    }
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        let min_len =
+            Nl80211HeMacCapInfo::LENGTH + Nl80211HePhyCapInfo::LENGTH + 4;
+        if buf.len() < min_len {
+            return Err(format!(
+                "Nl80211ElementHeCap buffer size is smaller than \
+                required size {min_len}: {buf:?}"
+            )
+            .into());
+        }
+        let mut offset = 0;
+        let mac_cap_info = Nl80211HeMacCapInfo::new(
+            &buf[offset..offset + Nl80211HeMacCapInfo::LENGTH],
+        );
+        offset += Nl80211HeMacCapInfo::LENGTH;
+        let phy_cap_info = Nl80211HePhyCapInfo::new(
+            &buf[offset..offset + Nl80211HePhyCapInfo::LENGTH],
+        );
+        offset += Nl80211HePhyCapInfo::LENGTH;
+
+        let mut ret = Self {
+            mac_cap_info,
+            phy_cap_info,
+            mcs_nss_supp: Nl80211HeMcsNssSupp {
+                rx_mcs_80: 0,
+                tx_mcs_80: 0,
+                rx_mcs_160: HE_MCS_NOT_SUPPORTED,
+                tx_mcs_160: HE_MCS_NOT_SUPPORTED,
+                rx_mcs_80p80: HE_MCS_NOT_SUPPORTED,
+                tx_mcs_80p80: HE_MCS_NOT_SUPPORTED,
+            },
+            ppe_thresholds: Vec::new(),
+        };
+        if buf.len() < offset + ret.mcs_nss_supp_len() {
+            return Err(format!(
+                "Nl80211ElementHeCap buffer size is smaller than \
+                required size {}: {buf:?}",
+                offset + ret.mcs_nss_supp_len()
+            )
+            .into());
+        }
+        let read_u16 =
+            |pos: usize| u16::from_le_bytes([buf[pos], buf[pos + 1]]);
+        ret.mcs_nss_supp.rx_mcs_80 = read_u16(offset);
+        ret.mcs_nss_supp.tx_mcs_80 = read_u16(offset + 2);
+        offset += 4;
+        if ret.has_mcs_160() {
+            ret.mcs_nss_supp.rx_mcs_160 = read_u16(offset);
+            ret.mcs_nss_supp.tx_mcs_160 = read_u16(offset + 2);
+            offset += 4;
+        }
+        if ret.has_mcs_80p80() {
+            ret.mcs_nss_supp.rx_mcs_80p80 = read_u16(offset);
+            ret.mcs_nss_supp.tx_mcs_80p80 = read_u16(offset + 2);
+            offset += 4;
+        }
+        if get_bit(&ret.phy_cap_info.0, HE_PHY_CAP_PPE_THRESHOLD_PRESENT) {
+            ret.ppe_thresholds = buf[offset..].to_vec();
+        }
+        Ok(ret)
+    }
+}
+
+impl Emitable for Nl80211ElementHeCap {
+    fn buffer_len(&self) -> usize {
+        Nl80211HeMacCapInfo::LENGTH
+            + Nl80211HePhyCapInfo::LENGTH
+            + self.mcs_nss_supp_len()
+            + self.ppe_thresholds.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        if buffer.len() < self.buffer_len() {
+            log::error!(
+                "Buffer size {} is smaller than Nl80211ElementHeCap size {}",
+                buffer.len(),
+                self.buffer_len()
+            );
+            return;
+        }
+        let mut offset = 0;
+        self.mac_cap_info.emit(&mut buffer[offset..]);
+        offset += Nl80211HeMacCapInfo::LENGTH;
+        self.phy_cap_info.emit(&mut buffer[offset..]);
+        offset += Nl80211HePhyCapInfo::LENGTH;
+        let mut mcs_maps =
+            vec![self.mcs_nss_supp.rx_mcs_80, self.mcs_nss_supp.tx_mcs_80];
+        if self.has_mcs_160() {
+            mcs_maps.push(self.mcs_nss_supp.rx_mcs_160);
+            mcs_maps.push(self.mcs_nss_supp.tx_mcs_160);
+        }
+        if self.has_mcs_80p80() {
+            mcs_maps.push(self.mcs_nss_supp.rx_mcs_80p80);
+            mcs_maps.push(self.mcs_nss_supp.tx_mcs_80p80);
+        }
+        for mcs_map in mcs_maps {
+            write_u16_le(&mut buffer[offset..offset + 2], mcs_map);
+            offset += 2;
+        }
+        buffer[offset..offset + self.ppe_thresholds.len()]
+            .copy_from_slice(&self.ppe_thresholds);
+    }
+}
+
+const HE_OPERATION_VHT_OPERATION_INFO_PRESENT: usize = 14;
+const HE_OPERATION_CO_HOSTED_BSS: usize = 15;
+const HE_OPERATION_6GHZ_OPERATION_INFO_PRESENT: usize = 17;
+const HE_OPERATION_PARAMS_LEN: usize = 3;
+const HE_OPERATION_VHT_OPERATION_INFO_LEN: usize = 3;
+
+/// "HE Operation element"
+///
+/// IEEE 802.11ax-2021 section 9.4.2.249
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementHeOperation {
+    /// HE Operation Parameters. The presence of optional fields is
+    /// determined by `vht_operation_info`, `max_co_hosted_bssid_indicator`
+    /// and `he_6ghz_operation_info` when emitting.
+    pub params: [u8; HE_OPERATION_PARAMS_LEN],
+    /// BSS Color Information
+    pub bss_color_info: u8,
+    /// Basic HE-MCS And NSS Set, 2 bits for each stream.
+    pub basic_mcs_nss: u16,
+    /// Channel Width, Channel Center Frequency Segment 0 and 1 of the
+    /// VHT Operation Information
+    pub vht_operation_info: Option<[u8; HE_OPERATION_VHT_OPERATION_INFO_LEN]>,
+    pub max_co_hosted_bssid_indicator: Option<u8>,
+    pub he_6ghz_operation_info: Option<Nl80211He6GhzOperationInfo>,
+}
+
+impl Nl80211ElementHeOperation {
+    const FIXED_LENGTH: usize = 6;
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::FIXED_LENGTH {
+            return Err(format!(
+                "Nl80211ElementHeOperation buffer size is smaller than \
+                required size {}: {buf:?}",
+                Self::FIXED_LENGTH
+            )
+            .into());
+        }
+        let mut params = [0u8; HE_OPERATION_PARAMS_LEN];
+        params.copy_from_slice(&buf[..HE_OPERATION_PARAMS_LEN]);
+        let mut ret = Self {
+            params,
+            bss_color_info: buf[3],
+            basic_mcs_nss: u16::from_le_bytes([buf[4], buf[5]]),
+            vht_operation_info: None,
+            max_co_hosted_bssid_indicator: None,
+            he_6ghz_operation_info: None,
+        };
+        let mut offset = Self::FIXED_LENGTH;
+        let err_msg = || {
+            DecodeError::from(format!(
+                "Nl80211ElementHeOperation buffer is too short for \
+                optional fields indicated by HE Operation Parameters: \
+                {buf:?}"
+            ))
+        };
+        if get_bit(&params, HE_OPERATION_VHT_OPERATION_INFO_PRESENT) {
+            let mut info = [0u8; HE_OPERATION_VHT_OPERATION_INFO_LEN];
+            info.copy_from_slice(
+                buf.get(offset..offset + HE_OPERATION_VHT_OPERATION_INFO_LEN)
+                    .ok_or_else(err_msg)?,
+            );
+            ret.vht_operation_info = Some(info);
+            offset += HE_OPERATION_VHT_OPERATION_INFO_LEN;
+        }
+        if get_bit(&params, HE_OPERATION_CO_HOSTED_BSS) {
+            ret.max_co_hosted_bssid_indicator =
+                Some(*buf.get(offset).ok_or_else(err_msg)?);
+            offset += 1;
+        }
+        if get_bit(&params, HE_OPERATION_6GHZ_OPERATION_INFO_PRESENT) {
+            ret.he_6ghz_operation_info =
+                Some(Nl80211He6GhzOperationInfo::parse(
+                    buf.get(offset..).ok_or_else(err_msg)?,
+                )?);
+        }
+        Ok(ret)
+    }
+
+    /// BSS Color of the BSS
+    pub fn bss_color(&self) -> u8 {
+        self.bss_color_info & 0b111111
+    }
+
+    pub fn partial_bss_color(&self) -> bool {
+        self.bss_color_info & 1 << 6 > 0
+    }
+
+    pub fn bss_color_disabled(&self) -> bool {
+        self.bss_color_info & 1 << 7 > 0
+    }
+
+    /// Default PE Duration in units of 4 μs
+    pub fn default_pe_duration(&self) -> u8 {
+        get_bits_as_u8(&self.params, 0, 2)
+    }
+
+    pub fn twt_required(&self) -> bool {
+        get_bit(&self.params, 3)
+    }
+
+    /// TXOP Duration RTS Threshold in units of 32 μs, 1023 means disabled
+    pub fn txop_duration_rts_threshold(&self) -> u16 {
+        u16::from_le_bytes([self.params[0], self.params[1]]) >> 4 & 0x3ff
+    }
+
+    pub fn er_su_disable(&self) -> bool {
+        get_bit(&self.params, 16)
+    }
+}
+
+impl Emitable for Nl80211ElementHeOperation {
+    fn buffer_len(&self) -> usize {
+        Self::FIXED_LENGTH
+            + self
+                .vht_operation_info
+                .map(|_| HE_OPERATION_VHT_OPERATION_INFO_LEN)
+                .unwrap_or_default()
+            + self
+                .max_co_hosted_bssid_indicator
+                .map(|_| 1)
+                .unwrap_or_default()
+            + self
+                .he_6ghz_operation_info
+                .as_ref()
+                .map(|_| Nl80211He6GhzOperationInfo::LENGTH)
+                .unwrap_or_default()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        if buffer.len() < self.buffer_len() {
+            log::error!(
+                "Buffer size {} is smaller than Nl80211ElementHeOperation \
+                size {}",
+                buffer.len(),
+                self.buffer_len()
+            );
+            return;
+        }
+        let mut params = self.params;
+        for (pos, present) in [
+            (
+                HE_OPERATION_VHT_OPERATION_INFO_PRESENT,
+                self.vht_operation_info.is_some(),
+            ),
+            (
+                HE_OPERATION_CO_HOSTED_BSS,
+                self.max_co_hosted_bssid_indicator.is_some(),
+            ),
+            (
+                HE_OPERATION_6GHZ_OPERATION_INFO_PRESENT,
+                self.he_6ghz_operation_info.is_some(),
+            ),
+        ] {
+            if present {
+                params[pos / 8] |= 1 << (pos % 8);
+            } else {
+                params[pos / 8] &= !(1 << (pos % 8));
+            }
+        }
+        buffer[..HE_OPERATION_PARAMS_LEN].copy_from_slice(&params);
+        buffer[3] = self.bss_color_info;
+        write_u16_le(&mut buffer[4..6], self.basic_mcs_nss);
+        let mut offset = Self::FIXED_LENGTH;
+        if let Some(info) = self.vht_operation_info.as_ref() {
+            buffer[offset..offset + HE_OPERATION_VHT_OPERATION_INFO_LEN]
+                .copy_from_slice(info);
+            offset += HE_OPERATION_VHT_OPERATION_INFO_LEN;
+        }
+        if let Some(indicator) = self.max_co_hosted_bssid_indicator {
+            buffer[offset] = indicator;
+            offset += 1;
+        }
+        if let Some(info) = self.he_6ghz_operation_info.as_ref() {
+            info.emit(&mut buffer[offset..]);
+        }
+    }
+}
+
+/// "6 GHz Operation Information field"
+///
+/// IEEE 802.11ax-2021 section 9.4.2.249
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211He6GhzOperationInfo {
+    /// Channel number of the primary channel in the 6 GHz band
+    pub primary_channel: u8,
+    /// Control field holding channel width(bits 0-1), duplicate
+    /// beacon(bit 2) and regulatory info(bits 3-5)
+    pub control: u8,
+    /// Channel Center Frequency Segment 0
+    pub center_freq_seg0: u8,
+    /// Channel Center Frequency Segment 1
+    pub center_freq_seg1: u8,
+    /// Minimum rate in units of 1 Mb/s
+    pub min_rate: u8,
+}
+
+impl Nl80211He6GhzOperationInfo {
+    pub const LENGTH: usize = 5;
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::LENGTH {
+            return Err(format!(
+                "Nl80211He6GhzOperationInfo buffer size is smaller than \
+                required size {}: {buf:?}",
+                Self::LENGTH
+            )
+            .into());
+        }
+        Ok(Self {
+            primary_channel: buf[0],
+            control: buf[1],
+            center_freq_seg0: buf[2],
+            center_freq_seg1: buf[3],
+            min_rate: buf[4],
+        })
+    }
+
+    /// Channel width: 0 for 20 MHz, 1 for 40 MHz, 2 for 80 MHz,
+    /// 3 for 160 MHz or 80+80 MHz.
+    pub fn channel_width(&self) -> u8 {
+        self.control & 0b11
+    }
+
+    pub fn duplicate_beacon(&self) -> bool {
+        self.control & 1 << 2 > 0
+    }
+
+    pub fn regulatory_info(&self) -> u8 {
+        (self.control >> 3) & 0b111
+    }
+}
+
+impl Emitable for Nl80211He6GhzOperationInfo {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.primary_channel;
+        buffer[1] = self.control;
+        buffer[2] = self.center_freq_seg0;
+        buffer[3] = self.center_freq_seg1;
+        buffer[4] = self.min_rate;
+    }
+}
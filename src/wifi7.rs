@@ -4,12 +4,14 @@
 
 use netlink_packet_utils::{DecodeError, Emitable};
 
+use crate::bytes::{get_bit, get_bits_as_u8};
+
 const EHT_MAC_CAP_INFO_LEN: usize = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtMacCapInfo(pub [u8; EHT_MAC_CAP_INFO_LEN]);
 
-// TODO: Failed to get WIFI7(802.11be) SPEC PDF, hence no parsing functions
 impl Nl80211EhtMacCapInfo {
     pub const LENGTH: usize = EHT_MAC_CAP_INFO_LEN;
 
@@ -22,6 +24,52 @@ impl Nl80211EhtMacCapInfo {
         }
         Self(data)
     }
+
+    pub fn epcs_priority_access(&self) -> bool {
+        get_bit(&self.0, 0)
+    }
+
+    pub fn om_control(&self) -> bool {
+        get_bit(&self.0, 1)
+    }
+
+    pub fn triggered_txop_sharing_mode1(&self) -> bool {
+        get_bit(&self.0, 2)
+    }
+
+    pub fn triggered_txop_sharing_mode2(&self) -> bool {
+        get_bit(&self.0, 3)
+    }
+
+    pub fn restricted_twt(&self) -> bool {
+        get_bit(&self.0, 4)
+    }
+
+    pub fn scs_traffic_description(&self) -> bool {
+        get_bit(&self.0, 5)
+    }
+
+    /// Raw value of Maximum MPDU Length subfield, see [Self::max_mpdu_len()]
+    pub fn max_mpdu_len_raw(&self) -> u8 {
+        get_bits_as_u8(&self.0, 6, 7)
+    }
+
+    /// Maximum A-MPDU length exponent extension, added to the exponent of HE
+    /// capabilities
+    pub fn max_ampdu_len_exponent_extension(&self) -> bool {
+        get_bit(&self.0, 8)
+    }
+
+    /// Maximum MPDU length in bytes: 3895, 7991 or 11454, `None` for
+    /// reserved value
+    pub fn max_mpdu_len(&self) -> Option<u16> {
+        match self.max_mpdu_len_raw() {
+            0 => Some(3895),
+            1 => Some(7991),
+            2 => Some(11454),
+            _ => None,
+        }
+    }
 }
 
 impl Emitable for Nl80211EhtMacCapInfo {
@@ -44,6 +92,7 @@ impl Emitable for Nl80211EhtMacCapInfo {
 const EHT_PHY_CAP_INFO_LEN: usize = 9;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtPhyCapInfo(pub [u8; EHT_PHY_CAP_INFO_LEN]);
 
 impl Nl80211EhtPhyCapInfo {
@@ -58,6 +107,197 @@ impl Nl80211EhtPhyCapInfo {
         }
         Self(data)
     }
+
+    pub fn support_320mhz_in_6ghz(&self) -> bool {
+        get_bit(&self.0, 1)
+    }
+
+    pub fn ru_242_tone_in_bw_wider_than_20mhz(&self) -> bool {
+        get_bit(&self.0, 2)
+    }
+
+    /// Whether NDP with 4x EHT-LTF and 3.2 µs GI is supported
+    pub fn ndp_4x_ltf_3_2us_gi(&self) -> bool {
+        get_bit(&self.0, 3)
+    }
+
+    pub fn partial_bandwidth_ul_mu_mimo(&self) -> bool {
+        get_bit(&self.0, 4)
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        get_bit(&self.0, 5)
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        get_bit(&self.0, 6)
+    }
+
+    /// Maximum number of spatial streams minus 1 the beamformee could receive
+    /// in 80 MHz or less
+    pub fn beamformee_ss_le_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 7, 9)
+    }
+
+    /// Maximum number of spatial streams minus 1 the beamformee could receive
+    /// in 160 MHz
+    pub fn beamformee_ss_160mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 10, 12)
+    }
+
+    /// Maximum number of spatial streams minus 1 the beamformee could receive
+    /// in 320 MHz
+    pub fn beamformee_ss_320mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 13, 15)
+    }
+
+    /// Number of sounding dimensions minus 1 of beamformer in 80 MHz or less
+    pub fn sounding_dimensions_le_80mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 16, 18)
+    }
+
+    /// Number of sounding dimensions minus 1 of beamformer in 160 MHz
+    pub fn sounding_dimensions_160mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 19, 21)
+    }
+
+    /// Number of sounding dimensions minus 1 of beamformer in 320 MHz
+    pub fn sounding_dimensions_320mhz(&self) -> u8 {
+        get_bits_as_u8(&self.0, 22, 24)
+    }
+
+    pub fn ng16_su_feedback(&self) -> bool {
+        get_bit(&self.0, 25)
+    }
+
+    pub fn ng16_mu_feedback(&self) -> bool {
+        get_bit(&self.0, 26)
+    }
+
+    pub fn codebook_size_4_2_su_feedback(&self) -> bool {
+        get_bit(&self.0, 27)
+    }
+
+    pub fn codebook_size_7_5_mu_feedback(&self) -> bool {
+        get_bit(&self.0, 28)
+    }
+
+    pub fn triggered_su_beamforming_feedback(&self) -> bool {
+        get_bit(&self.0, 29)
+    }
+
+    pub fn triggered_mu_beamforming_partial_bw_feedback(&self) -> bool {
+        get_bit(&self.0, 30)
+    }
+
+    pub fn triggered_cqi_feedback(&self) -> bool {
+        get_bit(&self.0, 31)
+    }
+
+    pub fn partial_bandwidth_dl_mu_mimo(&self) -> bool {
+        get_bit(&self.0, 32)
+    }
+
+    pub fn psr_based_sr_support(&self) -> bool {
+        get_bit(&self.0, 33)
+    }
+
+    pub fn power_boost_factor_support(&self) -> bool {
+        get_bit(&self.0, 34)
+    }
+
+    /// Whether EHT MU PPDU with 4x EHT-LTF and 0.8 µs GI is supported
+    pub fn mu_ppdu_4x_ltf_0_8us_gi(&self) -> bool {
+        get_bit(&self.0, 35)
+    }
+
+    /// Maximum number of columns minus 1 in the compressed beamforming feedback
+    pub fn max_nc(&self) -> u8 {
+        get_bits_as_u8(&self.0, 36, 39)
+    }
+
+    pub fn non_triggered_cqi_feedback(&self) -> bool {
+        get_bit(&self.0, 40)
+    }
+
+    /// Whether transmitting 1024-QAM and 4096-QAM in RU smaller than 242-tone
+    /// is supported
+    pub fn tx_1024_4096_qam_lt_242_tone_ru(&self) -> bool {
+        get_bit(&self.0, 41)
+    }
+
+    /// Whether receiving 1024-QAM and 4096-QAM in RU smaller than 242-tone is
+    /// supported
+    pub fn rx_1024_4096_qam_lt_242_tone_ru(&self) -> bool {
+        get_bit(&self.0, 42)
+    }
+
+    /// Whether the PPE Thresholds field is present, see [Nl80211EhtPpeThres]
+    pub fn ppe_threshold_present(&self) -> bool {
+        get_bit(&self.0, 43)
+    }
+
+    /// Common nominal packet padding: 0 for 0 µs, 1 for 8 µs, 2 for 16 µs, 3
+    /// for 20 µs
+    pub fn common_nominal_packet_padding(&self) -> u8 {
+        get_bits_as_u8(&self.0, 44, 45)
+    }
+
+    pub fn max_num_supported_eht_ltf(&self) -> u8 {
+        get_bits_as_u8(&self.0, 46, 50)
+    }
+
+    /// Bitmap of RU and MRU sizes supporting EHT-MCS 15: bit 0 for 52+26-tone
+    /// and 106+26-tone, bit 1 for 484+242-tone, bit 2 for 996+484-tone and
+    /// 996+484+242-tone, bit 3 for 3x996-tone
+    pub fn mcs15_support(&self) -> u8 {
+        get_bits_as_u8(&self.0, 51, 54)
+    }
+
+    /// Whether EHT DUP(MCS 14) in 6 GHz band is supported
+    pub fn eht_dup_in_6ghz(&self) -> bool {
+        get_bit(&self.0, 55)
+    }
+
+    pub fn sta_20mhz_rx_ndp_with_wider_bw(&self) -> bool {
+        get_bit(&self.0, 56)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_le_80mhz(&self) -> bool {
+        get_bit(&self.0, 57)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_160mhz(&self) -> bool {
+        get_bit(&self.0, 58)
+    }
+
+    pub fn non_ofdma_ul_mu_mimo_320mhz(&self) -> bool {
+        get_bit(&self.0, 59)
+    }
+
+    pub fn mu_beamformer_le_80mhz(&self) -> bool {
+        get_bit(&self.0, 60)
+    }
+
+    pub fn mu_beamformer_160mhz(&self) -> bool {
+        get_bit(&self.0, 61)
+    }
+
+    pub fn mu_beamformer_320mhz(&self) -> bool {
+        get_bit(&self.0, 62)
+    }
+
+    pub fn tb_sounding_feedback_rate_limit(&self) -> bool {
+        get_bit(&self.0, 63)
+    }
+
+    pub fn rx_1024_qam_wider_bw_dl_ofdma(&self) -> bool {
+        get_bit(&self.0, 64)
+    }
+
+    pub fn rx_4096_qam_wider_bw_dl_ofdma(&self) -> bool {
+        get_bit(&self.0, 65)
+    }
 }
 
 impl Emitable for Nl80211EhtPhyCapInfo {
@@ -80,6 +320,7 @@ impl Emitable for Nl80211EhtPhyCapInfo {
 /// MCS/NSS support for 20 MHz-only STA.
 // Kernel data type: `struct ieee80211_eht_mcs_nss_supp_20mhz_only`
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtMcsNssSuppOnly20Mhz {
     /// MCS 0 - 7
     pub rx_tx_mcs7_max_nss: u8,
@@ -126,6 +367,7 @@ impl Emitable for Nl80211EhtMcsNssSuppOnly20Mhz {
 
 // Kernel data type: `struct ieee80211_eht_mcs_nss_supp_bw`
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtMcsNssSuppBw {
     /// MCS 8 - 9
     pub rx_tx_mcs9_max_nss: u8,
@@ -167,6 +409,7 @@ impl Emitable for Nl80211EhtMcsNssSuppBw {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtMcsNssSuppMoreThan20Mhz {
     pub mhz_80: Nl80211EhtMcsNssSuppBw,
     pub mhz_160: Nl80211EhtMcsNssSuppBw,
@@ -213,6 +456,7 @@ impl Emitable for Nl80211EhtMcsNssSuppMoreThan20Mhz {
 // Kernel data type: `struct ieee80211_eht_mcs_nss_supp`
 ///  EHT max supported NSS per MCS
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211EhtMcsNssSupp {
     Only20Mhz(Nl80211EhtMcsNssSuppOnly20Mhz),
     MoreThan20Mhz(Nl80211EhtMcsNssSuppMoreThan20Mhz),
@@ -266,6 +510,7 @@ const IEEE80211_EHT_PPE_THRES_MAX_LEN: usize = 32;
 /// PPE thresholds
 // TODO: write passing function
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211EhtPpeThres(pub [u8; IEEE80211_EHT_PPE_THRES_MAX_LEN]);
 
 impl Nl80211EhtPpeThres {
@@ -298,3 +543,213 @@ impl Emitable for Nl80211EhtPpeThres {
         buffer[..Self::LENGTH].copy_from_slice(&self.0)
     }
 }
+
+/// "EHT Capabilities element"
+///
+/// IEEE 802.11be section 9.4.2.313
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211ElementEhtCap {
+    pub mac_cap_info: Nl80211EhtMacCapInfo,
+    pub phy_cap_info: Nl80211EhtPhyCapInfo,
+    /// Raw Supported EHT-MCS And NSS Set field followed by optional EHT PPE
+    /// Thresholds field. The size of the former depends on the HE
+    /// Capabilities element transmitted in the same frame, hence not parsed.
+    pub mcs_nss_supp_and_ppe_thres: Vec<u8>,
+}
+
+impl Nl80211ElementEhtCap {
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        let min_len =
+            Nl80211EhtMacCapInfo::LENGTH + Nl80211EhtPhyCapInfo::LENGTH;
+        if buf.len() < min_len {
+            return Err(format!(
+                "Nl80211ElementEhtCap buffer size is smaller than \
+                required size {min_len}: {buf:?}"
+            )
+            .into());
+        }
+        Ok(Self {
+            mac_cap_info: Nl80211EhtMacCapInfo::new(
+                &buf[..Nl80211EhtMacCapInfo::LENGTH],
+            ),
+            phy_cap_info: Nl80211EhtPhyCapInfo::new(
+                &buf[Nl80211EhtMacCapInfo::LENGTH..min_len],
+            ),
+            mcs_nss_supp_and_ppe_thres: buf[min_len..].to_vec(),
+        })
+    }
+}
+
+impl Emitable for Nl80211ElementEhtCap {
+    fn buffer_len(&self) -> usize {
+        Nl80211EhtMacCapInfo::LENGTH
+            + Nl80211EhtPhyCapInfo::LENGTH
+            + self.mcs_nss_supp_and_ppe_thres.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        if buffer.len() < self.buffer_len() {
+            log::error!(
+                "Buffer size is smaller than required length {}",
+                self.buffer_len()
+            );
+            return;
+        }
+        self.mac_cap_info.emit(buffer);
+        self.phy_cap_info
+            .emit(&mut buffer[Nl80211EhtMacCapInfo::LENGTH..]);
+        let offset =
+            Nl80211EhtMacCapInfo::LENGTH + Nl80211EhtPhyCapInfo::LENGTH;
+        buffer[offset..self.buffer_len()]
+            .copy_from_slice(&self.mcs_nss_supp_and_ppe_thres);
+    }
+}
+
+// Convert the encoded EMLSR padding delay or EMLMR delay into microseconds
+fn eml_delay_us(d: u8) -> Option<u32> {
+    match d {
+        0 => Some(0),
+        1..=4 => Some(16 << d),
+        _ => None,
+    }
+}
+
+/// EML Capabilities subfield of Basic Multi-Link element, IEEE 802.11be-2024
+/// Figure 9-1074p, reported by [crate::Nl80211Attr::EmlCapability].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211EmlCapability(pub u16);
+
+impl From<u16> for Nl80211EmlCapability {
+    fn from(d: u16) -> Self {
+        Self(d)
+    }
+}
+
+impl From<Nl80211EmlCapability> for u16 {
+    fn from(v: Nl80211EmlCapability) -> u16 {
+        v.0
+    }
+}
+
+impl Nl80211EmlCapability {
+    /// Whether enhanced multi-link single-radio(EMLSR) mode is supported
+    pub fn emlsr_support(&self) -> bool {
+        self.0 & 0x0001 > 0
+    }
+
+    /// Raw value of EMLSR Padding Delay subfield
+    pub fn emlsr_padding_delay(&self) -> u8 {
+        ((self.0 & 0x000e) >> 1) as u8
+    }
+
+    /// EMLSR padding delay in microseconds, `None` for reserved value
+    pub fn emlsr_padding_delay_us(&self) -> Option<u32> {
+        eml_delay_us(self.emlsr_padding_delay())
+    }
+
+    /// Raw value of EMLSR Transition Delay subfield
+    pub fn emlsr_transition_delay(&self) -> u8 {
+        ((self.0 & 0x0070) >> 4) as u8
+    }
+
+    /// EMLSR transition delay in microseconds, `None` for reserved value
+    pub fn emlsr_transition_delay_us(&self) -> Option<u32> {
+        match self.emlsr_transition_delay() {
+            0 => Some(0),
+            d @ 1..=5 => Some(8 << d),
+            _ => None,
+        }
+    }
+
+    /// Whether enhanced multi-link multi-radio(EMLMR) mode is supported
+    pub fn emlmr_support(&self) -> bool {
+        self.0 & 0x0080 > 0
+    }
+
+    /// Raw value of EMLMR Delay subfield
+    pub fn emlmr_delay(&self) -> u8 {
+        ((self.0 & 0x0700) >> 8) as u8
+    }
+
+    /// EMLMR delay in microseconds, `None` for reserved value
+    pub fn emlmr_delay_us(&self) -> Option<u32> {
+        eml_delay_us(self.emlmr_delay())
+    }
+
+    /// Raw value of Transition Timeout subfield
+    pub fn transition_timeout(&self) -> u8 {
+        ((self.0 & 0x7800) >> 11) as u8
+    }
+
+    /// Transition timeout in microseconds, `None` for reserved value
+    pub fn transition_timeout_us(&self) -> Option<u32> {
+        match self.transition_timeout() {
+            0 => Some(0),
+            d @ 1..=11 => Some(64 << d),
+            _ => None,
+        }
+    }
+}
+
+/// MLD Capabilities And Operations subfield of Basic Multi-Link element,
+/// IEEE 802.11be-2024 Figure 9-1074q, reported by
+/// [crate::Nl80211Attr::MldCapaAndOps].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211MldCapaAndOps(pub u16);
+
+impl From<u16> for Nl80211MldCapaAndOps {
+    fn from(d: u16) -> Self {
+        Self(d)
+    }
+}
+
+impl From<Nl80211MldCapaAndOps> for u16 {
+    fn from(v: Nl80211MldCapaAndOps) -> u16 {
+        v.0
+    }
+}
+
+impl Nl80211MldCapaAndOps {
+    /// Maximum number of affiliated STAs of the MLD that could be active
+    /// simultaneously
+    pub fn max_simultaneous_links(&self) -> u8 {
+        (self.0 & 0x000f) as u8 + 1
+    }
+
+    /// Whether the SRS Support subfield is set
+    pub fn srs_support(&self) -> bool {
+        self.0 & 0x0010 > 0
+    }
+
+    /// TID-to-link mapping negotiation support:
+    /// * 0 for not supported
+    /// * 1 for all TIDs mapped to the same link set
+    /// * 3 for each TID mapped to the same or different link set
+    pub fn tid_to_link_map_negotiation(&self) -> u8 {
+        ((self.0 & 0x0060) >> 5) as u8
+    }
+
+    /// Raw value of Frequency Separation For STR/AP MLD Type Indication
+    /// subfield
+    pub fn freq_separation_type(&self) -> u8 {
+        ((self.0 & 0x0f80) >> 7) as u8
+    }
+
+    /// Whether AP assistance request(AAR) is supported
+    pub fn aar_support(&self) -> bool {
+        self.0 & 0x1000 > 0
+    }
+
+    /// Whether link reconfiguration operation is supported
+    pub fn link_reconfig_support(&self) -> bool {
+        self.0 & 0x2000 > 0
+    }
+
+    /// Whether aligned target wake time(TWT) is supported
+    pub fn aligned_twt_support(&self) -> bool {
+        self.0 & 0x4000 > 0
+    }
+}
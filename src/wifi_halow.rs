@@ -0,0 +1,312 @@
+// SPDX-License-Identifier: MIT
+
+// WIFI HaLow(802.11ah) sub-1GHz(S1G) specific data types
+
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::bytes::{get_bit, get_bits_as_u8};
+
+const S1G_CAP_INFO_LEN: usize = 10;
+
+/// S1G Capabilities Information field, IEEE 802.11-2020 Figure 9-684
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211S1gCapInfo(pub [u8; S1G_CAP_INFO_LEN]);
+
+impl Nl80211S1gCapInfo {
+    pub const LENGTH: usize = S1G_CAP_INFO_LEN;
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid Nl80211S1gCapInfo, expected length {}, \
+                but got {buf:?}",
+                Self::LENGTH
+            )
+            .into());
+        }
+        let mut data = [0u8; Self::LENGTH];
+        data.copy_from_slice(&buf[..Self::LENGTH]);
+        Ok(Self(data))
+    }
+
+    /// Whether S1G long format PPDU is supported
+    pub fn s1g_long(&self) -> bool {
+        get_bit(&self.0, 0)
+    }
+
+    pub fn short_gi_for_1mhz(&self) -> bool {
+        get_bit(&self.0, 1)
+    }
+
+    pub fn short_gi_for_2mhz(&self) -> bool {
+        get_bit(&self.0, 2)
+    }
+
+    pub fn short_gi_for_4mhz(&self) -> bool {
+        get_bit(&self.0, 3)
+    }
+
+    pub fn short_gi_for_8mhz(&self) -> bool {
+        get_bit(&self.0, 4)
+    }
+
+    pub fn short_gi_for_16mhz(&self) -> bool {
+        get_bit(&self.0, 5)
+    }
+
+    /// Maximum supported channel width in MHz: 2, 4, 8 or 16
+    pub fn max_channel_width_mhz(&self) -> u8 {
+        2 << get_bits_as_u8(&self.0, 6, 7)
+    }
+
+    pub fn rx_ldpc(&self) -> bool {
+        get_bit(&self.0, 8)
+    }
+
+    pub fn tx_stbc(&self) -> bool {
+        get_bit(&self.0, 9)
+    }
+
+    pub fn rx_stbc(&self) -> bool {
+        get_bit(&self.0, 10)
+    }
+
+    pub fn su_beamformer(&self) -> bool {
+        get_bit(&self.0, 11)
+    }
+
+    pub fn su_beamformee(&self) -> bool {
+        get_bit(&self.0, 12)
+    }
+
+    /// Beamformee space-time streams capability, the maximum number of
+    /// space-time streams minus 1 the STA can receive in S1G NDP.
+    pub fn beamformee_sts(&self) -> u8 {
+        get_bits_as_u8(&self.0, 13, 15)
+    }
+
+    /// Number of sounding dimensions minus 1 for beamformer
+    pub fn sounding_dimensions(&self) -> u8 {
+        get_bits_as_u8(&self.0, 16, 18)
+    }
+
+    pub fn mu_beamformer(&self) -> bool {
+        get_bit(&self.0, 19)
+    }
+
+    pub fn mu_beamformee(&self) -> bool {
+        get_bit(&self.0, 20)
+    }
+
+    pub fn htc_vht(&self) -> bool {
+        get_bit(&self.0, 21)
+    }
+
+    /// Traveling pilot support:
+    /// * 0 for not supported
+    /// * 1 for one space-time stream only
+    /// * 2 for one and two space-time streams
+    pub fn traveling_pilot(&self) -> u8 {
+        get_bits_as_u8(&self.0, 22, 23)
+    }
+
+    pub fn rd_responder(&self) -> bool {
+        get_bit(&self.0, 24)
+    }
+
+    pub fn ht_delayed_block_ack(&self) -> bool {
+        get_bit(&self.0, 25)
+    }
+
+    /// Maximum MPDU length in bytes: 3895 or 7991
+    pub fn max_mpdu_len(&self) -> u16 {
+        if get_bit(&self.0, 26) {
+            7991
+        } else {
+            3895
+        }
+    }
+
+    /// Maximum A-MPDU length in bytes: `2^(13 + exponent) - 1`
+    pub fn max_ampdu_len(&self) -> u32 {
+        (1u32 << (13 + get_bits_as_u8(&self.0, 27, 28))) - 1
+    }
+
+    /// Minimum MPDU start spacing, same encoding as
+    /// [crate::Nl80211HtAMpduPara::min_space]
+    pub fn min_mpdu_start_spacing(&self) -> u8 {
+        get_bits_as_u8(&self.0, 29, 31)
+    }
+
+    pub fn uplink_sync(&self) -> bool {
+        get_bit(&self.0, 32)
+    }
+
+    pub fn dynamic_aid(&self) -> bool {
+        get_bit(&self.0, 33)
+    }
+
+    pub fn bat(&self) -> bool {
+        get_bit(&self.0, 34)
+    }
+
+    pub fn tim_ade(&self) -> bool {
+        get_bit(&self.0, 35)
+    }
+
+    pub fn non_tim(&self) -> bool {
+        get_bit(&self.0, 36)
+    }
+
+    pub fn group_aid(&self) -> bool {
+        get_bit(&self.0, 37)
+    }
+
+    /// Supported STA type:
+    /// * 0 for both sensor and non-sensor STA
+    /// * 1 for sensor STA only
+    /// * 2 for non-sensor STA only
+    pub fn sta_type(&self) -> u8 {
+        get_bits_as_u8(&self.0, 38, 39)
+    }
+
+    pub fn amsdu(&self) -> bool {
+        get_bit(&self.0, 42)
+    }
+
+    pub fn ampdu(&self) -> bool {
+        get_bit(&self.0, 43)
+    }
+
+    pub fn raw_operation(&self) -> bool {
+        get_bit(&self.0, 51)
+    }
+
+    pub fn twt_grouping(&self) -> bool {
+        get_bit(&self.0, 64)
+    }
+
+    pub fn twt_requester(&self) -> bool {
+        get_bit(&self.0, 69)
+    }
+
+    pub fn twt_responder(&self) -> bool {
+        get_bit(&self.0, 70)
+    }
+}
+
+impl Emitable for Nl80211S1gCapInfo {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..Self::LENGTH].copy_from_slice(&self.0)
+    }
+}
+
+const S1G_MCS_NSS_SET_LEN: usize = 5;
+
+/// Maximum S1G-MCS supported for specified number of spatial streams
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211S1gMcsSupport {
+    /// S1G-MCS 0 to 2
+    Mcs0To2,
+    /// S1G-MCS 0 to 7
+    Mcs0To7,
+    /// S1G-MCS 0 to 9
+    Mcs0To9,
+    NotSupported,
+}
+
+impl From<u8> for Nl80211S1gMcsSupport {
+    fn from(d: u8) -> Self {
+        match d & 0b11 {
+            0 => Self::Mcs0To2,
+            1 => Self::Mcs0To7,
+            2 => Self::Mcs0To9,
+            _ => Self::NotSupported,
+        }
+    }
+}
+
+/// Supported S1G-MCS and NSS Set field, IEEE 802.11-2020 Figure 9-686
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211S1gMcsNssSet(pub [u8; S1G_MCS_NSS_SET_LEN]);
+
+impl Nl80211S1gMcsNssSet {
+    pub const LENGTH: usize = S1G_MCS_NSS_SET_LEN;
+
+    pub fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::LENGTH {
+            return Err(format!(
+                "Invalid Nl80211S1gMcsNssSet, expected length {}, \
+                but got {buf:?}",
+                Self::LENGTH
+            )
+            .into());
+        }
+        let mut data = [0u8; Self::LENGTH];
+        data.copy_from_slice(&buf[..Self::LENGTH]);
+        Ok(Self(data))
+    }
+
+    /// Maximum receive S1G-MCS for `nss`(1 to 4) spatial streams
+    pub fn rx_mcs(&self, nss: u8) -> Nl80211S1gMcsSupport {
+        self.mcs_at(0, nss)
+    }
+
+    /// Maximum transmit S1G-MCS for `nss`(1 to 4) spatial streams
+    pub fn tx_mcs(&self, nss: u8) -> Nl80211S1gMcsSupport {
+        self.mcs_at(17, nss)
+    }
+
+    /// Highest long GI data rate in Mb/s the STA could receive, 0 means
+    /// not specified
+    pub fn rx_highest_long_gi_data_rate(&self) -> u16 {
+        self.u9_at(8)
+    }
+
+    /// Highest long GI data rate in Mb/s the STA could transmit, 0 means
+    /// not specified
+    pub fn tx_highest_long_gi_data_rate(&self) -> u16 {
+        self.u9_at(25)
+    }
+
+    /// Receive S1G-MCS support of single spatial stream on 1 MHz channel
+    pub fn rx_1mhz_mcs(&self) -> Nl80211S1gMcsSupport {
+        get_bits_as_u8(&self.0, 34, 35).into()
+    }
+
+    /// Transmit S1G-MCS support of single spatial stream on 1 MHz channel
+    pub fn tx_1mhz_mcs(&self) -> Nl80211S1gMcsSupport {
+        get_bits_as_u8(&self.0, 36, 37).into()
+    }
+
+    fn mcs_at(&self, start: usize, nss: u8) -> Nl80211S1gMcsSupport {
+        if !(1..=4).contains(&nss) {
+            return Nl80211S1gMcsSupport::NotSupported;
+        }
+        let pos = start + (nss as usize - 1) * 2;
+        get_bits_as_u8(&self.0, pos, pos + 1).into()
+    }
+
+    fn u9_at(&self, start: usize) -> u16 {
+        (get_bits_as_u8(&self.0, start, start + 7) as u16)
+            | ((get_bit(&self.0, start + 8) as u16) << 8)
+    }
+}
+
+impl Emitable for Nl80211S1gMcsNssSet {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..Self::LENGTH].copy_from_slice(&self.0)
+    }
+}
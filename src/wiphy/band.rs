@@ -38,13 +38,16 @@ use netlink_packet_utils::{
 
 use crate::{
     bytes::{write_u16, write_u32},
-    Nl80211EhtMacCapInfo, Nl80211EhtMcsNssSupp, Nl80211EhtPhyCapInfo,
-    Nl80211EhtPpeThres, Nl80211He6GhzCapa, Nl80211HeMacCapInfo,
-    Nl80211HeMcsNssSupp, Nl80211HePhyCapInfo, Nl80211HePpeThreshold,
-    Nl80211HtCaps, Nl80211HtMcsInfo, Nl80211VhtCapInfo, Nl80211VhtMcsInfo,
+    frequency_to_channel, mbm_to_dbm, Nl80211Ac, Nl80211EhtMacCapInfo,
+    Nl80211EhtMcsNssSupp, Nl80211EhtPhyCapInfo, Nl80211EhtPpeThres,
+    Nl80211Freq, Nl80211He6GhzCapa, Nl80211HeMacCapInfo, Nl80211HeMcsNssSupp,
+    Nl80211HePhyCapInfo, Nl80211HePpeThreshold, Nl80211HtCaps,
+    Nl80211HtMcsInfo, Nl80211S1gCapInfo, Nl80211S1gMcsNssSet,
+    Nl80211VhtCapInfo, Nl80211VhtMcsInfo,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211Band {
     pub kind: Nl80211BandType,
     pub info: Vec<Nl80211BandInfo>,
@@ -90,6 +93,7 @@ const NL80211_BAND_S1GHZ: u16 = 4;
 const NL80211_BAND_LC: u16 = 5;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211BandType {
     Band2GHz,
     Band5GHz,
@@ -133,6 +137,7 @@ bitflags::bitflags! {
     /// If not bands are set, it means don't care and the device will decide
     /// what to use
     #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[non_exhaustive]
     pub struct Nl80211BandTypes: u32 {
         const Band2GHz = 1 << NL80211_BAND_2GHZ;
@@ -176,12 +181,11 @@ const NL80211_BAND_ATTR_VHT_CAPA: u16 = 8;
 const NL80211_BAND_ATTR_IFTYPE_DATA: u16 = 9;
 const NL80211_BAND_ATTR_EDMG_CHANNELS: u16 = 10;
 const NL80211_BAND_ATTR_EDMG_BW_CONFIG: u16 = 11;
-// TODO: Kernel has no properly defined struct for 802.11ah sub-1G MCS and CAPA,
-// postpone the deserialization.
-// const NL80211_BAND_ATTR_S1G_MCS_NSS_SET: u16 = 12;
-// const NL80211_BAND_ATTR_S1G_CAPA: u16 = 13;
+const NL80211_BAND_ATTR_S1G_MCS_NSS_SET: u16 = 12;
+const NL80211_BAND_ATTR_S1G_CAPA: u16 = 13;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211BandInfo {
     /// Supported frequencies in this band.
     Freqs(Vec<Nl80211Frequency>),
@@ -208,9 +212,41 @@ pub enum Nl80211BandInfo {
     /// Channel BW Configuration subfield encodes the allowed channel bandwidth
     /// configurations.
     EdmgBwConfig(u8),
+    /// S1G(802.11ah) supported MCS and NSS set
+    S1gMcsNssSet(Nl80211S1gMcsNssSet),
+    /// S1G(802.11ah) capabilities information
+    S1gCapa(Nl80211S1gCapInfo),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
+impl Nl80211BandInfo {
+    /// Maximum A-MPDU length in bytes decoded from
+    /// [Nl80211BandInfo::HtAmpduFactor]: `2^(13 + factor) - 1`.
+    /// Return `None` for other variants or factor larger than 3.
+    pub fn ht_ampdu_max_len(&self) -> Option<u32> {
+        match self {
+            Self::HtAmpduFactor(d @ 0..=3) => Some((1u32 << (13 + d)) - 1),
+            _ => None,
+        }
+    }
+
+    /// Minimum MPDU start spacing in microseconds decoded from
+    /// [Nl80211BandInfo::HtAmpduDensity], `0.0` means no restriction.
+    /// Return `None` for other variants or invalid density code.
+    pub fn ht_ampdu_min_spacing_usec(&self) -> Option<f32> {
+        if let Self::HtAmpduDensity(d) = self {
+            match d {
+                0 => Some(0.0),
+                1..=7 => Some((1u32 << (d - 1)) as f32 / 4.0),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
 impl Nla for Nl80211BandInfo {
     fn value_len(&self) -> usize {
         match self {
@@ -227,6 +263,8 @@ impl Nla for Nl80211BandInfo {
             Self::IftypeData(s) => s.as_slice().buffer_len(),
             Self::EdmgChannels(_) => 1,
             Self::EdmgBwConfig(_) => 1,
+            Self::S1gMcsNssSet(_) => Nl80211S1gMcsNssSet::LENGTH,
+            Self::S1gCapa(_) => Nl80211S1gCapInfo::LENGTH,
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -244,6 +282,8 @@ impl Nla for Nl80211BandInfo {
             Self::IftypeData(_) => NL80211_BAND_ATTR_IFTYPE_DATA,
             Self::EdmgChannels(_) => NL80211_BAND_ATTR_EDMG_CHANNELS,
             Self::EdmgBwConfig(_) => NL80211_BAND_ATTR_EDMG_BW_CONFIG,
+            Self::S1gMcsNssSet(_) => NL80211_BAND_ATTR_S1G_MCS_NSS_SET,
+            Self::S1gCapa(_) => NL80211_BAND_ATTR_S1G_CAPA,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -263,6 +303,8 @@ impl Nla for Nl80211BandInfo {
             Self::IftypeData(d) => d.as_slice().emit(buffer),
             Self::EdmgChannels(d) => buffer[0] = *d,
             Self::EdmgBwConfig(d) => buffer[0] = *d,
+            Self::S1gMcsNssSet(d) => d.emit(buffer),
+            Self::S1gCapa(d) => d.emit(buffer),
             Self::Other(attr) => attr.emit(buffer),
         }
     }
@@ -357,6 +399,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                 );
                 Self::EdmgBwConfig(parse_u8(payload).context(err_msg)?)
             }
+            NL80211_BAND_ATTR_S1G_MCS_NSS_SET => {
+                Self::S1gMcsNssSet(Nl80211S1gMcsNssSet::parse(payload)?)
+            }
+            NL80211_BAND_ATTR_S1G_CAPA => {
+                Self::S1gCapa(Nl80211S1gCapInfo::parse(payload)?)
+            }
             _ => Self::Other(
                 DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
             ),
@@ -377,6 +425,7 @@ const NL80211_BAND_IFTYPE_ATTR_EHT_CAP_MCS_SET: u16 = 10;
 const NL80211_BAND_IFTYPE_ATTR_EHT_CAP_PPE: u16 = 11;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Nl80211BandIftypeData {
     IfTypes(Vec<Nl80211IfType>),
@@ -391,6 +440,7 @@ pub enum Nl80211BandIftypeData {
     EhtCapPhy(Nl80211EhtPhyCapInfo),
     EhtCapMcsSet(Nl80211EhtMcsNssSupp),
     EhtCapPpe(Nl80211EhtPpeThres),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -576,6 +626,7 @@ const NL80211_IFTYPE_OCB: u16 = 11;
 const NL80211_IFTYPE_NAN: u16 = 12;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211IfType {
     Adhoc,
     /// aka: managed or client
@@ -686,6 +737,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nl80211Frequency {
     pub index: u16,
     pub info: Vec<Nl80211FrequencyInfo>,
@@ -757,9 +809,11 @@ const NL80211_FREQUENCY_ATTR_NO_6GHZ_AFC_CLIENT: u16 = 31;
 const NL80211_FREQUENCY_ATTR_CAN_MONITOR: u16 = 32;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211FrequencyInfo {
-    /// Frequency in MHz
-    Freq(u32),
+    /// Frequency of the channel, emitted in MHz, the kHz part is carried by
+    /// [Nl80211FrequencyInfo::Offset]
+    Freq(Nl80211Freq),
     /// Channel is disabled in current regulatory domain
     Disabled,
     /// No mechanisms that initiate radiation are permitted on this channel,
@@ -814,7 +868,9 @@ pub enum Nl80211FrequencyInfo {
     /// 10 MHz operation is not allowed on this channel in current regulatory
     /// domain.
     No10Mhz,
-    /// this channel has WMM limitations.
+    /// this channel has WMM limitations. The index of outer `Vec` is the
+    /// [Nl80211Ac], use [Self::wmm_rule()] to lookup rules of an access
+    /// category.
     Wmm(Vec<Vec<Nl80211WmmRule>>),
     /// HE operation is not allowed on this channel in current regulatory
     /// domain.
@@ -853,9 +909,31 @@ pub enum Nl80211FrequencyInfo {
     /// restrictions, even if the channel is otherwise completely disabled.
     CanMonitor,
     /// Place holder for new attribute of `NL80211_BAND_ATTR_FREQS`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
+impl Nl80211FrequencyInfo {
+    /// IEEE 802.11 channel number when this is [Nl80211FrequencyInfo::Freq]
+    pub fn channel(&self) -> Option<u32> {
+        if let Self::Freq(d) = self {
+            frequency_to_channel(*d)
+        } else {
+            None
+        }
+    }
+
+    /// Maximum transmission power in dBm when this is
+    /// [Nl80211FrequencyInfo::MaxTxPower]
+    pub fn max_tx_power_dbm(&self) -> Option<f32> {
+        if let Self::MaxTxPower(d) = self {
+            Some(mbm_to_dbm(*d as i32))
+        } else {
+            None
+        }
+    }
+}
+
 impl Nla for Nl80211FrequencyInfo {
     fn value_len(&self) -> usize {
         match self {
@@ -937,10 +1015,10 @@ impl Nla for Nl80211FrequencyInfo {
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
-            Self::Freq(d)
-            | Self::MaxTxPower(d)
-            | Self::DfsTime(d)
-            | Self::DfsCacTime(d) => write_u32(buffer, *d),
+            Self::Freq(d) => write_u32(buffer, d.mhz()),
+            Self::MaxTxPower(d) | Self::DfsTime(d) | Self::DfsCacTime(d) => {
+                write_u32(buffer, *d)
+            }
             Self::DfsState(d) => write_u32(buffer, u32::from(d)),
             Self::Disabled
             | Self::NoIr
@@ -976,18 +1054,30 @@ impl Nla for Nl80211FrequencyInfo {
     }
 }
 
+impl Nl80211FrequencyInfo {
+    /// WMM rules of specified access category, `None` if this is not
+    /// [Self::Wmm] or the access category is not included.
+    pub fn wmm_rule(&self, ac: Nl80211Ac) -> Option<&[Nl80211WmmRule]> {
+        if let Self::Wmm(rules) = self {
+            rules.get(u8::from(ac) as usize).map(|r| r.as_slice())
+        } else {
+            None
+        }
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     for Nl80211FrequencyInfo
 {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
         Ok(match buf.kind() {
-            NL80211_FREQUENCY_ATTR_FREQ => {
-                Self::Freq(parse_u32(payload).context(format!(
+            NL80211_FREQUENCY_ATTR_FREQ => Self::Freq(Nl80211Freq::from_mhz(
+                parse_u32(payload).context(format!(
                     "Invalid NL80211_FREQUENCY_ATTR_FREQ value: {:?}",
                     payload
-                ))?)
-            }
+                ))?,
+            )),
             NL80211_FREQUENCY_ATTR_DISABLED => Self::Disabled,
             NL80211_FREQUENCY_ATTR_NO_IR => Self::NoIr,
             __NL80211_FREQUENCY_ATTR_NO_IBSS => Self::NoIbss,
@@ -1080,6 +1170,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Nl80211RateAttrsList(Vec<Nl80211RateAttrs>);
 
 impl std::ops::Deref for Nl80211RateAttrsList {
@@ -1109,6 +1200,7 @@ impl From<&Vec<Vec<Nl80211Rate>>> for Nl80211RateAttrsList {
 // `NL80211_BAND_ATTR_RATES` is a two levels array.
 // The second level is using index as NLA kind.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Nl80211RateAttrs {
     index: u16,
     attributes: Vec<Nl80211Rate>,
@@ -1152,11 +1244,13 @@ const NL80211_BITRATE_ATTR_RATE: u16 = 1;
 const NL80211_BITRATE_ATTR_2GHZ_SHORTPREAMBLE: u16 = 2;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211Rate {
     /// Bitrate in units of 100 kbps.
     Rate(u32),
     /// Short preamble supported in 2.4 GHz band.
     Support2GhzShortpreamble,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
@@ -1214,6 +1308,7 @@ const NL80211_DFS_AVAILABLE: u32 = 2;
 
 /// DFS states for channels
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211DfsState {
     /// The channel can be used, but channel availability check (CAC) must be
     /// performed before using it for AP or IBSS.
@@ -1250,6 +1345,7 @@ impl From<&Nl80211DfsState> for u32 {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Nl80211WmmRuleAttrsList(Vec<Nl80211WmmRuleAttrs>);
 
 impl std::ops::Deref for Nl80211WmmRuleAttrsList {
@@ -1279,6 +1375,7 @@ impl From<&Vec<Vec<Nl80211WmmRule>>> for Nl80211WmmRuleAttrsList {
 // `NL80211_FREQUENCY_ATTR_WMM` is a two levels array.
 // The second level is using index as NLA kind.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Nl80211WmmRuleAttrs {
     index: u16,
     attributes: Vec<Nl80211WmmRule>,
@@ -1325,6 +1422,7 @@ const NL80211_WMMR_TXOP: u16 = 4;
 
 /// DFS states for channels
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211WmmRule {
     /// Minimum contention window slot
     CwMin(u16),
@@ -1335,6 +1433,7 @@ pub enum Nl80211WmmRule {
     /// Maximum allowed tx operation time
     Txop(u16),
     /// Place holder for new entry of `enum nl80211_wmm_rule`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
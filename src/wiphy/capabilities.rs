@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    Nl80211Attr, Nl80211Band, Nl80211BandInfo, Nl80211BandType,
+    Nl80211CipherSuite, Nl80211CoalesceSupport, Nl80211Command, Nl80211Error,
+    Nl80211ExtFeature, Nl80211ExtendedCapability, Nl80211Features,
+    Nl80211IfMode, Nl80211IfaceComb, Nl80211IfaceCombParams,
+    Nl80211InterfaceType, Nl80211TidConfigSupport, Nl80211WiphyIndex,
+    Nl80211WiphyRadio,
+};
+
+/// Typed view of the wiphy attributes reported by `NL80211_CMD_GET_WIPHY`.
+/// When kernel split the wiphy dump into multiple messages, the attributes of
+/// all the messages belonging to the same wiphy could be concatenated before
+/// converting, the bands split across messages will be merged.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211WiphyCapabilities {
+    pub wiphy_index: Nl80211WiphyIndex,
+    pub name: Option<String>,
+    pub bands: Vec<Nl80211Band>,
+    pub cipher_suites: Vec<Nl80211CipherSuite>,
+    pub supported_iftypes: Vec<Nl80211IfMode>,
+    /// Interface types not restricted by interface combinations
+    pub software_iftypes: Vec<Nl80211InterfaceType>,
+    pub interface_combinations: Vec<Nl80211IfaceComb>,
+    pub supported_commands: Vec<Nl80211Command>,
+    pub features: Nl80211Features,
+    pub ext_features: Vec<Nl80211ExtFeature>,
+    pub ext_cap: Option<Nl80211ExtendedCapability>,
+    pub max_num_scan_ssids: Option<u8>,
+    pub max_num_sched_scan_ssids: Option<u8>,
+    pub max_scan_ie_len: Option<u16>,
+    pub max_sched_scan_ie_len: Option<u16>,
+    pub max_match_sets: Option<u8>,
+    /// Maximum remain-on-channel duration in milliseconds
+    pub max_remain_on_channel_duration: Option<u32>,
+    pub antenna_avail_tx: Option<u32>,
+    pub antenna_avail_rx: Option<u32>,
+    pub offchannel_tx_ok: bool,
+    pub roam_support: bool,
+    pub tdls_support: bool,
+    pub self_managed_reg: bool,
+    /// `None` if packet coalescing is not supported
+    pub coalesce: Option<Nl80211CoalesceSupport>,
+    /// `None` if per-TID configuration is not supported
+    pub tid_config: Option<Nl80211TidConfigSupport>,
+    /// Physical radios, empty unless wiphy has multiple radios
+    pub radios: Vec<Nl80211WiphyRadio>,
+    /// Interface combinations across all radios of multi-radio wiphy
+    pub radio_interface_combinations: Vec<Nl80211IfaceComb>,
+}
+
+impl Nl80211WiphyCapabilities {
+    pub fn band(&self, kind: Nl80211BandType) -> Option<&Nl80211Band> {
+        self.bands.iter().find(|band| band.kind == kind)
+    }
+
+    /// The radio covering specified frequency in MHz
+    pub fn radio_for_freq(&self, freq: u32) -> Option<&Nl80211WiphyRadio> {
+        self.radios.iter().find(|radio| radio.supports_freq(freq))
+    }
+
+    pub fn supports_band(&self, kind: Nl80211BandType) -> bool {
+        self.band(kind).is_some()
+    }
+
+    pub fn supports_iftype(&self, iftype: Nl80211IfMode) -> bool {
+        self.supported_iftypes.contains(&iftype)
+    }
+
+    pub fn supports_cipher(&self, cipher: Nl80211CipherSuite) -> bool {
+        self.cipher_suites.contains(&cipher)
+    }
+
+    pub fn supports_command(&self, cmd: Nl80211Command) -> bool {
+        self.supported_commands.contains(&cmd)
+    }
+
+    pub fn supports_ext_feature(&self, feature: Nl80211ExtFeature) -> bool {
+        self.ext_features.contains(&feature)
+    }
+
+    /// Whether any interface combination allows the interfaces described by
+    /// `params`, the [Self::software_iftypes] are ignored.
+    pub fn allows_iface_combination(
+        &self,
+        params: &Nl80211IfaceCombParams,
+    ) -> bool {
+        let mut params = params.clone();
+        params
+            .iftypes
+            .retain(|iftype| !self.software_iftypes.contains(iftype));
+        // Single interface is not restricted by interface combinations
+        if params.iftypes.len() <= 1 && params.num_channels <= 1 {
+            return true;
+        }
+        self.interface_combinations
+            .iter()
+            .any(|comb| comb.allows(&params))
+    }
+
+    fn merge_band(&mut self, band: Nl80211Band) {
+        let Some(existing) =
+            self.bands.iter_mut().find(|b| b.kind == band.kind)
+        else {
+            self.bands.push(band);
+            return;
+        };
+        for info in band.info {
+            if let Nl80211BandInfo::Freqs(freqs) = info {
+                if let Some(Nl80211BandInfo::Freqs(cur)) = existing
+                    .info
+                    .iter_mut()
+                    .find(|i| matches!(i, Nl80211BandInfo::Freqs(_)))
+                {
+                    cur.extend(freqs);
+                } else {
+                    existing.info.push(Nl80211BandInfo::Freqs(freqs));
+                }
+            } else if !existing.info.contains(&info) {
+                existing.info.push(info);
+            }
+        }
+    }
+}
+
+impl TryFrom<Vec<Nl80211Attr>> for Nl80211WiphyCapabilities {
+    type Error = Nl80211Error;
+
+    fn try_from(attributes: Vec<Nl80211Attr>) -> Result<Self, Self::Error> {
+        let mut ret = Self::default();
+        let mut wiphy_index = None;
+        for attr in attributes {
+            match attr {
+                Nl80211Attr::Wiphy(d) => {
+                    wiphy_index = Some(Nl80211WiphyIndex::new(d))
+                }
+                Nl80211Attr::WiphyName(s) => ret.name = Some(s),
+                Nl80211Attr::WiphyBands(bands) => {
+                    for band in bands {
+                        ret.merge_band(band);
+                    }
+                }
+                Nl80211Attr::CipherSuites(v) => ret.cipher_suites.extend(v),
+                Nl80211Attr::SupportedIftypes(v) => {
+                    ret.supported_iftypes.extend(v)
+                }
+                Nl80211Attr::SoftwareIftypes(v) => {
+                    ret.software_iftypes.extend(v)
+                }
+                Nl80211Attr::InterfaceCombination(v) => {
+                    ret.interface_combinations.extend(v)
+                }
+                Nl80211Attr::SupportedCommand(v) => {
+                    ret.supported_commands.extend(v)
+                }
+                Nl80211Attr::Features(d) => ret.features |= d,
+                Nl80211Attr::ExtFeatures(v) => ret.ext_features.extend(v),
+                Nl80211Attr::ExtCap(d) => ret.ext_cap = Some(d),
+                Nl80211Attr::MaxNumScanSsids(d) => {
+                    ret.max_num_scan_ssids = Some(d)
+                }
+                Nl80211Attr::MaxNumSchedScanSsids(d) => {
+                    ret.max_num_sched_scan_ssids = Some(d)
+                }
+                Nl80211Attr::MaxScanIeLen(d) => ret.max_scan_ie_len = Some(d),
+                Nl80211Attr::MaxSchedScanIeLen(d) => {
+                    ret.max_sched_scan_ie_len = Some(d)
+                }
+                Nl80211Attr::MaxMatchSets(d) => ret.max_match_sets = Some(d),
+                Nl80211Attr::MaxRemainOnChannelDuration(d) => {
+                    ret.max_remain_on_channel_duration = Some(d)
+                }
+                Nl80211Attr::WiphyAntennaAvailTx(d) => {
+                    ret.antenna_avail_tx = Some(d)
+                }
+                Nl80211Attr::WiphyAntennaAvailRx(d) => {
+                    ret.antenna_avail_rx = Some(d)
+                }
+                Nl80211Attr::OffchannelTxOk => ret.offchannel_tx_ok = true,
+                Nl80211Attr::RoamSupport => ret.roam_support = true,
+                Nl80211Attr::TdlsSupport => ret.tdls_support = true,
+                Nl80211Attr::WiphySelfManagedReg => ret.self_managed_reg = true,
+                Nl80211Attr::CoalesceRuleSupport(v) => ret.coalesce = Some(v),
+                Nl80211Attr::TidConfigSupport(v) => ret.tid_config = Some(v),
+                Nl80211Attr::WiphyRadios(v) => ret.radios.extend(v),
+                Nl80211Attr::WiphyInterfaceCombinations(v) => {
+                    ret.radio_interface_combinations.extend(v)
+                }
+                _ => (),
+            }
+        }
+        ret.wiphy_index = wiphy_index.ok_or_else(|| {
+            Nl80211Error::from(DecodeError::from(
+                "No NL80211_ATTR_WIPHY in wiphy attributes",
+            ))
+        })?;
+        Ok(ret)
+    }
+}
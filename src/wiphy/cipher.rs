@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: MIT
 
+use netlink_packet_utils::DecodeError;
+
 const WLAN_CIPHER_SUITE_USE_GROUP: u32 = 0x000FAC << 8;
 const WLAN_CIPHER_SUITE_WEP40: u32 = 0x000FAC << 8 | 1;
 const WLAN_CIPHER_SUITE_TKIP: u32 = 0x000FAC << 8 | 2;
 const WLAN_CIPHER_SUITE_CCMP: u32 = 0x000FAC << 8 | 4;
 const WLAN_CIPHER_SUITE_WEP104: u32 = 0x000FAC << 8 | 5;
 const WLAN_CIPHER_SUITE_AES_CMAC: u32 = 0x000FAC << 8 | 6;
+const WLAN_CIPHER_SUITE_NO_GROUP_ADDR: u32 = 0x000FAC << 8 | 7;
 const WLAN_CIPHER_SUITE_GCMP: u32 = 0x000FAC << 8 | 8;
 const WLAN_CIPHER_SUITE_GCMP_256: u32 = 0x000FAC << 8 | 9;
 const WLAN_CIPHER_SUITE_CCMP_256: u32 = 0x000FAC << 8 | 10;
@@ -14,14 +17,25 @@ const WLAN_CIPHER_SUITE_BIP_GMAC_256: u32 = 0x000FAC << 8 | 12;
 const WLAN_CIPHER_SUITE_BIP_CMAC_256: u32 = 0x000FAC << 8 | 13;
 const WLAN_CIPHER_SUITE_SMS4: u32 = 0x001472 << 8 | 1;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum Nl80211CipherSuit {
+/// Cipher suite selector.
+///
+/// The `u32` conversion uses the kernel `WLAN_CIPHER_SUITE_*` encoding
+/// (OUI in the upper three bytes, suite type in the lowest byte), which is
+/// also the big endian reading of the suite selector in the RSN element.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211CipherSuite {
     UseGroup,
     Wep40,
     Tkip,
+    // The 802.11-2020 said only non-DMG default to CCMP-128.
+    // But considering 60G 802.11ad(DMG) is rarely used, it is reasonable to
+    // assume CCMP-128 is default
+    #[default]
     Ccmp,
     Wep104,
     AesCmac,
+    GroupAddressedTrafficNotAllowed,
     Gcmp,
     Gcmp256,
     Ccmp256,
@@ -32,7 +46,11 @@ pub enum Nl80211CipherSuit {
     Other(u32),
 }
 
-impl From<u32> for Nl80211CipherSuit {
+/// Misspelled name kept for compatibility
+#[deprecated(since = "0.3.0", note = "Use Nl80211CipherSuite")]
+pub type Nl80211CipherSuit = Nl80211CipherSuite;
+
+impl From<u32> for Nl80211CipherSuite {
     fn from(d: u32) -> Self {
         match d {
             WLAN_CIPHER_SUITE_USE_GROUP => Self::UseGroup,
@@ -41,6 +59,9 @@ impl From<u32> for Nl80211CipherSuit {
             WLAN_CIPHER_SUITE_CCMP => Self::Ccmp,
             WLAN_CIPHER_SUITE_WEP104 => Self::Wep104,
             WLAN_CIPHER_SUITE_AES_CMAC => Self::AesCmac,
+            WLAN_CIPHER_SUITE_NO_GROUP_ADDR => {
+                Self::GroupAddressedTrafficNotAllowed
+            }
             WLAN_CIPHER_SUITE_GCMP => Self::Gcmp,
             WLAN_CIPHER_SUITE_GCMP_256 => Self::Gcmp256,
             WLAN_CIPHER_SUITE_CCMP_256 => Self::Ccmp256,
@@ -53,23 +74,52 @@ impl From<u32> for Nl80211CipherSuit {
     }
 }
 
-impl From<Nl80211CipherSuit> for u32 {
-    fn from(v: Nl80211CipherSuit) -> u32 {
+impl From<Nl80211CipherSuite> for u32 {
+    fn from(v: Nl80211CipherSuite) -> u32 {
         match v {
-            Nl80211CipherSuit::UseGroup => WLAN_CIPHER_SUITE_USE_GROUP,
-            Nl80211CipherSuit::Wep40 => WLAN_CIPHER_SUITE_WEP40,
-            Nl80211CipherSuit::Tkip => WLAN_CIPHER_SUITE_TKIP,
-            Nl80211CipherSuit::Ccmp => WLAN_CIPHER_SUITE_CCMP,
-            Nl80211CipherSuit::Wep104 => WLAN_CIPHER_SUITE_WEP104,
-            Nl80211CipherSuit::AesCmac => WLAN_CIPHER_SUITE_AES_CMAC,
-            Nl80211CipherSuit::Gcmp => WLAN_CIPHER_SUITE_GCMP,
-            Nl80211CipherSuit::Gcmp256 => WLAN_CIPHER_SUITE_GCMP_256,
-            Nl80211CipherSuit::Ccmp256 => WLAN_CIPHER_SUITE_CCMP_256,
-            Nl80211CipherSuit::BipGmac128 => WLAN_CIPHER_SUITE_BIP_GMAC_128,
-            Nl80211CipherSuit::BipGmac256 => WLAN_CIPHER_SUITE_BIP_GMAC_256,
-            Nl80211CipherSuit::BipCmac256 => WLAN_CIPHER_SUITE_BIP_CMAC_256,
-            Nl80211CipherSuit::Sms4 => WLAN_CIPHER_SUITE_SMS4,
-            Nl80211CipherSuit::Other(d) => d,
+            Nl80211CipherSuite::UseGroup => WLAN_CIPHER_SUITE_USE_GROUP,
+            Nl80211CipherSuite::Wep40 => WLAN_CIPHER_SUITE_WEP40,
+            Nl80211CipherSuite::Tkip => WLAN_CIPHER_SUITE_TKIP,
+            Nl80211CipherSuite::Ccmp => WLAN_CIPHER_SUITE_CCMP,
+            Nl80211CipherSuite::Wep104 => WLAN_CIPHER_SUITE_WEP104,
+            Nl80211CipherSuite::AesCmac => WLAN_CIPHER_SUITE_AES_CMAC,
+            Nl80211CipherSuite::GroupAddressedTrafficNotAllowed => {
+                WLAN_CIPHER_SUITE_NO_GROUP_ADDR
+            }
+            Nl80211CipherSuite::Gcmp => WLAN_CIPHER_SUITE_GCMP,
+            Nl80211CipherSuite::Gcmp256 => WLAN_CIPHER_SUITE_GCMP_256,
+            Nl80211CipherSuite::Ccmp256 => WLAN_CIPHER_SUITE_CCMP_256,
+            Nl80211CipherSuite::BipGmac128 => WLAN_CIPHER_SUITE_BIP_GMAC_128,
+            Nl80211CipherSuite::BipGmac256 => WLAN_CIPHER_SUITE_BIP_GMAC_256,
+            Nl80211CipherSuite::BipCmac256 => WLAN_CIPHER_SUITE_BIP_CMAC_256,
+            Nl80211CipherSuite::Sms4 => WLAN_CIPHER_SUITE_SMS4,
+            Nl80211CipherSuite::Other(d) => d,
         }
     }
 }
+
+impl Nl80211CipherSuite {
+    /// Length of the suite selector in information element
+    pub const LENGTH: usize = 4;
+
+    /// Parse the suite selector stored in information element
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid buffer length for Nl80211CipherSuite, \
+                expecting 4, but got {payload:?}"
+            )
+            .into())
+        } else {
+            Ok(u32::from_be_bytes([
+                payload[0], payload[1], payload[2], payload[3],
+            ])
+            .into())
+        }
+    }
+
+    /// Emit the suite selector in the information element byte order
+    pub fn emit(&self, buffer: &mut [u8]) {
+        buffer[..Self::LENGTH].copy_from_slice(&u32::from(*self).to_be_bytes());
+    }
+}
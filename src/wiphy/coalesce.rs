@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_TYPE_MASK},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{bytes::write_u32, Nl80211PacketPattern, Nl80211PatternSupport};
+
+const NL80211_ATTR_COALESCE_RULE_DELAY: u16 = 1;
+const NL80211_ATTR_COALESCE_RULE_CONDITION: u16 = 2;
+const NL80211_ATTR_COALESCE_RULE_PKT_PATTERN: u16 = 3;
+
+/// Packet coalesce rule to configure through
+/// [crate::Nl80211WiphyHandle::set_coalesce()] or reported by
+/// [crate::Nl80211Command::GetCoalesce].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211CoalesceRule {
+    pub index: u16,
+    pub attributes: Vec<Nl80211CoalesceRuleAttr>,
+}
+
+impl Nl80211CoalesceRule {
+    /// Coalesce the packets matching (or not matching, depending on
+    /// `condition`) any of the `patterns` for at most `delay_ms`
+    /// milliseconds.
+    pub fn new(
+        delay_ms: u32,
+        condition: Nl80211CoalesceCondition,
+        patterns: Vec<Nl80211PacketPattern>,
+    ) -> Self {
+        Self {
+            index: 0,
+            attributes: vec![
+                Nl80211CoalesceRuleAttr::Delay(delay_ms),
+                Nl80211CoalesceRuleAttr::Condition(condition),
+                Nl80211CoalesceRuleAttr::PktPattern(patterns),
+            ],
+        }
+    }
+
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        index: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg =
+            format!("Invalid NL80211_ATTR_COALESCE_RULE value {payload:?}");
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            attributes.push(Nl80211CoalesceRuleAttr::parse(nla)?);
+        }
+        Ok(Self { index, attributes })
+    }
+}
+
+impl Nla for Nl80211CoalesceRule {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211CoalesceRuleAttr {
+    /// Maximum delay in milliseconds for coalescing matched packets
+    Delay(u32),
+    Condition(Nl80211CoalesceCondition),
+    PktPattern(Vec<Nl80211PacketPattern>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211CoalesceRuleAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Delay(_) | Self::Condition(_) => 4,
+            Self::PktPattern(s) => s.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Delay(_) => NL80211_ATTR_COALESCE_RULE_DELAY,
+            Self::Condition(_) => NL80211_ATTR_COALESCE_RULE_CONDITION,
+            Self::PktPattern(_) => NL80211_ATTR_COALESCE_RULE_PKT_PATTERN,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Delay(d) => write_u32(buffer, *d),
+            Self::Condition(d) => write_u32(buffer, (*d).into()),
+            Self::PktPattern(s) => s.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211CoalesceRuleAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_ATTR_COALESCE_RULE_DELAY => {
+                Self::Delay(parse_u32(payload).context(format!(
+                    "Invalid NL80211_ATTR_COALESCE_RULE_DELAY {payload:?}"
+                ))?)
+            }
+            NL80211_ATTR_COALESCE_RULE_CONDITION => Self::Condition(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid NL80211_ATTR_COALESCE_RULE_CONDITION \
+                            {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            NL80211_ATTR_COALESCE_RULE_PKT_PATTERN => {
+                Self::PktPattern(Nl80211PacketPattern::parse_list(
+                    payload,
+                    "NL80211_ATTR_COALESCE_RULE_PKT_PATTERN",
+                )?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_COALESCE_CONDITION_MATCH: u32 = 0;
+const NL80211_COALESCE_CONDITION_NO_MATCH: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211CoalesceCondition {
+    /// Coalesce the packets matching any of the patterns
+    Match,
+    /// Coalesce the packets not matching any of the patterns
+    NoMatch,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211CoalesceCondition {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_COALESCE_CONDITION_MATCH => Self::Match,
+            NL80211_COALESCE_CONDITION_NO_MATCH => Self::NoMatch,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211CoalesceCondition> for u32 {
+    fn from(v: Nl80211CoalesceCondition) -> u32 {
+        match v {
+            Nl80211CoalesceCondition::Match => NL80211_COALESCE_CONDITION_MATCH,
+            Nl80211CoalesceCondition::NoMatch => {
+                NL80211_COALESCE_CONDITION_NO_MATCH
+            }
+            Nl80211CoalesceCondition::Other(d) => d,
+        }
+    }
+}
+
+/// Coalesce capabilities of wiphy reported in [crate::Nl80211Attr::Wiphy]
+/// dump, shares the attribute ID with [crate::Nl80211Attr::CoalesceRule].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211CoalesceSupport {
+    pub max_rules: u32,
+    pub pattern_support: Nl80211PatternSupport,
+    /// Maximum supported coalescing delay in milliseconds
+    pub max_delay: u32,
+}
+
+impl Nl80211CoalesceSupport {
+    pub(crate) const LENGTH: usize = 24;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid coalesce rule support, expecting length {} but got \
+                {}: {payload:?}",
+                Self::LENGTH,
+                payload.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                max_rules: parse_u32(&payload[..4])?,
+                pattern_support: Nl80211PatternSupport::parse(&payload[4..20])?,
+                max_delay: parse_u32(&payload[20..24])?,
+            })
+        }
+    }
+
+    // Kernel nests the rules with attribute ID starting from 1, while the
+    // capability struct starts with the small `max_rules` number which
+    // cannot be read as NLA header of valid length and non-zero kind.
+    pub(crate) fn is_support_payload(payload: &[u8]) -> bool {
+        payload.len() == Self::LENGTH
+            && (NativeEndian::read_u16(&payload[..2]) < 4
+                || NativeEndian::read_u16(&payload[2..4]) & NLA_TYPE_MASK == 0)
+    }
+
+    /// Whether specified rules are within the capabilities
+    pub fn allows(&self, rules: &[Nl80211CoalesceRule]) -> bool {
+        rules.len() <= self.max_rules as usize
+            && rules.iter().all(|rule| {
+                rule.attributes.iter().all(|attr| match attr {
+                    Nl80211CoalesceRuleAttr::Delay(d) => *d <= self.max_delay,
+                    Nl80211CoalesceRuleAttr::PktPattern(patterns) => {
+                        self.pattern_support.allows(patterns)
+                    }
+                    _ => true,
+                })
+            })
+    }
+}
+
+impl Emitable for Nl80211CoalesceSupport {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        write_u32(&mut buffer[0..4], self.max_rules);
+        self.pattern_support.emit(&mut buffer[4..20]);
+        write_u32(&mut buffer[20..24], self.max_delay);
+    }
+}
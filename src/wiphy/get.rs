@@ -1,36 +1,123 @@
 // SPDX-License-Identifier: MIT
 
-use futures::TryStream;
+use std::convert::TryFrom;
+
+use futures::{TryStream, TryStreamExt};
 use netlink_packet_core::{NLM_F_DUMP, NLM_F_REQUEST};
 use netlink_packet_generic::GenlMessage;
 
 use crate::{
     nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
-    Nl80211Message,
+    Nl80211Message, Nl80211ProtocolFeatures, Nl80211WiphyCapabilities,
+    Nl80211WiphyIndex,
 };
 
 pub struct Nl80211WiphyGetRequest {
     handle: Nl80211Handle,
+    wiphy_index: Option<u32>,
 }
 
 impl Nl80211WiphyGetRequest {
     pub(crate) fn new(handle: Nl80211Handle) -> Self {
-        Nl80211WiphyGetRequest { handle }
+        Nl80211WiphyGetRequest {
+            handle,
+            wiphy_index: None,
+        }
+    }
+
+    /// Only query the wiphy of specified index.
+    ///
+    /// The non-dump `NL80211_CMD_GET_WIPHY` of kernel does not support split
+    /// reply and fails on wiphy with large capability data, hence this
+    /// request is still sent as split dump but with [Nl80211Attr::Wiphy]
+    /// set, so kernel only replies the messages of that wiphy.
+    pub fn wiphy_index(mut self, index: Nl80211WiphyIndex) -> Self {
+        self.wiphy_index = Some(index.get());
+        self
     }
 
+    /// The [Nl80211Attr::SplitWiphyDump] is only requested when kernel
+    /// advertise [Nl80211ProtocolFeatures::SplitWiphyDump], so the data of
+    /// one wiphy might be split into multiple messages, use
+    /// [Self::capabilities()] to get them merged.
     pub async fn execute(
         self,
     ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
     {
-        let Nl80211WiphyGetRequest { mut handle } = self;
+        let Nl80211WiphyGetRequest {
+            mut handle,
+            wiphy_index,
+        } = self;
+
+        // Kernel ignores unknown attributes, so still request split dump
+        // when failed to query the protocol features.
+        let split = handle
+            .protocol_features()
+            .await
+            .map(|f| f.contains(Nl80211ProtocolFeatures::SplitWiphyDump))
+            .unwrap_or(true);
+
+        let mut attributes = Vec::new();
+        if split {
+            attributes.push(Nl80211Attr::SplitWiphyDump);
+        }
+        if let Some(index) = wiphy_index {
+            attributes.push(Nl80211Attr::Wiphy(index));
+        }
 
         let nl80211_msg = Nl80211Message {
             cmd: Nl80211Command::GetWiphy,
-            attributes: vec![Nl80211Attr::SplitWiphyDump],
+            attributes,
         };
 
         let flags = NLM_F_REQUEST | NLM_F_DUMP;
 
         nl80211_execute(&mut handle, nl80211_msg, flags).await
     }
+
+    /// Execute the request and merge the split messages of each wiphy into
+    /// single [Nl80211WiphyCapabilities], ordered by the first appearance of
+    /// the wiphy in dump reply.
+    pub async fn capabilities(
+        self,
+    ) -> Result<Vec<Nl80211WiphyCapabilities>, Nl80211Error> {
+        let response = self.execute().await;
+        futures::pin_mut!(response);
+        let mut msgs = Vec::new();
+        while let Some(msg) = response.try_next().await? {
+            msgs.push(msg.payload.attributes);
+        }
+        merge_wiphy_messages(msgs)
+    }
+}
+
+// Merge the split messages of each wiphy into single
+// [Nl80211WiphyCapabilities], ordered by the first appearance of the wiphy.
+pub(crate) fn merge_wiphy_messages(
+    msgs: impl IntoIterator<Item = Vec<Nl80211Attr>>,
+) -> Result<Vec<Nl80211WiphyCapabilities>, Nl80211Error> {
+    let mut wiphys: Vec<(u32, Vec<Nl80211Attr>)> = Vec::new();
+    for attributes in msgs {
+        let Some(index) = attributes.iter().find_map(|attr| {
+            if let Nl80211Attr::Wiphy(d) = attr {
+                Some(*d)
+            } else {
+                None
+            }
+        }) else {
+            log::warn!(
+                "Discarding wiphy message without NL80211_ATTR_WIPHY: \
+                    {attributes:?}"
+            );
+            continue;
+        };
+        match wiphys.iter_mut().find(|(i, _)| *i == index) {
+            Some((_, existing)) => existing.extend(attributes),
+            None => wiphys.push((index, attributes)),
+        }
+    }
+    wiphys
+        .into_iter()
+        .map(|(_, attributes)| Nl80211WiphyCapabilities::try_from(attributes))
+        .collect()
 }
@@ -1,6 +1,17 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Nl80211Handle, Nl80211WiphyGetRequest};
+use std::os::fd::AsFd;
+
+use netlink_packet_utils::nla::Nla;
+
+use crate::{
+    Nl80211Attr, Nl80211AttrsBuilder, Nl80211CoalesceRule,
+    Nl80211CoalesceSetRequest, Nl80211Handle, Nl80211IfIndex, Nl80211SarSpec,
+    Nl80211SarType, Nl80211SetSarSpecsRequest, Nl80211TxPowerSetting,
+    Nl80211TxqParams, Nl80211WiphyGetRequest, Nl80211WiphyIndex,
+    Nl80211WiphySetNetnsRequest, Nl80211WiphySetRequest,
+    Nl80211WowlanSetRequest, Nl80211WowlanTrigger,
+};
 
 #[derive(Debug)]
 pub struct Nl80211WiphyHandle(Nl80211Handle);
@@ -15,4 +26,186 @@ impl Nl80211WiphyHandle {
     pub fn get(&mut self) -> Nl80211WiphyGetRequest {
         Nl80211WiphyGetRequest::new(self.0.clone())
     }
+
+    /// Retrieve the wireless physical device of specified index only
+    /// (equivalent to `iw phy PHY info`). The split replies could be merged
+    /// by [crate::Nl80211WiphyCapabilities].
+    pub fn get_by_index(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+    ) -> Nl80211WiphyGetRequest {
+        Nl80211WiphyGetRequest::new(self.0.clone()).wiphy_index(wiphy_index)
+    }
+
+    /// Change wiphy configuration
+    /// (equivalent to `iw phy PHY set`).
+    /// The `attributes: Vec<Nl80211Attr>` could be generated by
+    /// [Nl80211Wiphy].
+    pub fn set(
+        &mut self,
+        attributes: Vec<Nl80211Attr>,
+    ) -> Nl80211WiphySetRequest {
+        Nl80211WiphySetRequest::new(self.0.clone(), attributes)
+    }
+
+    /// Move the wiphy and all its interfaces into the network namespace of
+    /// specified process (equivalent to `iw phy PHY set netns PID`).
+    /// Kernel rejects the request with `EOPNOTSUPP` if the driver does not
+    /// allow changing network namespace.
+    pub fn set_netns_by_pid(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+        pid: u32,
+    ) -> Nl80211WiphySetNetnsRequest<'static> {
+        Nl80211WiphySetNetnsRequest::new_by_pid(
+            self.0.clone(),
+            wiphy_index.get(),
+            pid,
+        )
+    }
+
+    /// Move the wiphy and all its interfaces into the network namespace
+    /// referred by file descriptor, for example of `/run/netns/NAME`
+    /// (equivalent to `iw phy PHY set netns name NAME`).
+    pub fn set_netns_by_fd<'a, T: AsFd + ?Sized>(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+        fd: &'a T,
+    ) -> Nl80211WiphySetNetnsRequest<'a> {
+        Nl80211WiphySetNetnsRequest::new_by_fd(
+            self.0.clone(),
+            wiphy_index.get(),
+            fd.as_fd(),
+        )
+    }
+
+    /// Configure the Wake-on-WLAN triggers used when system suspends
+    /// (equivalent to `iw phy PHY wowlan enable`).
+    /// Empty `triggers` disables WoWLAN.
+    ///
+    /// The [Nl80211WowlanTrigger::TcpConnection] could be used to let the
+    /// device maintain a TCP keepalive connection while the host is asleep.
+    pub fn set_wowlan(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+        triggers: Vec<Nl80211WowlanTrigger>,
+    ) -> Nl80211WowlanSetRequest {
+        Nl80211WowlanSetRequest::new(
+            self.0.clone(),
+            wiphy_index.get(),
+            triggers,
+        )
+    }
+
+    /// Configure the packet coalesce rules used to buffer the matching
+    /// packets for a while before waking up the host
+    /// (equivalent to `iw phy PHY coalesce enable`).
+    /// Empty `rules` disables coalescing.
+    pub fn set_coalesce(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+        rules: Vec<Nl80211CoalesceRule>,
+    ) -> Nl80211CoalesceSetRequest {
+        Nl80211CoalesceSetRequest::new(self.0.clone(), wiphy_index.get(), rules)
+    }
+
+    /// Apply SAR (specific absorption rate) limits to the frequency ranges
+    /// reported in [Nl80211Attr::SarSpec] of wiphy dump
+    /// (equivalent to `iw phy PHY set sar_specs`).
+    pub fn set_sar_specs(
+        &mut self,
+        wiphy_index: Nl80211WiphyIndex,
+        sar_type: Nl80211SarType,
+        specs: Vec<Nl80211SarSpec>,
+    ) -> Nl80211SetSarSpecsRequest {
+        Nl80211SetSarSpecsRequest::new(
+            self.0.clone(),
+            wiphy_index.get(),
+            sar_type,
+            specs,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Nl80211Wiphy;
+
+impl Nl80211Wiphy {
+    /// Change configuration of specified wiphy
+    pub fn new(wiphy_index: Nl80211WiphyIndex) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new()
+            .replace(Nl80211Attr::Wiphy(wiphy_index.get()))
+    }
+
+    /// Change configuration through specified interface. Transmit power set
+    /// this way only applies to that interface if driver supports
+    /// [crate::Nl80211Features::VifTxpower], other settings apply to the
+    /// whole wiphy.
+    pub fn new_interface(
+        if_index: Nl80211IfIndex,
+    ) -> Nl80211AttrsBuilder<Self> {
+        Nl80211AttrsBuilder::<Self>::new().if_index(if_index)
+    }
+}
+
+impl Nl80211AttrsBuilder<Nl80211Wiphy> {
+    /// Bitmap of antennas to use for transmitting and receiving. Each bit
+    /// is an antenna, the available ones are reported by
+    /// [Nl80211Attr::WiphyAntennaAvailTx] and
+    /// [Nl80211Attr::WiphyAntennaAvailRx].
+    /// Drivers may reject configurations they cannot support.
+    pub fn antenna(self, tx: u32, rx: u32) -> Self {
+        self.replace(Nl80211Attr::WiphyAntennaTx(tx))
+            .replace(Nl80211Attr::WiphyAntennaRx(rx))
+    }
+
+    /// TX retry limit for frames whose length is less than or equal to the
+    /// RTS threshold, allowed range: 1..=255
+    pub fn retry_short(self, value: u8) -> Self {
+        self.replace(Nl80211Attr::WiphyRetryShort(value))
+    }
+
+    /// TX retry limit for frames whose length is greater than the RTS
+    /// threshold, allowed range: 1..=255
+    pub fn retry_long(self, value: u8) -> Self {
+        self.replace(Nl80211Attr::WiphyRetryLong(value))
+    }
+
+    /// Fragmentation threshold in bytes, allowed range: 256..=8000,
+    /// `u32::MAX` disables fragmentation.
+    pub fn frag_threshold(self, value: u32) -> Self {
+        self.replace(Nl80211Attr::WiphyFragThreshold(value))
+    }
+
+    /// RTS threshold in bytes, allowed range: 0..=65536,
+    /// `u32::MAX` disables RTS.
+    pub fn rts_threshold(self, value: u32) -> Self {
+        self.replace(Nl80211Attr::WiphyRtsThreshold(value))
+    }
+
+    /// Coverage class which is used to calculate the air propagation time
+    /// (3 microseconds per class)
+    pub fn coverage_class(self, value: u8) -> Self {
+        self.replace(Nl80211Attr::WiphyCoverageClass(value))
+    }
+
+    /// TX queue parameters of access categories. Only valid when changing
+    /// configuration through [Nl80211Wiphy::new_interface()] on interface of
+    /// [crate::Nl80211InterfaceType::Ap] or
+    /// [crate::Nl80211InterfaceType::P2pGo].
+    pub fn txq_params(self, params: Vec<Nl80211TxqParams>) -> Self {
+        self.replace(Nl80211Attr::WiphyTxqParams(params))
+    }
+
+    /// Transmit power in mBm (100 * dBm). The `mbm` level is ignored for
+    /// [Nl80211TxPowerSetting::Automatic].
+    /// Use [crate::dbm_to_mbm()] to convert from dBm.
+    pub fn tx_power(self, setting: Nl80211TxPowerSetting, mbm: u32) -> Self {
+        let builder = self.replace(Nl80211Attr::WiphyTxPowerSetting(setting));
+        if setting == Nl80211TxPowerSetting::Automatic {
+            builder.remove(Nl80211Attr::WiphyTxPowerLevel(0).kind())
+        } else {
+            builder.replace(Nl80211Attr::WiphyTxPowerLevel(mbm))
+        }
+    }
 }
@@ -19,6 +19,7 @@ const NL80211_IFTYPE_OCB: u16 = 11;
 const NL80211_IFTYPE_NAN: u16 = 12;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Nl80211IfMode {
     Adhoc,
     Station,
@@ -1,24 +1,68 @@
 // SPDX-License-Identifier: MIT
 
 mod band;
+mod capabilities;
 mod cipher;
+mod coalesce;
 mod command;
 mod get;
 mod handle;
 mod ifmode;
+mod radio;
+mod sar;
+mod set;
+mod set_coalesce;
+mod set_netns;
+mod set_sar;
+mod set_wowlan;
+mod tx_power;
+mod txq;
 mod wowlan;
+mod wowlan_trigger;
 
 pub use self::band::{
     Nl80211Band, Nl80211BandInfo, Nl80211BandType, Nl80211BandTypes,
     Nl80211Frequency, Nl80211FrequencyInfo,
 };
+pub use self::capabilities::Nl80211WiphyCapabilities;
+#[allow(deprecated)]
 pub use self::cipher::Nl80211CipherSuit;
+pub use self::cipher::Nl80211CipherSuite;
+pub use self::coalesce::{
+    Nl80211CoalesceCondition, Nl80211CoalesceRule, Nl80211CoalesceRuleAttr,
+    Nl80211CoalesceSupport,
+};
 pub use self::get::Nl80211WiphyGetRequest;
-pub use self::handle::Nl80211WiphyHandle;
+pub use self::handle::{Nl80211Wiphy, Nl80211WiphyHandle};
 pub use self::ifmode::Nl80211IfMode;
+pub use self::radio::{
+    Nl80211WiphyRadio, Nl80211WiphyRadioAttribute, Nl80211WiphyRadioFreqRange,
+};
+pub use self::sar::{
+    Nl80211SarAttr, Nl80211SarSpec, Nl80211SarSpecAttr, Nl80211SarType,
+};
+pub use self::set::Nl80211WiphySetRequest;
+pub use self::set_coalesce::Nl80211CoalesceSetRequest;
+pub use self::set_netns::Nl80211WiphySetNetnsRequest;
+pub use self::set_sar::Nl80211SetSarSpecsRequest;
+pub use self::set_wowlan::Nl80211WowlanSetRequest;
+pub use self::tx_power::Nl80211TxPowerSetting;
+pub use self::txq::{Nl80211Ac, Nl80211TxqParams};
+#[allow(deprecated)]
 pub use self::wowlan::{
     Nl80211WowlanTcpTrigerSupport, Nl80211WowlanTrigerPatternSupport,
     Nl80211WowlanTrigersSupport,
 };
+pub use self::wowlan::{
+    Nl80211WowlanTcpTriggerSupport, Nl80211WowlanTriggerPatternSupport,
+    Nl80211WowlanTriggersSupport,
+};
+pub use self::wowlan_trigger::{
+    Nl80211WowlanTcp, Nl80211WowlanTcpDataSeq, Nl80211WowlanTcpDataToken,
+    Nl80211WowlanTrigger,
+};
 
 pub(crate) use self::command::Nl80211Commands;
+#[cfg(feature = "blocking")]
+pub(crate) use self::get::merge_wiphy_messages;
+pub(crate) use self::txq::Nl80211TxqParamsNla;
@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    DecodeError, Emitable, Parseable, ParseableParametrized,
+};
+
+use crate::{
+    bytes::write_u32, iface::parse_iface_combinations, Nl80211IfaceComb,
+};
+
+const NL80211_WIPHY_RADIO_ATTR_INDEX: u16 = 1;
+const NL80211_WIPHY_RADIO_ATTR_FREQ_RANGE: u16 = 2;
+const NL80211_WIPHY_RADIO_ATTR_INTERFACE_COMBINATION: u16 = 3;
+const NL80211_WIPHY_RADIO_ATTR_ANTENNA_MASK: u16 = 4;
+
+const NL80211_WIPHY_RADIO_FREQ_ATTR_START: u16 = 1;
+const NL80211_WIPHY_RADIO_FREQ_ATTR_END: u16 = 2;
+
+/// Physical radio of multi-radio wiphy, reported by newer kernel in
+/// [crate::Nl80211Attr::WiphyRadios].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211WiphyRadio {
+    /// Position of this radio in the nested array
+    pub nest_index: u16,
+    pub attributes: Vec<Nl80211WiphyRadioAttribute>,
+}
+
+impl Nla for Nl80211WiphyRadio {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.nest_index
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+}
+
+impl Nl80211WiphyRadio {
+    /// Radio index used by other nl80211 attributes referring this radio
+    pub fn index(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211WiphyRadioAttribute::Index(d) => Some(*d),
+            _ => None,
+        })
+    }
+
+    /// Frequency ranges this radio could operate on
+    pub fn freq_ranges(&self) -> Vec<Nl80211WiphyRadioFreqRange> {
+        self.attributes
+            .iter()
+            .filter_map(|attr| match attr {
+                Nl80211WiphyRadioAttribute::FreqRange(r) => Some(*r),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Interface combinations supported by this radio
+    pub fn interface_combinations(&self) -> &[Nl80211IfaceComb] {
+        self.attributes
+            .iter()
+            .find_map(|attr| match attr {
+                Nl80211WiphyRadioAttribute::InterfaceCombination(v) => {
+                    Some(v.as_slice())
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Bitmap of antennas connected to this radio
+    pub fn antenna_mask(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            Nl80211WiphyRadioAttribute::AntennaMask(d) => Some(*d),
+            _ => None,
+        })
+    }
+
+    /// Whether specified frequency in MHz is covered by the frequency
+    /// ranges of this radio
+    pub fn supports_freq(&self, freq: u32) -> bool {
+        self.freq_ranges().iter().any(|range| range.contains(freq))
+    }
+}
+
+impl<'a, T> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nl80211WiphyRadio
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        nest_index: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_WIPHY_RADIOS {payload:?} index {nest_index}"
+        );
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            attributes.push(Nl80211WiphyRadioAttribute::parse(nla)?);
+        }
+        Ok(Self {
+            nest_index,
+            attributes,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211WiphyRadioAttribute {
+    Index(u32),
+    FreqRange(Nl80211WiphyRadioFreqRange),
+    InterfaceCombination(Vec<Nl80211IfaceComb>),
+    /// Bitmap of antennas connected to the radio
+    AntennaMask(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WiphyRadioAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) | Self::AntennaMask(_) => 4,
+            Self::FreqRange(r) => r.buffer_len(),
+            Self::InterfaceCombination(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => NL80211_WIPHY_RADIO_ATTR_INDEX,
+            Self::FreqRange(_) => NL80211_WIPHY_RADIO_ATTR_FREQ_RANGE,
+            Self::InterfaceCombination(_) => {
+                NL80211_WIPHY_RADIO_ATTR_INTERFACE_COMBINATION
+            }
+            Self::AntennaMask(_) => NL80211_WIPHY_RADIO_ATTR_ANTENNA_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(d) | Self::AntennaMask(d) => write_u32(buffer, *d),
+            Self::FreqRange(r) => r.emit(buffer),
+            Self::InterfaceCombination(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WiphyRadioAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WIPHY_RADIO_ATTR_INDEX => {
+                Self::Index(parse_u32(payload).context(format!(
+                    "Invalid NL80211_WIPHY_RADIO_ATTR_INDEX {payload:?}"
+                ))?)
+            }
+            NL80211_WIPHY_RADIO_ATTR_FREQ_RANGE => {
+                Self::FreqRange(Nl80211WiphyRadioFreqRange::parse(payload)?)
+            }
+            NL80211_WIPHY_RADIO_ATTR_INTERFACE_COMBINATION => {
+                Self::InterfaceCombination(parse_iface_combinations(
+                    payload,
+                    "NL80211_WIPHY_RADIO_ATTR_INTERFACE_COMBINATION",
+                )?)
+            }
+            NL80211_WIPHY_RADIO_ATTR_ANTENNA_MASK => {
+                Self::AntennaMask(parse_u32(payload).context(format!(
+                    "Invalid NL80211_WIPHY_RADIO_ATTR_ANTENNA_MASK {payload:?}"
+                ))?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+/// Frequency range of wiphy radio in kHz, both ends included
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211WiphyRadioFreqRange {
+    pub start_khz: u32,
+    pub end_khz: u32,
+}
+
+impl Nl80211WiphyRadioFreqRange {
+    pub fn new(start_khz: u32, end_khz: u32) -> Self {
+        Self { start_khz, end_khz }
+    }
+
+    /// Whether specified frequency in MHz is inside this range
+    pub fn contains(&self, freq: u32) -> bool {
+        (self.start_khz..=self.end_khz).contains(&(freq * 1000))
+    }
+
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        for nla in NlasIterator::new(payload) {
+            let err_msg = format!(
+                "Invalid NL80211_WIPHY_RADIO_ATTR_FREQ_RANGE {payload:?}"
+            );
+            let nla = &nla.context(err_msg.clone())?;
+            match nla.kind() {
+                NL80211_WIPHY_RADIO_FREQ_ATTR_START => {
+                    ret.start_khz = parse_u32(nla.value()).context(err_msg)?
+                }
+                NL80211_WIPHY_RADIO_FREQ_ATTR_END => {
+                    ret.end_khz = parse_u32(nla.value()).context(err_msg)?
+                }
+                _ => (),
+            }
+        }
+        Ok(ret)
+    }
+
+    fn nlas(&self) -> [Nl80211WiphyRadioFreqRangeNla; 2] {
+        [
+            Nl80211WiphyRadioFreqRangeNla(
+                NL80211_WIPHY_RADIO_FREQ_ATTR_START,
+                self.start_khz,
+            ),
+            Nl80211WiphyRadioFreqRangeNla(
+                NL80211_WIPHY_RADIO_FREQ_ATTR_END,
+                self.end_khz,
+            ),
+        ]
+    }
+}
+
+impl Emitable for Nl80211WiphyRadioFreqRange {
+    fn buffer_len(&self) -> usize {
+        self.nlas().as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.nlas().as_slice().emit(buffer)
+    }
+}
+
+struct Nl80211WiphyRadioFreqRangeNla(u16, u32);
+
+impl Nla for Nl80211WiphyRadioFreqRangeNla {
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        write_u32(buffer, self.1)
+    }
+}
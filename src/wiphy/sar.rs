@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_u32},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::bytes::{write_i32, write_u32};
+
+const NL80211_SAR_ATTR_TYPE: u16 = 1;
+const NL80211_SAR_ATTR_SPECS: u16 = 2;
+
+/// SAR (specific absorption rate) configuration set by
+/// [crate::Nl80211WiphyHandle::set_sar_specs()]. In wiphy dump, it holds
+/// the supported SAR type and the frequency ranges.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211SarAttr {
+    Type(Nl80211SarType),
+    Specs(Vec<Nl80211SarSpec>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211SarAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_) => 4,
+            Self::Specs(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Type(_) => NL80211_SAR_ATTR_TYPE,
+            Self::Specs(_) => NL80211_SAR_ATTR_SPECS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(d) => write_u32(buffer, (*d).into()),
+            Self::Specs(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SarAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_SAR_ATTR_TYPE => Self::Type(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid NL80211_SAR_ATTR_TYPE {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            NL80211_SAR_ATTR_SPECS => {
+                let mut nlas = Vec::new();
+                for (index, nla) in NlasIterator::new(payload).enumerate() {
+                    let err_msg = format!(
+                        "Invalid NL80211_SAR_ATTR_SPECS value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211SarSpec::parse_with_param(
+                        nla,
+                        index as u16,
+                    )?);
+                }
+                Self::Specs(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_SAR_TYPE_POWER: u32 = 0;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211SarType {
+    /// Power limitation in unit of 0.25 dBm
+    Power,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211SarType {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_SAR_TYPE_POWER => Self::Power,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211SarType> for u32 {
+    fn from(v: Nl80211SarType) -> u32 {
+        match v {
+            Nl80211SarType::Power => NL80211_SAR_TYPE_POWER,
+            Nl80211SarType::Other(d) => d,
+        }
+    }
+}
+
+/// SAR limit of a frequency range
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Nl80211SarSpec {
+    pub index: u16,
+    pub attributes: Vec<Nl80211SarSpecAttr>,
+}
+
+impl Nl80211SarSpec {
+    /// Limit the transmit power of frequency range identified by
+    /// `range_index` (the index of range in wiphy dump) to
+    /// `power` in unit of 0.25 dBm.
+    pub fn new(range_index: u32, power: i32) -> Self {
+        Self {
+            index: 0,
+            attributes: vec![
+                Nl80211SarSpecAttr::Power(power),
+                Nl80211SarSpecAttr::RangeIndex(range_index),
+            ],
+        }
+    }
+
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        index: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg = format!("Invalid NL80211_SAR_ATTR_SPECS {payload:?}");
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            attributes.push(Nl80211SarSpecAttr::parse(nla)?);
+        }
+        Ok(Self { index, attributes })
+    }
+}
+
+impl Nla for Nl80211SarSpec {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+}
+
+const NL80211_SAR_ATTR_SPECS_POWER: u16 = 1;
+const NL80211_SAR_ATTR_SPECS_RANGE_INDEX: u16 = 2;
+const NL80211_SAR_ATTR_SPECS_START_FREQ: u16 = 3;
+const NL80211_SAR_ATTR_SPECS_END_FREQ: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211SarSpecAttr {
+    /// Power limit in unit of 0.25 dBm for [Nl80211SarType::Power]
+    Power(i32),
+    /// Index of the frequency range reported by wiphy dump
+    RangeIndex(u32),
+    /// Start frequency in KHz of the range, only in wiphy dump
+    StartFreq(u32),
+    /// End frequency in KHz of the range, only in wiphy dump
+    EndFreq(u32),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211SarSpecAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Power(_)
+            | Self::RangeIndex(_)
+            | Self::StartFreq(_)
+            | Self::EndFreq(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Power(_) => NL80211_SAR_ATTR_SPECS_POWER,
+            Self::RangeIndex(_) => NL80211_SAR_ATTR_SPECS_RANGE_INDEX,
+            Self::StartFreq(_) => NL80211_SAR_ATTR_SPECS_START_FREQ,
+            Self::EndFreq(_) => NL80211_SAR_ATTR_SPECS_END_FREQ,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Power(d) => write_i32(buffer, *d),
+            Self::RangeIndex(d) | Self::StartFreq(d) | Self::EndFreq(d) => {
+                write_u32(buffer, *d)
+            }
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211SarSpecAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_SAR_ATTR_SPECS_POWER => {
+                Self::Power(parse_i32(payload).context(format!(
+                    "Invalid NL80211_SAR_ATTR_SPECS_POWER {payload:?}"
+                ))?)
+            }
+            NL80211_SAR_ATTR_SPECS_RANGE_INDEX => {
+                Self::RangeIndex(parse_u32(payload).context(format!(
+                    "Invalid NL80211_SAR_ATTR_SPECS_RANGE_INDEX {payload:?}"
+                ))?)
+            }
+            NL80211_SAR_ATTR_SPECS_START_FREQ => {
+                Self::StartFreq(parse_u32(payload).context(format!(
+                    "Invalid NL80211_SAR_ATTR_SPECS_START_FREQ {payload:?}"
+                ))?)
+            }
+            NL80211_SAR_ATTR_SPECS_END_FREQ => {
+                Self::EndFreq(parse_u32(payload).context(format!(
+                    "Invalid NL80211_SAR_ATTR_SPECS_END_FREQ {payload:?}"
+                ))?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211CoalesceRule, Nl80211Command,
+    Nl80211Error, Nl80211Handle, Nl80211Message,
+};
+
+pub struct Nl80211CoalesceSetRequest {
+    handle: Nl80211Handle,
+    wiphy_index: u32,
+    rules: Vec<Nl80211CoalesceRule>,
+}
+
+impl Nl80211CoalesceSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wiphy_index: u32,
+        rules: Vec<Nl80211CoalesceRule>,
+    ) -> Self {
+        Nl80211CoalesceSetRequest {
+            handle,
+            wiphy_index,
+            rules,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211CoalesceSetRequest {
+            mut handle,
+            wiphy_index,
+            mut rules,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::Wiphy(wiphy_index)];
+        if !rules.is_empty() {
+            for (index, rule) in rules.iter_mut().enumerate() {
+                rule.index = index as u16;
+            }
+            attributes.push(Nl80211Attr::CoalesceRule(rules));
+        }
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetCoalesce,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
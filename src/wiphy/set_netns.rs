@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::fd::{AsRawFd, BorrowedFd};
+
+use futures::{TryStream, TryStreamExt};
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Nl80211Netns<'a> {
+    Pid(u32),
+    Fd(BorrowedFd<'a>),
+}
+
+/// The borrowed network namespace file descriptor, if any, is kept open by
+/// the lifetime `'a` until the reply stream is dropped.
+pub struct Nl80211WiphySetNetnsRequest<'a> {
+    handle: Nl80211Handle,
+    wiphy_index: u32,
+    netns: Nl80211Netns<'a>,
+}
+
+impl<'a> Nl80211WiphySetNetnsRequest<'a> {
+    pub(crate) fn new_by_pid(
+        handle: Nl80211Handle,
+        wiphy_index: u32,
+        pid: u32,
+    ) -> Self {
+        Nl80211WiphySetNetnsRequest {
+            handle,
+            wiphy_index,
+            netns: Nl80211Netns::Pid(pid),
+        }
+    }
+
+    pub(crate) fn new_by_fd(
+        handle: Nl80211Handle,
+        wiphy_index: u32,
+        fd: BorrowedFd<'a>,
+    ) -> Self {
+        Nl80211WiphySetNetnsRequest {
+            handle,
+            wiphy_index,
+            netns: Nl80211Netns::Fd(fd),
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error> + 'a
+    {
+        let Nl80211WiphySetNetnsRequest {
+            mut handle,
+            wiphy_index,
+            netns,
+        } = self;
+
+        let netns_attr = match netns {
+            Nl80211Netns::Pid(pid) => Nl80211Attr::Pid(pid),
+            Nl80211Netns::Fd(fd) => Nl80211Attr::NetnsFd(fd.as_raw_fd() as u32),
+        };
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetWiphyNetns,
+            attributes: vec![Nl80211Attr::Wiphy(wiphy_index), netns_attr],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        // Kernel resolves the file descriptor when processing the request
+        nl80211_execute(&mut handle, nl80211_msg, flags)
+            .await
+            .inspect_ok(move |_| {
+                let _ = &netns;
+            })
+    }
+}
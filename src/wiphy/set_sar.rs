@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211SarAttr, Nl80211SarSpec, Nl80211SarType,
+};
+
+pub struct Nl80211SetSarSpecsRequest {
+    handle: Nl80211Handle,
+    wiphy_index: u32,
+    sar_type: Nl80211SarType,
+    specs: Vec<Nl80211SarSpec>,
+}
+
+impl Nl80211SetSarSpecsRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wiphy_index: u32,
+        sar_type: Nl80211SarType,
+        specs: Vec<Nl80211SarSpec>,
+    ) -> Self {
+        Nl80211SetSarSpecsRequest {
+            handle,
+            wiphy_index,
+            sar_type,
+            specs,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211SetSarSpecsRequest {
+            mut handle,
+            wiphy_index,
+            sar_type,
+            mut specs,
+        } = self;
+
+        for (index, spec) in specs.iter_mut().enumerate() {
+            spec.index = index as u16;
+        }
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetSarSpecs,
+            attributes: vec![
+                Nl80211Attr::Wiphy(wiphy_index),
+                Nl80211Attr::SarSpec(vec![
+                    Nl80211SarAttr::Type(sar_type),
+                    Nl80211SarAttr::Specs(specs),
+                ]),
+            ],
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_core::{NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    nl80211_execute, Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211Handle,
+    Nl80211Message, Nl80211WowlanTrigger,
+};
+
+pub struct Nl80211WowlanSetRequest {
+    handle: Nl80211Handle,
+    wiphy_index: u32,
+    triggers: Vec<Nl80211WowlanTrigger>,
+}
+
+impl Nl80211WowlanSetRequest {
+    pub(crate) fn new(
+        handle: Nl80211Handle,
+        wiphy_index: u32,
+        triggers: Vec<Nl80211WowlanTrigger>,
+    ) -> Self {
+        Nl80211WowlanSetRequest {
+            handle,
+            wiphy_index,
+            triggers,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<Nl80211Message>, Error = Nl80211Error>
+    {
+        let Nl80211WowlanSetRequest {
+            mut handle,
+            wiphy_index,
+            triggers,
+        } = self;
+
+        let mut attributes = vec![Nl80211Attr::Wiphy(wiphy_index)];
+        if !triggers.is_empty() {
+            attributes.push(Nl80211Attr::WowlanTriggers(triggers));
+        }
+        let nl80211_msg = Nl80211Message {
+            cmd: Nl80211Command::SetWowlan,
+            attributes,
+        };
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        nl80211_execute(&mut handle, nl80211_msg, flags).await
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+const NL80211_TX_POWER_AUTOMATIC: u32 = 0;
+const NL80211_TX_POWER_LIMITED: u32 = 1;
+const NL80211_TX_POWER_FIXED: u32 = 2;
+
+/// TX power adjustment
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211TxPowerSetting {
+    /// Automatically determine transmit power
+    Automatic,
+    /// Limit TX power by the [crate::Nl80211Attr::WiphyTxPowerLevel]
+    Limited,
+    /// Fix TX power to the [crate::Nl80211Attr::WiphyTxPowerLevel]
+    Fixed,
+    Other(u32),
+}
+
+impl From<u32> for Nl80211TxPowerSetting {
+    fn from(d: u32) -> Self {
+        match d {
+            NL80211_TX_POWER_AUTOMATIC => Self::Automatic,
+            NL80211_TX_POWER_LIMITED => Self::Limited,
+            NL80211_TX_POWER_FIXED => Self::Fixed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211TxPowerSetting> for u32 {
+    fn from(v: Nl80211TxPowerSetting) -> u32 {
+        match v {
+            Nl80211TxPowerSetting::Automatic => NL80211_TX_POWER_AUTOMATIC,
+            Nl80211TxPowerSetting::Limited => NL80211_TX_POWER_LIMITED,
+            Nl80211TxPowerSetting::Fixed => NL80211_TX_POWER_FIXED,
+            Nl80211TxPowerSetting::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u8},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::bytes::write_u16;
+
+const NL80211_AC_VO: u8 = 0;
+const NL80211_AC_VI: u8 = 1;
+const NL80211_AC_BE: u8 = 2;
+const NL80211_AC_BK: u8 = 3;
+
+/// WMM access category
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211Ac {
+    /// Voice
+    Vo,
+    /// Video
+    Vi,
+    /// Best effort
+    Be,
+    /// Background
+    Bk,
+    Other(u8),
+}
+
+impl Nl80211Ac {
+    /// All access categories in the order of kernel index
+    pub const ALL: [Nl80211Ac; 4] = [Self::Vo, Self::Vi, Self::Be, Self::Bk];
+
+    /// Access category used by specified traffic identifier (802.1D user
+    /// priority), could be used to aggregate per-TID statistics into
+    /// per-AC ones. TIDs above 7 are not mapped to any WMM access category.
+    pub fn from_tid(tid: u8) -> Option<Self> {
+        match tid {
+            1 | 2 => Some(Self::Bk),
+            0 | 3 => Some(Self::Be),
+            4 | 5 => Some(Self::Vi),
+            6 | 7 => Some(Self::Vo),
+            _ => None,
+        }
+    }
+}
+
+impl From<u8> for Nl80211Ac {
+    fn from(d: u8) -> Self {
+        match d {
+            NL80211_AC_VO => Self::Vo,
+            NL80211_AC_VI => Self::Vi,
+            NL80211_AC_BE => Self::Be,
+            NL80211_AC_BK => Self::Bk,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Nl80211Ac> for u8 {
+    fn from(v: Nl80211Ac) -> u8 {
+        match v {
+            Nl80211Ac::Vo => NL80211_AC_VO,
+            Nl80211Ac::Vi => NL80211_AC_VI,
+            Nl80211Ac::Be => NL80211_AC_BE,
+            Nl80211Ac::Bk => NL80211_AC_BK,
+            Nl80211Ac::Other(d) => d,
+        }
+    }
+}
+
+const NL80211_TXQ_ATTR_AC: u16 = 1;
+const NL80211_TXQ_ATTR_TXOP: u16 = 2;
+const NL80211_TXQ_ATTR_CWMIN: u16 = 3;
+const NL80211_TXQ_ATTR_CWMAX: u16 = 4;
+const NL80211_TXQ_ATTR_AIFS: u16 = 5;
+
+/// Transmit queue parameters of an access category.
+/// Kernel requires all the fields when changing TX queue parameters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211TxqParams {
+    pub ac: Nl80211Ac,
+    /// Maximum burst time in units of 32 microseconds, 0 means disabled
+    pub txop: u16,
+    /// Minimum contention window, a value of the form 2^n-1 in the range
+    /// 1..=32767
+    pub cwmin: u16,
+    /// Maximum contention window, a value of the form 2^n-1 in the range
+    /// 1..=32767
+    pub cwmax: u16,
+    /// Arbitration interframe space, allowed range: 0..=255
+    pub aifs: u8,
+}
+
+impl Nl80211TxqParams {
+    fn attributes(&self) -> Vec<Nl80211TxqParam> {
+        vec![
+            Nl80211TxqParam::Ac(self.ac),
+            Nl80211TxqParam::Txop(self.txop),
+            Nl80211TxqParam::Cwmin(self.cwmin),
+            Nl80211TxqParam::Cwmax(self.cwmax),
+            Nl80211TxqParam::Aifs(self.aifs),
+        ]
+    }
+}
+
+// `NL80211_ATTR_WIPHY_TXQ_PARAMS` is an array using index as NLA kind.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct Nl80211TxqParamsNla {
+    index: u16,
+    params: Nl80211TxqParams,
+}
+
+impl Nl80211TxqParamsNla {
+    pub(crate) fn new_list(params: &[Nl80211TxqParams]) -> Vec<Self> {
+        params
+            .iter()
+            .enumerate()
+            .map(|(index, params)| Self {
+                index: index as u16,
+                params: *params,
+            })
+            .collect()
+    }
+}
+
+impl From<Nl80211TxqParamsNla> for Nl80211TxqParams {
+    fn from(v: Nl80211TxqParamsNla) -> Self {
+        v.params
+    }
+}
+
+impl Nla for Nl80211TxqParamsNla {
+    fn value_len(&self) -> usize {
+        self.params.attributes().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.params.attributes().as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxqParamsNla
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err_msg = format!(
+            "Invalid NL80211_ATTR_WIPHY_TXQ_PARAMS value {:?}",
+            payload
+        );
+        let mut ac = None;
+        let mut txop = None;
+        let mut cwmin = None;
+        let mut cwmax = None;
+        let mut aifs = None;
+        for nla in NlasIterator::new(payload) {
+            let nla = &nla.context(err_msg.clone())?;
+            match Nl80211TxqParam::parse(nla).context(err_msg.clone())? {
+                Nl80211TxqParam::Ac(d) => ac = Some(d),
+                Nl80211TxqParam::Txop(d) => txop = Some(d),
+                Nl80211TxqParam::Cwmin(d) => cwmin = Some(d),
+                Nl80211TxqParam::Cwmax(d) => cwmax = Some(d),
+                Nl80211TxqParam::Aifs(d) => aifs = Some(d),
+                Nl80211TxqParam::Other(attr) => {
+                    log::warn!("Unsupported NL80211_TXQ_ATTR {:?}", attr);
+                }
+            }
+        }
+        match (ac, txop, cwmin, cwmax, aifs) {
+            (Some(ac), Some(txop), Some(cwmin), Some(cwmax), Some(aifs)) => {
+                Ok(Self {
+                    index: buf.kind(),
+                    params: Nl80211TxqParams {
+                        ac,
+                        txop,
+                        cwmin,
+                        cwmax,
+                        aifs,
+                    },
+                })
+            }
+            _ => Err(format!(
+                "Incomplete NL80211_ATTR_WIPHY_TXQ_PARAMS {:?}",
+                payload
+            )
+            .into()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Nl80211TxqParam {
+    Ac(Nl80211Ac),
+    Txop(u16),
+    Cwmin(u16),
+    Cwmax(u16),
+    Aifs(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211TxqParam {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ac(_) | Self::Aifs(_) => 1,
+            Self::Txop(_) | Self::Cwmin(_) | Self::Cwmax(_) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ac(_) => NL80211_TXQ_ATTR_AC,
+            Self::Txop(_) => NL80211_TXQ_ATTR_TXOP,
+            Self::Cwmin(_) => NL80211_TXQ_ATTR_CWMIN,
+            Self::Cwmax(_) => NL80211_TXQ_ATTR_CWMAX,
+            Self::Aifs(_) => NL80211_TXQ_ATTR_AIFS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ac(d) => buffer[0] = (*d).into(),
+            Self::Aifs(d) => buffer[0] = *d,
+            Self::Txop(d) | Self::Cwmin(d) | Self::Cwmax(d) => {
+                write_u16(buffer, *d)
+            }
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211TxqParam
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_TXQ_ATTR_AC => {
+                let err_msg =
+                    format!("Invalid NL80211_TXQ_ATTR_AC value {:?}", payload);
+                Self::Ac(parse_u8(payload).context(err_msg)?.into())
+            }
+            NL80211_TXQ_ATTR_TXOP => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_TXOP value {:?}",
+                    payload
+                );
+                Self::Txop(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_CWMIN => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_CWMIN value {:?}",
+                    payload
+                );
+                Self::Cwmin(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_CWMAX => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_CWMAX value {:?}",
+                    payload
+                );
+                Self::Cwmax(parse_u16(payload).context(err_msg)?)
+            }
+            NL80211_TXQ_ATTR_AIFS => {
+                let err_msg = format!(
+                    "Invalid NL80211_TXQ_ATTR_AIFS value {:?}",
+                    payload
+                );
+                Self::Aifs(parse_u8(payload).context(err_msg)?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
@@ -36,7 +36,7 @@ use netlink_packet_utils::{
     DecodeError, Emitable, Parseable,
 };
 
-use crate::bytes::write_u32;
+use crate::{bytes::write_u32, Nl80211PatternSupport};
 
 const NL80211_WOWLAN_TRIG_ANY: u16 = 1;
 const NL80211_WOWLAN_TRIG_DISCONNECT: u16 = 2;
@@ -59,9 +59,17 @@ const NL80211_WOWLAN_TRIG_NET_DETECT: u16 = 18;
 // const NL80211_WOWLAN_TRIG_NET_DETECT_RESULTS: u16 = 19;
 // const NL80211_WOWLAN_TRIG_UNPROTECTED_DEAUTH_DISASSOC: u16 = 20;
 
+#[deprecated(since = "0.3.0", note = "Use Nl80211WowlanTriggersSupport")]
+pub type Nl80211WowlanTrigersSupport = Nl80211WowlanTriggersSupport;
+#[deprecated(since = "0.3.0", note = "Use Nl80211WowlanTriggerPatternSupport")]
+pub type Nl80211WowlanTrigerPatternSupport = Nl80211WowlanTriggerPatternSupport;
+#[deprecated(since = "0.3.0", note = "Use Nl80211WowlanTcpTriggerSupport")]
+pub type Nl80211WowlanTcpTrigerSupport = Nl80211WowlanTcpTriggerSupport;
+
 /// Supported WoWLAN trigger
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Nl80211WowlanTrigersSupport {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211WowlanTriggersSupport {
     /// Wake up on any activity, do not really put the chip into a special
     /// state -- works best with chips that have support for low-power
     /// operation already.
@@ -78,7 +86,7 @@ pub enum Nl80211WowlanTrigersSupport {
     /// The matching is done on the MSDU, i.e.  as though the packet was an
     /// 802.3 packet, so the pattern matching is done after the packet is
     /// converted to the MSDU.
-    PktPattern(Nl80211WowlanTrigerPatternSupport),
+    PktPattern(Nl80211WowlanTriggerPatternSupport),
     /// Not a real trigger, and cannot be used when setting, used only to
     /// indicate that GTK rekeying is supported by the device.
     GtkRekeySupported,
@@ -95,11 +103,12 @@ pub enum Nl80211WowlanTrigersSupport {
     /// configured network is detected.
     NetDetect(u32),
     /// TCP connection wake.
-    TcpConnection(Vec<Nl80211WowlanTcpTrigerSupport>),
+    TcpConnection(Vec<Nl80211WowlanTcpTriggerSupport>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
-impl Nla for Nl80211WowlanTrigersSupport {
+impl Nla for Nl80211WowlanTriggersSupport {
     fn value_len(&self) -> usize {
         match self {
             Self::Any
@@ -110,7 +119,7 @@ impl Nla for Nl80211WowlanTrigersSupport {
             | Self::EapIdentRequest
             | Self::FourWayHandshake
             | Self::RfkillRelease => 0,
-            Self::PktPattern(_) => Nl80211WowlanTrigerPatternSupport::LENGTH,
+            Self::PktPattern(_) => Nl80211WowlanTriggerPatternSupport::LENGTH,
             Self::NetDetect(_) => 4,
             Self::TcpConnection(s) => s.as_slice().buffer_len(),
             Self::Other(attr) => attr.value_len(),
@@ -153,7 +162,7 @@ impl Nla for Nl80211WowlanTrigersSupport {
 }
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for Nl80211WowlanTrigersSupport
+    for Nl80211WowlanTriggersSupport
 {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
@@ -167,7 +176,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
             NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE => Self::FourWayHandshake,
             NL80211_WOWLAN_TRIG_RFKILL_RELEASE => Self::RfkillRelease,
             NL80211_WOWLAN_TRIG_PKT_PATTERN => Self::PktPattern(
-                Nl80211WowlanTrigerPatternSupport::parse(payload)?,
+                Nl80211WowlanTriggerPatternSupport::parse(payload)?,
             ),
             NL80211_WOWLAN_TRIG_NET_DETECT => {
                 Self::NetDetect(parse_u32(payload).context(format!(
@@ -183,7 +192,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                         nla
                     );
                     let nla = &nla.context(err_msg.clone())?;
-                    nlas.push(Nl80211WowlanTcpTrigerSupport::parse(nla)?);
+                    nlas.push(Nl80211WowlanTcpTriggerSupport::parse(nla)?);
                 }
 
                 Self::TcpConnection(nlas)
@@ -196,50 +205,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
 }
 
 /// Support status of WoWLAN trigger pattern
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Nl80211WowlanTrigerPatternSupport {
-    pub max_patterns: u32,
-    pub min_pattern_len: u32,
-    pub max_pattern_len: u32,
-    pub max_pkt_offset: u32,
-}
-
-impl Nl80211WowlanTrigerPatternSupport {
-    const LENGTH: usize = 16;
-
-    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
-        if payload.len() < Self::LENGTH {
-            Err(format!(
-                "Invalid NL80211_WOWLAN_TRIG_PKT_PATTERN \
-                for support query, expecting length {} but got {}: \
-                {payload:?}",
-                Self::LENGTH,
-                payload.len()
-            )
-            .into())
-        } else {
-            Ok(Self {
-                max_patterns: parse_u32(&payload[..4])?,
-                min_pattern_len: parse_u32(&payload[4..8])?,
-                max_pattern_len: parse_u32(&payload[8..12])?,
-                max_pkt_offset: parse_u32(&payload[12..16])?,
-            })
-        }
-    }
-}
-
-impl Emitable for Nl80211WowlanTrigerPatternSupport {
-    fn buffer_len(&self) -> usize {
-        Self::LENGTH
-    }
-
-    fn emit(&self, buffer: &mut [u8]) {
-        write_u32(&mut buffer[0..4], self.max_patterns);
-        write_u32(&mut buffer[4..8], self.min_pattern_len);
-        write_u32(&mut buffer[8..12], self.max_pattern_len);
-        write_u32(&mut buffer[12..16], self.max_pkt_offset);
-    }
-}
+pub type Nl80211WowlanTriggerPatternSupport = Nl80211PatternSupport;
 
 const NL80211_WOWLAN_TCP_SRC_IPV4: u16 = 1;
 const NL80211_WOWLAN_TCP_DST_IPV4: u16 = 2;
@@ -255,7 +221,8 @@ const NL80211_WOWLAN_TCP_WAKE_MASK: u16 = 11;
 
 /// Supported WoWLAN TCP connection trigger
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Nl80211WowlanTcpTrigerSupport {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nl80211WowlanTcpTriggerSupport {
     SrcIpv4,
     DstIpv4,
     DstMac,
@@ -267,10 +234,11 @@ pub enum Nl80211WowlanTcpTrigerSupport {
     DataInterval(u32),
     WakePayload(u32),
     WakeMask,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
     Other(DefaultNla),
 }
 
-impl Nla for Nl80211WowlanTcpTrigerSupport {
+impl Nla for Nl80211WowlanTcpTriggerSupport {
     fn value_len(&self) -> usize {
         match self {
             Self::SrcIpv4
@@ -324,7 +292,7 @@ impl Nla for Nl80211WowlanTcpTrigerSupport {
 }
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for Nl80211WowlanTcpTrigerSupport
+    for Nl80211WowlanTcpTriggerSupport
 {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
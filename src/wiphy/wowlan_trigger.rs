@@ -0,0 +1,413 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::{
+    bytes::{write_u16, write_u32},
+    Nl80211PacketPattern,
+};
+
+const ETH_ALEN: usize = 6;
+
+const NL80211_WOWLAN_TRIG_ANY: u16 = 1;
+const NL80211_WOWLAN_TRIG_DISCONNECT: u16 = 2;
+const NL80211_WOWLAN_TRIG_MAGIC_PKT: u16 = 3;
+const NL80211_WOWLAN_TRIG_PKT_PATTERN: u16 = 4;
+const NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE: u16 = 6;
+const NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST: u16 = 7;
+const NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE: u16 = 8;
+const NL80211_WOWLAN_TRIG_RFKILL_RELEASE: u16 = 9;
+const NL80211_WOWLAN_TRIG_TCP_CONNECTION: u16 = 14;
+
+/// WoWLAN trigger to configure through
+/// [crate::Nl80211WiphyHandle::set_wowlan()] or reported by
+/// [crate::Nl80211Command::GetWowlan].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211WowlanTrigger {
+    /// Wake up on any activity, incompatible with all other triggers.
+    Any,
+    /// Wake up on disconnect.
+    Disconnect,
+    /// Wake up on magic packet.
+    MagicPkt,
+    /// Wake up on any of the packet patterns, the matching is done on the
+    /// MSDU as though the packet was an 802.3 packet.
+    PktPattern(Vec<Nl80211PacketPattern>),
+    /// Wake up on GTK rekey failure (if done by the device).
+    GtkRekeyFailure,
+    /// Wake up on EAP Identity Request packet.
+    EapIdentRequest,
+    /// Wake up on 4-way handshake.
+    FourWayHandshake,
+    /// Wake up when rfkill is released.
+    RfkillRelease,
+    /// TCP connection maintained by the device while the host is asleep,
+    /// waking up the host when the wake pattern is received or the
+    /// connection is lost.
+    TcpConnection(Vec<Nl80211WowlanTcp>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WowlanTrigger {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease => 0,
+            Self::PktPattern(s) => s.as_slice().buffer_len(),
+            Self::TcpConnection(s) => s.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Any => NL80211_WOWLAN_TRIG_ANY,
+            Self::Disconnect => NL80211_WOWLAN_TRIG_DISCONNECT,
+            Self::MagicPkt => NL80211_WOWLAN_TRIG_MAGIC_PKT,
+            Self::PktPattern(_) => NL80211_WOWLAN_TRIG_PKT_PATTERN,
+            Self::GtkRekeyFailure => NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE,
+            Self::EapIdentRequest => NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST,
+            Self::FourWayHandshake => NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE,
+            Self::RfkillRelease => NL80211_WOWLAN_TRIG_RFKILL_RELEASE,
+            Self::TcpConnection(_) => NL80211_WOWLAN_TRIG_TCP_CONNECTION,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Any
+            | Self::Disconnect
+            | Self::MagicPkt
+            | Self::GtkRekeyFailure
+            | Self::EapIdentRequest
+            | Self::FourWayHandshake
+            | Self::RfkillRelease => (),
+            Self::PktPattern(s) => s.as_slice().emit(buffer),
+            Self::TcpConnection(s) => s.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTrigger
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WOWLAN_TRIG_ANY => Self::Any,
+            NL80211_WOWLAN_TRIG_DISCONNECT => Self::Disconnect,
+            NL80211_WOWLAN_TRIG_MAGIC_PKT => Self::MagicPkt,
+            NL80211_WOWLAN_TRIG_PKT_PATTERN => {
+                Self::PktPattern(Nl80211PacketPattern::parse_list(
+                    payload,
+                    "NL80211_WOWLAN_TRIG_PKT_PATTERN",
+                )?)
+            }
+            NL80211_WOWLAN_TRIG_GTK_REKEY_FAILURE => Self::GtkRekeyFailure,
+            NL80211_WOWLAN_TRIG_EAP_IDENT_REQUEST => Self::EapIdentRequest,
+            NL80211_WOWLAN_TRIG_4WAY_HANDSHAKE => Self::FourWayHandshake,
+            NL80211_WOWLAN_TRIG_RFKILL_RELEASE => Self::RfkillRelease,
+            NL80211_WOWLAN_TRIG_TCP_CONNECTION => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let err_msg = format!(
+                        "Invalid NL80211_WOWLAN_TRIG_TCP_CONNECTION value {:?}",
+                        nla
+                    );
+                    let nla = &nla.context(err_msg.clone())?;
+                    nlas.push(Nl80211WowlanTcp::parse(nla)?);
+                }
+                Self::TcpConnection(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+const NL80211_WOWLAN_TCP_SRC_IPV4: u16 = 1;
+const NL80211_WOWLAN_TCP_DST_IPV4: u16 = 2;
+const NL80211_WOWLAN_TCP_DST_MAC: u16 = 3;
+const NL80211_WOWLAN_TCP_SRC_PORT: u16 = 4;
+const NL80211_WOWLAN_TCP_DST_PORT: u16 = 5;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD: u16 = 6;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ: u16 = 7;
+const NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN: u16 = 8;
+const NL80211_WOWLAN_TCP_DATA_INTERVAL: u16 = 9;
+const NL80211_WOWLAN_TCP_WAKE_PAYLOAD: u16 = 10;
+const NL80211_WOWLAN_TCP_WAKE_MASK: u16 = 11;
+
+/// WoWLAN TCP connection configuration. The limits of each field are
+/// reported by [crate::Nl80211WowlanTcpTriggerSupport].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Nl80211WowlanTcp {
+    /// Source IP address
+    SrcIpv4(Ipv4Addr),
+    /// Destination IP address
+    DstIpv4(Ipv4Addr),
+    /// Destination MAC address, this is given because route lookup
+    /// happens in userspace
+    DstMac([u8; ETH_ALEN]),
+    /// Source port, optional. If omitted the kernel will choose one.
+    SrcPort(u16),
+    /// Destination port
+    DstPort(u16),
+    /// Data packet payload sent periodically over the TCP connection
+    DataPayload(Vec<u8>),
+    /// Sequence number inserted into the data payload
+    DataPayloadSeq(Nl80211WowlanTcpDataSeq),
+    /// Token stream inserted into the data payload
+    DataPayloadToken(Nl80211WowlanTcpDataToken),
+    /// Data packet interval in seconds
+    DataInterval(u32),
+    /// Wake packet payload, compared against received data after applying
+    /// [Nl80211WowlanTcp::WakeMask]
+    WakePayload(Vec<u8>),
+    /// Wake packet mask, one bit per byte of
+    /// [Nl80211WowlanTcp::WakePayload]
+    WakeMask(Vec<u8>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_nla"))]
+    Other(DefaultNla),
+}
+
+impl Nla for Nl80211WowlanTcp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SrcIpv4(_) | Self::DstIpv4(_) | Self::DataInterval(_) => 4,
+            Self::DstMac(_) => ETH_ALEN,
+            Self::SrcPort(_) | Self::DstPort(_) => 2,
+            Self::DataPayload(v) | Self::WakePayload(v) | Self::WakeMask(v) => {
+                v.len()
+            }
+            Self::DataPayloadSeq(v) => v.buffer_len(),
+            Self::DataPayloadToken(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SrcIpv4(_) => NL80211_WOWLAN_TCP_SRC_IPV4,
+            Self::DstIpv4(_) => NL80211_WOWLAN_TCP_DST_IPV4,
+            Self::DstMac(_) => NL80211_WOWLAN_TCP_DST_MAC,
+            Self::SrcPort(_) => NL80211_WOWLAN_TCP_SRC_PORT,
+            Self::DstPort(_) => NL80211_WOWLAN_TCP_DST_PORT,
+            Self::DataPayload(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD,
+            Self::DataPayloadSeq(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ,
+            Self::DataPayloadToken(_) => NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN,
+            Self::DataInterval(_) => NL80211_WOWLAN_TCP_DATA_INTERVAL,
+            Self::WakePayload(_) => NL80211_WOWLAN_TCP_WAKE_PAYLOAD,
+            Self::WakeMask(_) => NL80211_WOWLAN_TCP_WAKE_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            // The kernel expects IPv4 addresses in network byte order
+            Self::SrcIpv4(v) | Self::DstIpv4(v) => {
+                buffer[..4].copy_from_slice(&v.octets())
+            }
+            Self::DstMac(v) => buffer[..ETH_ALEN].copy_from_slice(v),
+            Self::SrcPort(d) | Self::DstPort(d) => write_u16(buffer, *d),
+            Self::DataPayload(v) | Self::WakePayload(v) | Self::WakeMask(v) => {
+                buffer[..v.len()].copy_from_slice(v)
+            }
+            Self::DataPayloadSeq(v) => v.emit(buffer),
+            Self::DataPayloadToken(v) => v.emit(buffer),
+            Self::DataInterval(d) => write_u32(buffer, *d),
+            Self::Other(attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Nl80211WowlanTcp
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL80211_WOWLAN_TCP_SRC_IPV4 => {
+                Self::SrcIpv4(parse_ipv4(payload).context(format!(
+                    "Invalid NL80211_WOWLAN_TCP_SRC_IPV4 {payload:?}"
+                ))?)
+            }
+            NL80211_WOWLAN_TCP_DST_IPV4 => {
+                Self::DstIpv4(parse_ipv4(payload).context(format!(
+                    "Invalid NL80211_WOWLAN_TCP_DST_IPV4 {payload:?}"
+                ))?)
+            }
+            NL80211_WOWLAN_TCP_DST_MAC => {
+                Self::DstMac(if payload.len() == ETH_ALEN {
+                    let mut ret = [0u8; ETH_ALEN];
+                    ret.copy_from_slice(&payload[..ETH_ALEN]);
+                    ret
+                } else {
+                    return Err(format!(
+                        "Invalid length of NL80211_WOWLAN_TCP_DST_MAC, \
+                        expected length {} got {:?}",
+                        ETH_ALEN, payload
+                    )
+                    .into());
+                })
+            }
+            NL80211_WOWLAN_TCP_SRC_PORT => {
+                Self::SrcPort(parse_u16(payload).context(format!(
+                    "Invalid NL80211_WOWLAN_TCP_SRC_PORT {payload:?}"
+                ))?)
+            }
+            NL80211_WOWLAN_TCP_DST_PORT => {
+                Self::DstPort(parse_u16(payload).context(format!(
+                    "Invalid NL80211_WOWLAN_TCP_DST_PORT {payload:?}"
+                ))?)
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD => {
+                Self::DataPayload(payload.to_vec())
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ => {
+                Self::DataPayloadSeq(Nl80211WowlanTcpDataSeq::parse(payload)?)
+            }
+            NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN => Self::DataPayloadToken(
+                Nl80211WowlanTcpDataToken::parse(payload)?,
+            ),
+            NL80211_WOWLAN_TCP_DATA_INTERVAL => {
+                Self::DataInterval(parse_u32(payload).context(format!(
+                    "Invalid NL80211_WOWLAN_TCP_DATA_INTERVAL {payload:?}"
+                ))?)
+            }
+            NL80211_WOWLAN_TCP_WAKE_PAYLOAD => {
+                Self::WakePayload(payload.to_vec())
+            }
+            NL80211_WOWLAN_TCP_WAKE_MASK => Self::WakeMask(payload.to_vec()),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+fn parse_ipv4(payload: &[u8]) -> Result<Ipv4Addr, DecodeError> {
+    if payload.len() != 4 {
+        return Err(format!(
+            "Invalid IPv4 address, expecting length 4 but got {}",
+            payload.len()
+        )
+        .into());
+    }
+    Ok(Ipv4Addr::new(
+        payload[0], payload[1], payload[2], payload[3],
+    ))
+}
+
+/// Sequence number inserted into the WoWLAN TCP data payload
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211WowlanTcpDataSeq {
+    /// Starting sequence number
+    pub start: u32,
+    /// Offset of the sequence number in the data payload
+    pub offset: u32,
+    /// Length of the sequence number in bytes, valid values are 1, 2 or 4
+    pub len: u32,
+}
+
+impl Nl80211WowlanTcpDataSeq {
+    const LENGTH: usize = 12;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::LENGTH {
+            Err(format!(
+                "Invalid NL80211_WOWLAN_TCP_DATA_PAYLOAD_SEQ, \
+                expecting length {} but got {}: {payload:?}",
+                Self::LENGTH,
+                payload.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                start: parse_u32(&payload[..4])?,
+                offset: parse_u32(&payload[4..8])?,
+                len: parse_u32(&payload[8..12])?,
+            })
+        }
+    }
+}
+
+impl Emitable for Nl80211WowlanTcpDataSeq {
+    fn buffer_len(&self) -> usize {
+        Self::LENGTH
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        write_u32(&mut buffer[0..4], self.start);
+        write_u32(&mut buffer[4..8], self.offset);
+        write_u32(&mut buffer[8..12], self.len);
+    }
+}
+
+/// Token stream inserted into the WoWLAN TCP data payload, each packet gets
+/// the next `len` bytes of `token_stream`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nl80211WowlanTcpDataToken {
+    /// Offset of the token in the data payload
+    pub offset: u32,
+    /// Length of each token in bytes
+    pub len: u32,
+    pub token_stream: Vec<u8>,
+}
+
+impl Nl80211WowlanTcpDataToken {
+    const HEADER_LENGTH: usize = 8;
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < Self::HEADER_LENGTH {
+            Err(format!(
+                "Invalid NL80211_WOWLAN_TCP_DATA_PAYLOAD_TOKEN, \
+                expecting length at least {} but got {}: {payload:?}",
+                Self::HEADER_LENGTH,
+                payload.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                offset: parse_u32(&payload[..4])?,
+                len: parse_u32(&payload[4..8])?,
+                token_stream: payload[Self::HEADER_LENGTH..].to_vec(),
+            })
+        }
+    }
+}
+
+impl Emitable for Nl80211WowlanTcpDataToken {
+    fn buffer_len(&self) -> usize {
+        Self::HEADER_LENGTH + self.token_stream.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        write_u32(&mut buffer[0..4], self.offset);
+        write_u32(&mut buffer[4..8], self.len);
+        buffer[Self::HEADER_LENGTH..self.buffer_len()]
+            .copy_from_slice(&self.token_stream);
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+// Dropping the future or reply stream of a request must not break the handle
+// or the connection. These tests do not require any wireless device, when
+// nl80211 is not available every request fails with an error instead, which
+// still proves the handle is not stuck.
+
+#![cfg(feature = "tokio_socket")]
+
+use std::time::Duration;
+
+use futures::{FutureExt, TryStreamExt};
+use wl_nl80211::Nl80211Handle;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// Complete a full interface dump, the result does not matter as long as
+// the handle is responsive.
+async fn dump_interfaces(handle: &Nl80211Handle) {
+    let request = async {
+        let stream = handle.interface().get().execute().await;
+        let _ = stream.try_collect::<Vec<_>>().await;
+    };
+    tokio::time::timeout(TIMEOUT, request)
+        .await
+        .expect("handle is stuck after cancelling request");
+}
+
+#[tokio::test]
+async fn drop_in_flight_request_future() {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    for _ in 0..100 {
+        // Polled once, so the request is sent but the reply is not received
+        let request = handle.wireless_physic().get().execute();
+        drop(request.now_or_never());
+    }
+    dump_interfaces(&handle).await;
+}
+
+#[tokio::test]
+async fn drop_reply_stream_mid_dump() {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    for _ in 0..100 {
+        let stream = handle.wireless_physic().get().execute().await;
+        futures::pin_mut!(stream);
+        // Stop after the first message, leaving the rest of dump unread
+        let _ = stream.try_next().await;
+    }
+    dump_interfaces(&handle).await;
+}
+
+#[tokio::test]
+async fn drop_on_timeout_does_not_affect_clones() {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+    let cloned_handle = handle.clone();
+
+    let _ = tokio::time::timeout(Duration::ZERO, async {
+        let stream = handle.wireless_physic().get().execute().await;
+        let _ = stream.try_collect::<Vec<_>>().await;
+    })
+    .await;
+    dump_interfaces(&cloned_handle).await;
+    dump_interfaces(&handle).await;
+}
+
+#[tokio::test]
+async fn protocol_features_after_cancelled_query() {
+    let (connection, mut handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    drop(handle.protocol_features().now_or_never());
+
+    let first = tokio::time::timeout(TIMEOUT, handle.protocol_features())
+        .await
+        .expect("protocol features query is stuck after cancellation");
+    let second = tokio::time::timeout(TIMEOUT, handle.protocol_features())
+        .await
+        .unwrap();
+    assert_eq!(first.ok(), second.ok());
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::{
+    Nl80211CounterDelta, Nl80211CounterWidth, Nl80211StationCounters,
+    Nl80211StationInfo,
+};
+
+fn counters(infos: &[Nl80211StationInfo]) -> Nl80211StationCounters {
+    Nl80211StationCounters::from_station_info(infos)
+}
+
+#[test]
+fn station_counters_32bit_wrap() {
+    let previous = counters(&[
+        Nl80211StationInfo::AssociationAtBoottime(1000),
+        Nl80211StationInfo::TxBytes(u32::MAX - 9),
+        Nl80211StationInfo::TxPackets(u32::MAX),
+    ]);
+    let current = counters(&[
+        Nl80211StationInfo::AssociationAtBoottime(1000),
+        Nl80211StationInfo::TxBytes(10),
+        Nl80211StationInfo::TxPackets(4),
+    ]);
+    assert_eq!(current.tx_bytes_width, Nl80211CounterWidth::U32);
+
+    let delta = current.delta(&previous).delta().unwrap();
+    assert_eq!(delta.tx_bytes, Some(20));
+    assert_eq!(delta.tx_packets, Some(5));
+}
+
+#[test]
+fn station_counters_64bit_decrease_is_reset() {
+    let previous = counters(&[
+        Nl80211StationInfo::TxBytes(100),
+        Nl80211StationInfo::TxBytes64(100),
+    ]);
+    let current = counters(&[
+        Nl80211StationInfo::TxBytes(10),
+        Nl80211StationInfo::TxBytes64(10),
+    ]);
+    assert_eq!(current.tx_bytes_width, Nl80211CounterWidth::U64);
+    assert_eq!(current.delta(&previous), Nl80211CounterDelta::Reset);
+}
+
+#[test]
+fn station_counters_reassociation_is_reset() {
+    let previous = counters(&[
+        Nl80211StationInfo::AssociationAtBoottime(1000),
+        Nl80211StationInfo::TxPackets(100),
+    ]);
+    let current = counters(&[
+        Nl80211StationInfo::AssociationAtBoottime(2000),
+        Nl80211StationInfo::TxPackets(200),
+    ]);
+    assert!(current.delta(&previous).is_reset());
+}
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Parseable};
+use wl_nl80211::{Nl80211BssInfo, Nl80211Element, Nl80211ElementIter};
+
+// Information elements of beacon: SSID "test", supported rates, DSSS
+// channel 6, TIM and RSN (WPA2-PSK)
+const BEACON_IES: &[u8] = &[
+    0x00, 0x04, b't', b'e', b's', b't', // SSID
+    0x01, 0x04, 0x82, 0x84, 0x8b, 0x96, // supported rates
+    0x03, 0x01, 0x06, // DSSS
+    0x05, 0x04, 0x00, 0x01, 0x00, 0x00, // TIM
+    0x30, 0x14, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f,
+    0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, 0x0c, 0x00, // RSN
+];
+
+// Start offset of each element in `BEACON_IES`
+const BEACON_IE_OFFSETS: &[usize] = &[0, 6, 12, 15, 21];
+
+#[test]
+fn element_iter_valid_beacon() {
+    let elements: Vec<Nl80211Element> = Nl80211ElementIter::new(BEACON_IES)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(elements.len(), BEACON_IE_OFFSETS.len());
+}
+
+#[test]
+fn element_iter_empty_buffer() {
+    assert_eq!(Nl80211ElementIter::new(&[]).count(), 0);
+}
+
+// Cut the beacon at every possible position, the complete elements should
+// decode and the truncated one should be the last yielding error.
+#[test]
+fn element_iter_truncated() {
+    let full: Vec<Nl80211Element> = Nl80211ElementIter::new(BEACON_IES)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    for len in 0..BEACON_IES.len() {
+        let results: Vec<_> =
+            Nl80211ElementIter::new(&BEACON_IES[..len]).collect();
+        let complete = BEACON_IE_OFFSETS[1..]
+            .iter()
+            .chain([&BEACON_IES.len()])
+            .filter(|end| **end <= len)
+            .count();
+        let at_boundary = BEACON_IE_OFFSETS.contains(&len);
+        assert_eq!(
+            results.len(),
+            if at_boundary { complete } else { complete + 1 },
+            "Unexpected element count when truncated at {len}"
+        );
+        for (result, expected) in results.iter().zip(full.iter()).take(complete)
+        {
+            assert_eq!(result.as_ref().unwrap(), expected);
+        }
+        if !at_boundary {
+            assert!(results.last().unwrap().is_err());
+        }
+    }
+}
+
+#[test]
+fn element_iter_header_only() {
+    // Only the element ID
+    let results: Vec<_> = Nl80211ElementIter::new(&[0x00]).collect();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+
+    // Element header without payload
+    let results: Vec<_> = Nl80211ElementIter::new(&[0x30, 0x14]).collect();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
+
+// The declared length exceeds the remaining buffer
+#[test]
+fn element_iter_length_overrun() {
+    let mut buf = BEACON_IES[..6].to_vec();
+    buf.extend_from_slice(&[0xdd, 0xff, 0x00, 0x50, 0xf2]);
+    let results: Vec<_> = Nl80211ElementIter::new(&buf).collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+
+    let results: Vec<_> = Nl80211ElementIter::new(&[0xff; 64]).collect();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
+
+#[test]
+fn element_iter_zero_length() {
+    let buf = [
+        0x00, 0x00, // hidden SSID
+        0x03, 0x00, // DSSS without channel
+        0xdd, 0x00, // vendor specific without OUI
+        0x01, 0x00, // no supported rates
+        0x03, 0x01, 0x0b, // DSSS channel 11
+    ];
+    let results: Vec<_> = Nl80211ElementIter::new(&buf).collect();
+    assert_eq!(results.len(), 5);
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &Nl80211Element::Ssid(Default::default())
+    );
+    assert!(results[1].is_err());
+    assert!(results[4].is_ok());
+    assert_eq!(results[4].as_ref().unwrap(), &Nl80211Element::Channel(11));
+}
+
+// Malformed elements in BSS should not fail the decoding of whole BSS
+#[test]
+#[cfg(target_endian = "little")]
+fn bss_info_with_malformed_elements() {
+    let ies: &[u8] = &[
+        0x00, 0x04, b't', b'e', b's', b't', // SSID
+        0x03, 0x00, // DSSS without channel
+        0x30, 0x10, 0x01, 0x00, // RSN truncated
+    ];
+    let mut raw = vec![(ies.len() + 4) as u8, 0x00, 0x06, 0x00];
+    raw.extend_from_slice(ies);
+    raw.resize((raw.len() + 3) & !3, 0);
+
+    let info = Nl80211BssInfo::parse(&NlaBuffer::new(&raw)).unwrap();
+    let Nl80211BssInfo::InformationElements(elements) = info else {
+        panic!("Expecting InformationElements, got {info:?}");
+    };
+    assert_eq!(elements.len(), 3);
+    assert_eq!(elements[0], Nl80211Element::Ssid("test".into()));
+    assert_eq!(elements[1], Nl80211Element::Other(3, vec![]));
+    assert_eq!(elements[2], Nl80211Element::Other(0x30, vec![0x01, 0x00]));
+}
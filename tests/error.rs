@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::Nl80211Error;
+
+#[test]
+fn request_failed_display_includes_reason() {
+    let e = Nl80211Error::RequestFailed("Frame is not an action frame".into());
+    assert_eq!(
+        e.to_string(),
+        "A netlink request failed: Frame is not an action frame"
+    );
+    assert_eq!(e.errno(), None);
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Parseable};
+use wl_nl80211::{Nl80211BssDescription, Nl80211BssInfo, Nl80211IeFingerprint};
+
+const NL80211_BSS_INFORMATION_ELEMENTS: u16 = 6;
+
+// SSID, supported rates, TIM with specified partial virtual bitmap length,
+// HT capabilities and WPS
+fn ies(ssid: &[u8], tim_bitmap_len: usize) -> Vec<u8> {
+    let mut ret = vec![0, ssid.len() as u8];
+    ret.extend_from_slice(ssid);
+    ret.extend_from_slice(&[1, 4, 0x82, 0x84, 0x96, 0x0c]);
+    ret.extend_from_slice(&[5, 3 + tim_bitmap_len as u8, 0, 1, 0]);
+    ret.extend(std::iter::repeat(0).take(tim_bitmap_len));
+    ret.extend_from_slice(&[45, 26]);
+    ret.extend(std::iter::repeat(0).take(26));
+    ret.extend_from_slice(&[221, 5, 0x00, 0x50, 0xf2, 0x04, 0x10]);
+    ret
+}
+
+#[test]
+fn fingerprint_signature() {
+    let fingerprint = Nl80211IeFingerprint::from_ies(&ies(b"home", 1));
+    assert_eq!(fingerprint.as_str(), "0,1:4,5,45:26,221.0050f2-04:5");
+}
+
+#[test]
+fn fingerprint_ignores_ssid_and_tim_length() {
+    let fingerprint = Nl80211IeFingerprint::from_ies(&ies(b"home", 1));
+    for (ssid, tim_bitmap_len) in
+        [(&b"office-network"[..], 1), (b"home", 4), (b"", 251)]
+    {
+        let other = Nl80211IeFingerprint::from_ies(&ies(ssid, tim_bitmap_len));
+        assert_eq!(other, fingerprint);
+        assert_eq!(other.hash64(), fingerprint.hash64());
+    }
+}
+
+#[test]
+fn fingerprint_truncated_element() {
+    let fingerprint = Nl80211IeFingerprint::from_ies(&[1, 4, 0x82, 45, 26]);
+    assert_eq!(fingerprint.as_str(), "1!");
+}
+
+#[test]
+fn fingerprint_of_bss_description() {
+    let ies = ies(b"home", 1);
+    let mut raw = Vec::new();
+    raw.extend_from_slice(&(4 + ies.len() as u16).to_ne_bytes());
+    raw.extend_from_slice(&NL80211_BSS_INFORMATION_ELEMENTS.to_ne_bytes());
+    raw.extend_from_slice(&ies);
+    raw.resize((raw.len() + 3) & !3, 0);
+    let info = Nl80211BssInfo::parse(&NlaBuffer::new(&raw)).unwrap();
+
+    let desc = Nl80211BssDescription::from(&vec![info]);
+    assert_eq!(
+        desc.raw_information_elements.as_deref(),
+        Some(ies.as_slice())
+    );
+    assert_eq!(
+        desc.ie_fingerprint(),
+        Some(Nl80211IeFingerprint::from_ies(&ies))
+    );
+}
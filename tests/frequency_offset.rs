@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::{
+    Nl80211Attr, Nl80211ChannelSwitch, Nl80211ChannelWidth, Nl80211Frame,
+    Nl80211Freq, Nl80211IfIndex,
+};
+
+// S1G channel 1 of US operating class 68
+const S1G_FREQ: Nl80211Freq = Nl80211Freq::from_khz(902_500);
+
+#[test]
+fn builder_emits_frequency_offset() {
+    let attrs = Nl80211Frame::new(Nl80211IfIndex::new(3), vec![0xd0, 0x00])
+        .frequency(S1G_FREQ)
+        .build();
+    assert!(attrs.contains(&Nl80211Attr::WiphyFreq(S1G_FREQ)));
+    assert!(attrs.contains(&Nl80211Attr::WiphyFreqOffset(500)));
+
+    // Integral MHz frequency should not carry the offset attribute
+    let attrs = Nl80211Frame::new(Nl80211IfIndex::new(3), vec![0xd0, 0x00])
+        .frequency(S1G_FREQ)
+        .frequency(2412)
+        .build();
+    assert!(
+        attrs.contains(&Nl80211Attr::WiphyFreq(Nl80211Freq::from_mhz(2412)))
+    );
+    assert!(!attrs
+        .iter()
+        .any(|a| matches!(a, Nl80211Attr::WiphyFreqOffset(_))));
+}
+
+#[test]
+fn builder_emits_center_freq1_offset() {
+    let attrs = Nl80211ChannelSwitch::new(Nl80211IfIndex::new(3), S1G_FREQ, 5)
+        .channel_width(Nl80211ChannelWidth::Mhz(1), S1G_FREQ)
+        .build();
+    assert!(attrs.contains(&Nl80211Attr::WiphyFreqOffset(500)));
+    assert!(attrs.contains(&Nl80211Attr::CenterFreq1(S1G_FREQ)));
+    assert!(attrs.contains(&Nl80211Attr::CenterFreq1Offset(500)));
+}
+
+#[test]
+#[cfg(feature = "events")]
+fn remain_on_channel_event_with_frequency_offset() {
+    use wl_nl80211::{
+        Nl80211Command, Nl80211Message, Nl80211RemainOnChannelEvent,
+    };
+
+    let msg = Nl80211Message {
+        cmd: Nl80211Command::RemainOnChannel,
+        attributes: vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::WiphyFreq(Nl80211Freq::from_mhz(902)),
+            Nl80211Attr::WiphyFreqOffset(500),
+            Nl80211Attr::Cookie(1),
+            Nl80211Attr::Duration(100),
+        ],
+    };
+    let event = Nl80211RemainOnChannelEvent::from_message(&msg).unwrap();
+    assert_eq!(event.frequency, Some(S1G_FREQ));
+}
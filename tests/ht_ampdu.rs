@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::Nl80211BandInfo;
+
+#[test]
+fn ht_ampdu_max_len() {
+    let expected = [(0, 8191), (1, 16383), (2, 32767), (3, 65535)];
+    for (factor, len) in expected {
+        assert_eq!(
+            Nl80211BandInfo::HtAmpduFactor(factor).ht_ampdu_max_len(),
+            Some(len)
+        );
+    }
+    // Reserved by IEEE 802.11, the field is only 2 bits
+    for factor in [4, 5, 19, u8::MAX] {
+        assert_eq!(
+            Nl80211BandInfo::HtAmpduFactor(factor).ht_ampdu_max_len(),
+            None
+        );
+    }
+    assert_eq!(Nl80211BandInfo::HtAmpduDensity(3).ht_ampdu_max_len(), None);
+}
+
+#[test]
+fn ht_ampdu_min_spacing_usec() {
+    let info = Nl80211BandInfo::HtAmpduDensity(0);
+    assert_eq!(info.ht_ampdu_min_spacing_usec(), Some(0.0));
+    let info = Nl80211BandInfo::HtAmpduDensity(7);
+    assert_eq!(info.ht_ampdu_min_spacing_usec(), Some(16.0));
+    let info = Nl80211BandInfo::HtAmpduDensity(8);
+    assert_eq!(info.ht_ampdu_min_spacing_usec(), None);
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+// Scan on the virtual radio of `mac80211_hwsim`, skipped when the module is
+// not loaded, e.g. `modprobe mac80211_hwsim radios=1`. Triggering scan
+// requires `CAP_NET_ADMIN`.
+
+#![cfg(all(feature = "events", feature = "tokio_socket"))]
+
+use std::path::Path;
+use std::time::Duration;
+
+use futures::{StreamExt, TryStreamExt};
+use wl_nl80211::{
+    Nl80211Attr, Nl80211InterfaceType, Nl80211Scan, Nl80211ScanEvent,
+    Nl80211WiphyIndex,
+};
+
+const SCAN_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Index of the first wiphy created by mac80211_hwsim
+fn hwsim_wiphy_index() -> Option<Nl80211WiphyIndex> {
+    if !Path::new("/sys/module/mac80211_hwsim").exists() {
+        return None;
+    }
+    let mut phys: Vec<_> = std::fs::read_dir("/sys/class/ieee80211")
+        .ok()?
+        .filter_map(|entry| entry.ok()?.path().canonicalize().ok())
+        .filter(|path| path.to_string_lossy().contains("mac80211_hwsim"))
+        .collect();
+    phys.sort();
+    let index = std::fs::read_to_string(phys.first()?.join("index")).ok()?;
+    Some(Nl80211WiphyIndex::new(index.trim().parse().ok()?))
+}
+
+#[tokio::test]
+async fn hwsim_scan_events() {
+    let Some(wiphy) = hwsim_wiphy_index() else {
+        eprintln!("Skipping: mac80211_hwsim is not loaded");
+        return;
+    };
+
+    let (mut connection, handle, messages) =
+        wl_nl80211::new_connection().unwrap();
+    wl_nl80211::subscribe_scan(&mut connection).await.unwrap();
+    tokio::spawn(connection);
+    let events = handle.scan().events(messages).await.unwrap();
+
+    let mut iface = None;
+    let mut dump = handle.interface().get().execute().await;
+    while let Some(msg) = dump.try_next().await.unwrap() {
+        let msg = msg.payload;
+        let is_station = msg.attributes.iter().any(|attr| {
+            matches!(attr, Nl80211Attr::IfType(Nl80211InterfaceType::Station))
+        });
+        let name = msg.attributes.iter().find_map(|attr| match attr {
+            Nl80211Attr::IfName(name) => Some(name.clone()),
+            _ => None,
+        });
+        if msg.wiphy_index() == Some(wiphy) && is_station {
+            iface = msg.if_index().zip(name);
+            break;
+        }
+    }
+    let Some((if_index, if_name)) = iface else {
+        eprintln!("Skipping: no station interface on hwsim wiphy {wiphy}");
+        return;
+    };
+    // Scan requires the interface to be up
+    let _ = std::process::Command::new("ip")
+        .args(["link", "set", "dev", &if_name, "up"])
+        .status();
+
+    let attrs = Nl80211Scan::new(if_index).build();
+    if let Err(e) = handle
+        .scan()
+        .trigger(attrs)
+        .execute()
+        .await
+        .try_collect::<Vec<_>>()
+        .await
+    {
+        eprintln!("Skipping: failed to trigger scan on {if_name}: {e}");
+        return;
+    }
+
+    let is_done = |event: &Nl80211ScanEvent| {
+        matches!(
+            event,
+            Nl80211ScanEvent::NewResults(_) | Nl80211ScanEvent::Aborted(_)
+        ) && event.info().if_index == Some(if_index)
+    };
+    let event = tokio::time::timeout(
+        SCAN_TIMEOUT,
+        events
+            .filter(|event| futures::future::ready(is_done(event)))
+            .next(),
+    )
+    .await
+    .expect("No scan completion event received")
+    .unwrap();
+    assert!(matches!(event, Nl80211ScanEvent::NewResults(_)));
+    assert_eq!(event.info().wiphy, Some(wiphy));
+}
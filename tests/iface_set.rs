@@ -0,0 +1,334 @@
+// SPDX-License-Identifier: MIT
+
+// Requests of `Nl80211InterfaceHandle::set()` are checked against a fake
+// netlink socket which acknowledges everything and records the nl80211
+// messages sent, so no wireless device is required.
+
+#![cfg(feature = "tokio_socket")]
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+
+use netlink_packet_generic::GenlHeader;
+use netlink_packet_utils::ParseableParametrized;
+use netlink_sys::{AsyncSocket, Socket, SocketAddr};
+use wl_nl80211::{
+    Nl80211Attr, Nl80211Command, Nl80211Error, Nl80211InterfaceType,
+    Nl80211Message, Nl80211MonitorFlag,
+};
+
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_NEWFAMILY: u8 = 1;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const NLMSG_ERROR: u16 = 2;
+const FAKE_NL80211_FAMILY_ID: u16 = 0x30;
+
+const NL_HDR_LEN: usize = 16;
+const GENL_HDR_LEN: usize = 4;
+
+thread_local! {
+    // The connection is spawned on the local task set of the test thread
+    static SENT: RefCell<Vec<Nl80211Message>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+struct FakeSocket {
+    socket: Socket,
+    replies: Mutex<VecDeque<Vec<u8>>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl FakeSocket {
+    fn reply(&self, request: &[u8]) {
+        let msg_type = u16::from_ne_bytes([request[4], request[5]]);
+        let seq = &request[8..12];
+
+        let mut reply = Vec::new();
+        if msg_type == GENL_ID_CTRL {
+            // CTRL_CMD_GETFAMILY of nl80211
+            reply.extend_from_slice(&28u32.to_ne_bytes());
+            reply.extend_from_slice(&GENL_ID_CTRL.to_ne_bytes());
+            reply.extend_from_slice(&0u16.to_ne_bytes());
+            reply.extend_from_slice(seq);
+            reply.extend_from_slice(&0u32.to_ne_bytes());
+            reply.extend_from_slice(&[CTRL_CMD_NEWFAMILY, 2, 0, 0]);
+            reply.extend_from_slice(&6u16.to_ne_bytes());
+            reply.extend_from_slice(&CTRL_ATTR_FAMILY_ID.to_ne_bytes());
+            reply.extend_from_slice(&FAKE_NL80211_FAMILY_ID.to_ne_bytes());
+            reply.extend_from_slice(&[0, 0]);
+        } else {
+            let len = u32::from_ne_bytes(request[..4].try_into().unwrap());
+            let header = GenlHeader {
+                cmd: request[NL_HDR_LEN],
+                version: request[NL_HDR_LEN + 1],
+            };
+            let payload = &request[NL_HDR_LEN + GENL_HDR_LEN..len as usize];
+            let msg = Nl80211Message::parse_with_param(payload, header)
+                .expect("failed to parse nl80211 request");
+            SENT.with(|sent| sent.borrow_mut().push(msg));
+
+            // ACK carrying the header of request
+            reply.extend_from_slice(&36u32.to_ne_bytes());
+            reply.extend_from_slice(&NLMSG_ERROR.to_ne_bytes());
+            reply.extend_from_slice(&0u16.to_ne_bytes());
+            reply.extend_from_slice(seq);
+            reply.extend_from_slice(&0u32.to_ne_bytes());
+            reply.extend_from_slice(&0i32.to_ne_bytes());
+            reply.extend_from_slice(&request[..NL_HDR_LEN]);
+        }
+        self.replies.lock().unwrap().push_back(reply);
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    fn poll_reply(&self, cx: &mut Context<'_>) -> Poll<Vec<u8>> {
+        match self.replies.lock().unwrap().pop_front() {
+            Some(reply) => Poll::Ready(reply),
+            None => {
+                *self.waker.lock().unwrap() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl AsyncSocket for FakeSocket {
+    fn socket_ref(&self) -> &Socket {
+        &self.socket
+    }
+
+    fn socket_mut(&mut self) -> &mut Socket {
+        &mut self.socket
+    }
+
+    fn new(protocol: isize) -> io::Result<Self> {
+        Ok(Self {
+            socket: Socket::new(protocol)?,
+            replies: Mutex::new(VecDeque::new()),
+            waker: Mutex::new(None),
+        })
+    }
+
+    fn poll_send(
+        &self,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.reply(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_send_to(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        _addr: &SocketAddr,
+    ) -> Poll<io::Result<usize>> {
+        self.poll_send(cx, buf)
+    }
+
+    fn poll_recv<B>(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<()>>
+    where
+        B: bytes::BufMut,
+    {
+        self.poll_reply(cx).map(|reply| {
+            buf.put_slice(&reply);
+            Ok(())
+        })
+    }
+
+    fn poll_recv_from<B>(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<SocketAddr>>
+    where
+        B: bytes::BufMut,
+    {
+        self.poll_reply(cx).map(|reply| {
+            buf.put_slice(&reply);
+            Ok(SocketAddr::new(0, 0))
+        })
+    }
+
+    fn poll_recv_from_full(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<(Vec<u8>, SocketAddr)>> {
+        self.poll_reply(cx)
+            .map(|reply| Ok((reply, SocketAddr::new(0, 0))))
+    }
+}
+
+// Send the set request, return its result and the nl80211 messages sent
+async fn set_interface(
+    attributes: Vec<Nl80211Attr>,
+) -> (Result<(), Nl80211Error>, Vec<Nl80211Message>) {
+    let (connection, handle, _) =
+        wl_nl80211::new_connection_with_socket::<FakeSocket>().unwrap();
+    let connection = tokio::task::spawn_local(connection);
+    SENT.with(|sent| sent.borrow_mut().clear());
+
+    let result = handle.interface().set(attributes).execute().await;
+
+    connection.abort();
+    (result, SENT.with(|sent| sent.take()))
+}
+
+fn assert_rejected(attributes: Vec<Nl80211Attr>) {
+    run(async {
+        let (result, sent) = set_interface(attributes).await;
+        assert!(matches!(result, Err(Nl80211Error::RequestFailed(_))));
+        assert!(sent.is_empty());
+    })
+}
+
+fn assert_sent(attributes: Vec<Nl80211Attr>, expected: Vec<Nl80211Message>) {
+    run(async {
+        let (result, sent) = set_interface(attributes).await;
+        result.unwrap();
+        assert_eq!(sent, expected);
+    })
+}
+
+fn run<F: std::future::Future<Output = ()>>(future: F) {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, future);
+}
+
+fn set_msg(attributes: Vec<Nl80211Attr>) -> Nl80211Message {
+    Nl80211Message {
+        cmd: Nl80211Command::SetInterface,
+        attributes,
+    }
+}
+
+const PEER: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+#[test]
+fn reject_monitor_flags_on_station() {
+    assert_rejected(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::IfType(Nl80211InterfaceType::Station),
+        Nl80211Attr::MonitorFlags(vec![Nl80211MonitorFlag::Control]),
+    ]);
+}
+
+#[test]
+fn reject_use_4addr_on_ap() {
+    assert_rejected(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::IfType(Nl80211InterfaceType::Ap),
+        Nl80211Attr::Use4Addr(true),
+    ]);
+}
+
+#[test]
+fn reject_wds_peer_on_non_wds() {
+    assert_rejected(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::IfType(Nl80211InterfaceType::Station),
+        Nl80211Attr::Mac(PEER),
+    ]);
+}
+
+#[test]
+fn reject_mesh_id_on_monitor() {
+    assert_rejected(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::IfType(Nl80211InterfaceType::Monitor),
+        Nl80211Attr::MeshId(b"mesh".to_vec()),
+    ]);
+}
+
+#[test]
+fn reject_wds_peer_without_interface_index() {
+    assert_rejected(vec![Nl80211Attr::Mac(PEER)]);
+}
+
+#[test]
+fn accept_matching_interface_type() {
+    for attributes in [
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::Monitor),
+            Nl80211Attr::MonitorFlags(vec![Nl80211MonitorFlag::Control]),
+        ],
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::MeshPoint),
+            Nl80211Attr::MeshId(b"mesh".to_vec()),
+        ],
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::Station),
+            Nl80211Attr::Use4Addr(true),
+        ],
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::ApVlan),
+            Nl80211Attr::Use4Addr(true),
+        ],
+        // Disabling 4-address mode is allowed on any interface type
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::Ap),
+            Nl80211Attr::Use4Addr(false),
+        ],
+        // Without interface type, the kernel checks the current one
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::MonitorFlags(vec![Nl80211MonitorFlag::Control]),
+        ],
+    ] {
+        assert_sent(attributes.clone(), vec![set_msg(attributes)]);
+    }
+}
+
+#[test]
+fn split_wds_peer_from_interface_type() {
+    assert_sent(
+        vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::IfType(Nl80211InterfaceType::Wds),
+            Nl80211Attr::Mac(PEER),
+        ],
+        vec![
+            set_msg(vec![
+                Nl80211Attr::IfIndex(3),
+                Nl80211Attr::IfType(Nl80211InterfaceType::Wds),
+            ]),
+            Nl80211Message {
+                cmd: Nl80211Command::SetWdsPeer,
+                attributes: vec![
+                    Nl80211Attr::IfIndex(3),
+                    Nl80211Attr::Mac(PEER),
+                ],
+            },
+        ],
+    );
+}
+
+#[test]
+fn wds_peer_only() {
+    assert_sent(
+        vec![Nl80211Attr::IfIndex(3), Nl80211Attr::Mac(PEER)],
+        vec![Nl80211Message {
+            cmd: Nl80211Command::SetWdsPeer,
+            attributes: vec![Nl80211Attr::IfIndex(3), Nl80211Attr::Mac(PEER)],
+        }],
+    );
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_generic::GenlHeader;
+use netlink_packet_utils::ParseableParametrized;
+use wl_nl80211::{Nl80211Attr, Nl80211Command, Nl80211Message};
+
+const NL80211_CMD_NEW_INTERFACE: u8 = 7;
+
+#[test]
+#[cfg(target_endian = "little")]
+fn parse_malformed_attribute() {
+    let raw: [u8; 16] = [
+        0x08, 0x00, 0x01, 0x00, // NL80211_ATTR_WIPHY
+        0x01, 0x00, 0x00, 0x00, //
+        0x06, 0x00, 0x03, 0x00, // NL80211_ATTR_IFINDEX with 2 bytes only
+        0x05, 0x00, 0x00, 0x00,
+    ];
+    let header = GenlHeader {
+        cmd: NL80211_CMD_NEW_INTERFACE,
+        version: 1,
+    };
+
+    assert!(Nl80211Message::parse_with_param(&raw[..], header).is_err());
+
+    let msg = Nl80211Message::parse_lenient(&raw, header).unwrap();
+    assert_eq!(msg.cmd, Nl80211Command::NewInterface);
+    assert_eq!(msg.attributes[0], Nl80211Attr::Wiphy(1));
+    assert!(matches!(
+        &msg.attributes[1],
+        Nl80211Attr::MalformedNested(3, payload, _) if payload == &[5, 0]
+    ));
+}
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+
+// Guard against misspelled public names sneaking back. Names marked as
+// `#[deprecated]` are the compatibility aliases of old misspelled names and
+// are ignored.
+
+use std::path::{Path, PathBuf};
+
+const MISSPELLED_WORDS: &[&str] = &[
+    "adapttion",
+    "capbilities",
+    "dyanmic",
+    "extentsion",
+    "frequncies",
+    "regins",
+    "suit",
+    "suits",
+    "tracffic",
+    "triger",
+    "trigers",
+];
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "fn", "struct", "enum", "type", "const", "trait", "mod", "static",
+];
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn ident_prefix(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+fn words(ident: &str) -> Vec<String> {
+    let mut ret = Vec::new();
+    for part in ident.split('_') {
+        let mut word = String::new();
+        let mut prev_upper = false;
+        for c in part.chars() {
+            if c.is_ascii_uppercase() && !prev_upper && !word.is_empty() {
+                ret.push(std::mem::take(&mut word));
+            }
+            prev_upper = c.is_ascii_uppercase();
+            word.push(c.to_ascii_lowercase());
+        }
+        if !word.is_empty() {
+            ret.push(word);
+        }
+    }
+    ret
+}
+
+// Return the identifiers declared by public items, struct fields and enum
+// variants in specified source code.
+fn declared_names(code: &str) -> Vec<(usize, String)> {
+    let mut ret = Vec::new();
+    let mut deprecated = false;
+    let mut in_attr = false;
+    // Brace depth relative to the body of current enum
+    let mut enum_depth: Option<i32> = None;
+
+    for (line_no, line) in code.lines().enumerate() {
+        let line = line.trim();
+        if in_attr {
+            in_attr = !line.ends_with(")]");
+            continue;
+        }
+        if line.starts_with("#[deprecated") {
+            deprecated = true;
+            in_attr = !line.ends_with(']');
+            continue;
+        }
+        if line.is_empty() || line.starts_with("//") || line.starts_with('#') {
+            continue;
+        }
+
+        let mut name = None;
+        if let Some(depth) = enum_depth.as_mut() {
+            if *depth == 1 && line.starts_with(|c: char| c.is_ascii_uppercase())
+            {
+                name = Some(ident_prefix(line));
+            }
+            *depth += line.matches('{').count() as i32;
+            *depth -= line.matches('}').count() as i32;
+            if *depth <= 0 {
+                enum_depth = None;
+            }
+        } else if let Some(rest) = line
+            .strip_prefix("pub(crate) ")
+            .or_else(|| line.strip_prefix("pub "))
+        {
+            let rest = rest.trim_start_matches("async ");
+            let rest = rest.trim_start_matches("const fn ");
+            let (keyword, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+            if ITEM_KEYWORDS.contains(&keyword) {
+                name = Some(ident_prefix(rest));
+                if keyword == "enum" && line.ends_with('{') {
+                    enum_depth = Some(1);
+                }
+            } else if rest.is_empty() || keyword.ends_with(':') {
+                name = Some(ident_prefix(keyword));
+            }
+        }
+        if let Some(name) = name {
+            if !deprecated && !name.is_empty() {
+                ret.push((line_no + 1, name.to_string()));
+            }
+        }
+        deprecated = false;
+    }
+    ret
+}
+
+#[test]
+fn declared_names_are_found() {
+    let code = "pub struct FooBar {\n    pub some_field: u8,\n}\n\
+                pub enum Kind {\n    First(u8),\n    Second { a: u8 },\n    \
+                Third,\n}\n\
+                #[deprecated(\n    since = \"0.3.0\",\n    note = \"x\"\n)]\n\
+                pub fn old_name() {}\n\
+                pub(crate) async fn new_name() {}\n";
+    let names: Vec<String> =
+        declared_names(code).into_iter().map(|(_, n)| n).collect();
+    assert_eq!(
+        names,
+        vec![
+            "FooBar",
+            "some_field",
+            "Kind",
+            "First",
+            "Second",
+            "Third",
+            "new_name",
+        ]
+    );
+    assert_eq!(
+        words("Nl80211CipherSuit_akm_suits_TRIGER"),
+        vec!["nl80211", "cipher", "suit", "akm", "suits", "triger"]
+    );
+}
+
+#[test]
+fn public_names_are_spelled_correctly() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files = Vec::new();
+    rust_files(&src, &mut files);
+    files.sort();
+
+    let mut errors = Vec::new();
+    for file in files {
+        let code = std::fs::read_to_string(&file).unwrap();
+        for (line_no, name) in declared_names(&code) {
+            for word in words(&name) {
+                if MISSPELLED_WORDS.contains(&word.as_str()) {
+                    errors.push(format!(
+                        "{}:{line_no}: `{name}` contains misspelled `{word}`",
+                        file.display()
+                    ));
+                }
+            }
+        }
+    }
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
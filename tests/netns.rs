@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+#![cfg(feature = "tokio_socket")]
+
+use std::os::fd::{AsFd, OwnedFd};
+
+use wl_nl80211::Nl80211WiphyIndex;
+
+// The request borrows the file descriptor which should outlive it
+#[tokio::test]
+async fn set_netns_by_fd_borrows_fd() {
+    let (connection, handle, _) = wl_nl80211::new_connection().unwrap();
+    tokio::spawn(connection);
+    let mut wiphy_handle = handle.wireless_physic();
+    let file = std::fs::File::open("/proc/self/ns/net").unwrap();
+    let wiphy = Nl80211WiphyIndex::new(0);
+
+    let _request = wiphy_handle.set_netns_by_fd(wiphy, &file);
+    let _request = wiphy_handle.set_netns_by_fd(wiphy, &file.as_fd());
+
+    let fd = OwnedFd::from(file);
+    let _request = wiphy_handle.set_netns_by_fd(wiphy, &fd);
+}
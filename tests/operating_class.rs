@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::{
+    channel_def_to_operating_class, Nl80211ChannelDef, Nl80211ChannelWidth,
+    Nl80211Freq,
+};
+
+fn chandef(
+    control: u32,
+    width: Nl80211ChannelWidth,
+    center: Option<u32>,
+) -> Nl80211ChannelDef {
+    let mut ret = Nl80211ChannelDef::new(Nl80211Freq::from_mhz(control));
+    ret.width = Some(width);
+    ret.center_freq1 = center.map(Nl80211Freq::from_mhz);
+    ret
+}
+
+#[test]
+fn operating_class_of_channel() {
+    use Nl80211ChannelWidth::{Mhz, Mhz80Plus80, NoHt20};
+
+    // (control frequency, width, center frequency, operating class)
+    let cases: &[(u32, Nl80211ChannelWidth, Option<u32>, Option<u8>)] = &[
+        // 2.4 GHz
+        (2412, NoHt20, None, Some(81)),
+        (2437, Mhz(20), Some(2437), Some(81)),
+        (2472, Mhz(20), Some(2472), Some(81)),
+        (2484, NoHt20, None, Some(82)),
+        (2484, Mhz(20), Some(2484), None),
+        (2412, Mhz(40), Some(2422), Some(83)),
+        (2437, Mhz(40), Some(2447), Some(83)),
+        (2437, Mhz(40), Some(2427), Some(84)),
+        (2472, Mhz(40), Some(2462), Some(84)),
+        (2437, Mhz(40), None, None),
+        (2412, Mhz(40), Some(2402), None),
+        (2437, Mhz(80), Some(2457), None),
+        // 5 GHz 20 MHz
+        (5180, Mhz(20), Some(5180), Some(115)),
+        (5260, NoHt20, None, Some(118)),
+        (5500, Mhz(20), Some(5500), Some(121)),
+        (5720, Mhz(20), Some(5720), Some(121)),
+        (5745, Mhz(20), Some(5745), Some(124)),
+        (5825, Mhz(20), Some(5825), Some(125)),
+        (5865, Mhz(20), Some(5865), Some(125)),
+        (5885, Mhz(20), Some(5885), Some(125)),
+        // 5 GHz 40 MHz
+        (5180, Mhz(40), Some(5190), Some(116)),
+        (5200, Mhz(40), Some(5190), Some(117)),
+        (5200, Mhz(40), None, Some(117)),
+        (5180, Mhz(40), Some(5170), None),
+        (5260, Mhz(40), Some(5270), Some(119)),
+        (5320, Mhz(40), Some(5310), Some(120)),
+        (5500, Mhz(40), Some(5510), Some(122)),
+        (5720, Mhz(40), Some(5710), Some(123)),
+        (5745, Mhz(40), Some(5755), Some(126)),
+        (5765, Mhz(40), Some(5755), Some(127)),
+        (5865, Mhz(40), Some(5875), Some(126)),
+        (5885, Mhz(40), Some(5875), Some(127)),
+        // 5 GHz 80 MHz, the control channel could be any of the four
+        (5180, Mhz(80), Some(5210), Some(128)),
+        (5240, Mhz(80), Some(5210), Some(128)),
+        (5500, Mhz(80), Some(5530), Some(128)),
+        (5745, Mhz(80), Some(5775), Some(128)),
+        (5865, Mhz(80), Some(5855), Some(128)),
+        (5885, Mhz(80), Some(5855), Some(128)),
+        (5180, Mhz(80), None, None),
+        (5260, Mhz(80), Some(5210), None),
+        (5180, Mhz(80), Some(5200), None),
+        // 5 GHz 160 MHz
+        (5180, Mhz(160), Some(5250), Some(129)),
+        (5320, Mhz(160), Some(5250), Some(129)),
+        (5500, Mhz(160), Some(5570), Some(129)),
+        (5745, Mhz(160), Some(5815), Some(129)),
+        (5500, Mhz(160), Some(5250), None),
+        // 5 GHz 80+80 MHz
+        (5180, Mhz80Plus80, Some(5210), Some(130)),
+        (5745, Mhz80Plus80, Some(5775), Some(130)),
+        // 6 GHz
+        (5935, Mhz(20), Some(5935), Some(136)),
+        (5955, Mhz(20), Some(5955), Some(131)),
+        (5955, Mhz(40), Some(5965), Some(132)),
+        (5955, Mhz(80), Some(5985), Some(133)),
+        (5955, Mhz(160), Some(6025), Some(134)),
+        (5955, Mhz80Plus80, Some(5985), Some(135)),
+        (5955, Mhz(320), Some(6105), Some(137)),
+        // 60 GHz
+        (58320, NoHt20, None, Some(180)),
+        // Out of band or unsupported width
+        (5180, Mhz(10), Some(5180), None),
+        (3000, NoHt20, None, None),
+    ];
+
+    for (control, width, center, class) in cases {
+        assert_eq!(
+            channel_def_to_operating_class(&chandef(*control, *width, *center)),
+            *class,
+            "control {control} width {width:?} center {center:?}"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::{Nl80211Rate, Nl80211RateInfo, Nl80211RateMode};
+
+fn ht_nss(mcs: u8) -> Option<u8> {
+    let rate = Nl80211Rate::from_rate_info(&[Nl80211RateInfo::Mcs(mcs)]);
+    assert_eq!(rate.mode(), Nl80211RateMode::Ht);
+    assert_eq!(rate.mcs(), Some(mcs));
+    rate.nss()
+}
+
+#[test]
+fn ht_nss_of_equal_modulation() {
+    assert_eq!(ht_nss(0), Some(1));
+    assert_eq!(ht_nss(7), Some(1));
+    assert_eq!(ht_nss(8), Some(2));
+    assert_eq!(ht_nss(23), Some(3));
+    assert_eq!(ht_nss(31), Some(4));
+    assert_eq!(ht_nss(32), Some(1));
+}
+
+#[test]
+fn ht_nss_of_unequal_modulation() {
+    assert_eq!(ht_nss(33), Some(2));
+    assert_eq!(ht_nss(38), Some(2));
+    assert_eq!(ht_nss(39), Some(3));
+    assert_eq!(ht_nss(52), Some(3));
+    assert_eq!(ht_nss(53), Some(4));
+    assert_eq!(ht_nss(76), Some(4));
+    assert_eq!(ht_nss(77), None);
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+use wl_nl80211::{
+    Nl80211AkmSuite, Nl80211CipherSuite, Nl80211Element, Nl80211ElementRsn,
+    Nl80211Pmkid, Nl80211RsnCapabilities,
+};
+
+fn parse_emit_parse(raw: &[u8]) -> Nl80211ElementRsn {
+    let element = Nl80211Element::parse(raw).unwrap();
+    let mut buf = vec![0u8; element.buffer_len()];
+    element.emit(&mut buf);
+    assert_eq!(buf, raw);
+    assert_eq!(Nl80211Element::parse(&buf).unwrap(), element);
+    match element {
+        Nl80211Element::Rsn(rsn) => rsn,
+        e => panic!("Expecting RSN element, got {e:?}"),
+    }
+}
+
+#[test]
+fn rsn_wpa2_psk() {
+    let raw = [
+        0x30, 0x14, 0x01, 0x00, // version 1
+        0x00, 0x0f, 0xac, 0x04, // group cipher CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // pairwise CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, // AKM PSK
+        0x0c, 0x00, // 16 PTKSA replay counters
+    ];
+    let rsn = parse_emit_parse(&raw);
+    assert_eq!(rsn.version, 1);
+    assert_eq!(rsn.group_cipher, Some(Nl80211CipherSuite::Ccmp));
+    assert_eq!(rsn.pairwise_ciphers, vec![Nl80211CipherSuite::Ccmp]);
+    assert_eq!(rsn.akm_suites, vec![Nl80211AkmSuite::Psk]);
+    assert_eq!(
+        rsn.rsn_capabilities,
+        Some(
+            Nl80211RsnCapabilities::PtksaReplayCount2
+                | Nl80211RsnCapabilities::PtksaReplayCount4
+        )
+    );
+    assert!(rsn.pmkids.is_empty());
+    assert_eq!(rsn.group_mgmt_cipher, None);
+}
+
+#[test]
+fn rsn_wpa3_transition() {
+    let raw = [
+        0x30, 0x18, 0x01, 0x00, // version 1
+        0x00, 0x0f, 0xac, 0x04, // group cipher CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // pairwise CCMP
+        0x02, 0x00, 0x00, 0x0f, 0xac, 0x02, // AKM PSK
+        0x00, 0x0f, 0xac, 0x08, // AKM SAE
+        0x80, 0x00, // MFPC
+    ];
+    let rsn = parse_emit_parse(&raw);
+    assert_eq!(
+        rsn.akm_suites,
+        vec![Nl80211AkmSuite::Psk, Nl80211AkmSuite::Sae]
+    );
+    assert_eq!(rsn.rsn_capabilities, Some(Nl80211RsnCapabilities::Mfpc));
+}
+
+#[test]
+fn rsn_wpa3_sae_with_group_mgmt_cipher() {
+    let raw = [
+        0x30, 0x1a, 0x01, 0x00, // version 1
+        0x00, 0x0f, 0xac, 0x04, // group cipher CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // pairwise CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x08, // AKM SAE
+        0xc0, 0x00, // MFPR and MFPC
+        0x00, 0x00, // no PMKID
+        0x00, 0x0f, 0xac, 0x06, // group management cipher BIP-CMAC-128
+    ];
+    let rsn = parse_emit_parse(&raw);
+    assert!(rsn.pmkids.is_empty());
+    assert_eq!(rsn.group_mgmt_cipher, Some(Nl80211CipherSuite::AesCmac));
+}
+
+#[test]
+fn rsn_with_pmkid() {
+    let pmkid = [
+        0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98,
+        0x76, 0x54, 0x32, 0x10,
+    ];
+    let mut raw = vec![
+        0x30, 0x26, 0x01, 0x00, // version 1
+        0x00, 0x0f, 0xac, 0x04, // group cipher CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, // pairwise CCMP
+        0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, // AKM PSK
+        0x00, 0x00, // RSN capabilities
+        0x01, 0x00, // 1 PMKID
+    ];
+    raw.extend_from_slice(&pmkid);
+    let rsn = parse_emit_parse(&raw);
+    assert_eq!(rsn.pmkids, vec![Nl80211Pmkid(pmkid)]);
+}
+
+// Count fields are required for all the lists followed by present fields
+#[test]
+fn rsn_emit_empty_pairwise_list() {
+    let rsn = Nl80211ElementRsn {
+        version: 1,
+        group_cipher: Some(Nl80211CipherSuite::Ccmp),
+        akm_suites: vec![Nl80211AkmSuite::Sae],
+        ..Default::default()
+    };
+    let mut buf = vec![0u8; rsn.buffer_len()];
+    rsn.emit(&mut buf);
+    assert_eq!(
+        buf,
+        [
+            0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x0f, 0xac, 0x08,
+        ]
+    );
+    assert_eq!(Nl80211ElementRsn::parse(&buf).unwrap(), rsn);
+}
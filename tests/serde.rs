@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+#![cfg(feature = "serde")]
+
+use netlink_packet_utils::nla::DefaultNla;
+use wl_nl80211::{
+    Nl80211Attr, Nl80211BandTypes, Nl80211Command, Nl80211Freq, Nl80211Message,
+    Nl80211ProtocolFeatures, Nl80211Ssid,
+};
+
+#[test]
+fn message_json_round_trip() {
+    let msg = Nl80211Message {
+        cmd: Nl80211Command::NewInterface,
+        attributes: vec![
+            Nl80211Attr::IfIndex(3),
+            Nl80211Attr::Ssid(Nl80211Ssid::from(&b"caf\xe9"[..])),
+            Nl80211Attr::WiphyFreq(Nl80211Freq::from_khz(902_500)),
+            Nl80211Attr::Bands(
+                Nl80211BandTypes::Band2GHz | Nl80211BandTypes::Band6GHz,
+            ),
+            Nl80211Attr::ProtocolFeatures(
+                Nl80211ProtocolFeatures::SplitWiphyDump,
+            ),
+            Nl80211Attr::Other(DefaultNla::new(0xfff0, vec![1, 2, 3, 4])),
+        ],
+    };
+
+    let json = serde_json::to_string(&msg).unwrap();
+    let parsed: Nl80211Message = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed, msg);
+}
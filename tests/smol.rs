@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+// Connections on `async-io` based socket driven without tokio. These tests
+// do not require any wireless device, when nl80211 is not available the
+// request fails with an error instead, which still proves the connection
+// is processing requests.
+
+#![cfg(feature = "smol_socket")]
+
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use wl_nl80211::Nl80211Handle;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn dump_interfaces(handle: &Nl80211Handle) {
+    let request = async {
+        let stream = handle.interface().get().execute().await;
+        let _ = stream.try_collect::<Vec<_>>().await;
+    };
+    let timeout = async {
+        async_io::Timer::after(TIMEOUT).await;
+        panic!("no reply from connection within {TIMEOUT:?}");
+    };
+    futures::pin_mut!(request, timeout);
+    futures::future::select(request, timeout).await;
+}
+
+#[test]
+fn smol_connection_request() {
+    let (connection, mut handle, _) =
+        wl_nl80211::new_smol_connection().unwrap();
+    // No executor required, the connection could be driven by any thread
+    std::thread::spawn(move || async_io::block_on(connection));
+
+    async_io::block_on(async {
+        dump_interfaces(&handle).await;
+        let _ = handle.protocol_features().await;
+    });
+}
+
+#[cfg(feature = "async_global_executor")]
+#[test]
+fn async_global_executor_connection_request() {
+    async_global_executor::block_on(async {
+        let (handle, _) = wl_nl80211::new_smol_connection_spawned().unwrap();
+        dump_interfaces(&handle).await;
+        // Requests from another task share the spawned connection
+        let cloned_handle = handle.clone();
+        async_global_executor::spawn(async move {
+            dump_interfaces(&cloned_handle).await;
+        })
+        .await;
+    });
+}
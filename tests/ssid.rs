@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+use wl_nl80211::{Nl80211Attr, Nl80211Element, Nl80211Ssid, Nl80211SsidKind};
+
+// GBK encoded "无线" which is not valid UTF-8
+const GBK_SSID: [u8; 4] = [0xce, 0xde, 0xcf, 0xdf];
+
+#[test]
+#[cfg(target_endian = "little")]
+fn parse_attr_ssid_not_utf8() {
+    let raw: [u8; 8] = [
+        0x08, 0x00, 0x34, 0x00, // NL80211_ATTR_SSID
+        0xce, 0xde, 0xcf, 0xdf,
+    ];
+    let attr = Nl80211Attr::parse(&NlaBuffer::new(&raw)).unwrap();
+    let expected = Nl80211Ssid::from(&GBK_SSID[..]);
+    assert_eq!(attr, Nl80211Attr::Ssid(expected.clone()));
+    assert_eq!(expected.kind(), Nl80211SsidKind::Binary);
+    assert_eq!(expected.escaped(), "\\xce\\xde\\xcf\\xdf");
+
+    let mut buf = vec![0u8; attr.buffer_len()];
+    attr.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn parse_element_ssid_not_utf8() {
+    let raw: [u8; 6] = [0x00, 0x04, 0xce, 0xde, 0xcf, 0xdf];
+    let element = Nl80211Element::parse(&raw[..]).unwrap();
+    assert_eq!(
+        element,
+        Nl80211Element::Ssid(Nl80211Ssid::from(&GBK_SSID[..]))
+    );
+
+    let mut buf = vec![0u8; element.buffer_len()];
+    element.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use wl_nl80211::{
+    Nl80211Attr, Nl80211Command, Nl80211IfIndex, Nl80211Message,
+    Nl80211StationEntry, Nl80211StationInfo,
+};
+
+fn station_message(attributes: Vec<Nl80211Attr>) -> Nl80211Message {
+    Nl80211Message {
+        cmd: Nl80211Command::NewStation,
+        attributes,
+    }
+}
+
+#[test]
+fn station_entry_from_message() {
+    let msg = station_message(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::Mac([0x02, 0, 0, 0, 0, 1]),
+        Nl80211Attr::Generation(7),
+        Nl80211Attr::MloLinkId(1),
+        Nl80211Attr::StationInfo(vec![Nl80211StationInfo::Signal(-42)]),
+    ]);
+    let entry = Nl80211StationEntry::from_message(&msg).unwrap();
+
+    assert_eq!(entry.if_index, Some(Nl80211IfIndex::new(3)));
+    assert_eq!(entry.generation, Some(7));
+    assert!(entry.uses_link(1));
+    assert!(!entry.uses_link(0));
+}
+
+#[test]
+fn station_entry_requires_station_info() {
+    let msg = station_message(vec![
+        Nl80211Attr::IfIndex(3),
+        Nl80211Attr::Mac([0x02, 0, 0, 0, 0, 1]),
+    ]);
+    assert_eq!(Nl80211StationEntry::from_message(&msg), None);
+}
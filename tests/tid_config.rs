@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+use wl_nl80211::{
+    Nl80211Attr, Nl80211TidConfig, Nl80211TidConfigAttribute,
+    Nl80211TidConfigState, Nl80211TidConfigSupport,
+};
+
+// NL80211_ATTR_TID_CONFIG of ath10k wiphy dump captured on x86_64
+#[cfg(target_endian = "little")]
+const ATH10K_WIPHY_TID_CONFIG: [u8; 44] = [
+    0x2c, 0x00, 0x1d, 0x81, // NL80211_ATTR_TID_CONFIG | NLA_F_NESTED
+    0x0c, 0x00, 0x02, 0x00, // NL80211_TID_CONFIG_ATTR_VIF_SUPP
+    0xc0, 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    0x0c, 0x00, 0x03, 0x00, // NL80211_TID_CONFIG_ATTR_PEER_SUPP
+    0xc0, 0x37, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    0x05, 0x00, 0x07, 0x00, // NL80211_TID_CONFIG_ATTR_RETRY_SHORT
+    0x1e, 0x00, 0x00, 0x00, //
+    0x05, 0x00, 0x08, 0x00, // NL80211_TID_CONFIG_ATTR_RETRY_LONG
+    0x1e, 0x00, 0x00, 0x00,
+];
+
+#[test]
+#[cfg(target_endian = "little")]
+fn parse_wiphy_tid_config_support() {
+    let attr =
+        Nl80211Attr::parse(&NlaBuffer::new(&ATH10K_WIPHY_TID_CONFIG)).unwrap();
+    let Nl80211Attr::TidConfigSupport(support) = &attr else {
+        panic!("Expecting Nl80211Attr::TidConfigSupport, got {attr:?}");
+    };
+    assert_eq!(support.vif_support, 0x33c0);
+    assert_eq!(support.peer_support, 0x37c0);
+    assert_eq!(support.max_retry_short, Some(30));
+    assert_eq!(support.max_retry_long, Some(30));
+    assert!(support.vif_supports(&Nl80211TidConfigAttribute::Noack(
+        Nl80211TidConfigState::Enable
+    )));
+    assert!(
+        !support.vif_supports(&Nl80211TidConfigAttribute::RtsctsCtrl(
+            Nl80211TidConfigState::Enable
+        ))
+    );
+    assert!(
+        support.peer_supports(&Nl80211TidConfigAttribute::RtsctsCtrl(
+            Nl80211TidConfigState::Enable
+        ))
+    );
+
+    let mut buf = vec![0u8; attr.buffer_len()];
+    attr.emit(&mut buf);
+    assert_eq!(buf[4..], ATH10K_WIPHY_TID_CONFIG[4..]);
+}
+
+#[test]
+#[cfg(target_endian = "little")]
+fn parse_wiphy_tid_config_support_with_pad() {
+    let support = Nl80211TidConfigSupport::parse(&[
+        0x04, 0x00, 0x01, 0x00, // NL80211_TID_CONFIG_ATTR_PAD
+        0x0c, 0x00, 0x02, 0x00, // NL80211_TID_CONFIG_ATTR_VIF_SUPP
+        0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x0c, 0x00, 0x03, 0x00, // NL80211_TID_CONFIG_ATTR_PEER_SUPP
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ])
+    .unwrap();
+    assert_eq!(support.vif_support, 0x40);
+    assert_eq!(support.max_retry_short, None);
+}
+
+#[test]
+fn set_tid_config_round_trip() {
+    let attr = Nl80211Attr::TidConfig(vec![Nl80211TidConfig::new(1 << 6)
+        .retry_short(3)
+        .ampdu(Nl80211TidConfigState::Disable)]);
+    let mut buf = vec![0u8; attr.buffer_len()];
+    attr.emit(&mut buf);
+    assert_eq!(Nl80211Attr::parse(&NlaBuffer::new(&buf)).unwrap(), attr);
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+use wl_nl80211::{
+    Nl80211StationInfo, Nl80211TidStats, Nl80211TidStatsTotal,
+    Nl80211TransmitQueueStat,
+};
+
+const NL80211_STA_INFO_TID_STATS: u16 = 31;
+const NL80211_TID_STATS_RX_MSDU: u16 = 1;
+const NL80211_TID_STATS_TX_MSDU: u16 = 2;
+const NL80211_TID_STATS_TX_MSDU_RETRIES: u16 = 3;
+const NL80211_TID_STATS_TX_MSDU_FAILED: u16 = 4;
+const NL80211_TID_STATS_TXQ_STATS: u16 = 6;
+const NL80211_TXQ_STATS_FLOWS: u16 = 3;
+
+fn nla(kind: u16, payload: &[u8]) -> Vec<u8> {
+    let mut ret = Vec::new();
+    ret.extend_from_slice(&(4 + payload.len() as u16).to_ne_bytes());
+    ret.extend_from_slice(&kind.to_ne_bytes());
+    ret.extend_from_slice(payload);
+    ret.resize((ret.len() + 3) & !3, 0);
+    ret
+}
+
+// TID 0 and the non-QoS TID 16, nested as attribute kind TID + 1
+fn tid_stats_raw() -> Vec<u8> {
+    let tid0 = [
+        nla(NL80211_TID_STATS_RX_MSDU, &10u64.to_ne_bytes()),
+        nla(NL80211_TID_STATS_TX_MSDU, &20u64.to_ne_bytes()),
+    ]
+    .concat();
+    let non_qos = [
+        nla(NL80211_TID_STATS_TX_MSDU_RETRIES, &3u64.to_ne_bytes()),
+        nla(NL80211_TID_STATS_TX_MSDU_FAILED, &1u64.to_ne_bytes()),
+        nla(
+            NL80211_TID_STATS_TXQ_STATS,
+            &nla(NL80211_TXQ_STATS_FLOWS, &2u32.to_ne_bytes()),
+        ),
+    ]
+    .concat();
+    nla(
+        NL80211_STA_INFO_TID_STATS,
+        &[nla(1, &tid0), nla(17, &non_qos)].concat(),
+    )
+}
+
+#[test]
+fn tid_stats_parse_and_emit() {
+    let raw = tid_stats_raw();
+    let info = Nl80211StationInfo::parse(&NlaBuffer::new(&raw)).unwrap();
+
+    let Nl80211StationInfo::TidStats(tids) = &info else {
+        panic!("Expecting TidStats, got {info:?}");
+    };
+    assert_eq!(tids.len(), 2);
+    assert_eq!(tids[0].tid(), 0);
+    assert_eq!(
+        tids[0].stats(),
+        &[Nl80211TidStats::RxMsdu(10), Nl80211TidStats::TxMsdu(20)]
+    );
+    assert_eq!(tids[1].tid(), 16);
+    assert_eq!(
+        tids[1].stats()[2],
+        Nl80211TidStats::TransmitQueueStats(vec![
+            Nl80211TransmitQueueStat::Flows(2)
+        ])
+    );
+
+    let total = Nl80211TidStatsTotal::from_tid_stats(tids);
+    assert_eq!(total.rx_msdu, 10);
+    assert_eq!(total.tx_msdu, 20);
+    assert_eq!(total.tx_msdu_retries, 3);
+    assert_eq!(total.tx_msdu_failed, 1);
+
+    let mut emitted = vec![0u8; info.buffer_len()];
+    info.emit(&mut emitted);
+    assert_eq!(emitted, raw);
+}